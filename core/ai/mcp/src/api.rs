@@ -17,10 +17,10 @@
  */
 
 use crate::{
-    Permissions,
-    configs::{HttpApiConfig, configure_cors},
+    configs::{HttpApiConfig, ResourcesConfig, configure_cors},
     error::McpRuntimeError,
-    service::IggyService,
+    permissions::Permissions,
+    service::{IggyService, Redactor},
 };
 use axum::{Json, Router, routing::get};
 use axum_server::tls_rustls::RustlsConfig;
@@ -40,13 +40,17 @@ pub async fn init(
     iggy_client: Arc<IggyClient>,
     iggy_consumer: Arc<Consumer>,
     permissions: Permissions,
+    resources: ResourcesConfig,
+    redactor: Redactor,
 ) -> Result<(), McpRuntimeError> {
     let service = StreamableHttpService::new(
         move || {
             Ok(IggyService::new(
                 iggy_client.clone(),
                 iggy_consumer.clone(),
-                permissions,
+                permissions.clone(),
+                resources.clone(),
+                redactor.clone(),
             ))
         },
         LocalSessionManager::default().into(),
@@ -27,6 +27,8 @@ pub struct McpServerConfig {
     pub http: Option<HttpApiConfig>,
     pub iggy: IggyConfig,
     pub permissions: PermissionsConfig,
+    pub redaction: RedactionConfig,
+    pub resources: ResourcesConfig,
     pub transport: McpTransport,
 }
 
@@ -53,6 +55,48 @@ pub struct PermissionsConfig {
     pub read: bool,
     pub update: bool,
     pub delete: bool,
+    #[serde(default)]
+    pub tools: ToolsConfig,
+    #[serde(default)]
+    pub streams: StreamsScopeConfig,
+}
+
+/// The tools available to the MCP clients. When `allowed` is empty, all the tools are allowed.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ToolsConfig {
+    #[serde(default)]
+    pub allowed: Vec<String>,
+    #[serde(default)]
+    pub denied: Vec<String>,
+}
+
+/// The streams and topics available to the MCP clients, each entry is either `stream` or `stream/topic`
+/// (name or ID, `*` matches any). When `allowed` is empty, all the streams are allowed.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct StreamsScopeConfig {
+    #[serde(default)]
+    pub allowed: Vec<String>,
+    #[serde(default)]
+    pub denied: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RedactionConfig {
+    pub enabled: bool,
+    #[serde(default)]
+    pub json_paths: Vec<String>,
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    #[serde(default)]
+    pub headers: Vec<String>,
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResourcesConfig {
+    pub enabled: bool,
+    pub recent_messages_count: u32,
+    pub subscription_interval: String,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -90,6 +134,8 @@ impl Default for McpServerConfig {
             http: Some(HttpApiConfig::default()),
             iggy: IggyConfig::default(),
             permissions: PermissionsConfig::default(),
+            redaction: RedactionConfig::default(),
+            resources: ResourcesConfig::default(),
             transport: McpTransport::Http,
         }
     }
@@ -125,6 +171,30 @@ impl Default for PermissionsConfig {
             read: true,
             update: true,
             delete: true,
+            tools: ToolsConfig::default(),
+            streams: StreamsScopeConfig::default(),
+        }
+    }
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            json_paths: vec![],
+            patterns: vec![],
+            headers: vec![],
+            replacement: "***".to_owned(),
+        }
+    }
+}
+
+impl Default for ResourcesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            recent_messages_count: 10,
+            subscription_interval: "1s".to_owned(),
         }
     }
 }
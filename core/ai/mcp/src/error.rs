@@ -36,4 +36,6 @@ pub enum McpRuntimeError {
     FailedToCreateConsumerId,
     #[error("Invalid API path")]
     InvalidApiPath,
+    #[error("Invalid redaction configuration: {0}")]
+    InvalidRedactionConfig(String),
 }
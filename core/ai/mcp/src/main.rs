@@ -22,8 +22,9 @@ use dotenvy::dotenv;
 use error::McpRuntimeError;
 use figlet_rs::FIGfont;
 use iggy::prelude::{Client, Identifier};
-use rmcp::{ServiceExt, model::ErrorData, transport::stdio};
-use service::IggyService;
+use permissions::Permissions;
+use rmcp::{ServiceExt, transport::stdio};
+use service::{IggyService, Redactor};
 use std::{env, sync::Arc};
 use tracing::{error, info};
 use tracing_subscriber::{EnvFilter, Registry, layer::SubscriberExt, util::SubscriberInitExt};
@@ -31,6 +32,7 @@ use tracing_subscriber::{EnvFilter, Registry, layer::SubscriberExt, util::Subscr
 mod api;
 mod configs;
 mod error;
+mod permissions;
 mod service;
 mod stream;
 
@@ -88,21 +90,22 @@ async fn main() -> Result<(), McpRuntimeError> {
     let iggy_consumer = Arc::new(iggy::prelude::Consumer::new(consumer_id));
     let iggy_client = Arc::new(stream::init(config.iggy).await?);
     let client_to_shutdown = iggy_client.clone();
-    let permissions = Permissions {
-        create: config.permissions.create,
-        read: config.permissions.read,
-        update: config.permissions.update,
-        delete: config.permissions.delete,
-    };
+    let permissions = Permissions::new(&config.permissions);
+    let redactor = Redactor::new(&config.redaction)?;
 
     if transport == McpTransport::Stdio {
-        let Ok(service) = IggyService::new(iggy_client, iggy_consumer, permissions)
-            .serve(stdio())
-            .await
-            .inspect_err(|e| {
-                error!("Serving error: {:?}", e);
-            })
-        else {
+        let Ok(service) = IggyService::new(
+            iggy_client,
+            iggy_consumer,
+            permissions,
+            config.resources,
+            redactor,
+        )
+        .serve(stdio())
+        .await
+        .inspect_err(|e| {
+            error!("Serving error: {:?}", e);
+        }) else {
             error!("Failed to create service");
             return Err(McpRuntimeError::FailedToCreateService);
         };
@@ -116,7 +119,15 @@ async fn main() -> Result<(), McpRuntimeError> {
             return Err(McpRuntimeError::MissingConfig);
         };
 
-        api::init(http_config, iggy_client, iggy_consumer, permissions).await?;
+        api::init(
+            http_config,
+            iggy_client,
+            iggy_consumer,
+            permissions,
+            config.resources,
+            redactor,
+        )
+        .await?;
     }
 
     #[cfg(unix)]
@@ -142,57 +153,3 @@ async fn main() -> Result<(), McpRuntimeError> {
     info!("Iggy MCP Server stopped successfully");
     Ok(())
 }
-
-#[derive(Debug, Copy, Clone)]
-pub struct Permissions {
-    create: bool,
-    read: bool,
-    update: bool,
-    delete: bool,
-}
-
-impl Permissions {
-    pub fn ensure_read(&self) -> Result<(), ErrorData> {
-        if self.read {
-            Ok(())
-        } else {
-            Err(ErrorData::invalid_request(
-                "Insufficient 'read' permissions",
-                None,
-            ))
-        }
-    }
-
-    pub fn ensure_create(&self) -> Result<(), ErrorData> {
-        if self.create {
-            Ok(())
-        } else {
-            Err(ErrorData::invalid_request(
-                "Insufficient 'create' permissions",
-                None,
-            ))
-        }
-    }
-
-    pub fn ensure_update(&self) -> Result<(), ErrorData> {
-        if self.update {
-            Ok(())
-        } else {
-            Err(ErrorData::invalid_request(
-                "Insufficient 'update' permissions",
-                None,
-            ))
-        }
-    }
-
-    pub fn ensure_delete(&self) -> Result<(), ErrorData> {
-        if self.delete {
-            Ok(())
-        } else {
-            Err(ErrorData::invalid_request(
-                "Insufficient 'delete' permissions",
-                None,
-            ))
-        }
-    }
-}
@@ -0,0 +1,281 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::PermissionsConfig;
+use rmcp::model::ErrorData;
+
+#[derive(Debug, Clone)]
+pub struct Permissions {
+    create: bool,
+    read: bool,
+    update: bool,
+    delete: bool,
+    allowed_tools: Vec<String>,
+    denied_tools: Vec<String>,
+    streams: StreamsScope,
+}
+
+impl Permissions {
+    pub fn new(config: &PermissionsConfig) -> Self {
+        Self {
+            create: config.create,
+            read: config.read,
+            update: config.update,
+            delete: config.delete,
+            allowed_tools: config.tools.allowed.clone(),
+            denied_tools: config.tools.denied.clone(),
+            streams: StreamsScope {
+                allowed: config
+                    .streams
+                    .allowed
+                    .iter()
+                    .map(String::as_str)
+                    .map(ScopeEntry::parse)
+                    .collect(),
+                denied: config
+                    .streams
+                    .denied
+                    .iter()
+                    .map(String::as_str)
+                    .map(ScopeEntry::parse)
+                    .collect(),
+            },
+        }
+    }
+
+    pub fn allows_tool(&self, name: &str) -> bool {
+        !self.denied_tools.iter().any(|tool| tool == name)
+            && (self.allowed_tools.is_empty() || self.allowed_tools.iter().any(|tool| tool == name))
+    }
+
+    pub fn streams(&self) -> &StreamsScope {
+        &self.streams
+    }
+
+    pub fn ensure_read(&self) -> Result<(), ErrorData> {
+        if self.read {
+            Ok(())
+        } else {
+            Err(ErrorData::invalid_request(
+                "Insufficient 'read' permissions",
+                None,
+            ))
+        }
+    }
+
+    pub fn ensure_create(&self) -> Result<(), ErrorData> {
+        if self.create {
+            Ok(())
+        } else {
+            Err(ErrorData::invalid_request(
+                "Insufficient 'create' permissions",
+                None,
+            ))
+        }
+    }
+
+    pub fn ensure_update(&self) -> Result<(), ErrorData> {
+        if self.update {
+            Ok(())
+        } else {
+            Err(ErrorData::invalid_request(
+                "Insufficient 'update' permissions",
+                None,
+            ))
+        }
+    }
+
+    pub fn ensure_delete(&self) -> Result<(), ErrorData> {
+        if self.delete {
+            Ok(())
+        } else {
+            Err(ErrorData::invalid_request(
+                "Insufficient 'delete' permissions",
+                None,
+            ))
+        }
+    }
+}
+
+/// The stream or topic being accessed, matched against the scope by either its ID or name.
+#[derive(Debug, Clone, Default)]
+pub struct ScopeTarget {
+    id: Option<u32>,
+    name: Option<String>,
+}
+
+impl ScopeTarget {
+    pub fn new(id: u32, name: &str) -> Self {
+        Self {
+            id: Some(id),
+            name: Some(name.to_owned()),
+        }
+    }
+
+    pub fn named(id: Option<u32>, name: &str) -> Self {
+        Self {
+            id,
+            name: Some(name.to_owned()),
+        }
+    }
+
+    /// Creates the target from the raw identifier, when the resource does not exist (yet).
+    pub fn identifier(value: &str) -> Self {
+        match value.parse::<u32>() {
+            Ok(id) => Self {
+                id: Some(id),
+                name: None,
+            },
+            Err(_) => Self {
+                id: None,
+                name: Some(value.to_owned()),
+            },
+        }
+    }
+
+    fn matches(&self, pattern: &str) -> bool {
+        pattern == "*"
+            || self.name.as_deref() == Some(pattern)
+            || self.id.is_some_and(|id| id.to_string() == pattern)
+    }
+}
+
+impl std::fmt::Display for ScopeTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.name, self.id) {
+            (Some(name), _) => write!(f, "{name}"),
+            (None, Some(id)) => write!(f, "{id}"),
+            (None, None) => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ScopeEntry {
+    stream: String,
+    topic: Option<String>,
+}
+
+impl ScopeEntry {
+    fn parse(value: &str) -> Self {
+        match value.split_once('/') {
+            Some((stream, "*")) | Some((stream, "")) => Self {
+                stream: stream.to_owned(),
+                topic: None,
+            },
+            Some((stream, topic)) => Self {
+                stream: stream.to_owned(),
+                topic: Some(topic.to_owned()),
+            },
+            None => Self {
+                stream: value.to_owned(),
+                topic: None,
+            },
+        }
+    }
+
+    fn matches_topic(&self, stream: &ScopeTarget, topic: &ScopeTarget) -> bool {
+        stream.matches(&self.stream)
+            && self
+                .topic
+                .as_deref()
+                .is_none_or(|pattern| topic.matches(pattern))
+    }
+}
+
+/// The streams and topics available to the MCP clients, the denied entries take precedence over the allowed ones.
+#[derive(Debug, Clone, Default)]
+pub struct StreamsScope {
+    allowed: Vec<ScopeEntry>,
+    denied: Vec<ScopeEntry>,
+}
+
+impl StreamsScope {
+    pub fn is_unrestricted(&self) -> bool {
+        self.allowed.is_empty() && self.denied.is_empty()
+    }
+
+    /// Whether the stream is visible, which is the case when at least some of its topics are allowed.
+    pub fn allows_stream(&self, stream: &ScopeTarget) -> bool {
+        !self
+            .denied
+            .iter()
+            .any(|entry| entry.topic.is_none() && stream.matches(&entry.stream))
+            && (self.allowed.is_empty()
+                || self
+                    .allowed
+                    .iter()
+                    .any(|entry| stream.matches(&entry.stream)))
+    }
+
+    /// Whether the whole stream, including all its topics, is allowed e.g. to be deleted.
+    pub fn allows_whole_stream(&self, stream: &ScopeTarget) -> bool {
+        !self
+            .denied
+            .iter()
+            .any(|entry| stream.matches(&entry.stream))
+            && (self.allowed.is_empty()
+                || self
+                    .allowed
+                    .iter()
+                    .any(|entry| entry.topic.is_none() && stream.matches(&entry.stream)))
+    }
+
+    pub fn allows_topic(&self, stream: &ScopeTarget, topic: &ScopeTarget) -> bool {
+        !self
+            .denied
+            .iter()
+            .any(|entry| entry.matches_topic(stream, topic))
+            && (self.allowed.is_empty()
+                || self
+                    .allowed
+                    .iter()
+                    .any(|entry| entry.matches_topic(stream, topic)))
+    }
+
+    pub fn ensure_stream(&self, stream: &ScopeTarget) -> Result<(), ErrorData> {
+        if self.allows_stream(stream) {
+            Ok(())
+        } else {
+            Err(stream_not_allowed(stream))
+        }
+    }
+
+    pub fn ensure_whole_stream(&self, stream: &ScopeTarget) -> Result<(), ErrorData> {
+        if self.allows_whole_stream(stream) {
+            Ok(())
+        } else {
+            Err(stream_not_allowed(stream))
+        }
+    }
+
+    pub fn ensure_topic(&self, stream: &ScopeTarget, topic: &ScopeTarget) -> Result<(), ErrorData> {
+        if self.allows_topic(stream, topic) {
+            Ok(())
+        } else {
+            Err(ErrorData::invalid_request(
+                format!("Access to topic: {topic} in stream: {stream} is not allowed"),
+                None,
+            ))
+        }
+    }
+}
+
+fn stream_not_allowed(stream: &ScopeTarget) -> ErrorData {
+    ErrorData::invalid_request(format!("Access to stream: {stream} is not allowed"), None)
+}
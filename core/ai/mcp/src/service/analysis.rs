@@ -0,0 +1,298 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::IggyMessage;
+use rmcp::serde_json::Value;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+const MAX_PAYLOAD_PREVIEW_LENGTH: usize = 256;
+
+/// The message sampled from the particular partition of the topic.
+#[derive(Debug)]
+pub struct SampledMessage {
+    pub partition_id: u32,
+    pub message: IggyMessage,
+}
+
+/// The compact representation of the message, with the payload preview instead of the whole payload.
+#[derive(Debug, Serialize)]
+pub struct MessageSummary {
+    pub partition_id: u32,
+    pub offset: u64,
+    pub id: u128,
+    pub timestamp: u64,
+    pub size: usize,
+    pub payload: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SchemaSummary {
+    pub sampled_messages: usize,
+    pub json_messages: usize,
+    pub fields: Vec<FieldSummary>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FieldSummary {
+    pub path: String,
+    pub types: Vec<&'static str>,
+    pub nullable: bool,
+    pub optional: bool,
+    pub occurrences: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayloadStats {
+    pub sampled_messages: usize,
+    pub partitions: BTreeMap<u32, usize>,
+    pub first_timestamp: Option<u64>,
+    pub last_timestamp: Option<u64>,
+    pub payload_size: SizeDistribution,
+    pub payload_kinds: PayloadKinds,
+    pub headers: Vec<HeaderStats>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SizeDistribution {
+    pub total: usize,
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub p50: usize,
+    pub p90: usize,
+    pub p99: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PayloadKinds {
+    pub json: usize,
+    pub text: usize,
+    pub binary: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HeaderStats {
+    pub key: String,
+    pub occurrences: usize,
+    pub cardinality: usize,
+}
+
+#[derive(Debug)]
+struct FieldStats {
+    parent: Option<String>,
+    types: BTreeSet<&'static str>,
+    occurrences: usize,
+}
+
+impl MessageSummary {
+    pub fn new(sampled: &SampledMessage) -> Self {
+        let message = &sampled.message;
+        Self {
+            partition_id: sampled.partition_id,
+            offset: message.header.offset,
+            id: message.header.id,
+            timestamp: message.header.timestamp,
+            size: message.payload.len(),
+            payload: payload_preview(&message.payload),
+            headers: headers(message),
+        }
+    }
+}
+
+/// Infers the schema of the JSON payloads, where each field is identified by its path (e.g. `$.user.email` or `$.items[*].id`).
+/// The field is optional when it's missing in some of the objects containing it, and nullable when its value can be `null`.
+pub fn infer_schema(messages: &[SampledMessage]) -> SchemaSummary {
+    let mut fields = BTreeMap::new();
+    let mut objects = HashMap::new();
+    let mut json_messages = 0;
+    for sampled in messages {
+        let Ok(value) = rmcp::serde_json::from_slice::<Value>(&sampled.message.payload) else {
+            continue;
+        };
+
+        json_messages += 1;
+        record_field(&mut fields, &mut objects, "$".to_owned(), None, &value);
+    }
+
+    let fields = fields
+        .into_iter()
+        .map(|(path, stats)| FieldSummary {
+            optional: stats.parent.is_some_and(|parent| {
+                stats.occurrences < objects.get(&parent).copied().unwrap_or_default()
+            }),
+            nullable: stats.types.contains("null"),
+            types: stats
+                .types
+                .into_iter()
+                .filter(|kind| *kind != "null")
+                .collect(),
+            occurrences: stats.occurrences,
+            path,
+        })
+        .collect();
+
+    SchemaSummary {
+        sampled_messages: messages.len(),
+        json_messages,
+        fields,
+    }
+}
+
+pub fn payload_stats(messages: &[SampledMessage]) -> PayloadStats {
+    let mut partitions = BTreeMap::new();
+    let mut sizes = Vec::with_capacity(messages.len());
+    let mut payload_kinds = PayloadKinds::default();
+    let mut header_values = BTreeMap::<String, (usize, HashSet<String>)>::new();
+    for sampled in messages {
+        *partitions.entry(sampled.partition_id).or_default() += 1;
+        let payload = &sampled.message.payload;
+        sizes.push(payload.len());
+        if rmcp::serde_json::from_slice::<Value>(payload).is_ok() {
+            payload_kinds.json += 1;
+        } else if std::str::from_utf8(payload).is_ok() {
+            payload_kinds.text += 1;
+        } else {
+            payload_kinds.binary += 1;
+        }
+
+        for (key, value) in headers(&sampled.message) {
+            let (occurrences, values) = header_values.entry(key).or_default();
+            *occurrences += 1;
+            values.insert(value);
+        }
+    }
+
+    let timestamps = messages
+        .iter()
+        .map(|sampled| sampled.message.header.timestamp);
+    PayloadStats {
+        sampled_messages: messages.len(),
+        partitions,
+        first_timestamp: timestamps.clone().min(),
+        last_timestamp: timestamps.max(),
+        payload_size: size_distribution(sizes),
+        payload_kinds,
+        headers: header_values
+            .into_iter()
+            .map(|(key, (occurrences, values))| HeaderStats {
+                key,
+                occurrences,
+                cardinality: values.len(),
+            })
+            .collect(),
+    }
+}
+
+fn record_field(
+    fields: &mut BTreeMap<String, FieldStats>,
+    objects: &mut HashMap<String, usize>,
+    path: String,
+    parent: Option<&str>,
+    value: &Value,
+) {
+    let field = fields.entry(path.clone()).or_insert_with(|| FieldStats {
+        parent: parent.map(ToOwned::to_owned),
+        types: BTreeSet::new(),
+        occurrences: 0,
+    });
+    field.occurrences += 1;
+    field.types.insert(json_type(value));
+
+    match value {
+        Value::Object(object) => {
+            *objects.entry(path.clone()).or_default() += 1;
+            for (name, value) in object {
+                record_field(
+                    fields,
+                    objects,
+                    format!("{path}.{name}"),
+                    Some(&path),
+                    value,
+                );
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                record_field(fields, objects, format!("{path}[*]"), None, item);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn size_distribution(mut sizes: Vec<usize>) -> SizeDistribution {
+    if sizes.is_empty() {
+        return SizeDistribution::default();
+    }
+
+    sizes.sort_unstable();
+    let total = sizes.iter().sum::<usize>();
+    let percentile = |percentile: usize| {
+        let rank = (percentile * sizes.len()).div_ceil(100);
+        sizes[rank.saturating_sub(1)]
+    };
+    SizeDistribution {
+        total,
+        min: sizes[0],
+        max: sizes[sizes.len() - 1],
+        mean: total as f64 / sizes.len() as f64,
+        p50: percentile(50),
+        p90: percentile(90),
+        p99: percentile(99),
+    }
+}
+
+fn payload_preview(payload: &[u8]) -> String {
+    let Ok(text) = std::str::from_utf8(payload) else {
+        return format!("<binary payload, {} bytes>", payload.len());
+    };
+
+    match text.char_indices().nth(MAX_PAYLOAD_PREVIEW_LENGTH) {
+        Some((index, _)) => format!("{}...", &text[..index]),
+        None => text.to_owned(),
+    }
+}
+
+fn headers(message: &IggyMessage) -> BTreeMap<String, String> {
+    message
+        .user_headers_map()
+        .ok()
+        .flatten()
+        .map(|headers| {
+            headers
+                .into_iter()
+                .map(|(key, value)| (key.as_str().to_owned(), value.value_only_to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -16,25 +16,58 @@
  * under the License.
  */
 
+use analysis::{MessageSummary, SampledMessage};
 use iggy::prelude::{
-    Consumer, ConsumerGroupClient, ConsumerOffsetClient, Identifier, IggyClient, IggyError,
-    IggyMessage, IggyTimestamp, MessageClient, PartitionClient, Partitioning,
-    PersonalAccessTokenClient, PollingKind, PollingStrategy, SegmentClient, StreamClient,
-    SystemClient, SystemSnapshotType, TopicClient, UserClient, UserStatus,
+    Consumer, ConsumerGroupClient, ConsumerOffsetClient, Identifier, IggyClient, IggyDuration,
+    IggyError, IggyMessage, IggyTimestamp, MessageClient, PartitionClient, Partitioning,
+    PersonalAccessTokenClient, PollingKind, PollingStrategy, SegmentClient, Stream, StreamClient,
+    SystemClient, SystemSnapshotType, Topic, TopicClient, UserClient, UserStatus,
 };
 use requests::*;
+use resources::IggyResource;
 use rmcp::{
-    ServerHandler,
-    handler::server::{router::tool::ToolRouter, tool::Parameters},
-    model::{CallToolResult, Content, ErrorData, ServerCapabilities, ServerInfo},
-    tool, tool_handler, tool_router,
+    RoleServer, ServerHandler,
+    handler::server::{
+        router::tool::ToolRouter,
+        tool::{Parameters, ToolCallContext},
+    },
+    model::{
+        CallToolRequestParam, CallToolResult, Content, ErrorData, JsonObject,
+        ListResourceTemplatesResult, ListResourcesResult, ListToolsResult, PaginatedRequestParam,
+        ReadResourceRequestParam, ReadResourceResult, ServerCapabilities, ServerInfo,
+        SubscribeRequestParam, UnsubscribeRequestParam,
+    },
+    service::RequestContext,
+    tool, tool_router,
 };
 use serde::Serialize;
-use std::sync::Arc;
-use tracing::error;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
 
-use crate::Permissions;
+use crate::{
+    configs::ResourcesConfig,
+    permissions::{Permissions, ScopeTarget},
+};
+mod analysis;
+mod redaction;
 mod requests;
+mod resources;
+
+pub use redaction::Redactor;
+
+const DEFAULT_SAMPLE_SIZE: u32 = 100;
+const MAX_SAMPLE_SIZE: u32 = 1000;
+const TIME_RANGE_BATCH_SIZE: u32 = 100;
+/// The tool arguments which are never logged, as they may carry the credentials or the message payloads.
+const MASKED_ARGUMENTS: [&str; 6] = [
+    "password", "token", "secret", "payload", "messages", "headers",
+];
 
 #[derive(Debug, Clone)]
 pub struct IggyService {
@@ -42,16 +75,39 @@ pub struct IggyService {
     client: Arc<IggyClient>,
     consumer: Arc<Consumer>,
     permissions: Permissions,
+    resources: ResourcesConfig,
+    redactor: Redactor,
+    subscription_interval: Duration,
+    subscriptions: Arc<Mutex<HashMap<String, JoinHandle<()>>>>,
 }
 
 #[tool_router]
 impl IggyService {
-    pub fn new(client: Arc<IggyClient>, consumer: Arc<Consumer>, permissions: Permissions) -> Self {
+    pub fn new(
+        client: Arc<IggyClient>,
+        consumer: Arc<Consumer>,
+        permissions: Permissions,
+        resources: ResourcesConfig,
+        redactor: Redactor,
+    ) -> Self {
+        let subscription_interval = IggyDuration::from_str(&resources.subscription_interval)
+            .map(|interval| interval.get_duration())
+            .unwrap_or(Duration::from_secs(1));
+        let mut tool_router = ToolRouter::new();
+        for route in Self::tool_router() {
+            if permissions.allows_tool(route.name()) {
+                tool_router.add_route(route);
+            }
+        }
         Self {
-            tool_router: Self::tool_router(),
+            tool_router,
             client,
             consumer,
             permissions,
+            resources,
+            redactor,
+            subscription_interval,
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -67,13 +123,26 @@ impl IggyService {
         Parameters(GetStream { stream_id }): Parameters<GetStream>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
-        request(self.client.get_stream(&id(&stream_id)?).await)
+        self.ensure_stream(&stream_id).await?;
+        let stream = self
+            .client
+            .get_stream(&id(&stream_id)?)
+            .await
+            .map(|stream| {
+                stream.map(|mut stream| {
+                    let target = ScopeTarget::new(stream.id, &stream.name);
+                    stream.topics = self.visible_topics(&target, stream.topics);
+                    stream
+                })
+            });
+        request(stream)
     }
 
     #[tool(description = "Get streams")]
     pub async fn get_streams(&self) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
-        request(self.client.get_streams().await)
+        let streams = self.client.get_streams().await;
+        request(streams.map(|streams| self.visible_streams(streams)))
     }
 
     #[tool(description = "Create stream")]
@@ -82,6 +151,9 @@ impl IggyService {
         Parameters(CreateStream { name, stream_id }): Parameters<CreateStream>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_create()?;
+        self.permissions
+            .streams()
+            .ensure_whole_stream(&ScopeTarget::named(stream_id, &name))?;
         request(self.client.create_stream(&name, stream_id).await)
     }
 
@@ -91,6 +163,10 @@ impl IggyService {
         Parameters(UpdateStream { stream_id, name }): Parameters<UpdateStream>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_update()?;
+        self.permissions
+            .streams()
+            .ensure_whole_stream(&ScopeTarget::named(None, &name))?;
+        self.ensure_whole_stream(&stream_id).await?;
         request(self.client.update_stream(&id(&stream_id)?, &name).await)
     }
 
@@ -100,6 +176,7 @@ impl IggyService {
         Parameters(DeleteStream { stream_id }): Parameters<DeleteStream>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_whole_stream(&stream_id).await?;
         request(self.client.delete_stream(&id(&stream_id)?).await)
     }
 
@@ -109,6 +186,7 @@ impl IggyService {
         Parameters(PurgeStream { stream_id }): Parameters<PurgeStream>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_whole_stream(&stream_id).await?;
         request(self.client.purge_stream(&id(&stream_id)?).await)
     }
 
@@ -118,7 +196,14 @@ impl IggyService {
         Parameters(GetTopics { stream_id }): Parameters<GetTopics>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
-        request(self.client.get_topics(&id(&stream_id)?).await)
+        let topics = self.client.get_topics(&id(&stream_id)?).await;
+        if self.permissions.streams().is_unrestricted() {
+            return request(topics);
+        }
+
+        let (stream, _) = self.scope_targets(&stream_id, None).await?;
+        self.permissions.streams().ensure_stream(&stream)?;
+        request(topics.map(|topics| self.visible_topics(&stream, topics)))
     }
 
     #[tool(description = "Get topic")]
@@ -130,6 +215,7 @@ impl IggyService {
         }): Parameters<GetTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .get_topic(&id(&stream_id)?, &id(&topic_id)?)
@@ -152,6 +238,8 @@ impl IggyService {
         }): Parameters<CreateTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_create()?;
+        self.ensure_named_topic(&stream_id, &ScopeTarget::named(topic_id, &name))
+            .await?;
         let compression_algorithm = compression_algorithm
             .and_then(|ca| ca.parse().ok())
             .unwrap_or_default();
@@ -189,6 +277,9 @@ impl IggyService {
         }): Parameters<UpdateTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_update()?;
+        self.ensure_named_topic(&stream_id, &ScopeTarget::named(None, &name))
+            .await?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         let compression_algorithm = compression_algorithm
             .and_then(|ca| ca.parse().ok())
             .unwrap_or_default();
@@ -220,6 +311,7 @@ impl IggyService {
         }): Parameters<DeleteTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .delete_topic(&id(&stream_id)?, &id(&topic_id)?)
@@ -236,6 +328,7 @@ impl IggyService {
         }): Parameters<PurgeTopic>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .purge_topic(&id(&stream_id)?, &id(&topic_id)?)
@@ -253,6 +346,7 @@ impl IggyService {
         }): Parameters<CreatePartitions>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_create()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .create_partitions(&id(&stream_id)?, &id(&topic_id)?, partitions_count)
@@ -270,6 +364,7 @@ impl IggyService {
         }): Parameters<DeletePartitions>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .delete_partitions(&id(&stream_id)?, &id(&topic_id)?, partitions_count)
@@ -288,6 +383,7 @@ impl IggyService {
         }): Parameters<DeleteSegments>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .delete_segments(
@@ -315,6 +411,7 @@ impl IggyService {
         }): Parameters<PollMessages>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         let offset = offset.unwrap_or(0);
         let count = count.unwrap_or(10);
         let mut auto_commit = auto_commit.unwrap_or(false);
@@ -336,19 +433,163 @@ impl IggyService {
             auto_commit = true;
         }
 
-        request(
-            self.client
-                .poll_messages(
-                    &id(&stream_id)?,
-                    &id(&topic_id)?,
-                    partition_id,
-                    &self.consumer,
-                    &strategy,
-                    count,
-                    auto_commit,
-                )
-                .await,
-        )
+        let polled_messages = self
+            .client
+            .poll_messages(
+                &id(&stream_id)?,
+                &id(&topic_id)?,
+                partition_id,
+                &self.consumer,
+                &strategy,
+                count,
+                auto_commit,
+            )
+            .await
+            .map(|mut polled_messages| {
+                self.redactor.redact(&mut polled_messages.messages);
+                polled_messages
+            });
+        request(polled_messages)
+    }
+
+    #[tool(
+        description = "Sample the most recent messages across the topic partitions, with the payload previews"
+    )]
+    pub async fn sample_messages(
+        &self,
+        Parameters(SampleMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            count,
+        }): Parameters<SampleMessages>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
+        let messages = self
+            .sample(&stream_id, &topic_id, partition_id, count)
+            .await?;
+        request(Ok(messages
+            .iter()
+            .map(MessageSummary::new)
+            .collect::<Vec<_>>()))
+    }
+
+    #[tool(
+        description = "Infer the JSON schema (field paths, types and nullability) of the sampled messages payloads"
+    )]
+    pub async fn infer_schema(
+        &self,
+        Parameters(InferSchema {
+            stream_id,
+            topic_id,
+            partition_id,
+            count,
+        }): Parameters<InferSchema>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
+        let messages = self
+            .sample(&stream_id, &topic_id, partition_id, count)
+            .await?;
+        request(Ok(analysis::infer_schema(&messages)))
+    }
+
+    #[tool(
+        description = "Get the payload size distribution and headers cardinalities of the sampled messages"
+    )]
+    pub async fn get_payload_stats(
+        &self,
+        Parameters(GetPayloadStats {
+            stream_id,
+            topic_id,
+            partition_id,
+            count,
+        }): Parameters<GetPayloadStats>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
+        let messages = self
+            .sample(&stream_id, &topic_id, partition_id, count)
+            .await?;
+        request(Ok(analysis::payload_stats(&messages)))
+    }
+
+    #[tool(description = "Find the messages within the time range, ordered by timestamp")]
+    pub async fn find_messages_by_time(
+        &self,
+        Parameters(FindMessagesByTime {
+            stream_id,
+            topic_id,
+            partition_id,
+            from,
+            to,
+            count,
+        }): Parameters<FindMessagesByTime>,
+    ) -> Result<CallToolResult, ErrorData> {
+        self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
+        let to = to.unwrap_or(IggyTimestamp::now().as_micros());
+        if from > to {
+            return Err(ErrorData::invalid_params(
+                "The start of the time range cannot be greater than its end",
+                None,
+            ));
+        }
+
+        let count = count
+            .unwrap_or(DEFAULT_SAMPLE_SIZE)
+            .clamp(1, MAX_SAMPLE_SIZE) as usize;
+        let stream = id(&stream_id)?;
+        let topic = id(&topic_id)?;
+        let mut messages = Vec::new();
+        for partition_id in self.partitions(&stream, &topic, partition_id).await? {
+            let mut strategy = PollingStrategy::timestamp(IggyTimestamp::from(from));
+            let mut found = 0;
+            'partition: while found < count {
+                let polled_messages = self
+                    .client
+                    .poll_messages(
+                        &stream,
+                        &topic,
+                        Some(partition_id),
+                        &self.consumer,
+                        &strategy,
+                        TIME_RANGE_BATCH_SIZE,
+                        false,
+                    )
+                    .await
+                    .map_err(invocation_error)?;
+                let Some(last_offset) = polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.header.offset)
+                else {
+                    break;
+                };
+
+                for message in polled_messages.messages {
+                    if message.header.timestamp > to || found == count {
+                        break 'partition;
+                    }
+
+                    found += 1;
+                    messages.push(SampledMessage {
+                        partition_id,
+                        message,
+                    });
+                }
+                strategy = PollingStrategy::offset(last_offset + 1);
+            }
+        }
+
+        messages.sort_by_key(|sampled| sampled.message.header.timestamp);
+        messages.truncate(count);
+        self.redact(&mut messages);
+        request(Ok(messages
+            .iter()
+            .map(MessageSummary::new)
+            .collect::<Vec<_>>()))
     }
 
     #[tool(description = "Send messages")]
@@ -364,6 +605,7 @@ impl IggyService {
         }): Parameters<SendMessages>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_create()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         let partitioning = if let Some(partitioning) = partitioning {
             match partitioning.as_str() {
                 "balanced" => Partitioning::balanced(),
@@ -470,6 +712,7 @@ impl IggyService {
         }): Parameters<GetConsumerGroups>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .get_consumer_groups(&id(&stream_id)?, &id(&topic_id)?)
@@ -487,6 +730,7 @@ impl IggyService {
         }): Parameters<GetConsumerGroup>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .get_consumer_group(&id(&stream_id)?, &id(&topic_id)?, &id(&group_id)?)
@@ -505,6 +749,7 @@ impl IggyService {
         }): Parameters<CreateConsumerGroup>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_create()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .create_consumer_group(&id(&stream_id)?, &id(&topic_id)?, &name, group_id)
@@ -522,6 +767,7 @@ impl IggyService {
         }): Parameters<DeleteConsumerGroup>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_delete()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .delete_consumer_group(&id(&stream_id)?, &id(&topic_id)?, &id(&group_id)?)
@@ -539,6 +785,7 @@ impl IggyService {
         }): Parameters<GetConsumerOffset>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .get_consumer_offset(
@@ -562,6 +809,7 @@ impl IggyService {
         }): Parameters<StoreConsumerOffset>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .store_consumer_offset(
@@ -585,6 +833,7 @@ impl IggyService {
         }): Parameters<DeleteConsumerOffset>,
     ) -> Result<CallToolResult, ErrorData> {
         self.permissions.ensure_read()?;
+        self.ensure_topic(&stream_id, &topic_id).await?;
         request(
             self.client
                 .delete_consumer_offset(
@@ -737,15 +986,425 @@ impl IggyService {
     }
 }
 
-#[tool_handler]
+impl IggyService {
+    fn ensure_resources(&self) -> Result<(), ErrorData> {
+        if self.resources.enabled {
+            self.permissions.ensure_read()
+        } else {
+            Err(ErrorData::invalid_request("Resources are disabled", None))
+        }
+    }
+
+    /// Resolves the stream and its topic, so that they can be matched against the scope by both ID and name.
+    async fn scope_targets(
+        &self,
+        stream_id: &str,
+        topic_id: Option<&str>,
+    ) -> Result<(ScopeTarget, ScopeTarget), ErrorData> {
+        let topic_target = || topic_id.map(ScopeTarget::identifier).unwrap_or_default();
+        let Some(stream) = self
+            .client
+            .get_stream(&id(stream_id)?)
+            .await
+            .map_err(invocation_error)?
+        else {
+            return Ok((ScopeTarget::identifier(stream_id), topic_target()));
+        };
+
+        let topic = topic_id
+            .and_then(|topic_id| {
+                stream
+                    .topics
+                    .iter()
+                    .find(|topic| match topic_id.parse::<u32>() {
+                        Ok(id) => topic.id == id,
+                        Err(_) => topic.name == topic_id,
+                    })
+            })
+            .map(|topic| ScopeTarget::new(topic.id, &topic.name))
+            .unwrap_or_else(topic_target);
+        Ok((ScopeTarget::new(stream.id, &stream.name), topic))
+    }
+
+    async fn ensure_stream(&self, stream_id: &str) -> Result<(), ErrorData> {
+        let scope = self.permissions.streams();
+        if scope.is_unrestricted() {
+            return Ok(());
+        }
+
+        let (stream, _) = self.scope_targets(stream_id, None).await?;
+        scope.ensure_stream(&stream)
+    }
+
+    async fn ensure_whole_stream(&self, stream_id: &str) -> Result<(), ErrorData> {
+        let scope = self.permissions.streams();
+        if scope.is_unrestricted() {
+            return Ok(());
+        }
+
+        let (stream, _) = self.scope_targets(stream_id, None).await?;
+        scope.ensure_whole_stream(&stream)
+    }
+
+    async fn ensure_topic(&self, stream_id: &str, topic_id: &str) -> Result<(), ErrorData> {
+        let scope = self.permissions.streams();
+        if scope.is_unrestricted() {
+            return Ok(());
+        }
+
+        let (stream, topic) = self.scope_targets(stream_id, Some(topic_id)).await?;
+        scope.ensure_topic(&stream, &topic)
+    }
+
+    async fn ensure_named_topic(
+        &self,
+        stream_id: &str,
+        topic: &ScopeTarget,
+    ) -> Result<(), ErrorData> {
+        let scope = self.permissions.streams();
+        if scope.is_unrestricted() {
+            return Ok(());
+        }
+
+        let (stream, _) = self.scope_targets(stream_id, None).await?;
+        scope.ensure_topic(&stream, topic)
+    }
+
+    /// Returns the IDs of the non-empty partitions, or just the requested one.
+    async fn partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<u32>, ErrorData> {
+        let Some(topic) = self
+            .client
+            .get_topic(stream_id, topic_id)
+            .await
+            .map_err(invocation_error)?
+        else {
+            return Err(ErrorData::invalid_params(
+                format!("Topic: {topic_id} in stream: {stream_id} was not found"),
+                None,
+            ));
+        };
+
+        Ok(topic
+            .partitions
+            .iter()
+            .filter(|partition| partition_id.is_none_or(|id| id == partition.id))
+            .filter(|partition| partition.messages_count > 0)
+            .map(|partition| partition.id)
+            .collect())
+    }
+
+    /// Samples the most recent messages, evenly split across the partitions.
+    async fn sample(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: Option<u32>,
+        count: Option<u32>,
+    ) -> Result<Vec<SampledMessage>, ErrorData> {
+        let stream = id(stream_id)?;
+        let topic = id(topic_id)?;
+        let partitions = self.partitions(&stream, &topic, partition_id).await?;
+        if partitions.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let count = count
+            .unwrap_or(DEFAULT_SAMPLE_SIZE)
+            .clamp(1, MAX_SAMPLE_SIZE);
+        let count = count.div_ceil(partitions.len() as u32);
+        let mut messages = Vec::new();
+        for partition_id in partitions {
+            let polled_messages = self
+                .client
+                .poll_messages(
+                    &stream,
+                    &topic,
+                    Some(partition_id),
+                    &self.consumer,
+                    &PollingStrategy::last(),
+                    count,
+                    false,
+                )
+                .await
+                .map_err(invocation_error)?;
+            messages.extend(
+                polled_messages
+                    .messages
+                    .into_iter()
+                    .map(|message| SampledMessage {
+                        partition_id,
+                        message,
+                    }),
+            );
+        }
+
+        self.redact(&mut messages);
+        Ok(messages)
+    }
+
+    fn redact(&self, messages: &mut [SampledMessage]) {
+        for sampled in messages {
+            self.redactor
+                .redact(std::slice::from_mut(&mut sampled.message));
+        }
+    }
+
+    fn visible_streams(&self, mut streams: Vec<Stream>) -> Vec<Stream> {
+        let scope = self.permissions.streams();
+        streams.retain(|stream| scope.allows_stream(&ScopeTarget::new(stream.id, &stream.name)));
+        streams
+    }
+
+    fn visible_topics(&self, stream: &ScopeTarget, mut topics: Vec<Topic>) -> Vec<Topic> {
+        let scope = self.permissions.streams();
+        topics.retain(|topic| scope.allows_topic(stream, &ScopeTarget::new(topic.id, &topic.name)));
+        topics
+    }
+
+    fn parse_resource(uri: &str) -> Result<IggyResource, ErrorData> {
+        IggyResource::parse(uri).ok_or_else(|| {
+            ErrorData::resource_not_found(format!("Resource not found: {uri}"), None)
+        })
+    }
+}
+
 impl ServerHandler for IggyService {
     fn get_info(&self) -> ServerInfo {
+        let capabilities = if self.resources.enabled {
+            ServerCapabilities::builder()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_tools()
+                .build()
+        } else {
+            ServerCapabilities::builder().enable_tools().build()
+        };
         ServerInfo {
             instructions: Some("Iggy service".into()),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities,
             ..Default::default()
         }
     }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let tool = request.name.clone();
+        info!("Invoking tool: {tool}");
+        debug!(
+            "Invoking tool: {tool} with arguments: {}",
+            arguments(request.arguments.as_ref(), &self.redactor)
+        );
+        let started_at = Instant::now();
+        let result = self
+            .tool_router
+            .call(ToolCallContext::new(self, request, context))
+            .await;
+        let elapsed = started_at.elapsed().as_millis();
+        match &result {
+            Ok(_) => info!("Tool: {tool} invoked successfully in {elapsed} ms"),
+            Err(error) => warn!("Tool: {tool} invocation failed in {elapsed} ms. {error}"),
+        }
+        result
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        self.ensure_resources()?;
+        let mut resources =
+            vec![IggyResource::Streams.to_resource("streams", "List of all the streams")];
+        let streams = self.client.get_streams().await.map_err(invocation_error)?;
+        for stream in self.visible_streams(streams) {
+            let target = ScopeTarget::new(stream.id, &stream.name);
+            resources.push(
+                IggyResource::Stream {
+                    stream_id: stream.id.to_string(),
+                }
+                .to_resource(&stream.name, "Stream details"),
+            );
+
+            let topics = self
+                .client
+                .get_topics(&id(&stream.id.to_string())?)
+                .await
+                .map_err(invocation_error)?;
+            for topic in self.visible_topics(&target, topics) {
+                resources.push(
+                    IggyResource::Topic {
+                        stream_id: stream.id.to_string(),
+                        topic_id: topic.id.to_string(),
+                    }
+                    .to_resource(&format!("{}/{}", stream.name, topic.name), "Topic details"),
+                );
+            }
+        }
+
+        Ok(ListResourcesResult::with_all_items(resources))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, ErrorData> {
+        self.ensure_resources()?;
+        Ok(ListResourceTemplatesResult::with_all_items(
+            resources::templates(),
+        ))
+    }
+
+    async fn read_resource(
+        &self,
+        ReadResourceRequestParam { uri }: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        self.ensure_resources()?;
+        let text = match Self::parse_resource(&uri)? {
+            IggyResource::Streams => {
+                let streams = self.client.get_streams().await;
+                resource(
+                    Some(streams.map(|streams| self.visible_streams(streams))),
+                    &uri,
+                )?
+            }
+            IggyResource::Stream { stream_id } => {
+                self.ensure_stream(&stream_id).await?;
+                let stream = self
+                    .client
+                    .get_stream(&id(&stream_id)?)
+                    .await
+                    .map(|stream| {
+                        stream.map(|mut stream| {
+                            let target = ScopeTarget::new(stream.id, &stream.name);
+                            stream.topics = self.visible_topics(&target, stream.topics);
+                            stream
+                        })
+                    });
+                resource(stream.transpose(), &uri)?
+            }
+            IggyResource::Topic {
+                stream_id,
+                topic_id,
+            } => {
+                self.ensure_topic(&stream_id, &topic_id).await?;
+                resource(
+                    self.client
+                        .get_topic(&id(&stream_id)?, &id(&topic_id)?)
+                        .await
+                        .transpose(),
+                    &uri,
+                )?
+            }
+            IggyResource::Messages {
+                stream_id,
+                topic_id,
+                partition_id,
+            } => {
+                self.ensure_topic(&stream_id, &topic_id).await?;
+                let polled_messages = self
+                    .client
+                    .poll_messages(
+                        &id(&stream_id)?,
+                        &id(&topic_id)?,
+                        Some(partition_id),
+                        &self.consumer,
+                        &PollingStrategy::last(),
+                        self.resources.recent_messages_count,
+                        false,
+                    )
+                    .await
+                    .map(|mut polled_messages| {
+                        self.redactor.redact(&mut polled_messages.messages);
+                        polled_messages
+                    });
+                resource(Some(polled_messages), &uri)?
+            }
+        };
+
+        Ok(ReadResourceResult {
+            contents: vec![resources::text_contents(uri, text)],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        SubscribeRequestParam { uri }: SubscribeRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        self.ensure_resources()?;
+        let (stream_id, topic_id, partition_id) = match Self::parse_resource(&uri)? {
+            IggyResource::Topic {
+                stream_id,
+                topic_id,
+            } => (stream_id, topic_id, None),
+            IggyResource::Messages {
+                stream_id,
+                topic_id,
+                partition_id,
+            } => (stream_id, topic_id, Some(partition_id)),
+            _ => {
+                return Err(ErrorData::invalid_params(
+                    format!("Only topic and messages resources can be subscribed to: {uri}"),
+                    None,
+                ));
+            }
+        };
+        self.ensure_topic(&stream_id, &topic_id).await?;
+
+        let watcher = tokio::spawn(resources::watch(
+            self.client.clone(),
+            context.peer,
+            uri.clone(),
+            id(&stream_id)?,
+            id(&topic_id)?,
+            partition_id,
+            self.subscription_interval,
+        ));
+        let mut subscriptions = self
+            .subscriptions
+            .lock()
+            .expect("Failed to lock subscriptions");
+        if let Some(previous) = subscriptions.insert(uri.clone(), watcher) {
+            previous.abort();
+        }
+        info!("Subscribed to resource: {uri}");
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        UnsubscribeRequestParam { uri }: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let watcher = self
+            .subscriptions
+            .lock()
+            .expect("Failed to lock subscriptions")
+            .remove(&uri);
+        if let Some(watcher) = watcher {
+            watcher.abort();
+            info!("Unsubscribed from resource: {uri}");
+        }
+        Ok(())
+    }
 }
 
 fn id(id: &str) -> Result<Identifier, ErrorData> {
@@ -756,12 +1415,47 @@ fn id(id: &str) -> Result<Identifier, ErrorData> {
     })
 }
 
+/// Formats the tool arguments for logging, without revealing the credentials and the message payloads.
+fn arguments(arguments: Option<&JsonObject>, redactor: &Redactor) -> String {
+    let Some(arguments) = arguments else {
+        return "{}".to_owned();
+    };
+
+    let mut arguments = rmcp::serde_json::Value::Object(arguments.clone());
+    mask_arguments(&mut arguments, redactor);
+    arguments.to_string()
+}
+
+fn mask_arguments(value: &mut rmcp::serde_json::Value, redactor: &Redactor) {
+    match value {
+        rmcp::serde_json::Value::Object(fields) => {
+            for (name, value) in fields.iter_mut() {
+                let name = name.to_lowercase();
+                if MASKED_ARGUMENTS.iter().any(|masked| name.contains(masked)) {
+                    *value = "***".into();
+                } else {
+                    mask_arguments(value, redactor);
+                }
+            }
+        }
+        rmcp::serde_json::Value::Array(items) => {
+            for item in items {
+                mask_arguments(item, redactor);
+            }
+        }
+        rmcp::serde_json::Value::String(text) => *text = redactor.redact_str(text),
+        _ => {}
+    }
+}
+
+fn invocation_error(error: IggyError) -> ErrorData {
+    let message = format!("There was an error when invoking the method. {error}");
+    error!(message);
+    ErrorData::invalid_request(message, None)
+}
+
 fn request(result: Result<impl Sized + Serialize, IggyError>) -> Result<CallToolResult, ErrorData> {
-    let result = result.map_err(|e| {
-        let message = format!("There was an error when invoking the method. {e}");
-        error!(message);
-        ErrorData::invalid_request(message, None)
-    })?;
+    let result = result.map_err(invocation_error)?;
 
     let content = Content::json(result).map_err(|error| {
         let message = format!("Failed to serialize result. {error}");
@@ -771,3 +1465,22 @@ fn request(result: Result<impl Sized + Serialize, IggyError>) -> Result<CallTool
 
     Ok(CallToolResult::success(vec![content]))
 }
+
+fn resource(
+    result: Option<Result<impl Sized + Serialize, IggyError>>,
+    uri: &str,
+) -> Result<String, ErrorData> {
+    let Some(result) = result else {
+        return Err(ErrorData::resource_not_found(
+            format!("Resource not found: {uri}"),
+            None,
+        ));
+    };
+
+    let result = result.map_err(invocation_error)?;
+    rmcp::serde_json::to_string(&result).map_err(|error| {
+        let message = format!("Failed to serialize resource. {error}");
+        error!(message);
+        ErrorData::internal_error(message, None)
+    })
+}
@@ -0,0 +1,273 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{configs::RedactionConfig, error::McpRuntimeError};
+use iggy::prelude::{BytesSerializable, HeaderKey, HeaderKind, HeaderValue, IggyMessage};
+use regex::Regex;
+use rmcp::serde_json::{self, Value};
+use std::{collections::HashMap, str::FromStr};
+use tracing::warn;
+
+/// Redacts the sensitive data from the polled messages before they are returned to the MCP client.
+///
+/// The JSON paths (e.g. `$.user.email` or `$.cards[*].number`) are applied to the JSON payloads,
+/// the patterns to the text payloads and string header values, and the listed headers are redacted as a whole.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    enabled: bool,
+    json_paths: Vec<Vec<Segment>>,
+    patterns: Vec<Regex>,
+    headers: Vec<String>,
+    replacement: String,
+    header_replacement: HeaderValue,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    Wildcard,
+}
+
+impl Redactor {
+    pub fn new(config: &RedactionConfig) -> Result<Self, McpRuntimeError> {
+        let json_paths = config
+            .json_paths
+            .iter()
+            .map(|path| parse_json_path(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        let patterns = config
+            .patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|error| {
+                    McpRuntimeError::InvalidRedactionConfig(format!(
+                        "Invalid pattern: {pattern}. {error}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let header_replacement = HeaderValue::from_str(&config.replacement).map_err(|error| {
+            McpRuntimeError::InvalidRedactionConfig(format!(
+                "Invalid replacement: {}. {error}",
+                config.replacement
+            ))
+        })?;
+
+        Ok(Self {
+            enabled: config.enabled,
+            json_paths,
+            patterns,
+            headers: config
+                .headers
+                .iter()
+                .map(|header| header.to_lowercase())
+                .collect(),
+            replacement: config.replacement.clone(),
+            header_replacement,
+        })
+    }
+
+    pub fn redact(&self, messages: &mut [IggyMessage]) {
+        if !self.enabled {
+            return;
+        }
+
+        for message in messages {
+            self.redact_message(message);
+        }
+    }
+
+    pub fn redact_message(&self, message: &mut IggyMessage) {
+        if !self.enabled {
+            return;
+        }
+
+        if let Some(payload) = self.redact_payload(&message.payload) {
+            message.header.payload_length = payload.len() as u32;
+            message.payload = payload.into();
+        }
+
+        let headers = match message.user_headers_map() {
+            Ok(Some(headers)) => headers,
+            Ok(None) => return,
+            Err(error) => {
+                warn!(
+                    "Failed to read headers of message with offset: {}, removing them. {error}",
+                    message.header.offset
+                );
+                message.header.user_headers_length = 0;
+                message.user_headers = None;
+                return;
+            }
+        };
+
+        let headers = self.redact_headers(headers).to_bytes();
+        message.header.user_headers_length = headers.len() as u32;
+        message.user_headers = Some(headers);
+    }
+
+    /// Redacts the text with the configured patterns, e.g. the tool arguments before they're logged.
+    pub fn redact_str(&self, text: &str) -> String {
+        if !self.enabled || self.patterns.is_empty() {
+            return text.to_owned();
+        }
+
+        self.redact_text(text)
+    }
+
+    fn redact_payload(&self, payload: &[u8]) -> Option<Vec<u8>> {
+        let mut redacted = None;
+        if !self.json_paths.is_empty() {
+            if let Ok(mut value) = serde_json::from_slice::<Value>(payload) {
+                let replacement = Value::String(self.replacement.clone());
+                for path in &self.json_paths {
+                    redact_json_path(&mut value, path, &replacement);
+                }
+                redacted = serde_json::to_vec(&value).ok();
+            }
+        }
+
+        if self.patterns.is_empty() {
+            return redacted;
+        }
+
+        let payload = redacted.as_deref().unwrap_or(payload);
+        let Ok(text) = std::str::from_utf8(payload) else {
+            return redacted;
+        };
+        Some(self.redact_text(text).into_bytes())
+    }
+
+    fn redact_headers(
+        &self,
+        headers: HashMap<HeaderKey, HeaderValue>,
+    ) -> HashMap<HeaderKey, HeaderValue> {
+        headers
+            .into_iter()
+            .map(|(key, value)| {
+                if self.headers.contains(&key.as_str().to_lowercase()) {
+                    return (key, self.header_replacement.clone());
+                }
+
+                if value.kind != HeaderKind::String || self.patterns.is_empty() {
+                    return (key, value);
+                }
+
+                let redacted = value
+                    .as_str()
+                    .ok()
+                    .and_then(|text| HeaderValue::from_str(&self.redact_text(text)).ok())
+                    .unwrap_or_else(|| self.header_replacement.clone());
+                (key, redacted)
+            })
+            .collect()
+    }
+
+    fn redact_text(&self, text: &str) -> String {
+        let mut text = text.to_owned();
+        for pattern in &self.patterns {
+            text = pattern
+                .replace_all(&text, self.replacement.as_str())
+                .into_owned();
+        }
+        text
+    }
+}
+
+fn redact_json_path(value: &mut Value, path: &[Segment], replacement: &Value) {
+    let Some((segment, path)) = path.split_first() else {
+        *value = replacement.clone();
+        return;
+    };
+
+    match (segment, value) {
+        (Segment::Field(name), Value::Object(fields)) => {
+            if let Some(value) = fields.get_mut(name) {
+                redact_json_path(value, path, replacement);
+            }
+        }
+        (Segment::Index(index), Value::Array(items)) => {
+            if let Some(value) = items.get_mut(*index) {
+                redact_json_path(value, path, replacement);
+            }
+        }
+        (Segment::Wildcard, Value::Object(fields)) => {
+            for value in fields.values_mut() {
+                redact_json_path(value, path, replacement);
+            }
+        }
+        (Segment::Wildcard, Value::Array(items)) => {
+            for value in items {
+                redact_json_path(value, path, replacement);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Parses the subset of JSON path supporting the fields (`.name` or `['name']`), indexes (`[0]`) and wildcards (`.*` or `[*]`).
+fn parse_json_path(path: &str) -> Result<Vec<Segment>, McpRuntimeError> {
+    let invalid = |reason: &str| {
+        McpRuntimeError::InvalidRedactionConfig(format!("Invalid JSON path: {path}. {reason}"))
+    };
+
+    let mut rest = path
+        .strip_prefix('$')
+        .ok_or_else(|| invalid("The path must start with '$'"))?;
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(field) = rest.strip_prefix('.') {
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+            let segment = match &field[..end] {
+                "" => return Err(invalid("The field name cannot be empty")),
+                "*" => Segment::Wildcard,
+                name => Segment::Field(name.to_owned()),
+            };
+            segments.push(segment);
+            rest = &field[end..];
+        } else if let Some(index) = rest.strip_prefix('[') {
+            let end = index
+                .find(']')
+                .ok_or_else(|| invalid("The bracket is not closed"))?;
+            let value = &index[..end];
+            let segment = if value == "*" {
+                Segment::Wildcard
+            } else if let Ok(index) = value.parse::<usize>() {
+                Segment::Index(index)
+            } else if value.len() >= 2
+                && (value.starts_with('\'') && value.ends_with('\'')
+                    || value.starts_with('"') && value.ends_with('"'))
+            {
+                Segment::Field(value[1..value.len() - 1].to_owned())
+            } else {
+                return Err(invalid("Unsupported bracket expression"));
+            };
+            segments.push(segment);
+            rest = &index[end + 1..];
+        } else {
+            return Err(invalid("Expected '.' or '['"));
+        }
+    }
+
+    if segments.is_empty() {
+        return Err(invalid("The path must select a field"));
+    }
+
+    Ok(segments)
+}
@@ -206,6 +206,80 @@ pub struct PollMessages {
     pub auto_commit: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SampleMessages {
+    #[schemars(description = "stream identifier (name or number)")]
+    pub stream_id: String,
+
+    #[schemars(description = "topic identifier (name or number)")]
+    pub topic_id: String,
+
+    #[schemars(description = "partition identifier (optional, number, all partitions by default)")]
+    pub partition_id: Option<u32>,
+
+    #[schemars(
+        description = "count of the most recent messages to sample (optional, 100 by default)"
+    )]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct InferSchema {
+    #[schemars(description = "stream identifier (name or number)")]
+    pub stream_id: String,
+
+    #[schemars(description = "topic identifier (name or number)")]
+    pub topic_id: String,
+
+    #[schemars(description = "partition identifier (optional, number, all partitions by default)")]
+    pub partition_id: Option<u32>,
+
+    #[schemars(
+        description = "count of the most recent messages to sample (optional, 100 by default)"
+    )]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetPayloadStats {
+    #[schemars(description = "stream identifier (name or number)")]
+    pub stream_id: String,
+
+    #[schemars(description = "topic identifier (name or number)")]
+    pub topic_id: String,
+
+    #[schemars(description = "partition identifier (optional, number, all partitions by default)")]
+    pub partition_id: Option<u32>,
+
+    #[schemars(
+        description = "count of the most recent messages to sample (optional, 100 by default)"
+    )]
+    pub count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FindMessagesByTime {
+    #[schemars(description = "stream identifier (name or number)")]
+    pub stream_id: String,
+
+    #[schemars(description = "topic identifier (name or number)")]
+    pub topic_id: String,
+
+    #[schemars(description = "partition identifier (optional, number, all partitions by default)")]
+    pub partition_id: Option<u32>,
+
+    #[schemars(description = "start of the time range (required, microseconds from Unix Epoch)")]
+    pub from: u64,
+
+    #[schemars(
+        description = "end of the time range (optional, microseconds from Unix Epoch, now by default)"
+    )]
+    pub to: Option<u64>,
+
+    #[schemars(description = "maximum count of the messages to return (optional, 100 by default)")]
+    pub count: Option<u32>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct SendMessages {
     #[schemars(description = "stream identifier (name or number)")]
@@ -0,0 +1,208 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::{Identifier, IggyClient, TopicClient};
+use rmcp::{
+    Peer, RoleServer,
+    model::{
+        AnnotateAble, RawResource, RawResourceTemplate, Resource, ResourceContents,
+        ResourceTemplate, ResourceUpdatedNotificationParam,
+    },
+};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tracing::{debug, info, warn};
+
+const SCHEME: &str = "iggy://";
+const MIME_TYPE: &str = "application/json";
+
+/// The resources exposed by the MCP server, addressed by `iggy://` URIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IggyResource {
+    Streams,
+    Stream {
+        stream_id: String,
+    },
+    Topic {
+        stream_id: String,
+        topic_id: String,
+    },
+    Messages {
+        stream_id: String,
+        topic_id: String,
+        partition_id: u32,
+    },
+}
+
+impl IggyResource {
+    pub fn parse(uri: &str) -> Option<Self> {
+        let path = uri.strip_prefix(SCHEME)?;
+        let segments = path.split('/').collect::<Vec<_>>();
+        match segments.as_slice() {
+            ["streams"] => Some(IggyResource::Streams),
+            ["streams", stream_id] => Some(IggyResource::Stream {
+                stream_id: stream_id.to_string(),
+            }),
+            ["streams", stream_id, "topics", topic_id] => Some(IggyResource::Topic {
+                stream_id: stream_id.to_string(),
+                topic_id: topic_id.to_string(),
+            }),
+            [
+                "streams",
+                stream_id,
+                "topics",
+                topic_id,
+                "partitions",
+                partition_id,
+                "messages",
+            ] => Some(IggyResource::Messages {
+                stream_id: stream_id.to_string(),
+                topic_id: topic_id.to_string(),
+                partition_id: partition_id.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn uri(&self) -> String {
+        match self {
+            IggyResource::Streams => format!("{SCHEME}streams"),
+            IggyResource::Stream { stream_id } => format!("{SCHEME}streams/{stream_id}"),
+            IggyResource::Topic {
+                stream_id,
+                topic_id,
+            } => format!("{SCHEME}streams/{stream_id}/topics/{topic_id}"),
+            IggyResource::Messages {
+                stream_id,
+                topic_id,
+                partition_id,
+            } => format!(
+                "{SCHEME}streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/messages"
+            ),
+        }
+    }
+
+    pub fn to_resource(&self, name: &str, description: &str) -> Resource {
+        RawResource {
+            uri: self.uri(),
+            name: name.to_owned(),
+            description: Some(description.to_owned()),
+            mime_type: Some(MIME_TYPE.to_owned()),
+            size: None,
+        }
+        .no_annotation()
+    }
+}
+
+pub fn templates() -> Vec<ResourceTemplate> {
+    [
+        (
+            "streams/{stream_id}",
+            "stream",
+            "Stream details, including its topics",
+        ),
+        (
+            "streams/{stream_id}/topics/{topic_id}",
+            "topic",
+            "Topic details, including its partitions",
+        ),
+        (
+            "streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/messages",
+            "recent_messages",
+            "The most recent messages in the partition",
+        ),
+    ]
+    .into_iter()
+    .map(|(template, name, description)| {
+        RawResourceTemplate {
+            uri_template: format!("{SCHEME}{template}"),
+            name: name.to_owned(),
+            description: Some(description.to_owned()),
+            mime_type: Some(MIME_TYPE.to_owned()),
+        }
+        .no_annotation()
+    })
+    .collect()
+}
+
+pub fn text_contents(uri: String, text: String) -> ResourceContents {
+    ResourceContents::TextResourceContents {
+        uri,
+        mime_type: Some(MIME_TYPE.to_owned()),
+        text,
+    }
+}
+
+/// Watches the topic (or the single partition) for the new messages and notifies the subscribed peer about them.
+pub async fn watch(
+    client: Arc<IggyClient>,
+    peer: Peer<RoleServer>,
+    uri: String,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    interval: Duration,
+) {
+    info!("Watching resource: {uri} for updates...");
+    let mut interval = tokio::time::interval(interval);
+    let mut offsets: Option<HashMap<u32, u64>> = None;
+    loop {
+        interval.tick().await;
+        let topic = match client.get_topic(&stream_id, &topic_id).await {
+            Ok(Some(topic)) => topic,
+            Ok(None) => {
+                warn!("Topic for subscribed resource: {uri} was not found.");
+                continue;
+            }
+            Err(error) => {
+                warn!("Failed to get topic for subscribed resource: {uri}. {error}");
+                continue;
+            }
+        };
+
+        // The current offset is 0 also for the empty partition, so the messages count is used to tell them apart.
+        let current_offsets = topic
+            .partitions
+            .iter()
+            .filter(|partition| partition_id.is_none_or(|id| id == partition.id))
+            .filter(|partition| partition.messages_count > 0)
+            .map(|partition| (partition.id, partition.current_offset))
+            .collect::<HashMap<_, _>>();
+
+        let Some(previous_offsets) = offsets.replace(current_offsets.clone()) else {
+            continue;
+        };
+
+        let updated = current_offsets.iter().any(|(partition_id, offset)| {
+            previous_offsets
+                .get(partition_id)
+                .is_none_or(|previous| offset > previous)
+        });
+        if !updated {
+            continue;
+        }
+
+        debug!("New messages available for subscribed resource: {uri}");
+        if let Err(error) = peer
+            .notify_resource_updated(ResourceUpdatedNotificationParam { uri: uri.clone() })
+            .await
+        {
+            warn!("Failed to notify about updated resource: {uri}, stopping the watcher. {error}");
+            break;
+        }
+    }
+}
@@ -18,6 +18,7 @@
 use crate::config::get_api_base_url;
 use crate::error::{IggyBenchDashboardError, Result};
 use bench_dashboard_shared::BenchmarkReportLight;
+use bench_dashboard_shared::tags::{BenchmarkTagFilter, BenchmarkTagValues};
 use bench_report::hardware::BenchmarkHardware;
 use bench_report::report::BenchmarkReport;
 use gloo::console::log;
@@ -225,13 +226,17 @@ pub fn download_test_artifacts(uuid: &Uuid) {
     }
 }
 
-pub async fn fetch_recent_benchmarks(limit: Option<u32>) -> Result<Vec<BenchmarkReportLight>> {
+pub async fn fetch_recent_benchmarks(
+    limit: Option<u32>,
+    tag_filter: &BenchmarkTagFilter,
+) -> Result<Vec<BenchmarkReportLight>> {
     check_server_health().await?;
 
     let limit_param = limit.unwrap_or(20);
     let url = format!("{}/api/recent/{}", get_api_base_url(), limit_param);
 
     let resp = Request::get(&url)
+        .query(tag_filter.query_pairs())
         .send()
         .await
         .map_err(|e| IggyBenchDashboardError::Network(e.to_string()))?;
@@ -247,3 +252,25 @@ pub async fn fetch_recent_benchmarks(limit: Option<u32>) -> Result<Vec<Benchmark
         .await
         .map_err(|e| IggyBenchDashboardError::Parse(e.to_string()))
 }
+
+pub async fn fetch_tag_values() -> Result<BenchmarkTagValues> {
+    check_server_health().await?;
+
+    let url = format!("{}/api/tags", get_api_base_url());
+
+    let resp = Request::get(&url)
+        .send()
+        .await
+        .map_err(|e| IggyBenchDashboardError::Network(e.to_string()))?;
+
+    if !resp.ok() {
+        return Err(IggyBenchDashboardError::Server(format!(
+            "Failed to fetch tag values: {}",
+            resp.status()
+        )));
+    }
+
+    resp.json()
+        .await
+        .map_err(|e| IggyBenchDashboardError::Parse(e.to_string()))
+}
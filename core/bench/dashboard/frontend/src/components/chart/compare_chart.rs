@@ -0,0 +1,190 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::api::fetch_benchmark_report_full;
+use crate::components::chart::dispose_chart;
+use crate::components::selectors::measurement_type_selector::MeasurementType;
+use bench_report::comparison::{BenchmarkComparison, ComparisonThresholds};
+use bench_report::report::BenchmarkReport;
+use charming::theme::Theme;
+use charming::{Echarts, WasmRenderer};
+use gloo::console::log;
+use std::rc::Rc;
+use uuid::Uuid;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+use yew_hooks::use_size;
+
+type CleanupFn = Box<dyn FnOnce()>;
+
+const CHART_ELEMENT_ID: &str = "compare-chart-canvas";
+
+#[derive(Properties, PartialEq)]
+pub struct CompareChartProps {
+    pub baseline_uuid: Uuid,
+    pub candidate_uuid: Uuid,
+    pub measurement_type: MeasurementType,
+    pub is_dark: bool,
+}
+
+/// Overlays the charts of two benchmark runs and lists the changes of the group metrics.
+#[function_component(CompareChart)]
+pub fn compare_chart(props: &CompareChartProps) -> Html {
+    let reports = use_state(|| None::<Rc<(BenchmarkReport, BenchmarkReport)>>);
+    let chart_node = use_node_ref();
+    let chart_size = use_size(chart_node.clone());
+    let echarts = use_state(|| None::<Echarts>);
+
+    {
+        let reports = reports.clone();
+        use_effect_with(
+            (props.baseline_uuid, props.candidate_uuid),
+            move |(baseline_uuid, candidate_uuid)| {
+                let (baseline_uuid, candidate_uuid) = (*baseline_uuid, *candidate_uuid);
+                reports.set(None);
+                spawn_local(async move {
+                    let baseline = fetch_benchmark_report_full(&baseline_uuid).await;
+                    let candidate = fetch_benchmark_report_full(&candidate_uuid).await;
+                    match (baseline, candidate) {
+                        (Ok(baseline), Ok(candidate)) => {
+                            reports.set(Some(Rc::new((baseline, candidate))))
+                        }
+                        (Err(e), _) | (_, Err(e)) => {
+                            log!(format!("Error fetching compared reports: {}", e))
+                        }
+                    }
+                });
+                Box::new(|| ()) as CleanupFn
+            },
+        );
+    }
+
+    {
+        let echarts = echarts.clone();
+        use_effect_with(
+            (
+                (*reports).clone(),
+                props.measurement_type.clone(),
+                props.is_dark,
+                chart_size,
+            ),
+            move |(reports, measurement_type, is_dark, (width, height))| {
+                if echarts.is_some() {
+                    dispose_chart(CHART_ELEMENT_ID);
+                }
+
+                let Some(reports) = reports else {
+                    return Box::new(|| ()) as CleanupFn;
+                };
+                let (baseline, candidate) = reports.as_ref();
+                let chart = match measurement_type {
+                    MeasurementType::Latency => {
+                        bench_report::create_latency_comparison_chart(baseline, candidate, *is_dark)
+                    }
+                    MeasurementType::Throughput | MeasurementType::ServerResources => {
+                        bench_report::create_throughput_comparison_chart(
+                            baseline, candidate, *is_dark,
+                        )
+                    }
+                };
+
+                let renderer = if *is_dark {
+                    WasmRenderer::new(*width, *height).theme(Theme::Dark)
+                } else {
+                    WasmRenderer::new(*width, *height).theme(Theme::Default)
+                };
+                match renderer.render(CHART_ELEMENT_ID, &chart) {
+                    Ok(chart) => echarts.set(Some(chart)),
+                    Err(e) => log!(format!("Error rendering comparison chart: {}", e)),
+                }
+
+                Box::new(|| ()) as CleanupFn
+            },
+        );
+    }
+
+    let summary = match reports.as_ref() {
+        Some(reports) => comparison_summary(&reports.0, &reports.1),
+        None => html! {},
+    };
+    let loading_class = if reports.is_none() { "visible" } else { "" };
+
+    html! {
+        <div class="compare-view">
+            {summary}
+            <div ref={chart_node} class="compare-chart">
+                <div id={CHART_ELEMENT_ID} style="width: 100%; height: 100%;"></div>
+                <div class={classes!("loading-overlay", loading_class)}>
+                    <div class="loading-spinner"></div>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn comparison_summary(baseline: &BenchmarkReport, candidate: &BenchmarkReport) -> Html {
+    let thresholds = ComparisonThresholds::default();
+    let comparison = match BenchmarkComparison::new(baseline, candidate, thresholds) {
+        Ok(comparison) => comparison,
+        Err(e) => {
+            return html! {
+                <div class="comparison-summary comparison-error">{e.to_string()}</div>
+            };
+        }
+    };
+
+    html! {
+        <div class="comparison-summary">
+            <table class="comparison-table">
+                <thead>
+                    <tr>
+                        <th>{"Group"}</th>
+                        <th>{"Metric"}</th>
+                        <th>{comparison.baseline_label.clone()}</th>
+                        <th>{comparison.candidate_label.clone()}</th>
+                        <th>{"Change"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        comparison.groups.iter().flat_map(|group| {
+                            group.deltas.iter().map(move |delta| {
+                                let class = if delta.regression {
+                                    "regression"
+                                } else if delta.is_significant(&thresholds) {
+                                    "significant"
+                                } else {
+                                    ""
+                                };
+                                html! {
+                                    <tr class={class}>
+                                        <td>{group.label.clone()}</td>
+                                        <td>{delta.metric.to_string()}</td>
+                                        <td>{format!("{:.2}", delta.baseline)}</td>
+                                        <td>{format!("{:.2}", delta.candidate)}</td>
+                                        <td>{format!("{:+.2}%", delta.change_percent)}</td>
+                                    </tr>
+                                }
+                            })
+                        }).collect::<Html>()
+                    }
+                </tbody>
+            </table>
+        </div>
+    }
+}
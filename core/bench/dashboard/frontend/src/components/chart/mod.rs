@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod compare_chart;
 mod plot_trend;
 pub mod single_chart;
 pub mod trend_chart;
@@ -34,6 +35,7 @@ pub struct PlotConfig {
 pub enum PlotType {
     Latency,
     Throughput,
+    ServerResources,
 }
 
 #[wasm_bindgen]
@@ -48,6 +48,7 @@ pub fn create_chart(
     let chart = match plot_type {
         PlotType::Latency => create_latency_trend_chart(plot_data, config.is_dark),
         PlotType::Throughput => create_throughput_trend_chart(plot_data, config.is_dark),
+        PlotType::ServerResources => create_server_resources_trend_chart(plot_data, config.is_dark),
     };
 
     let renderer = if config.is_dark {
@@ -278,3 +279,54 @@ fn create_throughput_trend_chart(data: &[BenchmarkReportLight], is_dark: bool) -
         .inner
         .tooltip(Tooltip::new().trigger(Trigger::Axis))
 }
+
+fn create_server_resources_trend_chart(data: &[BenchmarkReportLight], is_dark: bool) -> Chart {
+    // Reports created before the server profiling was introduced have no resources
+    let data: Vec<_> = data
+        .iter()
+        .filter_map(|d| {
+            d.server_resources
+                .as_ref()
+                .map(|resources| (d, &resources.summary))
+        })
+        .collect();
+
+    let average_cpu: Vec<f64> = data.iter().map(|(_, s)| s.average_cpu_usage).collect();
+    let max_cpu: Vec<f64> = data.iter().map(|(_, s)| s.max_cpu_usage).collect();
+    let max_memory: Vec<f64> = data.iter().map(|(_, s)| s.max_memory_usage_mb).collect();
+
+    let (title, subtext) = data.first().map_or_else(
+        || (ChartKind::ServerResources.to_string(), String::new()),
+        |(d, _)| {
+            (
+                trend_chart_title(&d.params, ChartKind::ServerResources),
+                d.format_params(),
+            )
+        },
+    );
+    let gitrefs = data
+        .iter()
+        .map(|(d, _)| d.params.gitref.clone().unwrap_or("Unknown".to_string()))
+        .collect();
+
+    IggyChart::new(&title, &subtext, is_dark, true)
+        .with_category_x_axis("Version", gitrefs)
+        .with_dual_y_axis("CPU Usage [%]", "Memory [MB]")
+        .add_dual_series(
+            "Average CPU Usage [%]",
+            average_cpu,
+            Symbol::Circle,
+            "#5470c6",
+            0,
+        )
+        .add_dual_series("Max CPU Usage [%]", max_cpu, Symbol::Triangle, "#ee6666", 0)
+        .add_dual_series(
+            "Max Memory Usage [MB]",
+            max_memory,
+            Symbol::Diamond,
+            "#91cc75",
+            1,
+        )
+        .inner
+        .tooltip(Tooltip::new().trigger(Trigger::Axis))
+}
@@ -105,13 +105,28 @@ pub fn single_chart(props: &SingleChartProps) -> Html {
 
                 if !is_loading {
                     let chart = match measurement_type {
-                        MeasurementType::Latency => {
-                            bench_report::create_latency_chart(data, config.is_dark, true)
-                        }
-                        MeasurementType::Throughput => {
-                            bench_report::create_throughput_chart(data, config.is_dark, true)
+                        MeasurementType::Latency => Some(bench_report::create_latency_chart(
+                            data,
+                            config.is_dark,
+                            true,
+                        )),
+                        MeasurementType::Throughput => Some(bench_report::create_throughput_chart(
+                            data,
+                            config.is_dark,
+                            true,
+                        )),
+                        MeasurementType::ServerResources => {
+                            bench_report::create_server_resources_chart(data, config.is_dark, true)
                         }
                     };
+                    let Some(chart) = chart else {
+                        log!(format!(
+                            "No {} data in the benchmark report",
+                            measurement_type
+                        ));
+                        echarts.set(None);
+                        return Box::new(|| ()) as CleanupFn;
+                    };
 
                     let renderer = if config.is_dark {
                         WasmRenderer::new(config.width, config.height).theme(Theme::Dark)
@@ -87,6 +87,7 @@ pub fn trend_chart(props: &TrendChartProps) -> Html {
                 let plot_type = match measurement_type {
                     MeasurementType::Latency => PlotType::Latency,
                     MeasurementType::Throughput => PlotType::Throughput,
+                    MeasurementType::ServerResources => PlotType::ServerResources,
                 };
 
                 let (width, height) = *size;
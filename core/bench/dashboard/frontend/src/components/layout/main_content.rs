@@ -15,10 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::components::chart::compare_chart::CompareChart;
 use crate::components::chart::single_chart::SingleChart;
 use crate::components::layout::topbar::TopBar;
 use crate::state::benchmark::use_benchmark;
-use crate::state::ui::{ViewMode, use_ui};
+use crate::state::ui::{UiAction, ViewMode, use_ui};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -37,7 +38,52 @@ pub fn main_content(props: &MainContentProps) -> Html {
 
     let is_recent_view = matches!(props.view_mode, ViewMode::RecentBenchmarks);
 
-    let content = if let Some(selected_benchmark) = &benchmark_ctx.state.selected_benchmark {
+    // The baseline picked in the recent benchmarks is compared with the selected benchmark
+    let compare_baseline = ui
+        .compare_baseline
+        .as_ref()
+        .filter(|_| is_recent_view)
+        .filter(|baseline| {
+            benchmark_ctx
+                .state
+                .selected_benchmark
+                .as_ref()
+                .is_some_and(|selected| selected.uuid != baseline.uuid)
+        });
+
+    let content = if let (Some(baseline), Some(selected_benchmark)) =
+        (compare_baseline, &benchmark_ctx.state.selected_benchmark)
+    {
+        let on_clear_comparison = {
+            let ui = ui.clone();
+            Callback::from(move |_: MouseEvent| {
+                ui.dispatch(UiAction::SetCompareBaseline(Box::new(None)));
+            })
+        };
+        html! {
+            <div class="content-wrapper">
+                <div class="chart-title">
+                    <div class="chart-title-primary">
+                        { selected_benchmark.title(&selected_measurement.to_string()) }
+                    </div>
+                    <div class="chart-title-sub">
+                        { format!("Baseline: {} vs candidate: {}", baseline.timestamp, selected_benchmark.timestamp) }
+                    </div>
+                    <button class="clear-comparison-button" onclick={on_clear_comparison}>
+                        {"Clear comparison"}
+                    </button>
+                </div>
+                <div class="single-view">
+                    <CompareChart
+                        baseline_uuid={baseline.uuid}
+                        candidate_uuid={selected_benchmark.uuid}
+                        measurement_type={selected_measurement.clone()}
+                        is_dark={props.is_dark}
+                    />
+                </div>
+            </div>
+        }
+    } else if let Some(selected_benchmark) = &benchmark_ctx.state.selected_benchmark {
         html! {
             <div class="content-wrapper">
                 <div class="chart-title">
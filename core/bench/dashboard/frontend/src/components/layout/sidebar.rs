@@ -21,6 +21,7 @@ use crate::components::selectors::benchmark_selector::BenchmarkSelector;
 use crate::components::selectors::gitref_selector::GitrefSelector;
 use crate::components::selectors::hardware_selector::HardwareSelector;
 use crate::components::selectors::recent_benchmarks_selector::RecentBenchmarksSelector;
+use crate::components::selectors::tag_filter_selector::TagFilterSelector;
 use crate::components::selectors::view_mode_selector::ViewModeSelector;
 use crate::state::benchmark::{BenchmarkAction, use_benchmark};
 use crate::state::gitref::use_gitref;
@@ -33,6 +34,8 @@ pub enum BenchmarkTab {
     Pinned,
     Balanced,
     EndToEnd,
+    Scenario,
+    Chaos,
 }
 
 #[derive(Properties, PartialEq)]
@@ -59,6 +62,8 @@ pub fn sidebar(props: &SidebarProps) -> Html {
         BenchmarkKind::BalancedProducer
         | BenchmarkKind::BalancedConsumerGroup
         | BenchmarkKind::BalancedProducerAndConsumerGroup => BenchmarkTab::Balanced,
+        BenchmarkKind::Scenario => BenchmarkTab::Scenario,
+        BenchmarkKind::Chaos => BenchmarkTab::Chaos,
         _ => BenchmarkTab::EndToEnd,
     };
 
@@ -85,6 +90,8 @@ pub fn sidebar(props: &SidebarProps) -> Html {
                 | BenchmarkKind::EndToEndProducingConsumerGroup
         )
     };
+    let is_scenario = |b: &BenchmarkKind| matches!(b, BenchmarkKind::Scenario);
+    let is_chaos = |b: &BenchmarkKind| matches!(b, BenchmarkKind::Chaos);
 
     let has_benchmarks = |f: fn(&BenchmarkKind) -> bool| {
         benchmark_ctx
@@ -111,16 +118,22 @@ pub fn sidebar(props: &SidebarProps) -> Html {
     let has_pinned_benchmarks = has_benchmarks(is_pinned);
     let has_balanced_benchmarks = has_benchmarks(is_balanced);
     let has_end_to_end_benchmarks = has_benchmarks(is_end_to_end);
+    let has_scenario_benchmarks = has_benchmarks(is_scenario);
+    let has_chaos_benchmarks = has_benchmarks(is_chaos);
 
     let pinned_benchmark_count = count_benchmarks(is_pinned);
     let balanced_benchmark_count = count_benchmarks(is_balanced);
     let end_to_end_benchmark_count = count_benchmarks(is_end_to_end);
+    let scenario_benchmark_count = count_benchmarks(is_scenario);
+    let chaos_benchmark_count = count_benchmarks(is_chaos);
 
     fn get_default_kind_for_tab(tab: &BenchmarkTab) -> BenchmarkKind {
         match tab {
             BenchmarkTab::Pinned => BenchmarkKind::PinnedProducer,
             BenchmarkTab::Balanced => BenchmarkKind::BalancedProducer,
             BenchmarkTab::EndToEnd => BenchmarkKind::EndToEndProducingConsumer,
+            BenchmarkTab::Scenario => BenchmarkKind::Scenario,
+            BenchmarkTab::Chaos => BenchmarkKind::Chaos,
         }
     }
 
@@ -157,6 +170,7 @@ pub fn sidebar(props: &SidebarProps) -> Html {
                 <h3 class="benchmarks-heading">{"Benchmarks"}</h3>
                 if is_recent_view {
                     <BenchmarkSearchBox search_query={search_query.clone()} />
+                    <TagFilterSelector />
                 }
             </div>
 
@@ -208,6 +222,34 @@ pub fn sidebar(props: &SidebarProps) -> Html {
                         >
                             { "End to End (" }{end_to_end_benchmark_count}{")" }
                         </button>
+                        <button
+                            class={classes!(
+                                "tab-button",
+                                (active_tab == BenchmarkTab::Scenario).then_some("active"),
+                                (!has_scenario_benchmarks).then_some("inactive")
+                            )}
+                            disabled={!has_scenario_benchmarks}
+                            onclick={
+                                let on_tab_click = on_tab_click.clone();
+                                Callback::from(move |_| on_tab_click.emit(BenchmarkTab::Scenario))
+                            }
+                        >
+                            { "Scenario (" }{scenario_benchmark_count}{")" }
+                        </button>
+                        <button
+                            class={classes!(
+                                "tab-button",
+                                (active_tab == BenchmarkTab::Chaos).then_some("active"),
+                                (!has_chaos_benchmarks).then_some("inactive")
+                            )}
+                            disabled={!has_chaos_benchmarks}
+                            onclick={
+                                let on_tab_click = on_tab_click.clone();
+                                Callback::from(move |_| on_tab_click.emit(BenchmarkTab::Chaos))
+                            }
+                        >
+                            { "Chaos (" }{chaos_benchmark_count}{")" }
+                        </button>
                     </div>
 
                     <div class={classes!(
@@ -228,6 +270,18 @@ pub fn sidebar(props: &SidebarProps) -> Html {
                     )}>
                         <BenchmarkSelector kind={get_default_kind_for_tab(&BenchmarkTab::EndToEnd)} />
                     </div>
+                    <div class={classes!(
+                        "tab-content",
+                        (active_tab == BenchmarkTab::Scenario).then_some("active")
+                    )}>
+                        <BenchmarkSelector kind={get_default_kind_for_tab(&BenchmarkTab::Scenario)} />
+                    </div>
+                    <div class={classes!(
+                        "tab-content",
+                        (active_tab == BenchmarkTab::Chaos).then_some("active")
+                    )}>
+                        <BenchmarkSelector kind={get_default_kind_for_tab(&BenchmarkTab::Chaos)} />
+                    </div>
                 </div>
             }
             </div>
@@ -151,6 +151,15 @@ pub fn topbar(props: &TopBarProps) -> Html {
                                     >
                                         { "Throughput" }
                                     </button>
+                                    <button
+                                        class={classes!(
+                                            "measurement-button",
+                                            (selected_measurement == MeasurementType::ServerResources).then_some("active")
+                                        )}
+                                        onclick={on_measurement_select.reform(|_| MeasurementType::ServerResources)}
+                                    >
+                                        { "Server Resources" }
+                                    </button>
                                 </div>
                             </>
                         }
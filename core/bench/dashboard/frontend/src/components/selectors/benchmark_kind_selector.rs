@@ -145,6 +145,36 @@ pub fn benchmark_kind_selector(props: &BenchmarkKindSelectorProps) -> Html {
                         <span class="benchmark-option-label">{"Producer & Consumer Group ("}{count_benchmarks(BenchmarkKind::BalancedProducerAndConsumerGroup)}{")"}</span>
                     </button>
                 </>
+            } else if matches!(props.selected_kind, BenchmarkKind::Scenario) {
+                <button
+                    class={classes!(
+                        "benchmark-option",
+                        "active",
+                        (!props.available_kinds.contains(&BenchmarkKind::Scenario)).then_some("inactive")
+                    )}
+                    onclick={
+                        let on_kind_select = props.on_kind_select.clone();
+                        move |_| on_kind_select.emit(BenchmarkKind::Scenario)
+                    }
+                >
+                    <span class="benchmark-option-icon">{"⇅"}</span>
+                    <span class="benchmark-option-label">{"Mixed Workload ("}{count_benchmarks(BenchmarkKind::Scenario)}{")"}</span>
+                </button>
+            } else if matches!(props.selected_kind, BenchmarkKind::Chaos) {
+                <button
+                    class={classes!(
+                        "benchmark-option",
+                        "active",
+                        (!props.available_kinds.contains(&BenchmarkKind::Chaos)).then_some("inactive")
+                    )}
+                    onclick={
+                        let on_kind_select = props.on_kind_select.clone();
+                        move |_| on_kind_select.emit(BenchmarkKind::Chaos)
+                    }
+                >
+                    <span class="benchmark-option-icon">{"⚡"}</span>
+                    <span class="benchmark-option-label">{"Fault Injection ("}{count_benchmarks(BenchmarkKind::Chaos)}{")"}</span>
+                </button>
             } else {
                 <>
                     <button
@@ -66,6 +66,8 @@ pub fn benchmark_selector(props: &BenchmarkSelectorProps) -> Html {
                         | BenchmarkKind::EndToEndProducingConsumerGroup
                 )
             }
+            BenchmarkKind::Scenario => matches!(k, BenchmarkKind::Scenario),
+            BenchmarkKind::Chaos => matches!(k, BenchmarkKind::Chaos),
         })
         .cloned()
         .collect();
@@ -23,6 +23,7 @@ use yew::prelude::*;
 pub enum MeasurementType {
     Latency,
     Throughput,
+    ServerResources,
 }
 
 impl Display for MeasurementType {
@@ -30,6 +31,7 @@ impl Display for MeasurementType {
         match self {
             MeasurementType::Latency => write!(f, "Latency"),
             MeasurementType::Throughput => write!(f, "Throughput"),
+            MeasurementType::ServerResources => write!(f, "Server Resources"),
         }
     }
 }
@@ -41,6 +43,7 @@ impl FromStr for MeasurementType {
         match s {
             "Latency" => Ok(MeasurementType::Latency),
             "Throughput" => Ok(MeasurementType::Throughput),
+            "Server Resources" => Ok(MeasurementType::ServerResources),
             _ => Err(()),
         }
     }
@@ -54,24 +57,30 @@ pub struct MeasurementTypeSelectorProps {
 
 #[function_component(MeasurementTypeSelector)]
 pub fn measurement_type_selector(props: &MeasurementTypeSelectorProps) -> Html {
-    let is_latency = matches!(props.selected_measurement, MeasurementType::Latency);
+    let is_selected = |measurement: MeasurementType| props.selected_measurement == measurement;
 
     html! {
         <div class="view-mode-container">
             <h3>{"Measurements"}</h3>
             <div class="segmented-control">
                 <button
-                    class={if is_latency { "segment active" } else { "segment" }}
+                    class={if is_selected(MeasurementType::Latency) { "segment active" } else { "segment" }}
                     onclick={props.on_measurement_select.reform(|_| MeasurementType::Latency)}
                 >
                     {"Latency"}
                 </button>
                 <button
-                    class={if !is_latency { "segment active" } else { "segment" }}
+                    class={if is_selected(MeasurementType::Throughput) { "segment active" } else { "segment" }}
                     onclick={props.on_measurement_select.reform(|_| MeasurementType::Throughput)}
                 >
                     {"Throughput"}
                 </button>
+                <button
+                    class={if is_selected(MeasurementType::ServerResources) { "segment active" } else { "segment" }}
+                    onclick={props.on_measurement_select.reform(|_| MeasurementType::ServerResources)}
+                >
+                    {"Server Resources"}
+                </button>
             </div>
         </div>
     }
@@ -22,4 +22,5 @@ pub mod gitref_selector;
 pub mod hardware_selector;
 pub mod measurement_type_selector;
 pub mod recent_benchmarks_selector;
+pub mod tag_filter_selector;
 pub mod view_mode_selector;
@@ -17,7 +17,10 @@
 
 use crate::{
     api,
-    state::benchmark::{BenchmarkAction, use_benchmark},
+    state::{
+        benchmark::{BenchmarkAction, use_benchmark},
+        ui::{UiAction, use_ui},
+    },
 };
 use bench_dashboard_shared::BenchmarkReportLight;
 use chrono::{DateTime, Utc};
@@ -56,15 +59,17 @@ pub struct RecentBenchmarksSelectorProps {
 #[function_component(RecentBenchmarksSelector)]
 pub fn recent_benchmarks_selector(props: &RecentBenchmarksSelectorProps) -> Html {
     let benchmark_ctx = use_benchmark();
+    let ui_state = use_ui();
 
     let recent_benchmarks = use_state(Vec::<BenchmarkReportLight>::new);
 
     let fetch_benchmarks = {
         let recent_benchmarks = recent_benchmarks.clone();
         let limit = props.limit;
+        let tag_filter = ui_state.tag_filter.clone();
 
         use_async(async move {
-            match api::fetch_recent_benchmarks(Some(limit)).await {
+            match api::fetch_recent_benchmarks(Some(limit), &tag_filter).await {
                 Ok(mut data) => {
                     data.sort_by(|a, b| {
                         let parse_a = DateTime::parse_from_rfc3339(&a.timestamp);
@@ -87,12 +92,24 @@ pub fn recent_benchmarks_selector(props: &RecentBenchmarksSelectorProps) -> Html
 
     {
         let fetch_benchmarks_effect = fetch_benchmarks.clone();
-        use_effect_with((), move |_| {
+        use_effect_with(ui_state.tag_filter.clone(), move |_| {
             fetch_benchmarks_effect.run();
             || ()
         });
     }
 
+    let on_compare_toggle = {
+        let ui_state = ui_state.clone();
+        Callback::from(move |benchmark: BenchmarkReportLight| {
+            let is_baseline = ui_state
+                .compare_baseline
+                .as_ref()
+                .is_some_and(|baseline| baseline.uuid == benchmark.uuid);
+            let baseline = (!is_baseline).then_some(benchmark);
+            ui_state.dispatch(UiAction::SetCompareBaseline(Box::new(baseline)));
+        })
+    };
+
     let on_benchmark_select = {
         let benchmark_ctx = benchmark_ctx.clone();
         Callback::from(move |benchmark: BenchmarkReportLight| {
@@ -168,6 +185,16 @@ pub fn recent_benchmarks_selector(props: &RecentBenchmarksSelectorProps) -> Html
                                     on_benchmark_select.emit(benchmark_clone.clone());
                                 })
                             };
+                            let on_compare = {
+                                let on_compare_toggle = on_compare_toggle.clone();
+                                let benchmark_clone = benchmark.clone();
+                                Callback::from(move |e: MouseEvent| {
+                                    e.stop_propagation();
+                                    on_compare_toggle.emit(benchmark_clone.clone());
+                                })
+                            };
+                            let is_baseline = ui_state.compare_baseline.as_ref()
+                                .is_some_and(|baseline| baseline.uuid == benchmark.uuid);
                             let timestamp_display = format_relative_time(&benchmark.timestamp);
 
                             let is_selected = benchmark_ctx.state.selected_benchmark.as_ref()
@@ -183,6 +210,13 @@ pub fn recent_benchmarks_selector(props: &RecentBenchmarksSelectorProps) -> Html
                                                 {benchmark.params.benchmark_kind.to_string()}
                                             </div>
                                             <div class="benchmark-list-item-time">{timestamp_display}</div>
+                                            <button
+                                                class={classes!("compare-button", is_baseline.then_some("active"))}
+                                                title={if is_baseline { "Stop comparing with this benchmark" } else { "Compare other benchmarks with this one" }}
+                                                onclick={on_compare}
+                                            >
+                                                {if is_baseline { "Baseline" } else { "Compare" }}
+                                            </button>
                                         </div>
 
                                         <div class="benchmark-list-item-details">
@@ -197,6 +231,18 @@ pub fn recent_benchmarks_selector(props: &RecentBenchmarksSelectorProps) -> Html
                                                 </div>
                                             </div>
 
+                                            if !benchmark.tags.is_empty() {
+                                                <div class="benchmark-list-item-subtitle benchmark-tags">
+                                                    if let Some(branch) = benchmark.tags.branch.as_deref() {
+                                                        <span class="benchmark-list-item-label">{"Branch:"}</span>
+                                                        <span>{branch}</span>
+                                                    }
+                                                    {benchmark.tags.labels.iter().map(|(key, value)| html! {
+                                                        <span class="benchmark-tag">{format!("{key}={value}")}</span>
+                                                    }).collect::<Html>()}
+                                                </div>
+                                            }
+
                                             <div class="benchmark-list-item-metrics">
                                                 <div class="metrics-group">
                                                     {if let Some(metrics) = benchmark.group_metrics.first() {
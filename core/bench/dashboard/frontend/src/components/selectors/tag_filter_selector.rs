@@ -0,0 +1,102 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::api;
+use crate::state::ui::{UiAction, use_ui};
+use bench_dashboard_shared::tags::{BenchmarkTagFilter, BenchmarkTagValues};
+use gloo::console::log;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlSelectElement;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+const ANY_VALUE: &str = "";
+
+#[function_component(TagFilterSelector)]
+pub fn tag_filter_selector() -> Html {
+    let ui_state = use_ui();
+    let tag_values = use_state(BenchmarkTagValues::default);
+
+    {
+        let tag_values = tag_values.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match api::fetch_tag_values().await {
+                    Ok(values) => tag_values.set(values),
+                    Err(e) => log!(format!("Error fetching tag values: {}", e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    // Every select replaces a single field of the current filter
+    let on_select = |update: fn(&mut BenchmarkTagFilter, Option<String>)| {
+        let ui_state = ui_state.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e
+                .target()
+                .and_then(|t| t.dyn_into::<HtmlSelectElement>().ok())
+            {
+                let value = select.value();
+                let mut tag_filter = ui_state.tag_filter.clone();
+                update(&mut tag_filter, (value != ANY_VALUE).then_some(value));
+                ui_state.dispatch(UiAction::SetTagFilter(tag_filter));
+            }
+        })
+    };
+
+    let tag_select = |label: &str, values: &[String], selected: &Option<String>, onchange| {
+        let selected = selected.as_deref().unwrap_or(ANY_VALUE);
+        html! {
+            <div class="gitref-select tag-select">
+                <h3>{label}</h3>
+                <select {onchange} disabled={values.is_empty()}>
+                    <option value={ANY_VALUE} selected={selected == ANY_VALUE}>{"All"}</option>
+                    {
+                        values.iter().map(|value| {
+                            html! {
+                                <option value={value.clone()} selected={value == selected}>
+                                    {value}
+                                </option>
+                            }
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+        }
+    };
+
+    let tag_filter = &ui_state.tag_filter;
+    html! {
+        <div class="tag-filter">
+            { tag_select(
+                "Branch",
+                &tag_values.branches,
+                &tag_filter.branch,
+                on_select(|filter, value| filter.branch = value),
+            ) }
+            { tag_select(
+                "Label",
+                &tag_values.labels,
+                &tag_filter.labels,
+                on_select(|filter, value| filter.labels = value),
+            ) }
+        </div>
+    }
+}
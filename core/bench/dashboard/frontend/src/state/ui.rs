@@ -16,6 +16,8 @@
 // under the License.
 
 use crate::components::selectors::measurement_type_selector::MeasurementType;
+use bench_dashboard_shared::BenchmarkReportLight;
+use bench_dashboard_shared::tags::BenchmarkTagFilter;
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -33,6 +35,9 @@ pub struct UiState {
     pub selected_measurement: MeasurementType,
     pub is_benchmark_tooltip_visible: bool,
     pub is_server_stats_tooltip_visible: bool,
+    pub tag_filter: BenchmarkTagFilter,
+    /// Benchmark the selected one is compared with
+    pub compare_baseline: Option<BenchmarkReportLight>,
 }
 
 impl Default for UiState {
@@ -42,6 +47,8 @@ impl Default for UiState {
             selected_measurement: MeasurementType::Latency,
             is_benchmark_tooltip_visible: false,
             is_server_stats_tooltip_visible: false,
+            tag_filter: BenchmarkTagFilter::default(),
+            compare_baseline: None,
         }
     }
 }
@@ -51,6 +58,8 @@ pub enum UiAction {
     ToggleBenchmarkTooltip,
     ToggleServerStatsTooltip,
     SetViewMode(ViewMode),
+    SetTagFilter(BenchmarkTagFilter),
+    SetCompareBaseline(Box<Option<BenchmarkReportLight>>),
 }
 
 impl Reducible for UiState {
@@ -74,6 +83,14 @@ impl Reducible for UiState {
                 view_mode: vm,
                 ..(*self).clone()
             },
+            UiAction::SetTagFilter(tag_filter) => UiState {
+                tag_filter,
+                ..(*self).clone()
+            },
+            UiAction::SetCompareBaseline(baseline) => UiState {
+                compare_baseline: *baseline,
+                ..(*self).clone()
+            },
         };
         next.into()
     }
@@ -28,6 +28,18 @@ ARGS+=(--host "${HOST}")
 ARGS+=(--port "${PORT}")
 ARGS+=(--results-dir "${RESULTS_DIR}")
 
+if [ -n "${RETENTION_MAX_AGE_DAYS}" ]; then
+    ARGS+=(--retention-max-age-days "${RETENTION_MAX_AGE_DAYS}")
+fi
+
+if [ -n "${RETENTION_MAX_RUNS}" ]; then
+    ARGS+=(--retention-max-runs "${RETENTION_MAX_RUNS}")
+fi
+
+if [ -n "${UPLOAD_TOKEN}" ]; then
+    echo "Benchmark uploads enabled, UPLOAD_TOKEN set"
+fi
+
 if [ -n "${GITHUB_TOKEN}" ]; then
     echo "Polling GitHub enabled, GITHUB_TOKEN set"
     ARGS+=(poll-github)
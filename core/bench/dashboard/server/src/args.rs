@@ -15,9 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::cache::RetentionPolicy;
 use clap::{CommandFactory, Parser, Subcommand, error::ErrorKind};
 use serde::Deserialize;
 use std::path::PathBuf;
+use std::time::Duration;
+
+const UPLOAD_TOKEN_ENV: &str = "UPLOAD_TOKEN";
 
 #[derive(Debug, Subcommand, Deserialize)]
 pub enum PollGithub {
@@ -58,6 +62,23 @@ pub struct IggyBenchDashboardServerArgs {
     #[arg(long, default_value = "*")]
     pub cors_origins: String,
 
+    /// Maximum size of the uploaded benchmark report in megabytes, uploads are enabled
+    /// only when the UPLOAD_TOKEN env variable is set
+    #[arg(long, default_value_t = 64)]
+    pub upload_limit_mb: usize,
+
+    /// Remove the benchmark runs older than the given number of days
+    #[arg(long)]
+    pub retention_max_age_days: Option<u64>,
+
+    /// Keep only the given number of the newest runs of every benchmark on every hardware and branch
+    #[arg(long)]
+    pub retention_max_runs: Option<usize>,
+
+    /// How often to apply the retention policy, in seconds
+    #[arg(long, default_value_t = 3600)]
+    pub retention_interval_seconds: u64,
+
     /// Poll GitHub for new artifacts
     #[command(subcommand)]
     pub github: Option<PollGithub>,
@@ -91,6 +112,14 @@ impl IggyBenchDashboardServerArgs {
             .exit();
         }
 
+        if self.retention_max_runs == Some(0) {
+            cmd.error(
+                ErrorKind::InvalidValue,
+                "Retention max runs must be greater than 0",
+            )
+            .exit();
+        }
+
         if self.github.is_some() && std::env::var("GITHUB_TOKEN").is_err() {
             cmd.error(
                 ErrorKind::InvalidValue,
@@ -100,6 +129,25 @@ impl IggyBenchDashboardServerArgs {
         }
     }
 
+    pub fn upload_token(&self) -> Option<String> {
+        std::env::var(UPLOAD_TOKEN_ENV)
+            .ok()
+            .filter(|token| !token.is_empty())
+    }
+
+    pub fn retention_policy(&self) -> Option<RetentionPolicy> {
+        if self.retention_max_age_days.is_none() && self.retention_max_runs.is_none() {
+            return None;
+        }
+
+        Some(RetentionPolicy {
+            max_age: self
+                .retention_max_age_days
+                .map(|days| Duration::from_secs(days * 24 * 60 * 60)),
+            max_runs: self.retention_max_runs,
+        })
+    }
+
     pub fn server_addr(&self) -> String {
         format!("{}:{}", self.host, self.port)
     }
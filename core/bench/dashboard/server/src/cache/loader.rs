@@ -17,8 +17,11 @@
 
 use super::{BenchmarkCache, Result};
 use crate::error::IggyBenchDashboardServerError;
-use bench_dashboard_shared::BenchmarkReportLight;
-use std::path::Path;
+use bench_dashboard_shared::{
+    BenchmarkReportLight,
+    tags::{BenchmarkTags, TAGS_FILE_NAME},
+};
+use std::path::{Path, PathBuf};
 use tokio::io::AsyncReadExt;
 use tracing::{error, info};
 
@@ -71,38 +74,7 @@ impl BenchmarkCache {
 
             info!("Loaded light benchmark report for {:?}", &entry.path());
 
-            let identifier = if let Some(identifier) = &light_report.hardware.identifier {
-                identifier
-            } else {
-                error!(
-                    "No identifier found in benchmark report: {:#?}",
-                    &entry.path()
-                );
-                return;
-            };
-
-            let gitref = if let Some(gitref) = &light_report.params.gitref {
-                gitref
-            } else {
-                error!("No gitref found in benchmark report: {:#?}", &entry.path());
-                return;
-            };
-
-            // Update hardware to gitref mapping
-            self.hardware_to_gitref
-                .entry(identifier.clone())
-                .or_default()
-                .insert(gitref.clone());
-
-            // Update gitref to benchmarks mapping
-            self.gitref_to_benchmarks
-                .entry(gitref.clone())
-                .or_default()
-                .insert(light_report.uuid);
-
-            // Store the benchmark report
-            self.benchmarks
-                .insert(light_report.uuid, (light_report, path));
+            self.insert_benchmark(light_report, path);
         });
 
         info!(
@@ -113,13 +85,46 @@ impl BenchmarkCache {
         Ok(())
     }
 
+    /// Adds the benchmark to the cache, skipping the reports without hardware identifier or gitref
+    pub fn insert_benchmark(&self, light_report: BenchmarkReportLight, path: PathBuf) {
+        let identifier = if let Some(identifier) = &light_report.hardware.identifier {
+            identifier
+        } else {
+            error!("No identifier found in benchmark report: {:#?}", &path);
+            return;
+        };
+
+        let gitref = if let Some(gitref) = &light_report.params.gitref {
+            gitref
+        } else {
+            error!("No gitref found in benchmark report: {:#?}", &path);
+            return;
+        };
+
+        // Update hardware to gitref mapping
+        self.hardware_to_gitref
+            .entry(identifier.clone())
+            .or_default()
+            .insert(gitref.clone());
+
+        // Update gitref to benchmarks mapping
+        self.gitref_to_benchmarks
+            .entry(gitref.clone())
+            .or_default()
+            .insert(light_report.uuid);
+
+        // Store the benchmark report
+        self.benchmarks
+            .insert(light_report.uuid, (light_report, path));
+    }
+
     pub fn load_light_report(&self, path: &Path) -> Result<BenchmarkReportLight> {
         let data = std::fs::read_to_string(path).map_err(|e| {
             error!("Failed to read benchmark file {:?}: {}", path, e);
             IggyBenchDashboardServerError::Io(e)
         })?;
 
-        serde_json::from_str(&data).map_err(|e| {
+        let mut light_report: BenchmarkReportLight = serde_json::from_str(&data).map_err(|e| {
             error!(
                 "Failed to parse JSON from {:?}: {}. Content: {}",
                 path,
@@ -131,7 +136,24 @@ impl BenchmarkCache {
                 }
             );
             IggyBenchDashboardServerError::InvalidJson(e.to_string())
-        })
+        })?;
+
+        if let Some(tags) = path
+            .parent()
+            .and_then(|dir| self.load_tags(&dir.join(TAGS_FILE_NAME)))
+        {
+            light_report.tags = tags;
+        }
+
+        Ok(light_report)
+    }
+
+    /// Tags are optional, the runs downloaded before tagging was introduced have none
+    fn load_tags(&self, path: &Path) -> Option<BenchmarkTags> {
+        let data = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&data)
+            .map_err(|e| error!("Failed to parse tags from {:?}: {}", path, e))
+            .ok()
     }
 
     async fn load_gh_workflows(&self) {
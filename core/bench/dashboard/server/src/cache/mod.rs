@@ -29,9 +29,11 @@ use uuid::Uuid;
 
 mod loader;
 mod query;
+mod retention;
 mod storage;
 mod watcher;
 
+pub use retention::{RetentionPolicy, RetentionPruner};
 pub use watcher::CacheWatcher;
 
 pub type Result<T> = std::result::Result<T, IggyBenchDashboardServerError>;
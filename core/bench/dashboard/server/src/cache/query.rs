@@ -16,10 +16,13 @@
 // under the License.
 
 use super::BenchmarkCache;
-use bench_dashboard_shared::BenchmarkReportLight;
+use bench_dashboard_shared::{
+    BenchmarkReportLight,
+    tags::{BenchmarkTagFilter, BenchmarkTagValues},
+};
 use bench_report::hardware::BenchmarkHardware;
 use chrono::{self, DateTime, FixedOffset};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 impl BenchmarkCache {
     pub fn get_hardware_configurations(&self) -> Vec<BenchmarkHardware> {
@@ -80,25 +83,26 @@ impl BenchmarkCache {
         result
     }
 
+    /// Returns the runs of the benchmark matching the tag filter, optionally limited to
+    /// the hardware identifier, sorted by the gitref date
     pub fn get_benchmark_trend_data(
         &self,
         params_identifier: &str,
-        hardware: &str,
+        hardware: Option<&str>,
+        filter: &BenchmarkTagFilter,
     ) -> Option<Vec<BenchmarkReportLight>> {
         let mut matching_reports = Vec::new();
 
         for entry in self.benchmarks.iter() {
             let (report, _) = entry.value();
 
-            if let Some(identifier) = &report.hardware.identifier {
-                if identifier != hardware {
-                    continue;
-                }
-            } else {
+            if hardware
+                .is_some_and(|hardware| report.hardware.identifier.as_deref() != Some(hardware))
+            {
                 continue;
             }
 
-            if report.params.params_identifier == params_identifier {
+            if report.params.params_identifier == params_identifier && filter.matches(report) {
                 matching_reports.push(report.clone());
             }
         }
@@ -125,11 +129,16 @@ impl BenchmarkCache {
             .unwrap_or_else(|_| DateTime::parse_from_rfc3339("1970-01-01T00:00:00Z").unwrap())
     }
 
-    /// Get the most recently added benchmarks, sorted by creation timestamp (newest first)
-    pub fn get_recent_benchmarks(&self, limit: usize) -> Vec<BenchmarkReportLight> {
+    /// Get the most recently added benchmarks matching the tag filter, sorted by creation timestamp (newest first)
+    pub fn get_recent_benchmarks(
+        &self,
+        limit: usize,
+        filter: &BenchmarkTagFilter,
+    ) -> Vec<BenchmarkReportLight> {
         let mut recent_benchmarks: Vec<BenchmarkReportLight> = self
             .benchmarks
             .iter()
+            .filter(|entry| filter.matches(&entry.value().0))
             .map(|entry| entry.value().0.clone())
             .collect();
 
@@ -148,4 +157,193 @@ impl BenchmarkCache {
 
         recent_benchmarks
     }
+
+    /// Get all the tag values of the cached benchmarks, sorted and deduplicated
+    pub fn get_tag_values(&self) -> BenchmarkTagValues {
+        let mut branches = BTreeSet::new();
+        let mut commits = BTreeSet::new();
+        let mut hardware = BTreeSet::new();
+        let mut labels = BTreeSet::new();
+
+        for entry in self.benchmarks.iter() {
+            let (report, _) = entry.value();
+            if let Some(branch) = &report.tags.branch {
+                branches.insert(branch.clone());
+            }
+            if let Some(commit) = report.commit_tag() {
+                commits.insert(commit.to_owned());
+            }
+            if let Some(identifier) = report.hardware_tag() {
+                hardware.insert(identifier.to_owned());
+            }
+            for (key, value) in &report.tags.labels {
+                labels.insert(format!("{key}={value}"));
+            }
+        }
+
+        BenchmarkTagValues {
+            branches: branches.into_iter().collect(),
+            commits: commits.into_iter().collect(),
+            hardware: hardware.into_iter().collect(),
+            labels: labels.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use uuid::Uuid;
+
+    fn report(
+        timestamp: &str,
+        gitref_date: &str,
+        hardware: &str,
+        branch: &str,
+    ) -> BenchmarkReportLight {
+        let mut report = BenchmarkReportLight {
+            timestamp: timestamp.to_owned(),
+            uuid: Uuid::new_v4(),
+            ..Default::default()
+        };
+        report.hardware.identifier = Some(hardware.to_owned());
+        report.params.gitref = Some(format!("gitref_{gitref_date}"));
+        report.params.gitref_date = Some(gitref_date.to_owned());
+        report.params.params_identifier = "bench".to_owned();
+        report.tags.branch = Some(branch.to_owned());
+        report
+    }
+
+    async fn cache(reports: &[&BenchmarkReportLight]) -> (BenchmarkCache, TempDir) {
+        let results_dir = TempDir::new().unwrap();
+        let cache = BenchmarkCache::new(results_dir.path().to_path_buf()).await;
+        for report in reports {
+            cache.insert_benchmark(
+                (*report).clone(),
+                results_dir
+                    .path()
+                    .join(report.uuid.to_string())
+                    .join("report.json"),
+            );
+        }
+        (cache, results_dir)
+    }
+
+    fn branch_filter(branch: &str) -> BenchmarkTagFilter {
+        BenchmarkTagFilter {
+            branch: Some(branch.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn uuids(reports: &[BenchmarkReportLight]) -> Vec<Uuid> {
+        reports.iter().map(|report| report.uuid).collect()
+    }
+
+    #[tokio::test]
+    async fn trend_data_should_be_filtered_by_tags_and_hardware_and_sorted_by_gitref_date() {
+        let newer = report(
+            "2025-01-01T00:00:00Z",
+            "2025-01-02T00:00:00Z",
+            "runner",
+            "master",
+        );
+        let older = report(
+            "2025-01-03T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "master",
+        );
+        let other_branch = report(
+            "2025-01-01T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "feature",
+        );
+        let other_hardware = report(
+            "2025-01-01T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "other",
+            "master",
+        );
+        let (cache, _dir) = cache(&[&newer, &older, &other_branch, &other_hardware]).await;
+
+        let trend = cache
+            .get_benchmark_trend_data("bench", Some("runner"), &branch_filter("master"))
+            .unwrap();
+        assert_eq!(uuids(&trend), vec![older.uuid, newer.uuid]);
+
+        let trend = cache
+            .get_benchmark_trend_data("bench", None, &branch_filter("master"))
+            .unwrap();
+        assert_eq!(trend.len(), 3);
+
+        assert!(
+            cache
+                .get_benchmark_trend_data("bench", None, &branch_filter("missing"))
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn recent_benchmarks_should_be_filtered_by_tags_and_limited() {
+        let first = report(
+            "2025-01-01T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "master",
+        );
+        let second = report(
+            "2025-01-02T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "master",
+        );
+        let third = report(
+            "2025-01-03T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "master",
+        );
+        let other_branch = report(
+            "2025-01-04T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "feature",
+        );
+        let (cache, _dir) = cache(&[&first, &second, &third, &other_branch]).await;
+
+        let recent = cache.get_recent_benchmarks(2, &branch_filter("master"));
+
+        assert_eq!(uuids(&recent), vec![third.uuid, second.uuid]);
+    }
+
+    #[tokio::test]
+    async fn tag_values_should_be_sorted_and_deduplicated() {
+        let mut first = report(
+            "2025-01-01T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "runner",
+            "master",
+        );
+        first
+            .tags
+            .labels
+            .insert("nightly".to_owned(), "true".to_owned());
+        let second = report(
+            "2025-01-02T00:00:00Z",
+            "2025-01-01T00:00:00Z",
+            "other",
+            "master",
+        );
+        let (cache, _dir) = cache(&[&first, &second]).await;
+
+        let values = cache.get_tag_values();
+
+        assert_eq!(values.branches, vec!["master"]);
+        assert_eq!(values.commits, vec!["gitref_2025-01-01T00:00:00Z"]);
+        assert_eq!(values.hardware, vec!["other", "runner"]);
+        assert_eq!(values.labels, vec!["nightly=true"]);
+    }
 }
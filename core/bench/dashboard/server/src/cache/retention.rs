@@ -0,0 +1,271 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::BenchmarkCache;
+use chrono::{DateTime, Utc};
+use std::{cmp::Reverse, collections::HashMap, sync::Arc, time::Duration};
+use tokio::{sync::watch, task::JoinHandle, time::sleep};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Policy of removing the old benchmark runs from the results directory.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    /// Runs older than this are removed
+    pub max_age: Option<Duration>,
+    /// Only this many newest runs of every benchmark on every hardware and branch are kept
+    pub max_runs: Option<usize>,
+}
+
+/// Identifies the runs of the same benchmark, the `max_runs` is applied to each of them.
+type SeriesKey = (Option<String>, String, Option<String>);
+
+impl BenchmarkCache {
+    /// Removes the runs violating the retention policy from the cache and the results directory,
+    /// returns the number of removed runs.
+    pub async fn apply_retention_policy(&self, policy: &RetentionPolicy) -> usize {
+        let expired = self.select_expired_benchmarks(policy, Utc::now());
+        let mut removed = 0;
+        for uuid in expired {
+            let Some(report_path) = self.remove_benchmark(&uuid) else {
+                continue;
+            };
+            let Some(benchmark_dir) = report_path
+                .parent()
+                .filter(|dir| dir.starts_with(&self.results_dir) && *dir != self.results_dir)
+            else {
+                error!(
+                    "Refusing to remove benchmark {uuid} outside of the results directory: {}",
+                    report_path.display()
+                );
+                continue;
+            };
+
+            info!(
+                "Removing benchmark {uuid} due to the retention policy: {}",
+                benchmark_dir.display()
+            );
+            match tokio::fs::remove_dir_all(benchmark_dir).await {
+                Ok(()) => removed += 1,
+                Err(e) => error!(
+                    "Failed to remove benchmark directory {}: {}",
+                    benchmark_dir.display(),
+                    e
+                ),
+            }
+        }
+        removed
+    }
+
+    fn select_expired_benchmarks(&self, policy: &RetentionPolicy, now: DateTime<Utc>) -> Vec<Uuid> {
+        let mut expired = Vec::new();
+        let mut series: HashMap<SeriesKey, Vec<(DateTime<Utc>, Uuid)>> = HashMap::new();
+
+        for entry in self.benchmarks.iter() {
+            let (report, _) = entry.value();
+            let Ok(timestamp) = DateTime::parse_from_rfc3339(&report.timestamp)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+            else {
+                warn!(
+                    "Skipping benchmark {} with invalid timestamp '{}' in the retention policy",
+                    report.uuid, report.timestamp
+                );
+                continue;
+            };
+
+            let too_old = policy.max_age.is_some_and(|max_age| {
+                now.signed_duration_since(timestamp)
+                    .to_std()
+                    .is_ok_and(|age| age > max_age)
+            });
+            if too_old {
+                expired.push(report.uuid);
+                continue;
+            }
+
+            let key = (
+                report.hardware.identifier.clone(),
+                report.params.params_identifier.clone(),
+                report.tags.branch.clone(),
+            );
+            series
+                .entry(key)
+                .or_default()
+                .push((timestamp, report.uuid));
+        }
+
+        if let Some(max_runs) = policy.max_runs {
+            for runs in series.values_mut() {
+                runs.sort_by_key(|(timestamp, _)| Reverse(*timestamp));
+                expired.extend(runs.iter().skip(max_runs).map(|(_, uuid)| *uuid));
+            }
+        }
+
+        expired
+    }
+}
+
+/// A long-running background task that periodically applies the retention policy.
+pub struct RetentionPruner {
+    shutdown_tx: watch::Sender<bool>,
+    join_handle: JoinHandle<()>,
+}
+
+impl RetentionPruner {
+    pub fn start(cache: Arc<BenchmarkCache>, policy: RetentionPolicy, interval: Duration) -> Self {
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        let join_handle = tokio::spawn(async move {
+            info!(
+                "Applying retention policy {:?} every {} seconds",
+                policy,
+                interval.as_secs()
+            );
+            loop {
+                let removed = cache.apply_retention_policy(&policy).await;
+                if removed > 0 {
+                    info!("Removed {removed} benchmarks due to the retention policy");
+                }
+
+                tokio::select! {
+                    _ = shutdown_rx.changed() => {
+                        if *shutdown_rx.borrow() {
+                            info!("Shutdown signal received, stopping retention pruner");
+                            break;
+                        }
+                    }
+                    _ = sleep(interval) => {},
+                }
+            }
+        });
+
+        Self {
+            shutdown_tx,
+            join_handle,
+        }
+    }
+
+    pub async fn shutdown(self) {
+        if let Err(err) = self.shutdown_tx.send(true) {
+            error!("Error sending shutdown signal: {:?}", err);
+        }
+        if let Err(e) = self.join_handle.await {
+            error!("RetentionPruner task join error: {:?}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bench_dashboard_shared::BenchmarkReportLight;
+    use chrono::Duration as ChronoDuration;
+    use tempfile::TempDir;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn report(
+        timestamp: &str,
+        params_identifier: &str,
+        branch: Option<&str>,
+    ) -> BenchmarkReportLight {
+        let mut report = BenchmarkReportLight {
+            timestamp: timestamp.to_owned(),
+            uuid: Uuid::new_v4(),
+            ..Default::default()
+        };
+        report.hardware.identifier = Some("hardware".to_owned());
+        report.params.gitref = Some("master".to_owned());
+        report.params.params_identifier = params_identifier.to_owned();
+        report.tags.branch = branch.map(str::to_owned);
+        report
+    }
+
+    async fn cache(reports: &[&BenchmarkReportLight]) -> (BenchmarkCache, TempDir) {
+        let results_dir = TempDir::new().unwrap();
+        let cache = BenchmarkCache::new(results_dir.path().to_path_buf()).await;
+        for report in reports {
+            cache.insert_benchmark(
+                (*report).clone(),
+                results_dir
+                    .path()
+                    .join(report.uuid.to_string())
+                    .join("report.json"),
+            );
+        }
+        (cache, results_dir)
+    }
+
+    fn days_ago(now: DateTime<Utc>, days: i64) -> String {
+        (now - ChronoDuration::days(days)).to_rfc3339()
+    }
+
+    #[tokio::test]
+    async fn should_select_benchmarks_older_than_max_age() {
+        let now = Utc::now();
+        let old = report(&days_ago(now, 10), "bench", None);
+        let recent = report(&days_ago(now, 1), "bench", None);
+        let (cache, _dir) = cache(&[&old, &recent]).await;
+        let policy = RetentionPolicy {
+            max_age: Some(7 * DAY),
+            max_runs: None,
+        };
+
+        assert_eq!(
+            cache.select_expired_benchmarks(&policy, now),
+            vec![old.uuid]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_keep_max_runs_newest_benchmarks_of_each_series() {
+        let now = Utc::now();
+        let oldest = report(&days_ago(now, 3), "bench", Some("master"));
+        let older = report(&days_ago(now, 2), "bench", Some("master"));
+        let newest = report(&days_ago(now, 1), "bench", Some("master"));
+        let other_branch = report(&days_ago(now, 3), "bench", Some("feature"));
+        let other_benchmark = report(&days_ago(now, 3), "other", Some("master"));
+        let (cache, _dir) =
+            cache(&[&oldest, &older, &newest, &other_branch, &other_benchmark]).await;
+        let policy = RetentionPolicy {
+            max_age: None,
+            max_runs: Some(2),
+        };
+
+        assert_eq!(
+            cache.select_expired_benchmarks(&policy, now),
+            vec![oldest.uuid]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_skip_benchmarks_with_invalid_timestamp() {
+        let now = Utc::now();
+        let invalid = report("not a timestamp", "bench", None);
+        let old = report(&days_ago(now, 10), "bench", None);
+        let (cache, _dir) = cache(&[&invalid, &old]).await;
+        let policy = RetentionPolicy {
+            max_age: Some(DAY),
+            max_runs: Some(0),
+        };
+
+        assert_eq!(
+            cache.select_expired_benchmarks(&policy, now),
+            vec![old.uuid]
+        );
+    }
+}
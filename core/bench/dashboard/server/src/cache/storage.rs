@@ -17,7 +17,7 @@
 
 use super::BenchmarkCache;
 use bench_dashboard_shared::BenchmarkReportLight;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 impl BenchmarkCache {
@@ -51,6 +51,44 @@ impl BenchmarkCache {
             .map(|entry| entry.value().0.clone())
     }
 
+    pub fn results_dir(&self) -> &Path {
+        &self.results_dir
+    }
+
+    pub fn contains_benchmark(&self, uuid: &Uuid) -> bool {
+        self.benchmarks.contains_key(uuid)
+    }
+
+    /// Removes the benchmark from the cache, returns the path of its report
+    pub fn remove_benchmark(&self, uuid: &Uuid) -> Option<PathBuf> {
+        let (_, (report, path)) = self.benchmarks.remove(uuid)?;
+        let Some(gitref) = &report.params.gitref else {
+            return Some(path);
+        };
+
+        self.gitref_to_benchmarks
+            .remove_if(gitref, |_, benchmarks| {
+                benchmarks.remove(uuid);
+                benchmarks.is_empty()
+            });
+
+        if let Some(hardware) = &report.hardware.identifier {
+            let gitref_still_used = self.benchmarks.iter().any(|entry| {
+                let (other, _) = entry.value();
+                other.params.gitref.as_ref() == Some(gitref)
+                    && other.hardware.identifier.as_ref() == Some(hardware)
+            });
+            if !gitref_still_used {
+                self.hardware_to_gitref.remove_if(hardware, |_, gitrefs| {
+                    gitrefs.remove(gitref);
+                    gitrefs.is_empty()
+                });
+            }
+        }
+
+        Some(path)
+    }
+
     pub(crate) fn clear(&self) {
         self.benchmarks.clear();
         self.hardware_to_gitref.clear();
@@ -33,6 +33,14 @@ pub enum IggyBenchDashboardServerError {
     InvalidUuid(String),
     #[error("Internal error: {0}")]
     InternalError(String),
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+    #[error("Unauthorized")]
+    Unauthorized,
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Conflict: {0}")]
+    Conflict(String),
 }
 
 impl ResponseError for IggyBenchDashboardServerError {
@@ -41,6 +49,18 @@ impl ResponseError for IggyBenchDashboardServerError {
             IggyBenchDashboardServerError::NotFound(msg) => {
                 HttpResponse::NotFound().json(json!({ "error": msg }))
             }
+            IggyBenchDashboardServerError::BadRequest(msg) => {
+                HttpResponse::BadRequest().json(json!({ "error": msg }))
+            }
+            IggyBenchDashboardServerError::Unauthorized => {
+                HttpResponse::Unauthorized().json(json!({ "error": self.to_string() }))
+            }
+            IggyBenchDashboardServerError::Forbidden(msg) => {
+                HttpResponse::Forbidden().json(json!({ "error": msg }))
+            }
+            IggyBenchDashboardServerError::Conflict(msg) => {
+                HttpResponse::Conflict().json(json!({ "error": msg }))
+            }
             _ => HttpResponse::InternalServerError().json(json!({ "error": self.to_string() })),
         }
     }
@@ -17,6 +17,7 @@
 
 use crate::cache::BenchmarkCache;
 use crate::{error::IggyBenchDashboardServerError, github::client::IggyBenchDashboardGithubClient};
+use bench_dashboard_shared::tags::{BenchmarkTags, TAGS_FILE_NAME};
 use file_operation::async_copy_dir_files;
 use std::{path::PathBuf, sync::Arc, time::Duration};
 use tokio::{fs, sync::watch, task::JoinHandle, time::sleep};
@@ -140,6 +141,15 @@ async fn poll_github_with_shutdown(
                     })?;
 
                     async_copy_dir_files(source, destination).await?;
+
+                    let tags = BenchmarkTags {
+                        branch: Some(branch.clone()),
+                        commit: Some(sha1.clone()),
+                        ..Default::default()
+                    };
+                    let tags_json = serde_json::to_string_pretty(&tags)
+                        .map_err(|e| IggyBenchDashboardServerError::InternalError(e.to_string()))?;
+                    fs::write(bench_destination_dir.join(TAGS_FILE_NAME), tags_json).await?;
                 }
             }
 
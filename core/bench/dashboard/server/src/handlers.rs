@@ -16,8 +16,14 @@
 // under the License.
 
 use crate::{cache::BenchmarkCache, error::IggyBenchDashboardServerError};
-use actix_web::{HttpRequest, HttpResponse, get, web};
+use actix_web::{HttpRequest, HttpResponse, get, http::header, post, web};
+use bench_dashboard_shared::{
+    BenchmarkReportLight,
+    tags::{BenchmarkTagFilter, BenchmarkTags, BenchmarkUpload, TAGS_FILE_NAME},
+};
+use std::path::Path;
 use std::sync::Arc;
+use subtle::ConstantTimeEq;
 use tracing::{info, warn};
 use uuid::Uuid;
 use walkdir::WalkDir;
@@ -27,6 +33,8 @@ type Result<T> = std::result::Result<T, IggyBenchDashboardServerError>;
 
 pub struct AppState {
     pub cache: Arc<BenchmarkCache>,
+    /// Bearer token required to upload the reports, uploads are disabled when not set
+    pub upload_token: Option<String>,
 }
 
 #[get("/health")]
@@ -209,6 +217,7 @@ pub async fn get_benchmark_report_light(
 pub async fn get_benchmark_trend(
     data: web::Data<AppState>,
     path: web::Path<(String, String)>,
+    filter: web::Query<BenchmarkTagFilter>,
     req: HttpRequest,
 ) -> Result<HttpResponse> {
     let (hardware, params_identifier) = path.into_inner();
@@ -220,7 +229,7 @@ pub async fn get_benchmark_trend(
 
     let trend_data = data
         .cache
-        .get_benchmark_trend_data(&params_identifier, &hardware)
+        .get_benchmark_trend_data(&params_identifier, Some(&hardware), &filter)
         .ok_or_else(|| {
             IggyBenchDashboardServerError::NotFound(format!(
                 "Trend data not found for hardware '{hardware}' with params identifier '{params_identifier}'"
@@ -238,6 +247,183 @@ pub async fn get_benchmark_trend(
     Ok(HttpResponse::Ok().json(trend_data))
 }
 
+#[get("/api/trend/{params_identifier}")]
+pub async fn get_benchmark_trend_by_tags(
+    data: web::Data<AppState>,
+    params_identifier: web::Path<String>,
+    filter: web::Query<BenchmarkTagFilter>,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let params_identifier = params_identifier.into_inner();
+    let client_addr = get_client_addr(&req);
+    info!(
+        "{}: Requesting trend data for params identifier '{}' with tags {:?}",
+        client_addr, params_identifier, filter
+    );
+
+    let trend_data = data
+        .cache
+        .get_benchmark_trend_data(&params_identifier, None, &filter)
+        .ok_or_else(|| {
+            IggyBenchDashboardServerError::NotFound(format!(
+                "Trend data not found for params identifier '{params_identifier}' with tags {filter:?}"
+            ))
+        })?;
+
+    info!(
+        "{}: Found {} trend data points for params identifier '{}'",
+        client_addr,
+        trend_data.len(),
+        params_identifier
+    );
+
+    Ok(HttpResponse::Ok().json(trend_data))
+}
+
+#[get("/api/tags")]
+pub async fn list_tag_values(data: web::Data<AppState>, req: HttpRequest) -> Result<HttpResponse> {
+    let client_addr = get_client_addr(&req);
+    info!("{}: Listing tag values", client_addr);
+
+    Ok(HttpResponse::Ok().json(data.cache.get_tag_values()))
+}
+
+#[post("/api/benchmarks")]
+pub async fn upload_benchmark(
+    data: web::Data<AppState>,
+    body: web::Bytes,
+    req: HttpRequest,
+) -> Result<HttpResponse> {
+    let client_addr = get_client_addr(&req);
+    authorize_upload(&data, &req).inspect_err(|e| {
+        warn!("{client_addr}: Rejected benchmark upload: {e}");
+    })?;
+
+    let BenchmarkUpload { mut report, tags } = serde_json::from_slice(&body).map_err(|e| {
+        IggyBenchDashboardServerError::BadRequest(format!("Invalid benchmark upload: {e}"))
+    })?;
+
+    if report.params.gitref.is_none() {
+        report.params.gitref = tags.commit.clone();
+    }
+    let Some(hardware) = report.hardware.identifier.clone() else {
+        return Err(IggyBenchDashboardServerError::BadRequest(
+            "Benchmark report has no hardware identifier".to_owned(),
+        ));
+    };
+    if report.params.gitref.is_none() {
+        return Err(IggyBenchDashboardServerError::BadRequest(
+            "Benchmark report has no gitref, set it in the report or in the commit tag".to_owned(),
+        ));
+    }
+    if chrono::DateTime::parse_from_rfc3339(&report.timestamp).is_err() {
+        return Err(IggyBenchDashboardServerError::BadRequest(format!(
+            "Benchmark report has invalid timestamp: '{}', expected RFC 3339",
+            report.timestamp
+        )));
+    }
+    if data.cache.contains_benchmark(&report.uuid) {
+        return Err(IggyBenchDashboardServerError::Conflict(format!(
+            "Benchmark '{}' already exists",
+            report.uuid
+        )));
+    }
+
+    let dir_name = sanitize_dir_name(&format!(
+        "{}_{}_{}",
+        report.params.params_identifier, hardware, report.uuid
+    ));
+    let benchmark_dir = data.cache.results_dir().join(dir_name);
+    info!(
+        "{}: Uploading benchmark '{}' with tags {:?} to {}",
+        client_addr,
+        report.uuid,
+        tags,
+        benchmark_dir.display()
+    );
+
+    let report_json = serde_json::to_string(&report)
+        .map_err(|e| IggyBenchDashboardServerError::InternalError(e.to_string()))?;
+    tokio::fs::create_dir_all(data.cache.results_dir()).await?;
+    // Creating the directory reserves the uuid, a concurrent upload of the same report fails here
+    match tokio::fs::create_dir(&benchmark_dir).await {
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            return Err(IggyBenchDashboardServerError::Conflict(format!(
+                "Benchmark '{}' already exists",
+                report.uuid
+            )));
+        }
+        result => result?,
+    }
+
+    let report_path = benchmark_dir.join("report.json");
+    let light_report =
+        match write_benchmark(&data, &tags, &benchmark_dir, &report_path, report_json).await {
+            Ok(light_report) => light_report,
+            Err(e) => {
+                if let Err(remove_error) = tokio::fs::remove_dir_all(&benchmark_dir).await {
+                    warn!(
+                        "{client_addr}: Failed to remove {}: {remove_error}",
+                        benchmark_dir.display()
+                    );
+                }
+                return Err(e);
+            }
+        };
+    data.cache.insert_benchmark(light_report, report_path);
+
+    info!("{}: Uploaded benchmark '{}'", client_addr, report.uuid);
+    Ok(HttpResponse::Created().json(serde_json::json!({ "uuid": report.uuid })))
+}
+
+async fn write_benchmark(
+    data: &AppState,
+    tags: &BenchmarkTags,
+    benchmark_dir: &Path,
+    report_path: &Path,
+    report_json: String,
+) -> Result<BenchmarkReportLight> {
+    if !tags.is_empty() {
+        let tags_json = serde_json::to_string_pretty(tags)
+            .map_err(|e| IggyBenchDashboardServerError::InternalError(e.to_string()))?;
+        tokio::fs::write(benchmark_dir.join(TAGS_FILE_NAME), tags_json).await?;
+    }
+    tokio::fs::write(report_path, report_json).await?;
+    data.cache.load_light_report(report_path)
+}
+
+fn authorize_upload(data: &AppState, req: &HttpRequest) -> Result<()> {
+    let Some(expected_token) = &data.upload_token else {
+        return Err(IggyBenchDashboardServerError::Forbidden(
+            "Uploads are disabled, UPLOAD_TOKEN is not set".to_owned(),
+        ));
+    };
+
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(IggyBenchDashboardServerError::Unauthorized)?;
+
+    if !bool::from(token.trim().as_bytes().ct_eq(expected_token.as_bytes())) {
+        return Err(IggyBenchDashboardServerError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn sanitize_dir_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
 #[get("/api/artifacts/{uuid}")]
 pub async fn get_test_artifacts_zip(
     data: web::Data<AppState>,
@@ -343,6 +529,7 @@ pub async fn get_test_artifacts_zip(
 pub async fn get_recent_benchmarks(
     data: web::Data<AppState>,
     path: web::Path<usize>,
+    filter: web::Query<BenchmarkTagFilter>,
     req: HttpRequest,
 ) -> Result<HttpResponse> {
     let client_addr = get_client_addr(&req);
@@ -352,7 +539,7 @@ pub async fn get_recent_benchmarks(
         client_addr, limit
     );
 
-    let benchmarks = data.cache.get_recent_benchmarks(limit);
+    let benchmarks = data.cache.get_recent_benchmarks(limit, &filter);
 
     info!(
         "{}: Found {} recent benchmarks",
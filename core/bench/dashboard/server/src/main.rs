@@ -31,10 +31,11 @@ use actix_web::{
     web,
 };
 use args::{IggyBenchDashboardServerArgs, PollGithub};
-use cache::BenchmarkCache;
+use cache::{BenchmarkCache, RetentionPruner};
 use github::IggyBenchDashboardGithubPoller;
 use handlers::AppState;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{error, info};
 use tracing_subscriber::{
     EnvFilter,
@@ -70,6 +71,9 @@ async fn main() -> Result<(), std::io::Error> {
     let results_dir = args.results_dir.clone();
     let addr = args.server_addr();
     let cors_origins = args.cors_origins_list();
+    let upload_token = args.upload_token();
+    let upload_limit = args.upload_limit_mb * 1024 * 1024;
+    let retention_policy = args.retention_policy();
 
     let cache = Arc::new(BenchmarkCache::new(results_dir.clone()).await);
     info!("Starting cache load...");
@@ -106,6 +110,14 @@ async fn main() -> Result<(), std::io::Error> {
         None
     };
 
+    let pruner = retention_policy.map(|policy| {
+        RetentionPruner::start(
+            cache.clone(),
+            policy,
+            Duration::from_secs(args.retention_interval_seconds),
+        )
+    });
+
     let state = ServerState {
         cache: Arc::clone(&cache),
         _watcher: Arc::clone(&watcher),
@@ -115,6 +127,14 @@ async fn main() -> Result<(), std::io::Error> {
     info!("Results directory: {}", results_dir.display());
     info!("Log level: {}", args.log_level);
     info!("CORS origins: {}", args.cors_origins);
+    info!(
+        "Uploads: {}",
+        if upload_token.is_some() {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    );
 
     let server = HttpServer::new(move || {
         let state = state.clone();
@@ -122,7 +142,7 @@ async fn main() -> Result<(), std::io::Error> {
         let cors = if cors_origins.contains(&"*".to_string()) {
             Cors::default()
                 .allow_any_origin()
-                .allowed_methods(vec!["GET"])
+                .allowed_methods(vec!["GET", "POST"])
                 .allowed_headers(vec![header::AUTHORIZATION, header::ACCEPT])
                 .allowed_header(header::CONTENT_TYPE)
                 .max_age(3600)
@@ -134,7 +154,7 @@ async fn main() -> Result<(), std::io::Error> {
                         .iter()
                         .any(|allowed| origin.as_bytes().ends_with(allowed.as_bytes()))
                 })
-                .allowed_methods(vec!["GET"])
+                .allowed_methods(vec!["GET", "POST"])
                 .allowed_headers(vec![header::AUTHORIZATION, header::ACCEPT])
                 .allowed_header(header::CONTENT_TYPE)
                 .max_age(3600)
@@ -148,7 +168,9 @@ async fn main() -> Result<(), std::io::Error> {
             .wrap(Compress::default())
             .app_data(web::Data::new(AppState {
                 cache: Arc::clone(&state.cache),
+                upload_token: upload_token.clone(),
             }))
+            .app_data(web::PayloadConfig::new(upload_limit))
             .service(handlers::health_check)
             .service(handlers::list_hardware)
             .service(handlers::list_gitrefs_for_hardware)
@@ -158,6 +180,9 @@ async fn main() -> Result<(), std::io::Error> {
             .service(handlers::get_benchmark_report_full)
             .service(handlers::get_benchmark_report_light)
             .service(handlers::get_benchmark_trend)
+            .service(handlers::get_benchmark_trend_by_tags)
+            .service(handlers::list_tag_values)
+            .service(handlers::upload_benchmark)
             .service(handlers::get_test_artifacts_zip)
             .service(
                 fs::Files::new("/", "frontend/dist")
@@ -175,5 +200,9 @@ async fn main() -> Result<(), std::io::Error> {
         poller.shutdown().await;
     }
 
+    if let Some(pruner) = pruner {
+        pruner.shutdown().await;
+    }
+
     Ok(())
 }
@@ -16,14 +16,16 @@
 // under the License.
 
 pub mod subtext;
+pub mod tags;
 pub mod title;
 
 use bench_report::{
     group_metrics_summary::BenchmarkGroupMetricsSummary, hardware::BenchmarkHardware,
     individual_metrics_summary::BenchmarkIndividualMetricsSummary, params::BenchmarkParams,
-    server_stats::BenchmarkServerStats,
+    server_resources::BenchmarkServerResourcesSummary, server_stats::BenchmarkServerStats,
 };
 use serde::{Deserialize, Serialize};
+use tags::BenchmarkTags;
 use uuid::Uuid;
 
 /// A light version of the benchmark report that doesn't include the time series
@@ -32,10 +34,15 @@ pub struct BenchmarkReportLight {
     pub timestamp: String,
     pub uuid: Uuid,
     pub server_stats: BenchmarkServerStats,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_resources: Option<BenchmarkServerResourcesLight>,
     pub params: BenchmarkParams,
     pub hardware: BenchmarkHardware,
     pub group_metrics: Vec<BenchmarkGroupMetricsLight>,
     pub individual_metrics: Vec<BenchmarkIndividualMetricsLight>,
+    /// Not a part of the report, loaded from the tags file next to it
+    #[serde(default, skip_serializing_if = "BenchmarkTags::is_empty")]
+    pub tags: BenchmarkTags,
 }
 
 /// Same as BenchmarkGroupMetrics, but without the time series
@@ -49,3 +56,9 @@ pub struct BenchmarkGroupMetricsLight {
 pub struct BenchmarkIndividualMetricsLight {
     pub summary: BenchmarkIndividualMetricsSummary,
 }
+
+/// Same as BenchmarkServerResources, but without the time series
+#[derive(Debug, Clone, Serialize, PartialEq, Deserialize)]
+pub struct BenchmarkServerResourcesLight {
+    pub summary: BenchmarkServerResourcesSummary,
+}
@@ -40,6 +40,8 @@ impl BenchmarkReportLight {
             self.params.benchmark_kind,
             BenchmarkKind::PinnedProducerAndConsumer
                 | BenchmarkKind::BalancedProducerAndConsumerGroup
+                | BenchmarkKind::Scenario
+                | BenchmarkKind::Chaos
         ) {
             if let Some(total) = self.group_metrics.iter().find(|s| {
                 s.summary.kind == GroupMetricsKind::ProducersAndConsumers
@@ -70,7 +72,9 @@ impl BenchmarkReportLight {
         self.group_metrics
             .iter()
             .filter(|s| s.summary.kind != GroupMetricsKind::ProducersAndConsumers) // Skip total summary
-            .map(|summary| summary.format_latency())
+            .flat_map(|summary| {
+                std::iter::once(summary.format_latency()).chain(summary.format_corrected_latency())
+            })
             .collect::<Vec<String>>()
             .join("\n")
     }
@@ -219,4 +223,18 @@ impl BenchmarkGroupMetricsLight {
             self.summary.std_dev_latency_ms,
         )
     }
+
+    fn format_corrected_latency(&self) -> Option<String> {
+        self.summary.average_corrected_latency.map(|corrected| {
+            format!(
+                "{} Corrected Latency (open-loop)  •  P50: {:.2} ms  •  P99: {:.2} ms  •  P999: {:.2} ms  •  P9999: {:.2} ms  •  Max: {:.2} ms",
+                self.summary.kind,
+                corrected.p50_latency_ms,
+                corrected.p99_latency_ms,
+                corrected.p999_latency_ms,
+                corrected.p9999_latency_ms,
+                corrected.max_latency_ms,
+            )
+        })
+    }
 }
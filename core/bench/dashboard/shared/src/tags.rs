@@ -0,0 +1,237 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BenchmarkReportLight;
+use bench_report::report::BenchmarkReport;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Name of the file with the tags, stored next to the `report.json`
+pub const TAGS_FILE_NAME: &str = "tags.json";
+
+/// Tags attached to the benchmark run, either when uploaded or when downloaded from GitHub.
+/// Commit and hardware fall back to the gitref and hardware identifier from the report.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct BenchmarkTags {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hardware: Option<String>,
+    /// Custom labels, e.g. `nightly=true` or `kernel=6.8`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+impl BenchmarkTags {
+    pub fn is_empty(&self) -> bool {
+        self.branch.is_none()
+            && self.commit.is_none()
+            && self.hardware.is_none()
+            && self.labels.is_empty()
+    }
+}
+
+/// Filter of the benchmark runs by their tags, all the set fields have to match.
+/// Used as the query string of the dashboard API, hence the flat structure.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct BenchmarkTagFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Matches the commit by prefix, so the short sha can be used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hardware: Option<String>,
+    /// Comma separated `key=value` labels, a label without a value matches any value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<String>,
+}
+
+impl BenchmarkTagFilter {
+    pub fn is_empty(&self) -> bool {
+        self.branch.is_none()
+            && self.commit.is_none()
+            && self.hardware.is_none()
+            && self.labels.is_none()
+    }
+
+    /// Returns the filter as query string pairs, skipping the unset fields
+    pub fn query_pairs(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("branch", &self.branch),
+            ("commit", &self.commit),
+            ("hardware", &self.hardware),
+            ("labels", &self.labels),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.as_deref().map(|value| (key, value)))
+        .collect()
+    }
+
+    pub fn matches(&self, report: &BenchmarkReportLight) -> bool {
+        let matches_exactly = |expected: &Option<String>, actual: Option<&str>| {
+            expected
+                .as_deref()
+                .filter(|expected| !expected.is_empty())
+                .is_none_or(|expected| actual == Some(expected))
+        };
+
+        matches_exactly(&self.branch, report.tags.branch.as_deref())
+            && matches_exactly(&self.hardware, report.hardware_tag())
+            && self
+                .commit
+                .as_deref()
+                .is_none_or(|commit| report.commit_tag().is_some_and(|c| c.starts_with(commit)))
+            && self.labels().all(|(key, value)| {
+                report
+                    .tags
+                    .labels
+                    .get(key)
+                    .is_some_and(|actual| value.is_none_or(|value| actual == value))
+            })
+    }
+
+    fn labels(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.labels
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(|label| match label.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim())),
+                None => (label, None),
+            })
+    }
+}
+
+/// All the tag values of the cached benchmark runs, used to populate the filters.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct BenchmarkTagValues {
+    pub branches: Vec<String>,
+    pub commits: Vec<String>,
+    pub hardware: Vec<String>,
+    /// Labels formatted as `key=value`
+    pub labels: Vec<String>,
+}
+
+/// Body of the request uploading the benchmark report to the dashboard.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BenchmarkUpload {
+    pub report: BenchmarkReport,
+    #[serde(default)]
+    pub tags: BenchmarkTags,
+}
+
+impl BenchmarkReportLight {
+    /// Commit from the tags, falling back to the gitref of the benchmark
+    pub fn commit_tag(&self) -> Option<&str> {
+        self.tags
+            .commit
+            .as_deref()
+            .or(self.params.gitref.as_deref())
+    }
+
+    /// Hardware from the tags, falling back to the hardware identifier of the benchmark
+    pub fn hardware_tag(&self) -> Option<&str> {
+        self.tags
+            .hardware
+            .as_deref()
+            .or(self.hardware.identifier.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> BenchmarkReportLight {
+        let mut report = BenchmarkReportLight::default();
+        report.params.gitref = Some("0123456789abcdef".to_owned());
+        report.hardware.identifier = Some("runner".to_owned());
+        report.tags.branch = Some("master".to_owned());
+        report.tags.labels = BTreeMap::from([
+            ("nightly".to_owned(), "true".to_owned()),
+            ("kernel".to_owned(), "6.8".to_owned()),
+        ]);
+        report
+    }
+
+    fn filter(
+        branch: Option<&str>,
+        commit: Option<&str>,
+        hardware: Option<&str>,
+        labels: Option<&str>,
+    ) -> BenchmarkTagFilter {
+        BenchmarkTagFilter {
+            branch: branch.map(str::to_owned),
+            commit: commit.map(str::to_owned),
+            hardware: hardware.map(str::to_owned),
+            labels: labels.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn empty_filter_should_match_any_report() {
+        assert!(BenchmarkTagFilter::default().matches(&report()));
+        assert!(BenchmarkTagFilter::default().matches(&BenchmarkReportLight::default()));
+    }
+
+    #[test]
+    fn should_match_branch_and_hardware_exactly() {
+        let report = report();
+
+        assert!(filter(Some("master"), None, Some("runner"), None).matches(&report));
+        assert!(!filter(Some("mast"), None, None, None).matches(&report));
+        assert!(!filter(None, None, Some("other"), None).matches(&report));
+    }
+
+    #[test]
+    fn should_match_commit_by_prefix_falling_back_to_gitref() {
+        let mut report = report();
+
+        assert!(filter(None, Some("0123456"), None, None).matches(&report));
+        assert!(!filter(None, Some("abcdef"), None, None).matches(&report));
+
+        report.tags.commit = Some("fedcba9876543210".to_owned());
+        assert!(filter(None, Some("fedcba"), None, None).matches(&report));
+        assert!(!filter(None, Some("0123456"), None, None).matches(&report));
+    }
+
+    #[test]
+    fn should_match_all_labels_with_or_without_value() {
+        let report = report();
+
+        assert!(filter(None, None, None, Some("nightly=true, kernel")).matches(&report));
+        assert!(filter(None, None, None, Some(" kernel = 6.8 ,")).matches(&report));
+        assert!(!filter(None, None, None, Some("nightly=false")).matches(&report));
+        assert!(!filter(None, None, None, Some("nightly,missing")).matches(&report));
+    }
+
+    #[test]
+    fn query_pairs_should_skip_unset_fields() {
+        let filter = filter(Some("master"), None, None, Some("nightly"));
+
+        assert_eq!(
+            filter.query_pairs(),
+            vec![("branch", "master"), ("labels", "nightly")]
+        );
+    }
+}
@@ -0,0 +1,525 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::actor_kind::ActorKind;
+use crate::group_metrics_kind::GroupMetricsKind;
+use crate::report::BenchmarkReport;
+use crate::time_series::TimeSeries;
+use crate::utils::welch_t_test_p_value;
+use derive_more::derive::Display;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const REPORT_FILE_NAME: &str = "report.json";
+
+#[derive(Debug, Error)]
+pub enum ComparisonError {
+    #[error("Failed to read report {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    #[error("Failed to parse report {0}: {1}")]
+    Json(PathBuf, serde_json::Error),
+    #[error("No reports found in {0}")]
+    NoReports(PathBuf),
+    #[error("Benchmarks cannot be compared, mismatched parameters: {}", .0.join(", "))]
+    MismatchedParams(Vec<&'static str>),
+}
+
+/// Thresholds above which a change of the candidate metric is treated as a regression.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ComparisonThresholds {
+    /// Maximum allowed throughput drop in percents
+    pub max_throughput_drop_percent: f64,
+    /// Maximum allowed latency increase in percents
+    pub max_latency_increase_percent: f64,
+    /// Changes with p-value of the Welch's t-test above this level are not significant
+    pub significance_level: f64,
+}
+
+impl Default for ComparisonThresholds {
+    fn default() -> Self {
+        Self {
+            max_throughput_drop_percent: 5.0,
+            max_latency_increase_percent: 10.0,
+            significance_level: 0.05,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparedMetric {
+    #[display("Throughput [MB/s]")]
+    ThroughputMb,
+    #[display("Throughput [msg/s]")]
+    ThroughputMsg,
+    #[display("P50 latency [ms]")]
+    P50Latency,
+    #[display("P90 latency [ms]")]
+    P90Latency,
+    #[display("P95 latency [ms]")]
+    P95Latency,
+    #[display("P99 latency [ms]")]
+    P99Latency,
+    #[display("P999 latency [ms]")]
+    P999Latency,
+    #[display("P9999 latency [ms]")]
+    P9999Latency,
+    #[display("Avg latency [ms]")]
+    AvgLatency,
+}
+
+impl ComparedMetric {
+    pub fn is_throughput(&self) -> bool {
+        matches!(
+            self,
+            ComparedMetric::ThroughputMb | ComparedMetric::ThroughputMsg
+        )
+    }
+}
+
+/// Change of a single metric between the baseline and the candidate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MetricDelta {
+    pub metric: ComparedMetric,
+    pub baseline: f64,
+    pub candidate: f64,
+    pub change_percent: f64,
+    /// p-value of the Welch's t-test of the time series behind the metric,
+    /// None if any of the time series has fewer than 2 points
+    pub p_value: Option<f64>,
+    pub regression: bool,
+}
+
+impl MetricDelta {
+    fn new(
+        metric: ComparedMetric,
+        baseline: f64,
+        candidate: f64,
+        p_value: Option<f64>,
+        thresholds: &ComparisonThresholds,
+    ) -> Self {
+        let change_percent = if baseline == 0.0 {
+            0.0
+        } else {
+            (candidate - baseline) / baseline * 100.0
+        };
+        let significant = p_value.is_none_or(|p| p < thresholds.significance_level);
+        let exceeded = if metric.is_throughput() {
+            -change_percent > thresholds.max_throughput_drop_percent
+        } else {
+            change_percent > thresholds.max_latency_increase_percent
+        };
+
+        Self {
+            metric,
+            baseline,
+            candidate,
+            change_percent,
+            p_value,
+            regression: significant && exceeded,
+        }
+    }
+
+    pub fn is_significant(&self, thresholds: &ComparisonThresholds) -> bool {
+        self.p_value
+            .is_some_and(|p| p < thresholds.significance_level)
+    }
+}
+
+/// Metric deltas of the actors group (e.g. all producers) or a single actor.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ComparisonEntry {
+    pub label: String,
+    pub deltas: Vec<MetricDelta>,
+}
+
+impl ComparisonEntry {
+    pub fn regressions(&self) -> impl Iterator<Item = &MetricDelta> {
+        self.deltas.iter().filter(|delta| delta.regression)
+    }
+}
+
+struct ComparedValues<'a> {
+    throughput_mb: f64,
+    throughput_msg: f64,
+    latencies: [f64; 7],
+    throughput_ts: &'a TimeSeries,
+    latency_ts: &'a TimeSeries,
+}
+
+impl ComparedValues<'_> {
+    fn deltas(&self, candidate: &Self, thresholds: &ComparisonThresholds) -> Vec<MetricDelta> {
+        let throughput_p_value =
+            p_value_of_time_series(self.throughput_ts, candidate.throughput_ts);
+        let latency_p_value = p_value_of_time_series(self.latency_ts, candidate.latency_ts);
+        let mut deltas = vec![
+            MetricDelta::new(
+                ComparedMetric::ThroughputMb,
+                self.throughput_mb,
+                candidate.throughput_mb,
+                throughput_p_value,
+                thresholds,
+            ),
+            MetricDelta::new(
+                ComparedMetric::ThroughputMsg,
+                self.throughput_msg,
+                candidate.throughput_msg,
+                throughput_p_value,
+                thresholds,
+            ),
+        ];
+
+        let latency_metrics = [
+            ComparedMetric::P50Latency,
+            ComparedMetric::P90Latency,
+            ComparedMetric::P95Latency,
+            ComparedMetric::P99Latency,
+            ComparedMetric::P999Latency,
+            ComparedMetric::P9999Latency,
+            ComparedMetric::AvgLatency,
+        ];
+        for (index, metric) in latency_metrics.into_iter().enumerate() {
+            deltas.push(MetricDelta::new(
+                metric,
+                self.latencies[index],
+                candidate.latencies[index],
+                latency_p_value,
+                thresholds,
+            ));
+        }
+        deltas
+    }
+}
+
+fn p_value_of_time_series(baseline: &TimeSeries, candidate: &TimeSeries) -> Option<f64> {
+    let baseline = baseline.points.iter().map(|p| p.value).collect::<Vec<_>>();
+    let candidate = candidate.points.iter().map(|p| p.value).collect::<Vec<_>>();
+    welch_t_test_p_value(&baseline, &candidate)
+}
+
+/// Comparison of the candidate benchmark report with the baseline report of the same benchmark.
+///
+/// Throughput and latency percentiles are compared for every group of actors and every actor,
+/// significance of the changes is checked with the Welch's t-test of the throughput and latency
+/// time series. Only group regressions fail the comparison, as single actors are too noisy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchmarkComparison {
+    pub name: String,
+    pub baseline_label: String,
+    pub candidate_label: String,
+    pub thresholds: ComparisonThresholds,
+    pub groups: Vec<ComparisonEntry>,
+    pub actors: Vec<ComparisonEntry>,
+}
+
+impl BenchmarkComparison {
+    pub fn new(
+        baseline: &BenchmarkReport,
+        candidate: &BenchmarkReport,
+        thresholds: ComparisonThresholds,
+    ) -> Result<Self, ComparisonError> {
+        let mismatched = baseline.params.mismatched_params(&candidate.params);
+        if !mismatched.is_empty() {
+            return Err(ComparisonError::MismatchedParams(mismatched));
+        }
+
+        let mut groups = Vec::new();
+        for baseline_group in &baseline.group_metrics {
+            let Some(candidate_group) = candidate
+                .group_metrics
+                .iter()
+                .find(|group| group.summary.kind == baseline_group.summary.kind)
+            else {
+                continue;
+            };
+
+            let values = [baseline_group, candidate_group].map(|group| ComparedValues {
+                throughput_mb: group.summary.total_throughput_megabytes_per_second,
+                throughput_msg: group.summary.total_throughput_messages_per_second,
+                latencies: [
+                    group.summary.average_p50_latency_ms,
+                    group.summary.average_p90_latency_ms,
+                    group.summary.average_p95_latency_ms,
+                    group.summary.average_p99_latency_ms,
+                    group.summary.average_p999_latency_ms,
+                    group.summary.average_p9999_latency_ms,
+                    group.summary.average_latency_ms,
+                ],
+                throughput_ts: &group.avg_throughput_msg_ts,
+                latency_ts: &group.avg_latency_ts,
+            });
+            groups.push(ComparisonEntry {
+                label: group_label(baseline_group.summary.kind),
+                deltas: values[0].deltas(&values[1], &thresholds),
+            });
+        }
+
+        let mut actors = Vec::new();
+        for baseline_actor in &baseline.individual_metrics {
+            let Some(candidate_actor) = candidate.individual_metrics.iter().find(|actor| {
+                actor.summary.actor_kind == baseline_actor.summary.actor_kind
+                    && actor.summary.actor_id == baseline_actor.summary.actor_id
+            }) else {
+                continue;
+            };
+
+            let values = [baseline_actor, candidate_actor].map(|actor| ComparedValues {
+                throughput_mb: actor.summary.throughput_megabytes_per_second,
+                throughput_msg: actor.summary.throughput_messages_per_second,
+                latencies: [
+                    actor.summary.p50_latency_ms,
+                    actor.summary.p90_latency_ms,
+                    actor.summary.p95_latency_ms,
+                    actor.summary.p99_latency_ms,
+                    actor.summary.p999_latency_ms,
+                    actor.summary.p9999_latency_ms,
+                    actor.summary.avg_latency_ms,
+                ],
+                throughput_ts: &actor.throughput_msg_ts,
+                latency_ts: &actor.latency_ts,
+            });
+            actors.push(ComparisonEntry {
+                label: format!(
+                    "{} {}",
+                    actor_label(baseline_actor.summary.actor_kind),
+                    baseline_actor.summary.actor_id
+                ),
+                deltas: values[0].deltas(&values[1], &thresholds),
+            });
+        }
+
+        Ok(Self {
+            name: candidate.params.pretty_name.clone(),
+            baseline_label: report_label(baseline, "baseline"),
+            candidate_label: report_label(candidate, "candidate"),
+            thresholds,
+            groups,
+            actors,
+        })
+    }
+
+    pub fn has_regressions(&self) -> bool {
+        self.groups
+            .iter()
+            .any(|group| group.regressions().next().is_some())
+    }
+}
+
+fn group_label(kind: GroupMetricsKind) -> String {
+    match kind {
+        GroupMetricsKind::ProducersAndConsumers => "All Actors".to_owned(),
+        kind => format!("All {kind}"),
+    }
+}
+
+fn actor_label(kind: ActorKind) -> &'static str {
+    match kind {
+        ActorKind::Producer => "Producer",
+        ActorKind::Consumer => "Consumer",
+        ActorKind::ProducingConsumer => "Producing Consumer",
+    }
+}
+
+pub(crate) fn report_label(report: &BenchmarkReport, default: &str) -> String {
+    report
+        .params
+        .gitref
+        .clone()
+        .filter(|gitref| !gitref.is_empty())
+        .unwrap_or_else(|| default.to_owned())
+}
+
+impl BenchmarkReport {
+    pub fn load_from_json(path: &Path) -> Result<Self, ComparisonError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|error| ComparisonError::Io(path.to_path_buf(), error))?;
+        serde_json::from_str(&json)
+            .map_err(|error| ComparisonError::Json(path.to_path_buf(), error))
+    }
+}
+
+/// Loads the report from the given `report.json` file or all `report.json` files found
+/// (recursively) in the given directory.
+pub fn load_reports(path: &Path) -> Result<Vec<BenchmarkReport>, ComparisonError> {
+    if !path.is_dir() {
+        return Ok(vec![BenchmarkReport::load_from_json(path)?]);
+    }
+
+    let mut report_paths = Vec::new();
+    let mut directories = vec![path.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let entries = std::fs::read_dir(&directory)
+            .map_err(|error| ComparisonError::Io(directory.clone(), error))?;
+        for entry in entries {
+            let entry_path = entry
+                .map_err(|error| ComparisonError::Io(directory.clone(), error))?
+                .path();
+            if entry_path.is_dir() {
+                directories.push(entry_path);
+            } else if entry_path
+                .file_name()
+                .is_some_and(|name| name == REPORT_FILE_NAME)
+            {
+                report_paths.push(entry_path);
+            }
+        }
+    }
+
+    if report_paths.is_empty() {
+        return Err(ComparisonError::NoReports(path.to_path_buf()));
+    }
+
+    report_paths.sort();
+    report_paths
+        .iter()
+        .map(|report_path| BenchmarkReport::load_from_json(report_path))
+        .collect()
+}
+
+/// Pairs every candidate report with the baseline report of the same benchmark, i.e. with the
+/// same remark and no mismatched parameters. Candidates without baseline are returned separately.
+pub fn match_reports<'a>(
+    baseline: &'a [BenchmarkReport],
+    candidate: &'a [BenchmarkReport],
+) -> (
+    Vec<(&'a BenchmarkReport, &'a BenchmarkReport)>,
+    Vec<&'a BenchmarkReport>,
+) {
+    let mut matched = Vec::new();
+    let mut unmatched = Vec::new();
+    for candidate_report in candidate {
+        let baseline_report = baseline.iter().find(|baseline_report| {
+            baseline_report.params.remark == candidate_report.params.remark
+                && baseline_report
+                    .params
+                    .mismatched_params(&candidate_report.params)
+                    .is_empty()
+        });
+        match baseline_report {
+            Some(baseline_report) => matched.push((baseline_report, candidate_report)),
+            None => unmatched.push(candidate_report),
+        }
+    }
+    (matched, unmatched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::group_metrics::BenchmarkGroupMetrics;
+    use crate::group_metrics_summary::BenchmarkGroupMetricsSummary;
+    use crate::time_series::TimePoint;
+
+    fn series(values: &[f64]) -> TimeSeries {
+        TimeSeries {
+            points: values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| TimePoint::new(i as f64, *value))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn report(throughput: &[f64], latency: &[f64]) -> BenchmarkReport {
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        let summary = BenchmarkGroupMetricsSummary {
+            kind: GroupMetricsKind::Producers,
+            total_throughput_megabytes_per_second: mean(throughput),
+            total_throughput_messages_per_second: mean(throughput),
+            average_throughput_megabytes_per_second: mean(throughput),
+            average_throughput_messages_per_second: mean(throughput),
+            average_p50_latency_ms: mean(latency),
+            average_p90_latency_ms: mean(latency),
+            average_p95_latency_ms: mean(latency),
+            average_p99_latency_ms: mean(latency),
+            average_p999_latency_ms: mean(latency),
+            average_p9999_latency_ms: mean(latency),
+            average_latency_ms: mean(latency),
+            average_median_latency_ms: mean(latency),
+            min_latency_ms: 0.0,
+            max_latency_ms: 0.0,
+            std_dev_latency_ms: 0.0,
+            average_corrected_latency: None,
+        };
+        BenchmarkReport {
+            group_metrics: vec![BenchmarkGroupMetrics {
+                summary,
+                avg_throughput_mb_ts: series(throughput),
+                avg_throughput_msg_ts: series(throughput),
+                avg_latency_ts: series(latency),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn significant_throughput_drop_should_be_regression() {
+        let baseline = report(
+            &[100.0, 102.0, 98.0, 101.0, 99.0],
+            &[1.0, 1.1, 0.9, 1.0, 1.0],
+        );
+        let candidate = report(&[80.0, 82.0, 78.0, 81.0, 79.0], &[1.0, 1.1, 0.9, 1.0, 1.0]);
+
+        let comparison =
+            BenchmarkComparison::new(&baseline, &candidate, ComparisonThresholds::default())
+                .unwrap();
+
+        assert!(comparison.has_regressions());
+        let regressions = comparison.groups[0]
+            .regressions()
+            .map(|delta| delta.metric)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            regressions,
+            vec![ComparedMetric::ThroughputMb, ComparedMetric::ThroughputMsg]
+        );
+        assert!((comparison.groups[0].deltas[1].change_percent + 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn insignificant_latency_increase_should_not_be_regression() {
+        let baseline = report(&[100.0, 100.0, 100.0], &[1.0, 3.0, 1.0, 3.0]);
+        let candidate = report(&[100.0, 100.0, 100.0], &[1.0, 4.0, 1.0, 3.5]);
+
+        let comparison =
+            BenchmarkComparison::new(&baseline, &candidate, ComparisonThresholds::default())
+                .unwrap();
+
+        let avg_latency = comparison.groups[0].deltas.last().unwrap();
+        assert!(avg_latency.change_percent > 10.0);
+        assert!(!avg_latency.regression);
+        assert!(!comparison.has_regressions());
+    }
+
+    #[test]
+    fn reports_with_mismatched_params_should_not_be_compared() {
+        let baseline = report(&[100.0, 100.0], &[1.0, 1.0]);
+        let mut candidate = report(&[100.0, 100.0], &[1.0, 1.0]);
+        candidate.params.producers = 8;
+
+        let result =
+            BenchmarkComparison::new(&baseline, &candidate, ComparisonThresholds::default());
+
+        assert!(matches!(
+            result,
+            Err(ComparisonError::MismatchedParams(params)) if params == vec!["producers"]
+        ));
+    }
+}
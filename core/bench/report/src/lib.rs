@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod comparison;
 pub mod plotting;
 pub mod types;
 pub mod utils;
@@ -141,3 +142,147 @@ pub fn create_latency_chart(
 
     chart.inner
 }
+
+/// Server resource usage chart, percentages on the left axis and megabytes on the right one.
+/// Returns `None` if the report has no server resources.
+pub fn create_server_resources_chart(
+    report: &BenchmarkReport,
+    dark: bool,
+    strip_title_and_subtext: bool,
+) -> Option<Chart> {
+    let resources = report.server_resources.as_ref()?;
+    let title = report.title(ChartKind::ServerResources);
+
+    let mut chart = IggyChart::new(&title, &report.subtext(), dark, strip_title_and_subtext)
+        .with_time_x_axis()
+        .with_dual_y_axis("Usage [%]", "Memory [MB], Disk [MB/s]");
+
+    for (name, series, y_axis_index) in [
+        ("Server CPU [%]", &resources.cpu_usage_ts, 0),
+        ("Cache Hit Ratio [%]", &resources.cache_hit_ratio_ts, 0),
+        ("Server Memory [MB]", &resources.memory_usage_mb_ts, 1),
+        ("Server Disk Read [MB/s]", &resources.read_mb_ts, 1),
+        ("Server Disk Write [MB/s]", &resources.written_mb_ts, 1),
+    ] {
+        if series.points.is_empty() {
+            continue;
+        }
+        chart = chart.add_dual_time_line_series(
+            name,
+            series.as_charming_points(),
+            None,
+            1.0,
+            y_axis_index,
+            2.0,
+        );
+    }
+
+    if let Some(process) = &resources.process {
+        for (name, series, y_axis_index) in [
+            ("Process CPU [%]", &process.cpu_usage_ts, 0),
+            ("Process RSS [MB]", &process.memory_usage_mb_ts, 1),
+            (
+                "Process Virtual Memory [MB]",
+                &process.virtual_memory_mb_ts,
+                1,
+            ),
+            ("Process Disk Read [MB/s]", &process.disk_read_mb_ts, 1),
+            ("Process Disk Write [MB/s]", &process.disk_written_mb_ts, 1),
+        ] {
+            chart = chart.add_dual_time_line_series(
+                name,
+                series.as_charming_points(),
+                None,
+                0.5,
+                y_axis_index,
+                1.0,
+            );
+        }
+    }
+
+    Some(chart.inner)
+}
+
+/// Throughput chart overlaying the group series of the baseline and the candidate report.
+pub fn create_throughput_comparison_chart(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    dark: bool,
+) -> Chart {
+    let title = format!("{} - Comparison", candidate.title(ChartKind::Throughput));
+    let subtext = comparison_subtext(baseline, candidate);
+
+    let mut chart = IggyChart::new(&title, &subtext, dark, false)
+        .with_time_x_axis()
+        .with_dual_y_axis("Throughput [MB/s]", "Throughput [msg/s]");
+
+    for (report, label, opacity) in [(baseline, "baseline", 0.5), (candidate, "candidate", 1.0)] {
+        let label = comparison::report_label(report, label);
+        for metrics in &report.group_metrics {
+            // Skip aggregate metrics in charts
+            if metrics.summary.kind == GroupMetricsKind::ProducersAndConsumers {
+                continue;
+            }
+
+            chart = chart.add_dual_time_line_series(
+                &format!("All {}s {} [MB/s]", metrics.summary.kind.actor(), label),
+                metrics.avg_throughput_mb_ts.as_charming_points(),
+                None,
+                opacity,
+                0,
+                2.0,
+            );
+            chart = chart.add_dual_time_line_series(
+                &format!("All {}s {} [msg/s]", metrics.summary.kind.actor(), label),
+                metrics.avg_throughput_msg_ts.as_charming_points(),
+                None,
+                opacity,
+                1,
+                2.0,
+            );
+        }
+    }
+
+    chart.inner
+}
+
+/// Latency chart overlaying the group series of the baseline and the candidate report.
+pub fn create_latency_comparison_chart(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    dark: bool,
+) -> Chart {
+    let title = format!("{} - Comparison", candidate.title(ChartKind::Latency));
+    let subtext = comparison_subtext(baseline, candidate);
+
+    let mut chart = IggyChart::new(&title, &subtext, dark, false)
+        .with_time_x_axis()
+        .with_y_axis("Latency [ms]");
+
+    for (report, label, opacity) in [(baseline, "baseline", 0.5), (candidate, "candidate", 1.0)] {
+        let label = comparison::report_label(report, label);
+        for metrics in &report.group_metrics {
+            // Skip aggregate metrics in charts
+            if metrics.summary.kind == GroupMetricsKind::ProducersAndConsumers {
+                continue;
+            }
+
+            chart = chart.add_time_series(
+                &format!("Avg {}s {} [ms]", metrics.summary.kind.actor(), label),
+                metrics.avg_latency_ts.as_charming_points(),
+                None,
+                opacity,
+            );
+        }
+    }
+
+    chart.inner
+}
+
+fn comparison_subtext(baseline: &BenchmarkReport, candidate: &BenchmarkReport) -> String {
+    format!(
+        "{} vs {}",
+        comparison::report_label(baseline, "baseline"),
+        comparison::report_label(candidate, "candidate")
+    )
+}
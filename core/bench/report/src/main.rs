@@ -0,0 +1,202 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use anyhow::{Context, Result};
+use bench_report::comparison::{
+    BenchmarkComparison, ComparisonThresholds, load_reports, match_reports,
+};
+use bench_report::report::BenchmarkReport;
+use bench_report::{create_latency_comparison_chart, create_throughput_comparison_chart};
+use charming::theme::Theme;
+use charming::{Chart, HtmlRenderer};
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+const CHART_WIDTH: u64 = 1600;
+const CHART_HEIGHT: u64 = 1200;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Iggy benchmark report tool", long_about = None)]
+struct IggyBenchReportArgs {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Compare candidate benchmark reports with the baseline reports, exit with code 1 on regression.
+    ///
+    /// Both baseline and candidate can be a single `report.json` file or a directory,
+    /// which is searched recursively for `report.json` files. Reports are matched by the
+    /// benchmark parameters and remark.
+    Compare {
+        /// Baseline report file or directory
+        #[arg(long, short)]
+        baseline: PathBuf,
+
+        /// Candidate report file or directory
+        #[arg(long, short)]
+        candidate: PathBuf,
+
+        /// Maximum allowed throughput drop in percents
+        #[arg(long, default_value_t = ComparisonThresholds::default().max_throughput_drop_percent)]
+        max_throughput_drop: f64,
+
+        /// Maximum allowed latency increase in percents
+        #[arg(long, default_value_t = ComparisonThresholds::default().max_latency_increase_percent)]
+        max_latency_increase: f64,
+
+        /// Significance level of the Welch's t-test, changes with higher p-value are ignored
+        #[arg(long, default_value_t = ComparisonThresholds::default().significance_level)]
+        significance_level: f64,
+
+        /// Directory where to write the comparison.json and comparison charts
+        #[arg(long, short)]
+        output_dir: Option<PathBuf>,
+    },
+}
+
+fn main() -> Result<()> {
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(env_filter)
+        .init();
+
+    let args = IggyBenchReportArgs::parse();
+    match args.command {
+        Command::Compare {
+            baseline,
+            candidate,
+            max_throughput_drop,
+            max_latency_increase,
+            significance_level,
+            output_dir,
+        } => {
+            let thresholds = ComparisonThresholds {
+                max_throughput_drop_percent: max_throughput_drop,
+                max_latency_increase_percent: max_latency_increase,
+                significance_level,
+            };
+            let regressions = compare(&baseline, &candidate, thresholds, output_dir.as_deref())?;
+            if regressions > 0 {
+                error!("Found {regressions} benchmark(s) with performance regression");
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn compare(
+    baseline: &Path,
+    candidate: &Path,
+    thresholds: ComparisonThresholds,
+    output_dir: Option<&Path>,
+) -> Result<usize> {
+    let baseline_reports = load_reports(baseline)?;
+    let candidate_reports = load_reports(candidate)?;
+    let (matched, unmatched) = match_reports(&baseline_reports, &candidate_reports);
+    for report in unmatched {
+        warn!(
+            "No baseline found for benchmark: {}, skipping",
+            report.params.pretty_name
+        );
+    }
+
+    if matched.is_empty() {
+        anyhow::bail!("No benchmarks to compare");
+    }
+
+    let mut comparisons = Vec::with_capacity(matched.len());
+    for (baseline_report, candidate_report) in matched {
+        let comparison = BenchmarkComparison::new(baseline_report, candidate_report, thresholds)?;
+        comparison.print_summary();
+        if let Some(output_dir) = output_dir {
+            save_comparison_charts(
+                baseline_report,
+                candidate_report,
+                &output_dir.join(comparison_dir_name(candidate_report)),
+            )?;
+        }
+        comparisons.push(comparison);
+    }
+
+    if let Some(output_dir) = output_dir {
+        std::fs::create_dir_all(output_dir)?;
+        let path = output_dir.join("comparison.json");
+        std::fs::write(&path, serde_json::to_string_pretty(&comparisons)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        info!("Comparison saved to: {}", path.display());
+    }
+
+    Ok(comparisons
+        .iter()
+        .filter(|comparison| comparison.has_regressions())
+        .count())
+}
+
+fn comparison_dir_name(report: &BenchmarkReport) -> String {
+    let name = match &report.params.remark {
+        Some(remark) => format!("{}_{remark}", report.params.pretty_name),
+        None => report.params.pretty_name.clone(),
+    };
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn save_comparison_charts(
+    baseline: &BenchmarkReport,
+    candidate: &BenchmarkReport,
+    output_dir: &Path,
+) -> Result<()> {
+    std::fs::create_dir_all(output_dir)?;
+    let charts = [
+        (
+            "throughput_comparison",
+            create_throughput_comparison_chart(baseline, candidate, true),
+        ),
+        (
+            "latency_comparison",
+            create_latency_comparison_chart(baseline, candidate, true),
+        ),
+    ];
+    for (file_name, chart) in charts {
+        save_chart(&chart, file_name, output_dir)?;
+    }
+    Ok(())
+}
+
+fn save_chart(chart: &Chart, file_name: &str, output_dir: &Path) -> Result<()> {
+    let path = output_dir.join(format!("{file_name}.html"));
+    let mut renderer = HtmlRenderer::new(file_name, CHART_WIDTH, CHART_HEIGHT).theme(Theme::Dark);
+    renderer
+        .save(chart, &path)
+        .map_err(|e| anyhow::anyhow!("Failed to save HTML chart {}: {e}", path.display()))?;
+    info!("Generated comparison chart at: {}", path.display());
+    Ok(())
+}
@@ -21,8 +21,12 @@ use human_repr::HumanCount;
 use tracing::info;
 
 use crate::{
-    actor_kind::ActorKind, benchmark_kind::BenchmarkKind, group_metrics::BenchmarkGroupMetrics,
-    group_metrics_kind::GroupMetricsKind, report::BenchmarkReport,
+    actor_kind::ActorKind,
+    benchmark_kind::BenchmarkKind,
+    comparison::{BenchmarkComparison, ComparisonEntry},
+    group_metrics::BenchmarkGroupMetrics,
+    group_metrics_kind::GroupMetricsKind,
+    report::BenchmarkReport,
 };
 
 impl BenchmarkReport {
@@ -72,6 +76,56 @@ impl BenchmarkReport {
         self.group_metrics
             .iter()
             .for_each(|s| info!("{}\n", s.formatted_string()));
+
+        if let Some(resources) = &self.server_resources {
+            let summary = &resources.summary;
+            let cache_hit_ratio = summary
+                .average_cache_hit_ratio
+                .map(|ratio| format!(", average cache hit ratio: {ratio:.2}%"))
+                .unwrap_or_default();
+            let server_print = format!(
+                "Server Resources: average CPU: {:.2}%, max CPU: {:.2}%, average memory: {:.2} MB, max memory: {:.2} MB, disk read: {:.2} MB, disk written: {:.2} MB{}, {} samples every {} ms\n",
+                summary.average_cpu_usage,
+                summary.max_cpu_usage,
+                summary.average_memory_usage_mb,
+                summary.max_memory_usage_mb,
+                summary.total_read_mb,
+                summary.total_written_mb,
+                cache_hit_ratio,
+                summary.samples,
+                summary.sampling_interval_ms
+            )
+            .magenta();
+            info!("{}", server_print);
+        }
+
+        if let Some(chaos) = &self.chaos {
+            let summary = &chaos.summary;
+            let delivery = &summary.delivery;
+            let spikes = &summary.latency_spikes;
+            let chaos_print = format!(
+                "Chaos: {} faults injected, {} reconnects, average reconnect time: {:.2} ms, max reconnect time: {:.2} ms, \
+                acknowledged messages: {}, received: {}, lost: {}, duplicated: {}, offset gaps: {}, \
+                latency spikes over {:.2} ms: {}, max spike latency: {:.2} ms\n",
+                summary.faults_injected,
+                summary.reconnects,
+                summary.average_reconnect_time_ms,
+                summary.max_reconnect_time_ms,
+                delivery.messages_acknowledged,
+                delivery.messages_received,
+                delivery.lost_messages,
+                delivery.duplicate_messages,
+                delivery.offset_gaps,
+                spikes.threshold_ms,
+                spikes.count,
+                spikes.max_latency_ms
+            );
+            if delivery.lost_messages > 0 || delivery.duplicate_messages > 0 {
+                info!("{}", chaos_print.red());
+            } else {
+                info!("{}", chaos_print.green());
+            }
+        }
     }
 
     pub fn total_messages(&self) -> u64 {
@@ -169,12 +223,78 @@ impl BenchmarkGroupMetrics {
             self.avg_throughput_mb_ts.points.last().unwrap().time_s
         );
 
+        let corrected = self
+            .summary
+            .average_corrected_latency
+            .map(|corrected| {
+                format!(
+                    ", corrected (open-loop) p50 latency: {:.2} ms, p99 latency: {:.2} ms, p999 latency: {:.2} ms, p9999 latency: {:.2} ms, max: {:.2} ms",
+                    corrected.p50_latency_ms,
+                    corrected.p99_latency_ms,
+                    corrected.p999_latency_ms,
+                    corrected.p9999_latency_ms,
+                    corrected.max_latency_ms
+                )
+            })
+            .unwrap_or_default();
+
         format!(
             "{prefix}: Total throughput: {total_mb} MB/s, {total_msg} messages/s, average throughput per {actor}: {avg_mb} MB/s, \
             p50 latency: {p50} ms, p90 latency: {p90} ms, p95 latency: {p95} ms, \
             p99 latency: {p99} ms, p999 latency: {p999} ms, p9999 latency: {p9999} ms, average latency: {avg} ms, \
-            median latency: {median} ms, min: {min} ms, max: {max} ms, std dev: {std_dev} ms, total time: {total_test_time} s"
+            median latency: {median} ms, min: {min} ms, max: {max} ms, std dev: {std_dev} ms, total time: {total_test_time} s{corrected}"
         )
         .color(color)
     }
 }
+
+impl BenchmarkComparison {
+    pub fn print_summary(&self) {
+        println!();
+        info!(
+            "{}\n",
+            format!(
+                "Comparison: {}, baseline: {}, candidate: {}",
+                self.name, self.baseline_label, self.candidate_label
+            )
+            .blue()
+        );
+
+        self.groups
+            .iter()
+            .for_each(|group| info!("{}\n", group.formatted_string()));
+
+        if self.has_regressions() {
+            info!("{}", "Performance regression detected".red().bold());
+        } else {
+            info!("{}", "No performance regression detected".green());
+        }
+    }
+}
+
+impl ComparisonEntry {
+    pub fn formatted_string(&self) -> String {
+        let deltas = self
+            .deltas
+            .iter()
+            .map(|delta| {
+                let p_value = delta
+                    .p_value
+                    .map(|p| format!("{p:.3}"))
+                    .unwrap_or_else(|| "n/a".to_owned());
+                let text = format!(
+                    "{}: {:.2} -> {:.2} ({:+.2}%, p={p_value})",
+                    delta.metric, delta.baseline, delta.candidate, delta.change_percent
+                );
+                if delta.regression {
+                    text.red().to_string()
+                } else {
+                    text.green().to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("{}: {deltas}", self.label)
+    }
+}
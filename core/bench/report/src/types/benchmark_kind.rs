@@ -59,4 +59,10 @@ pub enum BenchmarkKind {
     #[display("End To End Producing Consumer Group")]
     #[serde(rename = "end_to_end_producing_consumer_group")]
     EndToEndProducingConsumerGroup,
+    #[display("Scenario")]
+    #[serde(rename = "scenario")]
+    Scenario,
+    #[display("Chaos")]
+    #[serde(rename = "chaos")]
+    Chaos,
 }
@@ -0,0 +1,172 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::round_float;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Behavior of the actors under the faults injected during the chaos benchmark.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkChaosMetrics {
+    pub summary: BenchmarkChaosSummary,
+    /// Faults in the order they were injected
+    pub faults: Vec<BenchmarkChaosFault>,
+    /// Time it took an actor to get a successful response after every failed request [ms]
+    pub reconnect_times_ms: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum ChaosFaultKind {
+    /// Server process killed with SIGKILL and started again on the same data
+    #[display("server restart")]
+    ServerRestart,
+    /// All the client connections reset by the proxy in front of the server
+    #[display("connection reset")]
+    ConnectionReset,
+    /// Server process paused with SIGSTOP and resumed with SIGCONT
+    #[display("server pause")]
+    ServerPause,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchmarkChaosFault {
+    pub kind: ChaosFaultKind,
+    /// Time since the start of the benchmark [s]
+    #[serde(serialize_with = "round_float")]
+    pub started_at_secs: f64,
+    /// How long the server was down or paused, zero for connection resets [ms]
+    #[serde(serialize_with = "round_float")]
+    pub duration_ms: f64,
+}
+
+/// Delivery of the acknowledged messages verified by the consumers by the message ids and offsets.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct BenchmarkChaosDelivery {
+    /// Messages acknowledged by the server to the producers
+    pub messages_acknowledged: u64,
+    /// Unique acknowledged messages polled by the consumers
+    pub messages_received: u64,
+    /// Acknowledged messages never polled by the consumers
+    pub lost_messages: u64,
+    /// Messages polled more than once, e.g. sent again after a lost acknowledgement
+    pub duplicate_messages: u64,
+    /// Polled messages whose offset didn't follow the offset of the previous message
+    pub offset_gaps: u64,
+}
+
+/// Requests whose latency exceeded the threshold, by all the actors.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkChaosLatencySpikes {
+    #[serde(serialize_with = "round_float")]
+    pub threshold_ms: f64,
+    pub count: u64,
+    #[serde(serialize_with = "round_float")]
+    pub max_latency_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkChaosSummary {
+    pub faults_injected: u64,
+    pub reconnects: u64,
+    #[serde(serialize_with = "round_float")]
+    pub average_reconnect_time_ms: f64,
+    #[serde(serialize_with = "round_float")]
+    pub max_reconnect_time_ms: f64,
+    pub delivery: BenchmarkChaosDelivery,
+    pub latency_spikes: BenchmarkChaosLatencySpikes,
+}
+
+impl BenchmarkChaosMetrics {
+    /// Creates the metrics with the reconnect times summarized.
+    pub fn new(
+        faults: Vec<BenchmarkChaosFault>,
+        reconnect_times_ms: Vec<f64>,
+        delivery: BenchmarkChaosDelivery,
+        latency_spikes: BenchmarkChaosLatencySpikes,
+    ) -> Self {
+        let reconnects = reconnect_times_ms.len() as u64;
+        let average_reconnect_time_ms = if reconnect_times_ms.is_empty() {
+            0.0
+        } else {
+            reconnect_times_ms.iter().sum::<f64>() / reconnect_times_ms.len() as f64
+        };
+        let max_reconnect_time_ms = reconnect_times_ms.iter().copied().fold(0.0, f64::max);
+
+        Self {
+            summary: BenchmarkChaosSummary {
+                faults_injected: faults.len() as u64,
+                reconnects,
+                average_reconnect_time_ms,
+                max_reconnect_time_ms,
+                delivery,
+                latency_spikes,
+            },
+            faults,
+            reconnect_times_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_summarize_reconnect_times() {
+        let faults = vec![
+            BenchmarkChaosFault {
+                kind: ChaosFaultKind::ServerRestart,
+                started_at_secs: 5.0,
+                duration_ms: 1000.0,
+            },
+            BenchmarkChaosFault {
+                kind: ChaosFaultKind::ConnectionReset,
+                started_at_secs: 10.0,
+                duration_ms: 0.0,
+            },
+        ];
+
+        let metrics = BenchmarkChaosMetrics::new(
+            faults,
+            vec![1200.0, 300.0, 1500.0],
+            BenchmarkChaosDelivery::default(),
+            BenchmarkChaosLatencySpikes::default(),
+        );
+
+        let summary = &metrics.summary;
+        assert_eq!(summary.faults_injected, 2);
+        assert_eq!(summary.reconnects, 3);
+        assert_eq!(summary.average_reconnect_time_ms, 1000.0);
+        assert_eq!(summary.max_reconnect_time_ms, 1500.0);
+    }
+
+    #[test]
+    fn should_summarize_run_without_reconnects() {
+        let metrics = BenchmarkChaosMetrics::new(
+            Vec::new(),
+            Vec::new(),
+            BenchmarkChaosDelivery::default(),
+            BenchmarkChaosLatencySpikes::default(),
+        );
+
+        assert_eq!(metrics.summary.reconnects, 0);
+        assert_eq!(metrics.summary.average_reconnect_time_ms, 0.0);
+        assert_eq!(metrics.summary.max_reconnect_time_ms, 0.0);
+    }
+}
@@ -0,0 +1,99 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::round_float;
+use hdrhistogram::Histogram;
+use serde::{Deserialize, Serialize};
+
+/// Latency percentiles of the open-loop benchmark, measured from the intended (scheduled)
+/// send time instead of the actual one, so the queueing delay caused by slow responses
+/// is not hidden (coordinated omission correction).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkCorrectedLatency {
+    #[serde(serialize_with = "round_float")]
+    pub p50_latency_ms: f64,
+    #[serde(serialize_with = "round_float")]
+    pub p99_latency_ms: f64,
+    #[serde(serialize_with = "round_float")]
+    pub p999_latency_ms: f64,
+    #[serde(serialize_with = "round_float")]
+    pub p9999_latency_ms: f64,
+    #[serde(serialize_with = "round_float")]
+    pub max_latency_ms: f64,
+}
+
+impl BenchmarkCorrectedLatency {
+    /// Reads the percentiles from the histogram of the latencies recorded in microseconds.
+    pub fn from_histogram(histogram: &Histogram<u64>) -> Self {
+        let quantile_ms = |quantile: f64| histogram.value_at_quantile(quantile) as f64 / 1_000.0;
+
+        Self {
+            p50_latency_ms: quantile_ms(0.5),
+            p99_latency_ms: quantile_ms(0.99),
+            p999_latency_ms: quantile_ms(0.999),
+            p9999_latency_ms: quantile_ms(0.9999),
+            max_latency_ms: histogram.max() as f64 / 1_000.0,
+        }
+    }
+
+    /// Merges the histograms of multiple actors and reads the percentiles from the merged one,
+    /// returns `None` if there are none.
+    pub fn merge<'a>(histograms: impl Iterator<Item = &'a Histogram<u64>>) -> Option<Self> {
+        let mut merged: Option<Histogram<u64>> = None;
+        for histogram in histograms {
+            match &mut merged {
+                Some(merged) => merged
+                    .add(histogram)
+                    .expect("Corrected latency histograms have the same bounds"),
+                None => merged = Some(histogram.clone()),
+            }
+        }
+
+        merged.as_ref().map(Self::from_histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn histogram(latencies_us: &[u64]) -> Histogram<u64> {
+        let mut histogram = Histogram::new_with_bounds(1, 3_600_000_000, 3).unwrap();
+        for latency in latencies_us {
+            histogram.record(*latency).unwrap();
+        }
+        histogram
+    }
+
+    #[test]
+    fn should_read_percentiles_from_merged_histograms() {
+        let fast = histogram(&[1_000; 99]);
+        let slow = histogram(&[100_000]);
+
+        let merged = BenchmarkCorrectedLatency::merge([&fast, &slow].into_iter()).unwrap();
+
+        assert_eq!(merged.p50_latency_ms, 1.0);
+        assert!(merged.p999_latency_ms >= 99.0);
+        assert!(merged.max_latency_ms >= 99.0);
+    }
+
+    #[test]
+    fn should_return_none_without_histograms() {
+        assert!(BenchmarkCorrectedLatency::merge(std::iter::empty()).is_none());
+    }
+}
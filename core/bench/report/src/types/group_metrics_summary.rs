@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use super::corrected_latency::BenchmarkCorrectedLatency;
 use super::group_metrics_kind::GroupMetricsKind;
 use crate::utils::round_float;
 use serde::{Deserialize, Serialize};
@@ -53,4 +54,7 @@ pub struct BenchmarkGroupMetricsSummary {
     pub max_latency_ms: f64,
     #[serde(default, serialize_with = "round_float")]
     pub std_dev_latency_ms: f64,
+    /// Percentiles of the merged corrected latencies of all the actors, present only for open-loop benchmarks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub average_corrected_latency: Option<BenchmarkCorrectedLatency>,
 }
@@ -20,6 +20,7 @@ use super::{
     individual_metrics_summary::BenchmarkIndividualMetricsSummary, time_series::TimeSeries,
 };
 use crate::utils::{max, min, std_dev};
+use hdrhistogram::Histogram;
 use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
@@ -30,6 +31,10 @@ pub struct BenchmarkIndividualMetrics {
     pub throughput_mb_ts: TimeSeries,
     pub throughput_msg_ts: TimeSeries,
     pub latency_ts: TimeSeries,
+    /// Corrected latencies of the open-loop benchmark, kept only in memory to merge them
+    /// into the group metrics
+    #[serde(skip)]
+    pub corrected_latency_histogram: Option<Histogram<u64>>,
 }
 
 // Custom deserializer implementation
@@ -109,6 +114,7 @@ impl<'de> Deserialize<'de> for BenchmarkIndividualMetrics {
                     throughput_mb_ts,
                     throughput_msg_ts,
                     latency_ts,
+                    corrected_latency_histogram: None,
                 })
             }
         }
@@ -17,6 +17,7 @@
  */
 
 use super::actor_kind::ActorKind;
+use super::corrected_latency::BenchmarkCorrectedLatency;
 use crate::benchmark_kind::BenchmarkKind;
 use crate::utils::round_float;
 use serde::{Deserialize, Serialize};
@@ -59,4 +60,7 @@ pub struct BenchmarkIndividualMetricsSummary {
     pub max_latency_ms: f64,
     #[serde(default, serialize_with = "round_float")]
     pub std_dev_latency_ms: f64,
+    /// Present only for open-loop benchmarks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corrected_latency: Option<BenchmarkCorrectedLatency>,
 }
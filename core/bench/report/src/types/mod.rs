@@ -18,6 +18,8 @@
 
 pub mod actor_kind;
 pub mod benchmark_kind;
+pub mod chaos;
+pub mod corrected_latency;
 pub mod group_metrics;
 pub mod group_metrics_kind;
 pub mod group_metrics_summary;
@@ -27,6 +29,7 @@ pub mod individual_metrics_summary;
 pub mod numeric_parameter;
 pub mod params;
 pub mod report;
+pub mod server_resources;
 pub mod server_stats;
 pub mod time_series;
 pub mod transport;
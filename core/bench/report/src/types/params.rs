@@ -40,12 +40,54 @@ pub struct BenchmarkParams {
     pub partitions: u32,
     pub consumer_groups: u32,
     pub rate_limit: Option<String>,
+    #[serde(default)]
+    pub open_loop: bool,
     pub pretty_name: String,
     pub bench_command: String,
     pub params_identifier: String,
 }
 
 impl BenchmarkParams {
+    /// Returns names of the parameters defining the workload which differ between the benchmarks,
+    /// results of benchmarks with no mismatched parameters can be compared with each other.
+    pub fn mismatched_params(&self, other: &BenchmarkParams) -> Vec<&'static str> {
+        let mut mismatched = Vec::new();
+        if self.benchmark_kind != other.benchmark_kind {
+            mismatched.push("benchmark_kind");
+        }
+        if self.transport != other.transport {
+            mismatched.push("transport");
+        }
+        if self.messages_per_batch != other.messages_per_batch {
+            mismatched.push("messages_per_batch");
+        }
+        if self.message_size != other.message_size {
+            mismatched.push("message_size");
+        }
+        if self.producers != other.producers {
+            mismatched.push("producers");
+        }
+        if self.consumers != other.consumers {
+            mismatched.push("consumers");
+        }
+        if self.streams != other.streams {
+            mismatched.push("streams");
+        }
+        if self.partitions != other.partitions {
+            mismatched.push("partitions");
+        }
+        if self.consumer_groups != other.consumer_groups {
+            mismatched.push("consumer_groups");
+        }
+        if self.rate_limit != other.rate_limit {
+            mismatched.push("rate_limit");
+        }
+        if self.open_loop != other.open_loop {
+            mismatched.push("open_loop");
+        }
+        mismatched
+    }
+
     pub fn format_actors_info(&self) -> String {
         match self.benchmark_kind {
             BenchmarkKind::PinnedProducer => format!("{} Producers", self.producers),
@@ -73,6 +115,9 @@ impl BenchmarkParams {
                     self.producers, self.consumer_groups
                 )
             }
+            BenchmarkKind::Scenario | BenchmarkKind::Chaos => {
+                format!("{} Producers/{} Consumers", self.producers, self.consumers)
+            }
         }
     }
 }
@@ -16,6 +16,8 @@
  * under the License.
  */
 
+use super::chaos::BenchmarkChaosMetrics;
+use super::server_resources::BenchmarkServerResources;
 use super::server_stats::BenchmarkServerStats;
 use crate::group_metrics::BenchmarkGroupMetrics;
 use crate::individual_metrics::BenchmarkIndividualMetrics;
@@ -36,6 +38,14 @@ pub struct BenchmarkReport {
     /// Benchmark server statistics
     pub server_stats: BenchmarkServerStats,
 
+    /// Server resource usage sampled during the benchmark, absent when profiling was disabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_resources: Option<BenchmarkServerResources>,
+
+    /// Faults injected during the chaos benchmark and their impact, absent for other benchmarks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chaos: Option<BenchmarkChaosMetrics>,
+
     /// Benchmark hardware
     pub hardware: BenchmarkHardware,
 
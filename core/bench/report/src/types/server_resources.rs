@@ -0,0 +1,186 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::time_series::TimeSeries;
+use crate::utils::{max, round_float};
+use serde::{Deserialize, Serialize};
+
+/// Resource usage of the server sampled throughout the benchmark.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkServerResources {
+    pub summary: BenchmarkServerResourcesSummary,
+    /// CPU usage of the server process reported by the server stats [%]
+    pub cpu_usage_ts: TimeSeries,
+    /// Memory usage of the server process reported by the server stats [MB]
+    pub memory_usage_mb_ts: TimeSeries,
+    /// Disk reads of the server process reported by the server stats [MB/s]
+    pub read_mb_ts: TimeSeries,
+    /// Disk writes of the server process reported by the server stats [MB/s]
+    pub written_mb_ts: TimeSeries,
+    /// Cache hit ratio of all the partitions within the sampling interval [%]
+    pub cache_hit_ratio_ts: TimeSeries,
+    /// Present only when the server was started by the benchmark
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process: Option<BenchmarkServerProcessMetrics>,
+}
+
+/// Metrics of the locally started server process, sampled by the benchmark itself.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkServerProcessMetrics {
+    pub pid: u32,
+    /// CPU usage of the process, 100% per fully utilized core [%]
+    pub cpu_usage_ts: TimeSeries,
+    /// Resident memory of the process [MB]
+    pub memory_usage_mb_ts: TimeSeries,
+    /// Virtual memory of the process [MB]
+    pub virtual_memory_mb_ts: TimeSeries,
+    /// Disk reads of the process [MB/s]
+    pub disk_read_mb_ts: TimeSeries,
+    /// Disk writes of the process [MB/s]
+    pub disk_written_mb_ts: TimeSeries,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BenchmarkServerResourcesSummary {
+    pub sampling_interval_ms: u64,
+    pub samples: u64,
+    #[serde(serialize_with = "round_float")]
+    pub average_cpu_usage: f64,
+    #[serde(serialize_with = "round_float")]
+    pub max_cpu_usage: f64,
+    #[serde(serialize_with = "round_float")]
+    pub average_memory_usage_mb: f64,
+    #[serde(serialize_with = "round_float")]
+    pub max_memory_usage_mb: f64,
+    #[serde(serialize_with = "round_float")]
+    pub total_read_mb: f64,
+    #[serde(serialize_with = "round_float")]
+    pub total_written_mb: f64,
+    /// Absent when the server doesn't report the cache metrics
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub average_cache_hit_ratio: Option<f64>,
+}
+
+impl BenchmarkServerResources {
+    /// Creates the resources with the summary calculated from the time series sampled
+    /// every `sampling_interval_ms`.
+    pub fn new(
+        sampling_interval_ms: u64,
+        cpu_usage_ts: TimeSeries,
+        memory_usage_mb_ts: TimeSeries,
+        read_mb_ts: TimeSeries,
+        written_mb_ts: TimeSeries,
+        cache_hit_ratio_ts: TimeSeries,
+        process: Option<BenchmarkServerProcessMetrics>,
+    ) -> Self {
+        let interval_s = sampling_interval_ms as f64 / 1000.0;
+        let summary = BenchmarkServerResourcesSummary {
+            sampling_interval_ms,
+            samples: cpu_usage_ts.points.len() as u64,
+            average_cpu_usage: average(&cpu_usage_ts).unwrap_or_default(),
+            max_cpu_usage: max(&cpu_usage_ts).unwrap_or_default(),
+            average_memory_usage_mb: average(&memory_usage_mb_ts).unwrap_or_default(),
+            max_memory_usage_mb: max(&memory_usage_mb_ts).unwrap_or_default(),
+            total_read_mb: total(&read_mb_ts, interval_s),
+            total_written_mb: total(&written_mb_ts, interval_s),
+            average_cache_hit_ratio: average(&cache_hit_ratio_ts),
+        };
+
+        Self {
+            summary,
+            cpu_usage_ts,
+            memory_usage_mb_ts,
+            read_mb_ts,
+            written_mb_ts,
+            cache_hit_ratio_ts,
+            process,
+        }
+    }
+}
+
+fn average(series: &TimeSeries) -> Option<f64> {
+    if series.points.is_empty() {
+        return None;
+    }
+    let sum: f64 = series.points.iter().map(|p| p.value).sum();
+    Some(sum / series.points.len() as f64)
+}
+
+/// Total amount of a rate sampled at the fixed interval.
+fn total(series: &TimeSeries, interval_s: f64) -> f64 {
+    series.points.iter().map(|p| p.value * interval_s).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time_series::TimePoint;
+
+    fn series(values: &[f64]) -> TimeSeries {
+        TimeSeries {
+            points: values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| TimePoint::new(i as f64 * 0.5, *value))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_summarize_sampled_resources() {
+        let resources = BenchmarkServerResources::new(
+            500,
+            series(&[10.0, 30.0, 20.0]),
+            series(&[100.0, 150.0, 200.0]),
+            series(&[0.0, 4.0, 2.0]),
+            series(&[10.0, 10.0, 20.0]),
+            TimeSeries::default(),
+            None,
+        );
+
+        let summary = &resources.summary;
+        assert_eq!(summary.samples, 3);
+        assert_eq!(summary.average_cpu_usage, 20.0);
+        assert_eq!(summary.max_cpu_usage, 30.0);
+        assert_eq!(summary.average_memory_usage_mb, 150.0);
+        assert_eq!(summary.max_memory_usage_mb, 200.0);
+        assert_eq!(summary.total_read_mb, 3.0);
+        assert_eq!(summary.total_written_mb, 20.0);
+        assert_eq!(summary.average_cache_hit_ratio, None);
+    }
+
+    #[test]
+    fn should_deserialize_report_without_process_metrics() {
+        let resources = BenchmarkServerResources::new(
+            1000,
+            series(&[1.0]),
+            series(&[1.0]),
+            series(&[1.0]),
+            series(&[1.0]),
+            series(&[99.0]),
+            None,
+        );
+
+        let json = serde_json::to_string(&resources).unwrap();
+        assert!(!json.contains("process"));
+        let deserialized: BenchmarkServerResources = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.summary.average_cache_hit_ratio, Some(99.0));
+        assert!(deserialized.process.is_none());
+    }
+}
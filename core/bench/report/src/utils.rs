@@ -73,3 +73,138 @@ pub fn std_dev(series: &TimeSeries) -> Option<f64> {
 
     Some(variance.sqrt())
 }
+
+/// Two-sided p-value of the Welch's t-test checking whether two samples have equal means
+///
+/// Returns None if any of the samples has fewer than 2 values
+pub fn welch_t_test_p_value(a: &[f64], b: &[f64]) -> Option<f64> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+
+    let (mean_a, variance_a) = mean_and_variance(a);
+    let (mean_b, variance_b) = mean_and_variance(b);
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let standard_error_sq = variance_a / n_a + variance_b / n_b;
+    if standard_error_sq == 0.0 {
+        return Some(if mean_a == mean_b { 1.0 } else { 0.0 });
+    }
+
+    let t = (mean_a - mean_b) / standard_error_sq.sqrt();
+    let degrees_of_freedom = standard_error_sq.powi(2)
+        / ((variance_a / n_a).powi(2) / (n_a - 1.0) + (variance_b / n_b).powi(2) / (n_b - 1.0));
+    let x = degrees_of_freedom / (degrees_of_freedom + t * t);
+    Some(regularized_incomplete_beta(x, degrees_of_freedom / 2.0, 0.5).clamp(0.0, 1.0))
+}
+
+/// Mean and unbiased sample variance of the values
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (count - 1.0);
+    (mean, variance)
+}
+
+/// Natural logarithm of the gamma function (Lanczos approximation)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut series = 1.000_000_000_190_015;
+    let mut y = x;
+    for coefficient in COEFFICIENTS {
+        y += 1.0;
+        series += coefficient / y;
+    }
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+/// Regularized incomplete beta function I_x(a, b)
+fn regularized_incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Continued fraction of the incomplete beta function evaluated with the modified Lentz's method
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 1e-12;
+    const TINY: f64 = 1e-300;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut fraction = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        for numerator in [
+            m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m)),
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            fraction *= d * c;
+        }
+        if (d * c - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn welch_t_test_should_match_reference_values() {
+        // Reference values: t = -2.377, degrees of freedom = 20.487, p = 0.0273
+        let a = [
+            27.5, 21.0, 19.0, 23.6, 17.0, 17.9, 16.9, 20.1, 21.9, 22.6, 23.1, 19.6, 19.0,
+        ];
+        let b = [
+            27.1, 22.0, 20.8, 23.4, 23.4, 23.5, 25.8, 22.0, 24.8, 20.2, 21.9, 22.1, 22.9,
+        ];
+
+        let p_value = welch_t_test_p_value(&a, &b).unwrap();
+
+        assert!((p_value - 0.0273).abs() < 0.001, "p-value: {p_value}");
+    }
+
+    #[test]
+    fn welch_t_test_should_not_detect_difference_of_identical_samples() {
+        let a = [1.0, 2.0, 3.0, 4.0];
+
+        assert!((welch_t_test_p_value(&a, &a).unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(welch_t_test_p_value(&a[..1], &a), None);
+    }
+}
@@ -18,9 +18,13 @@
 mod local_benchmark_runner;
 mod utils;
 use anyhow::{Context, Result};
+use bench_report::comparison::{
+    BenchmarkComparison, ComparisonThresholds, load_reports, match_reports,
+};
 use dircpy::copy_dir;
 use local_benchmark_runner::LocalBenchmarkRunner;
-use tracing::info;
+use std::path::Path;
+use tracing::{info, warn};
 
 use crate::args::IggyBenchRunnerArgs;
 
@@ -74,6 +78,47 @@ impl IggyBenchRunnerApp {
         // Recursively copy the entire directory
         copy_dir(&source_dir, &target_dir)?;
 
+        if let Some(baseline) = &args.baseline {
+            self.compare_with_baseline(baseline, &target_dir)?;
+        }
+
+        Ok(())
+    }
+
+    fn compare_with_baseline(&self, baseline_dir: &str, results_dir: &str) -> Result<()> {
+        info!("Comparing {} with baseline {}", results_dir, baseline_dir);
+        let thresholds = ComparisonThresholds {
+            max_throughput_drop_percent: self.args.max_throughput_drop,
+            max_latency_increase_percent: self.args.max_latency_increase,
+            ..Default::default()
+        };
+        let baseline = load_reports(Path::new(baseline_dir))?;
+        let candidate = load_reports(Path::new(results_dir))?;
+        let (matched, unmatched) = match_reports(&baseline, &candidate);
+        for report in unmatched {
+            warn!(
+                "No baseline found for benchmark: {}, skipping",
+                report.params.pretty_name
+            );
+        }
+
+        let mut regressions = Vec::new();
+        for (baseline_report, candidate_report) in matched {
+            let comparison =
+                BenchmarkComparison::new(baseline_report, candidate_report, thresholds)?;
+            comparison.print_summary();
+            if comparison.has_regressions() {
+                regressions.push(comparison.name);
+            }
+        }
+
+        if !regressions.is_empty() {
+            anyhow::bail!(
+                "Performance regression detected in: {}",
+                regressions.join(", ")
+            );
+        }
+
         Ok(())
     }
 }
@@ -46,6 +46,19 @@ pub struct IggyBenchRunnerArgs {
     /// Skip checking out master branch before running benchmarks
     #[arg(long)]
     pub skip_master_checkout: bool,
+
+    /// Directory with baseline benchmark results, when set results are compared
+    /// with the baseline and the run fails on performance regression
+    #[arg(long)]
+    pub baseline: Option<String>,
+
+    /// Maximum allowed throughput drop in percents when comparing with the baseline
+    #[arg(long, default_value_t = 5.0)]
+    pub max_throughput_drop: f64,
+
+    /// Maximum allowed latency increase in percents when comparing with the baseline
+    #[arg(long, default_value_t = 10.0)]
+    pub max_latency_increase: f64,
 }
 
 impl IggyBenchRunnerArgs {
@@ -61,6 +74,12 @@ impl IggyBenchRunnerArgs {
             anyhow::bail!("Directory '{}' is not a git repository", self.directory);
         }
 
+        if let Some(baseline) = &self.baseline
+            && !Path::new(baseline).exists()
+        {
+            anyhow::bail!("Baseline directory '{}' does not exist", baseline);
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,261 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{ChaosOutage, ChaosStreamState, split_message_id};
+use crate::analytics::chaos_stats::BenchmarkChaosStats;
+use crate::analytics::metrics::individual::from_records;
+use crate::analytics::record::BenchmarkRecord;
+use crate::utils::{batch_total_size_bytes, batch_user_size_bytes};
+use bench_report::actor_kind::ActorKind;
+use bench_report::benchmark_kind::BenchmarkKind;
+use bench_report::chaos::BenchmarkChaosDelivery;
+use bench_report::individual_metrics::BenchmarkIndividualMetrics;
+use bench_report::numeric_parameter::BenchmarkNumericParameter;
+use human_repr::HumanCount;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{Instant, timeout};
+use tracing::{info, warn};
+
+/// Consumer of the chaos benchmark, polls its stream by offset until the producer finished
+/// and verifies the delivery of the acknowledged messages by their ids and offsets.
+pub struct BenchmarkChaosConsumer {
+    client_factory: Arc<dyn ClientFactory>,
+    benchmark_kind: BenchmarkKind,
+    consumer_id: u32,
+    producer_id: u32,
+    stream_id: u32,
+    messages_per_batch: BenchmarkNumericParameter,
+    sampling_time: IggyDuration,
+    moving_average_window: u32,
+    max_outage: Duration,
+    stats: Arc<BenchmarkChaosStats>,
+    stream_state: Arc<ChaosStreamState>,
+}
+
+/// Messages polled by the consumer, counted by their sequence numbers.
+#[derive(Default)]
+struct DeliveryVerifier {
+    next_offset: u64,
+    received: Vec<u8>,
+    offset_gaps: u64,
+}
+
+impl DeliveryVerifier {
+    fn verify(&mut self, producer_id: u32, message: &IggyMessage) {
+        if message.header.offset != self.next_offset {
+            self.offset_gaps += 1;
+        }
+        self.next_offset = message.header.offset + 1;
+
+        let (message_producer_id, sequence) = split_message_id(message.header.id);
+        if message_producer_id != producer_id {
+            return;
+        }
+        let sequence = usize::try_from(sequence).unwrap_or(usize::MAX);
+        if self.received.len() <= sequence {
+            self.received.resize(sequence + 1, 0);
+        }
+        self.received[sequence] = self.received[sequence].saturating_add(1);
+    }
+
+    fn delivery(&self, acknowledged: u64) -> BenchmarkChaosDelivery {
+        let acknowledged_sequences = usize::try_from(acknowledged).unwrap_or(usize::MAX);
+        let received = self
+            .received
+            .iter()
+            .take(acknowledged_sequences)
+            .filter(|count| **count > 0)
+            .count() as u64;
+        let duplicates = self
+            .received
+            .iter()
+            .map(|count| u64::from(count.saturating_sub(1)))
+            .sum();
+
+        BenchmarkChaosDelivery {
+            messages_acknowledged: acknowledged,
+            messages_received: received,
+            lost_messages: acknowledged - received,
+            duplicate_messages: duplicates,
+            offset_gaps: self.offset_gaps,
+        }
+    }
+}
+
+impl BenchmarkChaosConsumer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_factory: Arc<dyn ClientFactory>,
+        benchmark_kind: BenchmarkKind,
+        consumer_id: u32,
+        producer_id: u32,
+        stream_id: u32,
+        messages_per_batch: BenchmarkNumericParameter,
+        sampling_time: IggyDuration,
+        moving_average_window: u32,
+        max_outage: Duration,
+        stats: Arc<BenchmarkChaosStats>,
+        stream_state: Arc<ChaosStreamState>,
+    ) -> Self {
+        Self {
+            client_factory,
+            benchmark_kind,
+            consumer_id,
+            producer_id,
+            stream_id,
+            messages_per_batch,
+            sampling_time,
+            moving_average_window,
+            max_outage,
+            stats,
+            stream_state,
+        }
+    }
+
+    #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+    pub async fn run(self) -> Result<BenchmarkIndividualMetrics, IggyError> {
+        let topic_id: u32 = 1;
+        let partition_id: u32 = 1;
+        let client = self.client_factory.create_client().await;
+        let client = IggyClient::create(client, None, None);
+        login_root(&client).await;
+
+        let stream_id = self.stream_id.try_into()?;
+        let topic_id = topic_id.try_into()?;
+        let consumer = Consumer::new(self.consumer_id.try_into()?);
+        let mut outage = ChaosOutage::new(
+            format!("Chaos consumer #{}", self.consumer_id),
+            self.max_outage,
+            self.stats.clone(),
+        );
+
+        info!(
+            "Chaos consumer #{} → polling in {} messages per batch from stream {}, verifying messages of producer #{}...",
+            self.consumer_id, self.messages_per_batch, stream_id, self.producer_id
+        );
+
+        let mut verifier = DeliveryVerifier::default();
+        let mut records: Vec<BenchmarkRecord> = Vec::new();
+        let mut messages_processed = 0;
+        let mut batches_processed = 0;
+        let mut total_bytes_processed = 0;
+        let mut user_data_bytes_processed = 0;
+        let start_timestamp = Instant::now();
+
+        loop {
+            // Checked before polling, so that the empty poll after the last acknowledged batch ends the run
+            let producer_finished = self.stream_state.is_finished();
+            let before_poll = Instant::now();
+            let polled_messages = loop {
+                let request_started = Instant::now();
+                match timeout(
+                    outage.remaining(),
+                    client.poll_messages(
+                        &stream_id,
+                        &topic_id,
+                        Some(partition_id),
+                        &consumer,
+                        &PollingStrategy::offset(verifier.next_offset),
+                        self.messages_per_batch.get(),
+                        false,
+                    ),
+                )
+                .await
+                {
+                    Ok(Ok(polled_messages)) => {
+                        outage.succeeded();
+                        break polled_messages;
+                    }
+                    Ok(Err(e)) => outage.recover(&client, request_started, Some(e)).await?,
+                    Err(_) => outage.recover(&client, request_started, None).await?,
+                }
+            };
+            let latency = before_poll.elapsed();
+            self.stats.record_latency(latency);
+
+            if polled_messages.messages.is_empty() {
+                // Unsaved messages are gone after the server got killed, the partition continues
+                // from its last saved offset, and the lost messages are found by their ids.
+                if polled_messages.current_offset + 1 < verifier.next_offset {
+                    warn!(
+                        "Chaos consumer #{} → partition truncated from offset {} to {}",
+                        self.consumer_id,
+                        verifier.next_offset - 1,
+                        polled_messages.current_offset
+                    );
+                    verifier.next_offset = polled_messages.current_offset + 1;
+                }
+                if producer_finished {
+                    break;
+                }
+                continue;
+            }
+
+            for message in &polled_messages.messages {
+                verifier.verify(self.producer_id, message);
+            }
+            messages_processed += polled_messages.messages.len() as u64;
+            batches_processed += 1;
+            user_data_bytes_processed += batch_user_size_bytes(&polled_messages);
+            total_bytes_processed += batch_total_size_bytes(&polled_messages);
+
+            records.push(BenchmarkRecord {
+                elapsed_time_us: u64::try_from(start_timestamp.elapsed().as_micros())
+                    .unwrap_or(u64::MAX),
+                latency_us: u64::try_from(latency.as_micros()).unwrap_or(u64::MAX),
+                messages: messages_processed,
+                message_batches: batches_processed,
+                user_data_bytes: user_data_bytes_processed,
+                total_bytes: total_bytes_processed,
+            });
+        }
+
+        let delivery = verifier.delivery(self.stream_state.acknowledged());
+        self.stats.record_delivery(delivery);
+
+        let metrics = from_records(
+            &records,
+            self.benchmark_kind,
+            ActorKind::Consumer,
+            self.consumer_id,
+            self.sampling_time,
+            self.moving_average_window,
+            None,
+        );
+
+        info!(
+            "Chaos consumer #{} → polled {} messages in {} batches in {:.2} s, average throughput: {:.2} MB/s, \
+            acknowledged: {}, received: {}, lost: {}, duplicated: {}, offset gaps: {}",
+            self.consumer_id,
+            messages_processed.human_count_bare(),
+            batches_processed.human_count_bare(),
+            metrics.summary.total_time_secs,
+            metrics.summary.throughput_megabytes_per_second,
+            delivery.messages_acknowledged,
+            delivery.messages_received,
+            delivery.lost_messages,
+            delivery.duplicate_messages,
+            delivery.offset_gaps,
+        );
+
+        Ok(metrics)
+    }
+}
@@ -0,0 +1,160 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+pub mod consumer;
+pub mod producer;
+
+pub use consumer::BenchmarkChaosConsumer;
+pub use producer::BenchmarkChaosProducer;
+
+use crate::analytics::chaos_stats::BenchmarkChaosStats;
+use iggy::prelude::*;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
+use std::time::Duration;
+use tokio::time::{Instant, sleep, timeout};
+use tracing::{info, warn};
+
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Progress of the producer of a stream, shared with the consumer verifying its messages.
+#[derive(Default)]
+pub struct ChaosStreamState {
+    finished: AtomicBool,
+    acknowledged: AtomicU64,
+}
+
+impl ChaosStreamState {
+    pub fn acknowledge(&self, messages: u64) {
+        self.acknowledged.fetch_add(messages, Ordering::Release);
+    }
+
+    pub fn finish(&self) {
+        self.finished.store(true, Ordering::Release);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
+
+    pub fn acknowledged(&self) -> u64 {
+        self.acknowledged.load(Ordering::Acquire)
+    }
+}
+
+/// Message id of the chaos benchmark, identifying the producer and the sequence number of the message.
+pub const fn message_id(producer_id: u32, sequence: u64) -> u128 {
+    ((producer_id as u128) << 64) | sequence as u128
+}
+
+/// Splits the message id into the producer id and the sequence number.
+#[allow(clippy::cast_possible_truncation)]
+pub const fn split_message_id(id: u128) -> (u32, u64) {
+    ((id >> 64) as u32, id as u64)
+}
+
+/// Tracks the outage of an actor, from the start of its first failed request until the next
+/// successful one. The server forgets the sessions when restarted, so the actor logs in again
+/// before every retry.
+pub struct ChaosOutage {
+    actor: String,
+    max_outage: Duration,
+    stats: Arc<BenchmarkChaosStats>,
+    started: Option<Instant>,
+}
+
+impl ChaosOutage {
+    pub const fn new(actor: String, max_outage: Duration, stats: Arc<BenchmarkChaosStats>) -> Self {
+        Self {
+            actor,
+            max_outage,
+            stats,
+            started: None,
+        }
+    }
+
+    /// Time left for the next request before the actor gives up.
+    pub fn remaining(&self) -> Duration {
+        self.started.map_or(self.max_outage, |started| {
+            self.max_outage.saturating_sub(started.elapsed())
+        })
+    }
+
+    /// Handles the failed (or timed out) request started at the given time, fails when
+    /// the server has been unavailable for longer than the max outage.
+    pub async fn recover(
+        &mut self,
+        client: &IggyClient,
+        request_started: Instant,
+        error: Option<IggyError>,
+    ) -> Result<(), IggyError> {
+        let started = *self.started.get_or_insert(request_started);
+        let error = error.unwrap_or(IggyError::CannotEstablishConnection);
+        if started.elapsed() >= self.max_outage {
+            warn!(
+                "{} → server unavailable for over {} ms, giving up: {error}",
+                self.actor,
+                self.max_outage.as_millis()
+            );
+            return Err(error);
+        }
+
+        warn!("{} → request failed: {error}, retrying...", self.actor);
+        sleep(RETRY_INTERVAL).await;
+        // The client reconnects by itself only after some of the connection errors,
+        // e.g. not after writing to a reset connection
+        if matches!(error, IggyError::Unauthenticated) && self.login(client).await.is_ok() {
+            return Ok(());
+        }
+        let reconnect = async {
+            client.disconnect().await?;
+            client.connect().await
+        };
+        match timeout(self.remaining(), reconnect).await {
+            Ok(Ok(())) => {
+                let _ = self.login(client).await;
+            }
+            Ok(Err(e)) => warn!("{} → failed to reconnect: {e}", self.actor),
+            Err(_) => warn!("{} → reconnecting timed out", self.actor),
+        }
+        Ok(())
+    }
+
+    async fn login(&self, client: &IggyClient) -> Result<(), IggyError> {
+        client
+            .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+            .await
+            .map(|_| ())
+            .inspect_err(|e| warn!("{} → failed to log in again: {e}", self.actor))
+    }
+
+    /// Records the reconnect time after a request succeeded following an outage.
+    pub fn succeeded(&mut self) {
+        if let Some(started) = self.started.take() {
+            let reconnect_time = started.elapsed();
+            info!(
+                "{} → recovered after {} ms",
+                self.actor,
+                reconnect_time.as_millis()
+            );
+            self.stats.record_reconnect(reconnect_time);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{ChaosOutage, ChaosStreamState, message_id};
+use crate::analytics::chaos_stats::BenchmarkChaosStats;
+use crate::analytics::metrics::individual::from_records;
+use crate::analytics::record::BenchmarkRecord;
+use crate::utils::batch_generator::BenchmarkBatchGenerator;
+use crate::utils::finish_condition::BenchmarkFinishCondition;
+use crate::utils::rate_limiter::BenchmarkRateLimiter;
+use bench_report::actor_kind::ActorKind;
+use bench_report::benchmark_kind::BenchmarkKind;
+use bench_report::individual_metrics::BenchmarkIndividualMetrics;
+use bench_report::numeric_parameter::BenchmarkNumericParameter;
+use human_repr::HumanCount;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{Instant, timeout};
+use tracing::info;
+
+/// Producer of the chaos benchmark, retries every batch until it's acknowledged by the server.
+/// Every message gets a unique id made of the producer id and its sequence number,
+/// so that the consumer can verify the delivery.
+pub struct BenchmarkChaosProducer {
+    client_factory: Arc<dyn ClientFactory>,
+    benchmark_kind: BenchmarkKind,
+    producer_id: u32,
+    stream_id: u32,
+    messages_per_batch: BenchmarkNumericParameter,
+    message_size: BenchmarkNumericParameter,
+    finish_condition: Arc<BenchmarkFinishCondition>,
+    sampling_time: IggyDuration,
+    moving_average_window: u32,
+    limit_bytes_per_second: Option<IggyByteSize>,
+    max_outage: Duration,
+    stats: Arc<BenchmarkChaosStats>,
+    stream_state: Arc<ChaosStreamState>,
+}
+
+impl BenchmarkChaosProducer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client_factory: Arc<dyn ClientFactory>,
+        benchmark_kind: BenchmarkKind,
+        producer_id: u32,
+        stream_id: u32,
+        messages_per_batch: BenchmarkNumericParameter,
+        message_size: BenchmarkNumericParameter,
+        finish_condition: Arc<BenchmarkFinishCondition>,
+        sampling_time: IggyDuration,
+        moving_average_window: u32,
+        limit_bytes_per_second: Option<IggyByteSize>,
+        max_outage: Duration,
+        stats: Arc<BenchmarkChaosStats>,
+        stream_state: Arc<ChaosStreamState>,
+    ) -> Self {
+        Self {
+            client_factory,
+            benchmark_kind,
+            producer_id,
+            stream_id,
+            messages_per_batch,
+            message_size,
+            finish_condition,
+            sampling_time,
+            moving_average_window,
+            limit_bytes_per_second,
+            max_outage,
+            stats,
+            stream_state,
+        }
+    }
+
+    #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+    pub async fn run(self) -> Result<BenchmarkIndividualMetrics, IggyError> {
+        let mut batch_generator =
+            BenchmarkBatchGenerator::new(self.message_size, self.messages_per_batch);
+        let rate_limiter = self.limit_bytes_per_second.map(BenchmarkRateLimiter::new);
+        let topic_id: u32 = 1;
+        let partition_id: u32 = 1;
+        let client = self.client_factory.create_client().await;
+        let client = IggyClient::create(client, None, None);
+        login_root(&client).await;
+
+        let stream_id = self.stream_id.try_into()?;
+        let topic_id = topic_id.try_into()?;
+        let partitioning = Partitioning::partition_id(partition_id);
+        let mut outage = ChaosOutage::new(
+            format!("Chaos producer #{}", self.producer_id),
+            self.max_outage,
+            self.stats.clone(),
+        );
+
+        info!(
+            "Chaos producer #{} → sending {} in batches of {} messages to stream {}, rate limit: {:?}...",
+            self.producer_id,
+            self.finish_condition.total_str(),
+            self.messages_per_batch,
+            stream_id,
+            self.limit_bytes_per_second,
+        );
+
+        let max_capacity = self.finish_condition.max_capacity();
+        let mut records: Vec<BenchmarkRecord> = Vec::with_capacity(max_capacity);
+        let mut messages_processed = 0;
+        let mut batches_processed = 0;
+        let mut total_bytes_processed = 0;
+        let mut user_data_bytes_processed = 0;
+        let start_timestamp = Instant::now();
+
+        while !self.finish_condition.is_done() {
+            let batch = batch_generator.generate_batch();
+            for (sequence, message) in (messages_processed..).zip(batch.messages.iter_mut()) {
+                message.header.id = message_id(self.producer_id, sequence);
+            }
+
+            // Latency includes the retries, so that the outages show up as latency spikes
+            let before_send = Instant::now();
+            loop {
+                let request_started = Instant::now();
+                match timeout(
+                    outage.remaining(),
+                    client.send_messages(&stream_id, &topic_id, &partitioning, &mut batch.messages),
+                )
+                .await
+                {
+                    Ok(Ok(())) => {
+                        outage.succeeded();
+                        break;
+                    }
+                    Ok(Err(e)) => outage.recover(&client, request_started, Some(e)).await?,
+                    Err(_) => outage.recover(&client, request_started, None).await?,
+                }
+            }
+            let latency = before_send.elapsed();
+            self.stats.record_latency(latency);
+
+            let messages = batch.messages.len() as u64;
+            self.stream_state.acknowledge(messages);
+            self.stats.record_acknowledged(messages);
+            messages_processed += messages;
+            batches_processed += 1;
+            user_data_bytes_processed += batch.user_data_bytes;
+            total_bytes_processed += batch.total_bytes;
+
+            records.push(BenchmarkRecord {
+                elapsed_time_us: u64::try_from(start_timestamp.elapsed().as_micros())
+                    .unwrap_or(u64::MAX),
+                latency_us: u64::try_from(latency.as_micros()).unwrap_or(u64::MAX),
+                messages: messages_processed,
+                message_batches: batches_processed,
+                user_data_bytes: user_data_bytes_processed,
+                total_bytes: total_bytes_processed,
+            });
+
+            if let Some(rate_limiter) = &rate_limiter {
+                rate_limiter
+                    .wait_until_necessary(batch.user_data_bytes)
+                    .await;
+            }
+
+            if self
+                .finish_condition
+                .account_and_check(batch.user_data_bytes)
+            {
+                break;
+            }
+        }
+        self.stream_state.finish();
+
+        let metrics = from_records(
+            &records,
+            self.benchmark_kind,
+            ActorKind::Producer,
+            self.producer_id,
+            self.sampling_time,
+            self.moving_average_window,
+            None,
+        );
+
+        info!(
+            "Chaos producer #{} → sent {} messages in {} batches in {:.2} s, average throughput: {:.2} MB/s, \
+            p99 latency: {:.2} ms, max latency: {:.2} ms",
+            self.producer_id,
+            messages_processed.human_count_bare(),
+            batches_processed.human_count_bare(),
+            metrics.summary.total_time_secs,
+            metrics.summary.throughput_megabytes_per_second,
+            metrics.summary.p99_latency_ms,
+            metrics.summary.max_latency_ms,
+        );
+
+        Ok(metrics)
+    }
+}
@@ -23,6 +23,7 @@ use super::backend::{
 use crate::analytics::metrics::individual::from_records;
 use crate::analytics::record::BenchmarkRecord;
 use crate::utils::finish_condition::BenchmarkFinishCondition;
+use crate::utils::open_loop_scheduler::BenchmarkOpenLoopScheduler;
 use crate::utils::rate_limiter::BenchmarkRateLimiter;
 use bench_report::actor_kind::ActorKind;
 use bench_report::benchmark_kind::BenchmarkKind;
@@ -43,6 +44,7 @@ pub struct BenchmarkConsumer {
     sampling_time: IggyDuration,
     moving_average_window: u32,
     limit_bytes_per_second: Option<IggyByteSize>,
+    open_loop: bool,
     config: BenchmarkConsumerConfig,
 }
 
@@ -61,6 +63,7 @@ impl BenchmarkConsumer {
         moving_average_window: u32,
         polling_kind: PollingKind,
         limit_bytes_per_second: Option<IggyByteSize>,
+        open_loop: bool,
         origin_timestamp_latency_calculation: bool,
         use_high_level_api: bool,
     ) -> Self {
@@ -87,6 +90,7 @@ impl BenchmarkConsumer {
             sampling_time,
             moving_average_window,
             limit_bytes_per_second,
+            open_loop,
             config,
         }
     }
@@ -100,6 +104,7 @@ impl BenchmarkConsumer {
                     self.sampling_time,
                     self.moving_average_window,
                     self.limit_bytes_per_second,
+                    self.open_loop,
                     self.config,
                     backend,
                 )
@@ -112,6 +117,7 @@ impl BenchmarkConsumer {
                     self.sampling_time,
                     self.moving_average_window,
                     self.limit_bytes_per_second,
+                    self.open_loop,
                     self.config,
                     backend,
                 )
@@ -120,12 +126,14 @@ impl BenchmarkConsumer {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn run_with_backend<B: BenchmarkConsumerBackend>(
         benchmark_kind: BenchmarkKind,
         finish_condition: Arc<BenchmarkFinishCondition>,
         sampling_time: IggyDuration,
         moving_average_window: u32,
         limit_bytes_per_second: Option<IggyByteSize>,
+        open_loop: bool,
         config: BenchmarkConsumerConfig,
         backend: B,
     ) -> Result<BenchmarkIndividualMetrics, IggyError> {
@@ -145,15 +153,31 @@ impl BenchmarkConsumer {
         let mut bytes_processed = 0;
         let mut user_data_bytes_processed = 0;
         let start_timestamp = Instant::now();
-        let rate_limiter = limit_bytes_per_second.map(BenchmarkRateLimiter::new);
+        let rate_limiter = limit_bytes_per_second
+            .filter(|_| !open_loop)
+            .map(BenchmarkRateLimiter::new);
+        let mut open_loop_scheduler = limit_bytes_per_second
+            .filter(|_| open_loop)
+            .map(BenchmarkOpenLoopScheduler::new);
 
         while !finish_condition.is_done() {
+            let intended_poll_time = match &open_loop_scheduler {
+                Some(scheduler) => Some(scheduler.wait_for_next_send().await),
+                None => None,
+            };
             let batch_opt = backend.consume_batch(&mut consumer).await?;
 
             let Some(batch) = batch_opt else {
                 continue;
             };
 
+            if let (Some(scheduler), Some(intended_poll_time)) =
+                (&mut open_loop_scheduler, intended_poll_time)
+            {
+                scheduler.record_latency(intended_poll_time.elapsed());
+                scheduler.schedule_next(batch.user_data_bytes);
+            }
+
             messages_processed += u64::from(batch.messages);
             batches_processed += 1;
             user_data_bytes_processed += batch.user_data_bytes;
@@ -187,6 +211,9 @@ impl BenchmarkConsumer {
             config.consumer_id,
             sampling_time,
             moving_average_window,
+            open_loop_scheduler
+                .as_ref()
+                .map(BenchmarkOpenLoopScheduler::histogram),
         );
 
         Self::log_statistics(
@@ -230,5 +257,17 @@ impl BenchmarkConsumer {
             metrics.summary.max_latency_ms,
             metrics.summary.std_dev_latency_ms,
         );
+
+        if let Some(corrected) = &metrics.summary.corrected_latency {
+            info!(
+                "Consumer #{} → corrected (open-loop) p50 latency: {:.2} ms, p99 latency: {:.2} ms, p999 latency: {:.2} ms, p9999 latency: {:.2} ms, max latency: {:.2} ms",
+                consumer_id,
+                corrected.p50_latency_ms,
+                corrected.p99_latency_ms,
+                corrected.p999_latency_ms,
+                corrected.p9999_latency_ms,
+                corrected.max_latency_ms,
+            );
+        }
     }
 }
@@ -20,6 +20,9 @@ use crate::analytics::metrics::individual::from_records;
 use crate::analytics::record::BenchmarkRecord;
 use crate::utils::batch_generator::BenchmarkBatchGenerator;
 use crate::utils::finish_condition::BenchmarkFinishCondition;
+use crate::utils::key_distribution::BenchmarkKeyDistribution;
+use crate::utils::load_profile::BenchmarkLoadProfile;
+use crate::utils::open_loop_scheduler::BenchmarkOpenLoopScheduler;
 use crate::utils::rate_limiter::BenchmarkRateLimiter;
 use bench_report::actor_kind::ActorKind;
 use bench_report::benchmark_kind::BenchmarkKind;
@@ -46,6 +49,9 @@ pub struct BenchmarkProducer {
     sampling_time: IggyDuration,
     moving_average_window: u32,
     limit_bytes_per_second: Option<IggyByteSize>,
+    open_loop: bool,
+    load_profile: Option<Arc<BenchmarkLoadProfile>>,
+    key_distribution: Option<BenchmarkKeyDistribution>,
 }
 
 impl BenchmarkProducer {
@@ -63,6 +69,9 @@ impl BenchmarkProducer {
         sampling_time: IggyDuration,
         moving_average_window: u32,
         limit_bytes_per_second: Option<IggyByteSize>,
+        open_loop: bool,
+        load_profile: Option<Arc<BenchmarkLoadProfile>>,
+        key_distribution: Option<BenchmarkKeyDistribution>,
     ) -> Self {
         Self {
             client_factory,
@@ -77,14 +86,27 @@ impl BenchmarkProducer {
             sampling_time,
             moving_average_window,
             limit_bytes_per_second,
+            open_loop,
+            load_profile,
+            key_distribution,
         }
     }
 
-    #[allow(clippy::cognitive_complexity)]
-    pub async fn run(self) -> Result<BenchmarkIndividualMetrics, IggyError> {
+    #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
+    pub async fn run(mut self) -> Result<BenchmarkIndividualMetrics, IggyError> {
         let mut batch_generator =
             BenchmarkBatchGenerator::new(self.message_size, self.messages_per_batch);
-        let rate_limiter = self.limit_bytes_per_second.map(BenchmarkRateLimiter::new);
+        let rate_limiter = self
+            .limit_bytes_per_second
+            .filter(|_| !self.open_loop)
+            .map(|limit| {
+                let rate_limiter = BenchmarkRateLimiter::new(limit);
+                match &self.load_profile {
+                    Some(load_profile) => rate_limiter.with_load_profile(load_profile.clone()),
+                    None => rate_limiter,
+                }
+            });
+        let mut key_distribution = self.key_distribution.take();
 
         let topic_id: u32 = 1;
         let default_partition_id: u32 = 1;
@@ -124,14 +146,19 @@ impl BenchmarkProducer {
         // -----------------------
 
         info!(
-            "Producer #{} → sending {} in batches of {} messages to stream {} with {} partitions, partitioning: {}, rate limit: {:?}...",
+            "Producer #{} → sending {} in batches of {} messages to stream {} with {} partitions, partitioning: {}, rate limit: {:?}, open-loop: {}...",
             self.producer_id,
             self.finish_condition.total_str(),
             self.messages_per_batch,
             stream_id,
             partitions,
-            partitioning,
-            self.limit_bytes_per_second
+            if key_distribution.is_some() {
+                "messages_key".to_owned()
+            } else {
+                partitioning.to_string()
+            },
+            self.limit_bytes_per_second,
+            self.open_loop
         );
 
         let max_capacity = self.finish_condition.max_capacity();
@@ -141,18 +168,44 @@ impl BenchmarkProducer {
         let mut total_bytes_processed = 0;
         let mut user_data_bytes_processed = 0;
         let start_timestamp = Instant::now();
+        let mut open_loop_scheduler = self
+            .limit_bytes_per_second
+            .filter(|_| self.open_loop)
+            .map(BenchmarkOpenLoopScheduler::new);
 
         loop {
+            if let Some(load_profile) = &self.load_profile {
+                load_profile.wait_while_paused().await;
+            }
             if self.finish_condition.is_done() {
                 break;
             }
+            let intended_send_time = match &open_loop_scheduler {
+                Some(scheduler) => Some(scheduler.wait_for_next_send().await),
+                None => None,
+            };
             let batch = batch_generator.generate_batch();
+            let key_partitioning = key_distribution
+                .as_mut()
+                .map(|keys| Partitioning::messages_key_u64(keys.next_key()));
             let before_send = Instant::now();
             client
-                .send_messages(&stream_id, &topic_id, &partitioning, &mut batch.messages)
+                .send_messages(
+                    &stream_id,
+                    &topic_id,
+                    key_partitioning.as_ref().unwrap_or(&partitioning),
+                    &mut batch.messages,
+                )
                 .await?;
             let latency = before_send.elapsed();
 
+            if let (Some(scheduler), Some(intended_send_time)) =
+                (&mut open_loop_scheduler, intended_send_time)
+            {
+                scheduler.record_latency(intended_send_time.elapsed());
+                scheduler.schedule_next(batch.user_data_bytes);
+            }
+
             messages_processed += batch.messages.len() as u64;
             batches_processed += 1;
             user_data_bytes_processed += batch.user_data_bytes;
@@ -189,6 +242,9 @@ impl BenchmarkProducer {
             self.producer_id,
             self.sampling_time,
             self.moving_average_window,
+            open_loop_scheduler
+                .as_ref()
+                .map(BenchmarkOpenLoopScheduler::histogram),
         );
 
         Self::log_statistics(
@@ -232,5 +288,17 @@ impl BenchmarkProducer {
             metrics.summary.max_latency_ms,
             metrics.summary.std_dev_latency_ms,
         );
+
+        if let Some(corrected) = &metrics.summary.corrected_latency {
+            info!(
+                "Producer #{} → corrected (open-loop) p50 latency: {:.2} ms, p99 latency: {:.2} ms, p999 latency: {:.2} ms, p9999 latency: {:.2} ms, max latency: {:.2} ms",
+                producer_id,
+                corrected.p50_latency_ms,
+                corrected.p99_latency_ms,
+                corrected.p999_latency_ms,
+                corrected.p9999_latency_ms,
+                corrected.max_latency_ms,
+            );
+        }
     }
 }
@@ -20,6 +20,7 @@ use crate::analytics::record::BenchmarkRecord;
 use crate::benchmarks::common::create_consumer;
 use crate::utils::batch_generator::BenchmarkBatchGenerator;
 use crate::utils::finish_condition::BenchmarkFinishCondition;
+use crate::utils::open_loop_scheduler::BenchmarkOpenLoopScheduler;
 use crate::utils::{batch_total_size_bytes, batch_user_size_bytes};
 use crate::{
     analytics::metrics::individual::from_records, utils::rate_limiter::BenchmarkRateLimiter,
@@ -51,6 +52,7 @@ pub struct BenchmarkProducingConsumer {
     sampling_time: IggyDuration,
     moving_average_window: u32,
     limit_bytes_per_second: Option<IggyByteSize>,
+    open_loop: bool,
     polling_kind: PollingKind,
 }
 
@@ -71,6 +73,7 @@ impl BenchmarkProducingConsumer {
         sampling_time: IggyDuration,
         moving_average_window: u32,
         limit_bytes_per_second: Option<IggyByteSize>,
+        open_loop: bool,
         polling_kind: PollingKind,
     ) -> Self {
         Self {
@@ -88,6 +91,7 @@ impl BenchmarkProducingConsumer {
             sampling_time,
             moving_average_window,
             limit_bytes_per_second,
+            open_loop,
             polling_kind,
         }
     }
@@ -178,7 +182,10 @@ impl BenchmarkProducingConsumer {
 
         let batch_generator =
             BenchmarkBatchGenerator::new(self.message_size, self.messages_per_batch);
-        let rate_limiter = self.limit_bytes_per_second.map(BenchmarkRateLimiter::new);
+        let rate_limiter = self
+            .limit_bytes_per_second
+            .filter(|_| !self.open_loop)
+            .map(BenchmarkRateLimiter::new);
 
         Ok((
             client,
@@ -284,13 +291,14 @@ impl BenchmarkProducingConsumer {
         rate_limiter: Option<BenchmarkRateLimiter>,
     ) -> Result<BenchmarkIndividualMetrics, IggyError> {
         info!(
-            "ProducingConsumer #{} → sending {} and polling {} ({} msgs/batch) on stream {}, rate limit: {:?}",
+            "ProducingConsumer #{} → sending {} and polling {} ({} msgs/batch) on stream {}, rate limit: {:?}, open-loop: {}",
             self.actor_id,
             self.send_finish_condition.total_str(),
             self.poll_finish_condition.total_str(),
             self.messages_per_batch,
             stream_id,
-            self.limit_bytes_per_second
+            self.limit_bytes_per_second,
+            self.open_loop
         );
 
         let max_capacity = self
@@ -326,6 +334,14 @@ impl BenchmarkProducingConsumer {
         let require_reply = is_producer && is_consumer && self.consumer_group_id.is_none();
         let mut awaiting_reply = false;
 
+        // In open-loop mode sends are scheduled, and the delay of the last send behind its
+        // intended time is added to the end-to-end latency of the polled messages.
+        let mut open_loop_scheduler = self
+            .limit_bytes_per_second
+            .filter(|_| self.open_loop)
+            .map(BenchmarkOpenLoopScheduler::new);
+        let mut send_delay = Duration::ZERO;
+
         let start_timestamp = Instant::now();
 
         while !(self.send_finish_condition.is_done() && self.poll_finish_condition.is_done()) {
@@ -334,11 +350,18 @@ impl BenchmarkProducingConsumer {
                 && (!require_reply || !awaiting_reply);
 
             if may_send {
+                if let Some(scheduler) = &open_loop_scheduler {
+                    send_delay = scheduler.wait_for_next_send().await.elapsed();
+                }
                 let batch = batch_generator.generate_batch();
                 client
                     .send_messages(&stream_id, &topic_id, &partitioning, &mut batch.messages)
                     .await?;
 
+                if let Some(scheduler) = &mut open_loop_scheduler {
+                    scheduler.schedule_next(batch.user_data_bytes);
+                }
+
                 rl_value += batch.user_data_bytes;
 
                 sent_total_bytes_processed += batch.total_bytes;
@@ -427,6 +450,10 @@ impl BenchmarkProducingConsumer {
                 total_messages_processed = received_messages + sent_messages;
                 total_batches_processed = received_batches + sent_batches;
 
+                if let Some(scheduler) = &mut open_loop_scheduler {
+                    scheduler.record_latency(latency + send_delay);
+                }
+
                 records.push(BenchmarkRecord {
                     elapsed_time_us: u64::try_from(start_timestamp.elapsed().as_micros())
                         .unwrap_or(u64::MAX),
@@ -458,6 +485,9 @@ impl BenchmarkProducingConsumer {
             self.actor_id,
             self.sampling_time,
             self.moving_average_window,
+            open_loop_scheduler
+                .as_ref()
+                .map(BenchmarkOpenLoopScheduler::histogram),
         );
 
         Self::log_statistics(
@@ -500,5 +530,17 @@ impl BenchmarkProducingConsumer {
             metrics.summary.max_latency_ms,
             metrics.summary.std_dev_latency_ms,
         );
+
+        if let Some(corrected) = &metrics.summary.corrected_latency {
+            info!(
+                "ProducingConsumer #{} → corrected (open-loop) p50 latency: {:.2} ms, p99 latency: {:.2} ms, p999 latency: {:.2} ms, p9999 latency: {:.2} ms, max latency: {:.2} ms",
+                actor_id,
+                corrected.p50_latency_ms,
+                corrected.p99_latency_ms,
+                corrected.p999_latency_ms,
+                corrected.p9999_latency_ms,
+                corrected.max_latency_ms,
+            );
+        }
     }
 }
@@ -0,0 +1,118 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bench_report::chaos::{
+    BenchmarkChaosDelivery, BenchmarkChaosFault, BenchmarkChaosLatencySpikes, BenchmarkChaosMetrics,
+};
+use iggy::prelude::IggyDuration;
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+/// Reconnects, latency spikes and delivery verification results collected by all the actors
+/// of the chaos benchmark.
+pub struct BenchmarkChaosStats {
+    latency_spike_threshold: Duration,
+    reconnect_times: Mutex<Vec<Duration>>,
+    latency_spikes: AtomicU64,
+    max_spike_latency_us: AtomicU64,
+    messages_acknowledged: AtomicU64,
+    messages_received: AtomicU64,
+    lost_messages: AtomicU64,
+    duplicate_messages: AtomicU64,
+    offset_gaps: AtomicU64,
+}
+
+impl BenchmarkChaosStats {
+    pub fn new(latency_spike_threshold: IggyDuration) -> Self {
+        Self {
+            latency_spike_threshold: latency_spike_threshold.get_duration(),
+            reconnect_times: Mutex::new(Vec::new()),
+            latency_spikes: AtomicU64::new(0),
+            max_spike_latency_us: AtomicU64::new(0),
+            messages_acknowledged: AtomicU64::new(0),
+            messages_received: AtomicU64::new(0),
+            lost_messages: AtomicU64::new(0),
+            duplicate_messages: AtomicU64::new(0),
+            offset_gaps: AtomicU64::new(0),
+        }
+    }
+
+    /// Records the time between the first failed request of an actor and the next successful one.
+    pub fn record_reconnect(&self, reconnect_time: Duration) {
+        self.reconnect_times.lock().unwrap().push(reconnect_time);
+    }
+
+    /// Records the latency of a request, counted as a spike when it exceeds the threshold.
+    pub fn record_latency(&self, latency: Duration) {
+        if latency <= self.latency_spike_threshold {
+            return;
+        }
+
+        self.latency_spikes.fetch_add(1, Ordering::Relaxed);
+        let latency_us = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        self.max_spike_latency_us
+            .fetch_max(latency_us, Ordering::Relaxed);
+    }
+
+    pub fn record_acknowledged(&self, messages: u64) {
+        self.messages_acknowledged
+            .fetch_add(messages, Ordering::Relaxed);
+    }
+
+    /// Records the delivery verified by a consumer once it polled all the messages of its stream.
+    pub fn record_delivery(&self, delivery: BenchmarkChaosDelivery) {
+        self.messages_received
+            .fetch_add(delivery.messages_received, Ordering::Relaxed);
+        self.lost_messages
+            .fetch_add(delivery.lost_messages, Ordering::Relaxed);
+        self.duplicate_messages
+            .fetch_add(delivery.duplicate_messages, Ordering::Relaxed);
+        self.offset_gaps
+            .fetch_add(delivery.offset_gaps, Ordering::Relaxed);
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_metrics(&self, faults: Vec<BenchmarkChaosFault>) -> BenchmarkChaosMetrics {
+        let reconnect_times_ms = self
+            .reconnect_times
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|reconnect_time| reconnect_time.as_secs_f64() * 1000.0)
+            .collect();
+        let delivery = BenchmarkChaosDelivery {
+            messages_acknowledged: self.messages_acknowledged.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            lost_messages: self.lost_messages.load(Ordering::Relaxed),
+            duplicate_messages: self.duplicate_messages.load(Ordering::Relaxed),
+            offset_gaps: self.offset_gaps.load(Ordering::Relaxed),
+        };
+        let latency_spikes = BenchmarkChaosLatencySpikes {
+            threshold_ms: self.latency_spike_threshold.as_secs_f64() * 1000.0,
+            count: self.latency_spikes.load(Ordering::Relaxed),
+            max_latency_ms: self.max_spike_latency_us.load(Ordering::Relaxed) as f64 / 1000.0,
+        };
+
+        BenchmarkChaosMetrics::new(faults, reconnect_times_ms, delivery, latency_spikes)
+    }
+}
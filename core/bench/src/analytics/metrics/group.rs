@@ -25,6 +25,7 @@ use crate::analytics::time_series::{
 };
 use bench_report::{
     actor_kind::ActorKind,
+    corrected_latency::BenchmarkCorrectedLatency,
     group_metrics::BenchmarkGroupMetrics,
     group_metrics_kind::GroupMetricsKind,
     group_metrics_summary::BenchmarkGroupMetricsSummary,
@@ -79,6 +80,11 @@ pub fn from_individual_metrics(
         min_latency_ms: min_latency_ms_value,
         max_latency_ms: max_latency_ms_value,
         std_dev_latency_ms: std_dev(&time_series.2).unwrap_or(0.0),
+        average_corrected_latency: BenchmarkCorrectedLatency::merge(
+            stats
+                .iter()
+                .filter_map(|r| r.corrected_latency_histogram.as_ref()),
+        ),
     };
 
     Some(BenchmarkGroupMetrics {
@@ -26,10 +26,12 @@ use crate::analytics::time_series::processors::TimeSeriesProcessor;
 use crate::analytics::time_series::processors::moving_average::MovingAverageProcessor;
 use bench_report::actor_kind::ActorKind;
 use bench_report::benchmark_kind::BenchmarkKind;
+use bench_report::corrected_latency::BenchmarkCorrectedLatency;
 use bench_report::individual_metrics::BenchmarkIndividualMetrics;
 use bench_report::individual_metrics_summary::BenchmarkIndividualMetricsSummary;
 use bench_report::time_series::TimeSeries;
 use bench_report::utils::{max, min, std_dev};
+use hdrhistogram::Histogram;
 use iggy::prelude::IggyDuration;
 
 pub fn from_records(
@@ -39,6 +41,7 @@ pub fn from_records(
     actor_id: u32,
     sampling_time: IggyDuration,
     moving_average_window: u32,
+    corrected_latencies: Option<&Histogram<u64>>,
 ) -> BenchmarkIndividualMetrics {
     if records.is_empty() {
         return create_empty_metrics(benchmark_kind, actor_kind, actor_id);
@@ -88,10 +91,12 @@ pub fn from_records(
             min_latency_ms: latency_metrics.min,
             max_latency_ms: latency_metrics.max,
             std_dev_latency_ms: latency_metrics.std_dev,
+            corrected_latency: corrected_latencies.map(BenchmarkCorrectedLatency::from_histogram),
         },
         throughput_mb_ts,
         throughput_msg_ts,
         latency_ts,
+        corrected_latency_histogram: corrected_latencies.cloned(),
     }
 }
 
@@ -123,10 +128,12 @@ fn create_empty_metrics(
             min_latency_ms: 0.0,
             max_latency_ms: 0.0,
             std_dev_latency_ms: 0.0,
+            corrected_latency: None,
         },
         throughput_mb_ts: TimeSeries::default(),
         throughput_msg_ts: TimeSeries::default(),
         latency_ts: TimeSeries::default(),
+        corrected_latency_histogram: None,
     }
 }
 
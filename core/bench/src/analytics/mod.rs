@@ -16,7 +16,9 @@
  * under the License.
  */
 
+pub mod chaos_stats;
 pub mod metrics;
 pub mod record;
 pub mod report_builder;
+pub mod server_profiler;
 pub mod time_series;
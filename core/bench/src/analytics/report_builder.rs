@@ -23,10 +23,12 @@ use crate::utils::get_server_stats;
 use bench_report::{
     actor_kind::ActorKind,
     benchmark_kind::BenchmarkKind,
+    chaos::BenchmarkChaosMetrics,
     hardware::BenchmarkHardware,
     individual_metrics::BenchmarkIndividualMetrics,
     params::BenchmarkParams,
     report::BenchmarkReport,
+    server_resources::BenchmarkServerResources,
     server_stats::{BenchmarkCacheMetrics, BenchmarkCacheMetricsKey, BenchmarkServerStats},
 };
 use chrono::{DateTime, Utc};
@@ -44,6 +46,8 @@ impl BenchmarkReportBuilder {
         mut individual_metrics: Vec<BenchmarkIndividualMetrics>,
         moving_average_window: u32,
         client_factory: &Arc<dyn ClientFactory>,
+        server_resources: Option<BenchmarkServerResources>,
+        chaos: Option<BenchmarkChaosMetrics>,
     ) -> BenchmarkReport {
         let uuid = uuid::Uuid::new_v4();
 
@@ -108,6 +112,8 @@ impl BenchmarkReportBuilder {
             params.benchmark_kind,
             BenchmarkKind::PinnedProducerAndConsumer
                 | BenchmarkKind::BalancedProducerAndConsumerGroup
+                | BenchmarkKind::Scenario
+                | BenchmarkKind::Chaos
         ) && !producer_metrics.is_empty()
             && !consumer_metrics.is_empty()
         {
@@ -132,6 +138,8 @@ impl BenchmarkReportBuilder {
         BenchmarkReport {
             uuid,
             server_stats: stats_to_benchmark_server_stats(server_stats),
+            server_resources,
+            chaos,
             timestamp,
             hardware,
             params,
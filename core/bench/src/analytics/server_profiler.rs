@@ -0,0 +1,299 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bench_report::{
+    server_resources::{BenchmarkServerProcessMetrics, BenchmarkServerResources},
+    time_series::{TimePoint, TimeSeries},
+};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::sync::Arc;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tokio::{
+    sync::oneshot,
+    task::JoinHandle,
+    time::{Instant, MissedTickBehavior, interval},
+};
+use tracing::{info, warn};
+
+const BYTES_IN_MB: f64 = 1_000_000.0;
+
+/// Samples the server stats (and the process of a locally started server) in the background
+/// until stopped, the samples end up in the benchmark report as time series.
+pub struct BenchmarkServerProfiler {
+    stop: oneshot::Sender<()>,
+    handle: JoinHandle<Option<BenchmarkServerResources>>,
+}
+
+impl BenchmarkServerProfiler {
+    /// Starts the profiler, returns `None` if the interval is zero (profiling disabled).
+    pub fn start(
+        client_factory: &Arc<dyn ClientFactory>,
+        sampling_interval: IggyDuration,
+        server_pid: Option<u32>,
+    ) -> Option<Self> {
+        if sampling_interval.get_duration().is_zero() {
+            info!("Server profiling is disabled");
+            return None;
+        }
+
+        info!(
+            "Starting server profiling every {sampling_interval}{}",
+            server_pid.map_or_else(String::new, |pid| format!(", server process pid: {pid}"))
+        );
+        let client_factory = client_factory.clone();
+        let (stop, mut stop_receiver) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let client = client_factory.create_client().await;
+            let client = IggyClient::create(client, None, None);
+            login_root(&client).await;
+
+            let mut sampler = ResourcesSampler::new(sampling_interval, server_pid);
+            let mut ticker = interval(sampling_interval.get_duration());
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = &mut stop_receiver => break,
+                    _ = ticker.tick() => sampler.sample(&client).await,
+                }
+            }
+            Some(sampler.finish())
+        });
+
+        Some(Self { stop, handle })
+    }
+
+    /// Stops the profiler and returns the sampled resources.
+    pub async fn stop(self) -> Option<BenchmarkServerResources> {
+        let _ = self.stop.send(());
+        self.handle.await.unwrap_or_else(|e| {
+            warn!("Server profiler failed: {e}");
+            None
+        })
+    }
+}
+
+/// Cumulative counters of the previous sample, used to calculate the rates.
+struct StatsCounters {
+    time_s: f64,
+    read_bytes: u64,
+    written_bytes: u64,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+struct ResourcesSampler {
+    start: Instant,
+    sampling_interval: IggyDuration,
+    previous: Option<StatsCounters>,
+    cpu_usage: Vec<TimePoint>,
+    memory_usage_mb: Vec<TimePoint>,
+    read_mb: Vec<TimePoint>,
+    written_mb: Vec<TimePoint>,
+    cache_hit_ratio: Vec<TimePoint>,
+    process: Option<ProcessSampler>,
+}
+
+impl ResourcesSampler {
+    fn new(sampling_interval: IggyDuration, server_pid: Option<u32>) -> Self {
+        Self {
+            start: Instant::now(),
+            sampling_interval,
+            previous: None,
+            cpu_usage: Vec::new(),
+            memory_usage_mb: Vec::new(),
+            read_mb: Vec::new(),
+            written_mb: Vec::new(),
+            cache_hit_ratio: Vec::new(),
+            process: server_pid.map(ProcessSampler::new),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    async fn sample(&mut self, client: &IggyClient) {
+        let time_s = self.start.elapsed().as_secs_f64();
+        if let Some(process) = &mut self.process {
+            process.sample(time_s);
+        }
+
+        let stats = match client.get_stats().await {
+            Ok(stats) => stats,
+            Err(e) => {
+                warn!("Failed to sample server stats: {e}");
+                // The server forgets the sessions when it's restarted, e.g. by the chaos benchmark
+                if matches!(e, IggyError::Unauthenticated)
+                    && let Err(e) = client
+                        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+                        .await
+                {
+                    warn!("Failed to log in again: {e}");
+                }
+                return;
+            }
+        };
+
+        let (cache_hits, cache_misses) = stats
+            .cache_metrics
+            .values()
+            .fold((0, 0), |(hits, misses), metrics| {
+                (hits + metrics.hits, misses + metrics.misses)
+            });
+        let current = StatsCounters {
+            time_s,
+            read_bytes: stats.read_bytes.as_bytes_u64(),
+            written_bytes: stats.written_bytes.as_bytes_u64(),
+            cache_hits,
+            cache_misses,
+        };
+
+        self.cpu_usage
+            .push(TimePoint::new(time_s, f64::from(stats.cpu_usage)));
+        self.memory_usage_mb.push(TimePoint::new(
+            time_s,
+            stats.memory_usage.as_bytes_u64() as f64 / BYTES_IN_MB,
+        ));
+
+        if let Some(previous) = &self.previous {
+            let elapsed_s = current.time_s - previous.time_s;
+            if elapsed_s > 0.0 {
+                let rate_mb = |current: u64, previous: u64| {
+                    current.saturating_sub(previous) as f64 / BYTES_IN_MB / elapsed_s
+                };
+                self.read_mb.push(TimePoint::new(
+                    time_s,
+                    rate_mb(current.read_bytes, previous.read_bytes),
+                ));
+                self.written_mb.push(TimePoint::new(
+                    time_s,
+                    rate_mb(current.written_bytes, previous.written_bytes),
+                ));
+            }
+
+            let hits = current.cache_hits.saturating_sub(previous.cache_hits);
+            let misses = current.cache_misses.saturating_sub(previous.cache_misses);
+            if hits + misses > 0 {
+                self.cache_hit_ratio.push(TimePoint::new(
+                    time_s,
+                    hits as f64 * 100.0 / (hits + misses) as f64,
+                ));
+            }
+        }
+        self.previous = Some(current);
+    }
+
+    fn finish(self) -> BenchmarkServerResources {
+        BenchmarkServerResources::new(
+            self.sampling_interval.as_micros() / 1000,
+            time_series(self.cpu_usage),
+            time_series(self.memory_usage_mb),
+            time_series(self.read_mb),
+            time_series(self.written_mb),
+            time_series(self.cache_hit_ratio),
+            self.process.map(ProcessSampler::finish),
+        )
+    }
+}
+
+/// Samples the process of the locally started server with `sysinfo`.
+struct ProcessSampler {
+    pid: Pid,
+    system: System,
+    refreshed_at: Instant,
+    metrics: BenchmarkServerProcessMetrics,
+}
+
+impl ProcessSampler {
+    fn new(pid: u32) -> Self {
+        let mut sampler = Self {
+            pid: Pid::from_u32(pid),
+            system: System::new(),
+            refreshed_at: Instant::now(),
+            metrics: BenchmarkServerProcessMetrics {
+                pid,
+                ..Default::default()
+            },
+        };
+        // CPU usage and disk usage are calculated since the previous refresh
+        sampler.refresh();
+        sampler
+    }
+
+    fn refresh(&mut self) -> f64 {
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[self.pid]),
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_disk_usage(),
+        );
+        let now = Instant::now();
+        let elapsed_s = now.duration_since(self.refreshed_at).as_secs_f64();
+        self.refreshed_at = now;
+        elapsed_s
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn sample(&mut self, time_s: f64) {
+        let elapsed_s = self.refresh();
+        let Some(process) = self.system.process(self.pid) else {
+            return;
+        };
+
+        let disk_usage = process.disk_usage();
+        let rate_mb = |bytes: u64| {
+            if elapsed_s > 0.0 {
+                bytes as f64 / BYTES_IN_MB / elapsed_s
+            } else {
+                0.0
+            }
+        };
+        let metrics = &mut self.metrics;
+        metrics
+            .cpu_usage_ts
+            .points
+            .push(TimePoint::new(time_s, f64::from(process.cpu_usage())));
+        metrics.memory_usage_mb_ts.points.push(TimePoint::new(
+            time_s,
+            process.memory() as f64 / BYTES_IN_MB,
+        ));
+        metrics.virtual_memory_mb_ts.points.push(TimePoint::new(
+            time_s,
+            process.virtual_memory() as f64 / BYTES_IN_MB,
+        ));
+        metrics
+            .disk_read_mb_ts
+            .points
+            .push(TimePoint::new(time_s, rate_mb(disk_usage.read_bytes)));
+        metrics
+            .disk_written_mb_ts
+            .points
+            .push(TimePoint::new(time_s, rate_mb(disk_usage.written_bytes)));
+    }
+
+    fn finish(self) -> BenchmarkServerProcessMetrics {
+        self.metrics
+    }
+}
+
+fn time_series(points: Vec<TimePoint>) -> TimeSeries {
+    TimeSeries {
+        points,
+        ..Default::default()
+    }
+}
@@ -17,14 +17,16 @@
  */
 
 use super::kind::BenchmarkKindCommand;
+use super::kinds::chaos::faults::ChaosArgs;
+use super::kinds::scenario::definition::BenchmarkScenario;
 use super::output::BenchmarkOutputCommand;
 use super::props::{BenchmarkKindProps, BenchmarkTransportProps};
 use super::{
     defaults::{
         DEFAULT_MESSAGE_BATCHES, DEFAULT_MESSAGE_SIZE, DEFAULT_MESSAGES_PER_BATCH,
         DEFAULT_MOVING_AVERAGE_WINDOW, DEFAULT_PERFORM_CLEANUP, DEFAULT_SAMPLING_TIME,
-        DEFAULT_SERVER_STDOUT_VISIBILITY, DEFAULT_SKIP_SERVER_START, DEFAULT_START_STREAM_ID,
-        DEFAULT_WARMUP_TIME,
+        DEFAULT_SERVER_PROFILING_INTERVAL, DEFAULT_SERVER_STDOUT_VISIBILITY,
+        DEFAULT_SKIP_SERVER_START, DEFAULT_START_STREAM_ID, DEFAULT_WARMUP_TIME,
     },
     transport::BenchmarkTransportCommand,
 };
@@ -74,6 +76,20 @@ pub struct IggyBenchArgs {
     #[arg(long, short = 'r', verbatim_doc_comment)]
     pub rate_limit: Option<IggyByteSize>,
 
+    /// Open-loop mode: schedule requests at fixed intervals derived from the rate limit, independently
+    /// of the responses, and additionally report latency measured from the scheduled send time
+    /// (corrected for coordinated omission). Each actor still awaits its requests one at a time,
+    /// so when the server stalls, the delayed requests are sent back-to-back to catch up with
+    /// the schedule and the time they spent waiting is included in the corrected latency.
+    /// Requires `--rate-limit`.
+    #[arg(
+        long,
+        requires = "rate_limit",
+        default_value_t = false,
+        verbatim_doc_comment
+    )]
+    pub open_loop: bool,
+
     /// Warmup time in human readable format, e.g. "1s", "2m", "3h"
     #[arg(long, short = 'w', default_value_t = IggyDuration::from_str(DEFAULT_WARMUP_TIME).unwrap())]
     pub warmup_time: IggyDuration,
@@ -90,6 +106,11 @@ pub struct IggyBenchArgs {
     #[arg(long, short = 'W', default_value_t = DEFAULT_MOVING_AVERAGE_WINDOW)]
     pub moving_average_window: u32,
 
+    /// Interval of sampling the server resource usage (server stats and, for a locally started
+    /// server, its process) during the benchmark, "0s" disables the profiling.
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_SERVER_PROFILING_INTERVAL).unwrap(), value_parser = IggyDuration::from_str, verbatim_doc_comment)]
+    pub server_profiling_interval: IggyDuration,
+
     /// Shutdown iggy-server and remove server `local_data` directory after the benchmark is finished.
     /// Only applicable to local benchmarks.
     #[arg(long, default_value_t = DEFAULT_PERFORM_CLEANUP, verbatim_doc_comment)]
@@ -171,6 +192,41 @@ impl IggyBenchArgs {
                 .exit();
         }
 
+        if self.scenario().is_some()
+            && (self.message_batches.is_some()
+                || self.total_data.is_some()
+                || self.rate_limit.is_some())
+        {
+            Self::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "Scenario runs for the duration of its phases with the rates of its groups, --message-batches, --total-data and --rate-limit are not supported",
+                )
+                .exit();
+        }
+
+        if self.chaos().is_some() && (self.open_loop || self.high_level_api) {
+            Self::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "Chaos benchmark uses closed-loop low-level API actors, --open-loop and --high-level-api are not supported",
+                )
+                .exit();
+        }
+
+        if self.skip_server_start
+            && self
+                .chaos()
+                .is_some_and(|chaos| chaos.restart_every.is_some() || chaos.pause_every.is_some())
+        {
+            Self::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "Server restarts (--restart-every) and pauses (--pause-every) require the server started by the benchmark, --skip-server-start is not supported",
+                )
+                .exit();
+        }
+
         if (self.message_batches, self.total_data) == (None, None) {
             self.message_batches = Some(DEFAULT_MESSAGE_BATCHES);
         }
@@ -187,7 +243,10 @@ impl IggyBenchArgs {
             }
         }
 
-        if self.high_level_api && !self.messages_per_batch.is_fixed() {
+        let messages_per_batch = self.scenario().map_or(self.messages_per_batch, |scenario| {
+            scenario.messages_per_batch_span(self.messages_per_batch)
+        });
+        if self.high_level_api && !messages_per_batch.is_fixed() {
             Self::command()
                 .error(
                     ErrorKind::ArgumentConflict,
@@ -217,6 +276,9 @@ impl IggyBenchArgs {
 
     // Used only for generation of unique directory name
     pub fn data_volume_identifier(&self) -> String {
+        if let Some(scenario) = self.scenario() {
+            return format!("{}s", scenario.duration().as_secs());
+        }
         self.total_data().map_or_else(
             || self.message_batches().unwrap().to_string(),
             |total_messages_size| format!("{}B", total_messages_size.as_bytes_u64()),
@@ -259,10 +321,18 @@ impl IggyBenchArgs {
         self.moving_average_window
     }
 
+    pub const fn server_profiling_interval(&self) -> IggyDuration {
+        self.server_profiling_interval
+    }
+
     pub const fn rate_limit(&self) -> Option<IggyByteSize> {
         self.rate_limit
     }
 
+    pub const fn open_loop(&self) -> bool {
+        self.open_loop
+    }
+
     pub fn output_dir(&self) -> Option<String> {
         self.benchmark_kind
             .inner()
@@ -348,6 +418,20 @@ impl IggyBenchArgs {
         self.high_level_api
     }
 
+    pub const fn scenario(&self) -> Option<&BenchmarkScenario> {
+        match &self.benchmark_kind {
+            BenchmarkKindCommand::Scenario(args) => Some(&args.file),
+            _ => None,
+        }
+    }
+
+    pub const fn chaos(&self) -> Option<&ChaosArgs> {
+        match &self.benchmark_kind {
+            BenchmarkKindCommand::Chaos(args) => Some(args),
+            _ => None,
+        }
+    }
+
     /// Generates the output directory name based on benchmark parameters.
     pub fn generate_dir_name(&self) -> String {
         let benchmark_kind = match &self.benchmark_kind {
@@ -363,6 +447,8 @@ impl IggyBenchArgs {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => {
                 "end_to_end_producing_consumer_group"
             }
+            BenchmarkKindCommand::Scenario(_) => "scenario",
+            BenchmarkKindCommand::Chaos(_) => "chaos",
             BenchmarkKindCommand::Examples => unreachable!(),
         };
 
@@ -380,13 +466,14 @@ impl IggyBenchArgs {
             BenchmarkKindCommand::PinnedConsumer(_)
             | BenchmarkKindCommand::BalancedConsumerGroup(_) => self.consumers(),
             BenchmarkKindCommand::PinnedProducerAndConsumer(_)
-            | BenchmarkKindCommand::BalancedProducerAndConsumerGroup(_) => {
-                self.producers() + self.consumers()
-            }
+            | BenchmarkKindCommand::BalancedProducerAndConsumerGroup(_)
+            | BenchmarkKindCommand::Scenario(_)
+            | BenchmarkKindCommand::Chaos(_) => self.producers() + self.consumers(),
             BenchmarkKindCommand::Examples => unreachable!(),
         };
 
         let data_volume_arg = match (self.total_data, self.message_batches) {
+            _ if self.scenario().is_some() => self.data_volume_identifier(),
             (Some(total), None) => format!("{total}"),
             (None, Some(batches)) => format!("{batches}"),
             _ => unreachable!(),
@@ -401,6 +488,10 @@ impl IggyBenchArgs {
             transport.to_string(),
         ];
 
+        if let Some(scenario) = self.scenario() {
+            parts.push(scenario.name.replace(' ', "_"));
+        }
+
         if let Some(remark) = &self.remark() {
             parts.push(remark.to_string());
         }
@@ -427,7 +518,8 @@ impl IggyBenchArgs {
                 format!("{} consumers", self.consumers())
             }
             BenchmarkKindCommand::PinnedProducerAndConsumer(_)
-            | BenchmarkKindCommand::BalancedProducerAndConsumerGroup(_) => format!(
+            | BenchmarkKindCommand::BalancedProducerAndConsumerGroup(_)
+            | BenchmarkKindCommand::Chaos(_) => format!(
                 "{} producers/{} consumers",
                 self.producers(),
                 self.consumers()
@@ -442,6 +534,19 @@ impl IggyBenchArgs {
                     self.consumers()
                 )
             }
+            BenchmarkKindCommand::Scenario(args) => {
+                let mut name = format!(
+                    "{} scenario, {} groups, {} producers/{} consumers",
+                    args.file.name,
+                    args.file.groups.len(),
+                    self.producers(),
+                    self.consumers()
+                );
+                if let Some(remark) = &self.remark() {
+                    name = format!("{name} ({remark})");
+                }
+                return name;
+            }
             BenchmarkKindCommand::Examples => unreachable!(),
         };
 
@@ -52,4 +52,11 @@ pub const DEFAULT_WARMUP_TIME: &str = "0s";
 pub const DEFAULT_SKIP_SERVER_START: bool = false;
 
 pub const DEFAULT_SAMPLING_TIME: &str = "10ms";
+pub const DEFAULT_SERVER_PROFILING_INTERVAL: &str = "1s";
 pub const DEFAULT_MOVING_AVERAGE_WINDOW: u32 = 20;
+
+pub const DEFAULT_CHAOS_FIRST_FAULT_AFTER: &str = "5s";
+pub const DEFAULT_CHAOS_RESTART_DOWNTIME: &str = "1s";
+pub const DEFAULT_CHAOS_PAUSE_DURATION: &str = "2s";
+pub const DEFAULT_CHAOS_LATENCY_SPIKE_THRESHOLD: &str = "100ms";
+pub const DEFAULT_CHAOS_MAX_OUTAGE: &str = "60s";
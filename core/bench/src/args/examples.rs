@@ -116,7 +116,35 @@ const EXAMPLES: &str = r#"EXAMPLES:
         --producers 5 \
         tcp
 
-5) Remote Server Benchmarking:
+5) Scenario Benchmarking:
+
+    Run groups of producers and consumers side by side, each with its own streams, transport,
+    message sizes, rate, partitioning (balanced or uniform/Zipf message keys), slow consumers
+    and phases (ramp_up, steady, spike, failover), as described in a TOML scenario file:
+
+    $ cargo r -r --bin iggy-bench -- scenario --file core/bench/scenarios/mixed_workload.toml tcp
+
+    The benchmark runs for the duration of the longest group, --message-batches,
+    --total-messages-size and --rate-limit can't be used with it.
+
+6) Chaos Benchmarking:
+
+    Run pinned producers and consumers while the local server is killed and restarted,
+    paused (SIGSTOP) or the client connections are reset, and report reconnect times,
+    lost and duplicated messages and latency spikes:
+
+    $ cargo r -r --bin iggy-bench -- \
+        --message-batches 5000 \
+        chaos \
+        --producers 2 \
+        --restart-every 20s \
+        --reset-connections-every 7s \
+        --pause-every 13s --pause-duration 3s \
+        tcp
+
+    Server restarts and pauses require the server to be started by the benchmark.
+
+7) Remote Server Benchmarking:
 
     To benchmark a remote server, specify the server address in the transport subcommand:
 
@@ -124,7 +152,7 @@ const EXAMPLES: &str = r#"EXAMPLES:
         --streams 5 --producers 5 \
         tcp --server-address 192.168.1.100:8090
 
-6) Output Data and Results:
+8) Output Data and Results:
 
     The benchmark tool can store detailed results for analysis and comparison:
 
@@ -151,7 +179,7 @@ const EXAMPLES: &str = r#"EXAMPLES:
     --remark            : Additional context (e.g., "production-config")
     --extra-info        : Custom metadata for future analysis, currently unused
 
-7) Help and Documentation:
+9) Help and Documentation:
 
     For more details on available options:
 
@@ -24,9 +24,11 @@ use super::kinds::end_to_end::producing_consumer_group::EndToEndProducingConsume
 use super::props::BenchmarkKindProps;
 use super::transport::BenchmarkTransportCommand;
 use crate::args::kinds::balanced::consumer_group::BalancedConsumerGroupArgs;
+use crate::args::kinds::chaos::faults::ChaosArgs;
 use crate::args::kinds::pinned::consumer::PinnedConsumerArgs;
 use crate::args::kinds::pinned::producer::PinnedProducerArgs;
 use crate::args::kinds::pinned::producer_and_consumer::PinnedProducerAndConsumerArgs;
+use crate::args::kinds::scenario::workload::ScenarioArgs;
 use bench_report::benchmark_kind::BenchmarkKind;
 use clap::Subcommand;
 use iggy::prelude::IggyByteSize;
@@ -93,6 +95,22 @@ pub enum BenchmarkKindCommand {
     )]
     EndToEndProducingConsumerGroup(EndToEndProducingConsumerGroupArgs),
 
+    #[command(
+        about = "Mixed workload of multiple groups of producers and consumers described in a scenario file",
+        long_about = "Groups of producers and consumers with their own streams, transports, message sizes, rates, partitioning and phases (ramp-up, steady, spike, failover) described in a TOML scenario file",
+        visible_alias = "sc",
+        verbatim_doc_comment
+    )]
+    Scenario(ScenarioArgs),
+
+    #[command(
+        about = "Pinned producers and consumers while the server is restarted, paused or its connections are reset",
+        long_about = "N producers sending to N separated streams polled by N consumers while faults are injected: the local server is killed and restarted, paused (SIGSTOP) or the client connections are reset. Measures reconnect times, lost and duplicated messages (verified by message ids and offsets) and latency spikes",
+        visible_alias = "ch",
+        verbatim_doc_comment
+    )]
+    Chaos(ChaosArgs),
+
     #[command(about = "Print examples", visible_alias = "e", verbatim_doc_comment)]
     Examples,
 }
@@ -112,6 +130,8 @@ impl BenchmarkKindCommand {
             Self::EndToEndProducingConsumerGroup(_) => {
                 BenchmarkKind::EndToEndProducingConsumerGroup
             }
+            Self::Scenario(_) => BenchmarkKind::Scenario,
+            Self::Chaos(_) => BenchmarkKind::Chaos,
             Self::Examples => {
                 print_examples();
                 std::process::exit(0);
@@ -159,6 +179,8 @@ impl BenchmarkKindProps for BenchmarkKindCommand {
             Self::BalancedProducerAndConsumerGroup(args) => args,
             Self::EndToEndProducingConsumer(args) => args,
             Self::EndToEndProducingConsumerGroup(args) => args,
+            Self::Scenario(args) => args,
+            Self::Chaos(args) => args,
             Self::Examples => {
                 print_examples();
                 std::process::exit(0);
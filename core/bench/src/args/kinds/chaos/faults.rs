@@ -0,0 +1,184 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::args::{
+    common::IggyBenchArgs,
+    defaults::{
+        DEFAULT_CHAOS_FIRST_FAULT_AFTER, DEFAULT_CHAOS_LATENCY_SPIKE_THRESHOLD,
+        DEFAULT_CHAOS_MAX_OUTAGE, DEFAULT_CHAOS_PAUSE_DURATION, DEFAULT_CHAOS_RESTART_DOWNTIME,
+        DEFAULT_NUMBER_OF_PRODUCERS,
+    },
+    props::{BenchmarkKindProps, BenchmarkTransportProps},
+    transport::BenchmarkTransportCommand,
+};
+use clap::{CommandFactory, Parser, error::ErrorKind};
+use iggy::prelude::{IggyByteSize, IggyDuration};
+use integration::test_server::Transport;
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+/// Pinned producers and consumers running while faults are injected into the server
+#[derive(Parser, Debug, Clone)]
+pub struct ChaosArgs {
+    #[command(subcommand)]
+    pub transport: BenchmarkTransportCommand,
+
+    /// Number of producers, each one sending to its own stream polled by its own consumer
+    #[arg(long, short = 'p', default_value_t = DEFAULT_NUMBER_OF_PRODUCERS)]
+    pub producers: NonZeroU32,
+
+    /// Kill (SIGKILL) and restart the local server every given interval, e.g. "20s"
+    #[arg(long, value_parser = IggyDuration::from_str)]
+    pub restart_every: Option<IggyDuration>,
+
+    /// How long the killed server stays down before it's started again
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_CHAOS_RESTART_DOWNTIME).unwrap(), value_parser = IggyDuration::from_str)]
+    pub restart_downtime: IggyDuration,
+
+    /// Reset all the client connections every given interval, e.g. "10s".
+    /// The clients connect through a proxy in front of the server, which resets their connections.
+    #[arg(long, value_parser = IggyDuration::from_str, verbatim_doc_comment)]
+    pub reset_connections_every: Option<IggyDuration>,
+
+    /// Pause (SIGSTOP) the local server every given interval, e.g. "15s"
+    #[arg(long, value_parser = IggyDuration::from_str)]
+    pub pause_every: Option<IggyDuration>,
+
+    /// How long the paused server stays paused before it's resumed (SIGCONT)
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_CHAOS_PAUSE_DURATION).unwrap(), value_parser = IggyDuration::from_str)]
+    pub pause_duration: IggyDuration,
+
+    /// Time after the start of the benchmark before the first fault is injected
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_CHAOS_FIRST_FAULT_AFTER).unwrap(), value_parser = IggyDuration::from_str)]
+    pub first_fault_after: IggyDuration,
+
+    /// Requests slower than this are counted as latency spikes
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_CHAOS_LATENCY_SPIKE_THRESHOLD).unwrap(), value_parser = IggyDuration::from_str)]
+    pub latency_spike_threshold: IggyDuration,
+
+    /// Actor fails the benchmark when it can't get a successful response for this long
+    #[arg(long, default_value_t = IggyDuration::from_str(DEFAULT_CHAOS_MAX_OUTAGE).unwrap(), value_parser = IggyDuration::from_str)]
+    pub max_outage: IggyDuration,
+
+    /// Max topic size in human readable format, e.g. "1GiB", "2MB", "1GiB". If not provided then the server default will be used.
+    #[arg(long, short = 'T')]
+    pub max_topic_size: Option<IggyByteSize>,
+}
+
+impl ChaosArgs {
+    /// Server restarts and pauses require the server to be started by the benchmark
+    pub const fn requires_local_server(&self) -> bool {
+        self.restart_every.is_some() || self.pause_every.is_some()
+    }
+
+    /// Identifies the injected faults in the params identifier, e.g. `restart_20s_reset_7s`
+    pub fn faults_identifier(&self) -> String {
+        let faults = [
+            ("restart", self.restart_every),
+            ("reset", self.reset_connections_every),
+            ("pause", self.pause_every),
+        ];
+        faults
+            .iter()
+            .filter_map(|(name, interval)| {
+                interval.map(|interval| format!("{name}_{}", interval.as_human_time_string()))
+            })
+            .collect::<Vec<_>>()
+            .join("_")
+            .replace(' ', "")
+    }
+}
+
+impl BenchmarkKindProps for ChaosArgs {
+    fn streams(&self) -> u32 {
+        self.producers.get()
+    }
+
+    fn partitions(&self) -> u32 {
+        1
+    }
+
+    fn consumers(&self) -> u32 {
+        self.producers.get()
+    }
+
+    fn producers(&self) -> u32 {
+        self.producers.get()
+    }
+
+    fn transport_command(&self) -> &BenchmarkTransportCommand {
+        &self.transport
+    }
+
+    fn number_of_consumer_groups(&self) -> u32 {
+        0
+    }
+
+    fn max_topic_size(&self) -> Option<IggyByteSize> {
+        self.max_topic_size
+    }
+
+    fn validate(&self) {
+        let mut cmd = IggyBenchArgs::command();
+
+        if self.restart_every.is_none()
+            && self.reset_connections_every.is_none()
+            && self.pause_every.is_none()
+        {
+            cmd.error(
+                ErrorKind::MissingRequiredArgument,
+                "Chaos benchmark requires at least one fault: --restart-every, --reset-connections-every or --pause-every",
+            )
+            .exit();
+        }
+
+        let intervals = [
+            self.restart_every,
+            self.reset_connections_every,
+            self.pause_every,
+        ];
+        if intervals
+            .iter()
+            .flatten()
+            .any(|interval| interval.get_duration().is_zero())
+        {
+            cmd.error(
+                ErrorKind::ValueValidation,
+                "Intervals of the chaos faults must be greater than zero",
+            )
+            .exit();
+        }
+
+        if self.reset_connections_every.is_some() && *self.transport.transport() == Transport::Quic
+        {
+            cmd.error(
+                ErrorKind::ArgumentConflict,
+                "Connection resets (--reset-connections-every) are supported only for TCP and HTTP transports",
+            )
+            .exit();
+        }
+
+        if cfg!(not(unix)) && self.pause_every.is_some() {
+            cmd.error(
+                ErrorKind::ArgumentConflict,
+                "Server pauses (--pause-every) are supported only on Unix",
+            )
+            .exit();
+        }
+    }
+}
@@ -17,5 +17,7 @@
  */
 
 pub mod balanced;
+pub mod chaos;
 pub mod end_to_end;
 pub mod pinned;
+pub mod scenario;
@@ -0,0 +1,374 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bench_report::numeric_parameter::BenchmarkNumericParameter;
+use iggy::prelude::{IggyByteSize, IggyDuration};
+use integration::test_server::Transport;
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, serde_as};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::time::Duration;
+
+const DEFAULT_SPIKE_RATE_MULTIPLIER: f64 = 3.0;
+const DEFAULT_ZIPF_EXPONENT: f64 = 1.0;
+
+/// Mixed workload benchmark described in a TOML scenario file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BenchmarkScenario {
+    /// Path of the scenario file, used to recreate the benchmark command.
+    #[serde(skip)]
+    pub path: String,
+    pub name: String,
+    /// Phases of the groups which do not define their own.
+    #[serde(default)]
+    pub phases: Vec<ScenarioPhase>,
+    pub groups: Vec<ScenarioGroup>,
+}
+
+/// Group of actors sharing streams, transport, message sizes, rate, partitioning and phases.
+/// Every stream of the group has a single topic, consumers poll it in a consumer group.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioGroup {
+    pub name: String,
+    /// Overrides the transport of the benchmark command.
+    pub transport: Option<ScenarioTransport>,
+    /// Overrides the server address, defaults to the address of the benchmark command if
+    /// the transport is the same, otherwise to the default address of the transport.
+    pub server_address: Option<String>,
+    #[serde(default = "default_one")]
+    pub streams: u32,
+    #[serde(default = "default_one")]
+    pub partitions: u32,
+    #[serde(default)]
+    pub producers: u32,
+    #[serde(default)]
+    pub consumers: u32,
+    /// Defaults to `--message-size` of the benchmark command.
+    pub message_size: Option<BenchmarkNumericParameter>,
+    /// Defaults to `--messages-per-batch` of the benchmark command.
+    pub messages_per_batch: Option<BenchmarkNumericParameter>,
+    /// Total rate of the producers of the group, the phases scale it.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub rate: Option<IggyByteSize>,
+    #[serde(default)]
+    pub partitioning: ScenarioPartitioning,
+    /// Number of consumers polling at `slow_consumer_rate` instead of as fast as possible.
+    #[serde(default)]
+    pub slow_consumers: u32,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub slow_consumer_rate: Option<IggyByteSize>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub max_topic_size: Option<IggyByteSize>,
+    #[serde(default)]
+    pub phases: Vec<ScenarioPhase>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioTransport {
+    Tcp,
+    Quic,
+    Http,
+}
+
+impl From<ScenarioTransport> for Transport {
+    fn from(transport: ScenarioTransport) -> Self {
+        match transport {
+            ScenarioTransport::Tcp => Self::Tcp,
+            ScenarioTransport::Quic => Self::Quic,
+            ScenarioTransport::Http => Self::Http,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScenarioPartitioning {
+    /// Balanced across the partitions (or the single partition of the topic).
+    #[default]
+    Balanced,
+    /// Every batch is sent with a message key drawn from `1..=keys`, which skews the load
+    /// across the partitions when the distribution is not uniform.
+    MessagesKey {
+        keys: u64,
+        #[serde(default)]
+        distribution: ScenarioKeyDistribution,
+        #[serde(default = "default_zipf_exponent")]
+        exponent: f64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioKeyDistribution {
+    #[default]
+    Uniform,
+    Zipf,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioPhase {
+    pub kind: ScenarioPhaseKind,
+    #[serde_as(as = "DisplayFromStr")]
+    pub duration: IggyDuration,
+    /// Multiplier of the group rate during the spike phase.
+    pub rate_multiplier: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioPhaseKind {
+    /// The rate grows linearly from zero to the group rate.
+    RampUp,
+    /// The group rate (or as fast as possible without the rate).
+    Steady,
+    /// The group rate multiplied by `rate_multiplier`.
+    Spike,
+    /// Producers stop sending, as during a failover of the upstream, while consumers
+    /// keep polling. Afterwards the next phase resumes sending.
+    Failover,
+}
+
+const fn default_one() -> u32 {
+    1
+}
+
+const fn default_zipf_exponent() -> f64 {
+    DEFAULT_ZIPF_EXPONENT
+}
+
+impl ScenarioPhase {
+    pub fn rate_multiplier(&self) -> f64 {
+        match self.kind {
+            ScenarioPhaseKind::Spike => self
+                .rate_multiplier
+                .unwrap_or(DEFAULT_SPIKE_RATE_MULTIPLIER),
+            ScenarioPhaseKind::Failover => 0.0,
+            ScenarioPhaseKind::RampUp | ScenarioPhaseKind::Steady => 1.0,
+        }
+    }
+}
+
+impl BenchmarkScenario {
+    /// Loads and validates the scenario file, used as the clap value parser of `--file`.
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read scenario file: {path}, error: {e}"))?;
+        let mut scenario: Self = toml::from_str(&content)
+            .map_err(|e| format!("Failed to parse scenario file: {path}, error: {e}"))?;
+        path.clone_into(&mut scenario.path);
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Phases of the group, falling back to the phases of the scenario.
+    pub fn phases_of<'a>(&'a self, group: &'a ScenarioGroup) -> &'a [ScenarioPhase] {
+        if group.phases.is_empty() {
+            &self.phases
+        } else {
+            &group.phases
+        }
+    }
+
+    /// Duration of the group, the sum of the durations of its phases.
+    pub fn duration_of(&self, group: &ScenarioGroup) -> Duration {
+        self.phases_of(group)
+            .iter()
+            .map(|phase| phase.duration.get_duration())
+            .sum()
+    }
+
+    /// Duration of the whole scenario, the duration of the longest group.
+    pub fn duration(&self) -> Duration {
+        self.groups
+            .iter()
+            .map(|group| self.duration_of(group))
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn streams(&self) -> u32 {
+        self.groups.iter().map(|group| group.streams).sum()
+    }
+
+    pub fn max_partitions(&self) -> u32 {
+        self.groups
+            .iter()
+            .map(|group| group.partitions)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn producers(&self) -> u32 {
+        self.groups.iter().map(|group| group.producers).sum()
+    }
+
+    pub fn consumers(&self) -> u32 {
+        self.groups.iter().map(|group| group.consumers).sum()
+    }
+
+    /// Every stream of a group with consumers has its own consumer group.
+    pub fn consumer_groups(&self) -> u32 {
+        self.groups
+            .iter()
+            .filter(|group| group.consumers > 0)
+            .map(|group| group.streams)
+            .sum()
+    }
+
+    /// Span of the message sizes of all the groups, used in the benchmark report.
+    pub fn message_size_span(
+        &self,
+        default: BenchmarkNumericParameter,
+    ) -> BenchmarkNumericParameter {
+        Self::span(
+            self.groups
+                .iter()
+                .map(|group| group.message_size.unwrap_or(default)),
+        )
+    }
+
+    /// Span of the messages per batch of all the groups, used in the benchmark report.
+    pub fn messages_per_batch_span(
+        &self,
+        default: BenchmarkNumericParameter,
+    ) -> BenchmarkNumericParameter {
+        Self::span(
+            self.groups
+                .iter()
+                .map(|group| group.messages_per_batch.unwrap_or(default)),
+        )
+    }
+
+    fn span(
+        parameters: impl Iterator<Item = BenchmarkNumericParameter>,
+    ) -> BenchmarkNumericParameter {
+        let (min, max) = parameters.fold((u32::MAX, 0), |(min, max), parameter| {
+            (min.min(parameter.min()), max.max(parameter.max()))
+        });
+        if min >= max {
+            BenchmarkNumericParameter::Value(max)
+        } else {
+            BenchmarkNumericParameter::Range { min, max }
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.groups.is_empty() {
+            return Err(format!("Scenario: {} contains no groups", self.name));
+        }
+
+        let mut names = HashSet::new();
+        for group in &self.groups {
+            if !names.insert(group.name.as_str()) {
+                return Err(format!("Group name: {} is not unique", group.name));
+            }
+            self.validate_group(group)
+                .map_err(|e| format!("Invalid group: {}, {e}", group.name))?;
+        }
+        Ok(())
+    }
+
+    fn validate_group(&self, group: &ScenarioGroup) -> Result<(), String> {
+        if group.streams == 0 || group.partitions == 0 {
+            return Err("streams and partitions must be greater than 0".to_owned());
+        }
+        if group.producers == 0 && group.consumers == 0 {
+            return Err("at least one producer or consumer is required".to_owned());
+        }
+        if group.message_size.is_some_and(|size| size.min() == 0)
+            || group
+                .messages_per_batch
+                .is_some_and(|messages| messages.min() == 0)
+        {
+            return Err("message size and messages per batch must be greater than 0".to_owned());
+        }
+        if group.rate.is_some_and(|rate| rate.as_bytes_u64() == 0) {
+            return Err("rate must be greater than 0".to_owned());
+        }
+        if group.slow_consumers > group.consumers {
+            return Err("slow consumers must not outnumber the consumers".to_owned());
+        }
+        if group.slow_consumers > 0
+            && group
+                .slow_consumer_rate
+                .is_none_or(|rate| rate.as_bytes_u64() == 0)
+        {
+            return Err("slow consumers require slow_consumer_rate greater than 0".to_owned());
+        }
+        if let ScenarioPartitioning::MessagesKey { keys, exponent, .. } = group.partitioning {
+            if keys == 0 {
+                return Err("number of message keys must be greater than 0".to_owned());
+            }
+            if exponent <= 0.0 {
+                return Err("Zipf exponent must be greater than 0".to_owned());
+            }
+        }
+
+        let phases = self.phases_of(group);
+        if phases.is_empty() {
+            return Err("no phases defined for the group or the scenario".to_owned());
+        }
+        for phase in phases {
+            if phase.duration.get_duration().is_zero() {
+                return Err(format!(
+                    "{} phase duration must be greater than 0",
+                    phase.kind
+                ));
+            }
+            if phase.rate_multiplier.is_some() && phase.kind != ScenarioPhaseKind::Spike {
+                return Err(format!(
+                    "rate_multiplier is only supported by the spike phase, not {}",
+                    phase.kind
+                ));
+            }
+            if phase.rate_multiplier() <= 0.0 && phase.kind == ScenarioPhaseKind::Spike {
+                return Err("spike rate multiplier must be greater than 0".to_owned());
+            }
+            if matches!(
+                phase.kind,
+                ScenarioPhaseKind::RampUp | ScenarioPhaseKind::Spike
+            ) && group.rate.is_none()
+                && group.producers > 0
+            {
+                return Err(format!("{} phase requires the group rate", phase.kind));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for ScenarioPhaseKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RampUp => write!(f, "ramp-up"),
+            Self::Steady => write!(f, "steady"),
+            Self::Spike => write!(f, "spike"),
+            Self::Failover => write!(f, "failover"),
+        }
+    }
+}
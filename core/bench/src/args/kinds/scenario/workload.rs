@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::definition::BenchmarkScenario;
+use crate::args::{props::BenchmarkKindProps, transport::BenchmarkTransportCommand};
+use clap::Parser;
+use iggy::prelude::IggyByteSize;
+
+/// Mixed workload benchmark described in a scenario file
+#[derive(Parser, Debug, Clone)]
+pub struct ScenarioArgs {
+    #[command(subcommand)]
+    pub transport: BenchmarkTransportCommand,
+
+    /// Path to the TOML scenario file describing the groups of actors and their phases
+    #[arg(long, short = 'f', value_parser = BenchmarkScenario::from_file)]
+    pub file: BenchmarkScenario,
+}
+
+impl BenchmarkKindProps for ScenarioArgs {
+    fn streams(&self) -> u32 {
+        self.file.streams()
+    }
+
+    fn partitions(&self) -> u32 {
+        self.file.max_partitions()
+    }
+
+    fn consumers(&self) -> u32 {
+        self.file.consumers()
+    }
+
+    fn producers(&self) -> u32 {
+        self.file.producers()
+    }
+
+    fn transport_command(&self) -> &BenchmarkTransportCommand {
+        &self.transport
+    }
+
+    fn number_of_consumer_groups(&self) -> u32 {
+        self.file.consumer_groups()
+    }
+
+    // Max topic size is defined per group in the scenario file
+    fn max_topic_size(&self) -> Option<IggyByteSize> {
+        None
+    }
+
+    // Scenario file is validated when it's parsed
+    fn validate(&self) {}
+}
@@ -16,7 +16,9 @@
  * under the License.
  */
 
+use crate::analytics::chaos_stats::BenchmarkChaosStats;
 use crate::args::kind::BenchmarkKindCommand;
+use crate::utils::chaos_proxy::BenchmarkChaosProxy;
 use crate::{args::common::IggyBenchArgs, utils::client_factory::create_client_factory};
 use async_trait::async_trait;
 use bench_report::benchmark_kind::BenchmarkKind;
@@ -31,11 +33,13 @@ use tracing::info;
 use super::balanced_consumer_group::BalancedConsumerGroupBenchmark;
 use super::balanced_producer::BalancedProducerBenchmark;
 use super::balanced_producer_and_consumer_group::BalancedProducerAndConsumerGroupBenchmark;
+use super::chaos::ChaosBenchmark;
 use super::end_to_end_producing_consumer::EndToEndProducingConsumerBenchmark;
 use super::end_to_end_producing_consumer_group::EndToEndProducingConsumerGroupBenchmark;
 use super::pinned_consumer::PinnedConsumerBenchmark;
 use super::pinned_producer::PinnedProducerBenchmark;
 use super::pinned_producer_and_consumer::PinnedProducerAndConsumerBenchmark;
+use super::scenario::ScenarioBenchmark;
 
 impl From<IggyBenchArgs> for Box<dyn Benchmarkable> {
     fn from(args: IggyBenchArgs) -> Self {
@@ -74,6 +78,11 @@ impl From<IggyBenchArgs> for Box<dyn Benchmarkable> {
             BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => Box::new(
                 EndToEndProducingConsumerGroupBenchmark::new(Arc::new(args), client_factory),
             ),
+
+            BenchmarkKindCommand::Scenario(_) => {
+                Box::new(ScenarioBenchmark::new(Arc::new(args), client_factory))
+            }
+            BenchmarkKindCommand::Chaos(_) => Box::new(ChaosBenchmark::new(Arc::new(args))),
             BenchmarkKindCommand::Examples => {
                 unreachable!("Examples should be handled before this point")
             }
@@ -91,6 +100,16 @@ pub trait Benchmarkable: Send {
     fn client_factory(&self) -> &Arc<dyn ClientFactory>;
     fn print_info(&self);
 
+    /// Stats collected by the actors of the chaos benchmark, `None` for the other benchmarks.
+    fn chaos_stats(&self) -> Option<&Arc<BenchmarkChaosStats>> {
+        None
+    }
+
+    /// Proxy resetting the connections of the chaos benchmark, if connection resets are enabled.
+    fn chaos_proxy(&self) -> Option<&Arc<BenchmarkChaosProxy>> {
+        None
+    }
+
     /// Below methods have common implementation for all benchmarks.
     /// Initializes the streams and topics for the benchmark.
     /// This method is called before the benchmark is executed.
@@ -175,7 +194,12 @@ pub trait Benchmarkable: Send {
             .rate_limit()
             .map(|rl| format!(" global rate limit: {rl}/s"))
             .unwrap_or_default();
+        let open_loop = if self.args().open_loop() {
+            ", open-loop"
+        } else {
+            ""
+        };
 
-        format!("{message_size}{messages_per_batch}{data}{rate_limit}",)
+        format!("{message_size}{messages_per_batch}{data}{rate_limit}{open_loop}",)
     }
 }
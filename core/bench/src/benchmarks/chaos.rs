@@ -0,0 +1,187 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::benchmark::Benchmarkable;
+use crate::{
+    actors::chaos::{BenchmarkChaosConsumer, BenchmarkChaosProducer, ChaosStreamState},
+    analytics::chaos_stats::BenchmarkChaosStats,
+    args::{common::IggyBenchArgs, kinds::chaos::faults::ChaosArgs},
+    benchmarks::common::rate_limit_per_actor,
+    utils::{
+        chaos_proxy::BenchmarkChaosProxy,
+        client_factory::{create_client_factory, create_client_factory_with_address},
+        finish_condition::{BenchmarkFinishCondition, BenchmarkFinishConditionMode},
+    },
+};
+use async_trait::async_trait;
+use bench_report::{benchmark_kind::BenchmarkKind, individual_metrics::BenchmarkIndividualMetrics};
+use iggy::prelude::*;
+use integration::test_server::ClientFactory;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::info;
+
+/// Pinned producers and consumers, one pair per stream with a single partition, running while
+/// the faults are injected by the runner. When connection resets are enabled, the clients
+/// connect through a proxy in front of the server.
+pub struct ChaosBenchmark {
+    args: Arc<IggyBenchArgs>,
+    client_factory: Arc<dyn ClientFactory>,
+    proxy: Option<Arc<BenchmarkChaosProxy>>,
+    stats: Arc<BenchmarkChaosStats>,
+}
+
+impl ChaosBenchmark {
+    pub fn new(args: Arc<IggyBenchArgs>) -> Self {
+        let chaos = args
+            .chaos()
+            .expect("Chaos benchmark requires chaos arguments");
+        let stats = Arc::new(BenchmarkChaosStats::new(chaos.latency_spike_threshold));
+        let proxy = chaos.reset_connections_every.map(|_| {
+            Arc::new(
+                BenchmarkChaosProxy::start(args.server_address())
+                    .expect("Failed to start the chaos proxy"),
+            )
+        });
+        let client_factory = proxy.as_ref().map_or_else(
+            || create_client_factory(&args),
+            |proxy| create_client_factory_with_address(&args, proxy.address().to_string()),
+        );
+
+        Self {
+            args,
+            client_factory,
+            proxy,
+            stats,
+        }
+    }
+
+    fn chaos(&self) -> &ChaosArgs {
+        self.args
+            .chaos()
+            .expect("Chaos benchmark requires chaos arguments")
+    }
+}
+
+#[async_trait]
+impl Benchmarkable for ChaosBenchmark {
+    async fn run(
+        &mut self,
+    ) -> Result<JoinSet<Result<BenchmarkIndividualMetrics, IggyError>>, IggyError> {
+        self.init_streams().await?;
+        let args = &self.args;
+        let producers = args.producers();
+        let kind = self.kind();
+        let messages_per_batch = args.messages_per_batch();
+        let sampling_time = args.sampling_time();
+        let moving_average_window = args.moving_average_window();
+        let max_outage = self.chaos().max_outage.get_duration();
+        let rate_limit = rate_limit_per_actor(args.rate_limit(), producers);
+        let mut tasks = JoinSet::new();
+
+        for producer_id in 1..=producers {
+            let consumer_id = producers + producer_id;
+            let stream_id = args.start_stream_id() + producer_id;
+            let stream_state = Arc::new(ChaosStreamState::default());
+
+            let producer = BenchmarkChaosProducer::new(
+                self.client_factory.clone(),
+                kind,
+                producer_id,
+                stream_id,
+                messages_per_batch,
+                args.message_size(),
+                BenchmarkFinishCondition::new(args, BenchmarkFinishConditionMode::PerProducer),
+                sampling_time,
+                moving_average_window,
+                rate_limit,
+                max_outage,
+                self.stats.clone(),
+                stream_state.clone(),
+            );
+            let consumer = BenchmarkChaosConsumer::new(
+                self.client_factory.clone(),
+                kind,
+                consumer_id,
+                producer_id,
+                stream_id,
+                messages_per_batch,
+                sampling_time,
+                moving_average_window,
+                max_outage,
+                self.stats.clone(),
+                stream_state,
+            );
+            tasks.spawn(producer.run());
+            tasks.spawn(consumer.run());
+        }
+
+        Ok(tasks)
+    }
+
+    fn kind(&self) -> BenchmarkKind {
+        self.args.kind()
+    }
+
+    fn args(&self) -> &IggyBenchArgs {
+        &self.args
+    }
+
+    fn client_factory(&self) -> &Arc<dyn ClientFactory> {
+        &self.client_factory
+    }
+
+    fn chaos_stats(&self) -> Option<&Arc<BenchmarkChaosStats>> {
+        Some(&self.stats)
+    }
+
+    fn chaos_proxy(&self) -> Option<&Arc<BenchmarkChaosProxy>> {
+        self.proxy.as_ref()
+    }
+
+    fn print_info(&self) {
+        let chaos = self.chaos();
+        let faults = [
+            ("restart every", chaos.restart_every),
+            ("connection reset every", chaos.reset_connections_every),
+            ("pause every", chaos.pause_every),
+        ]
+        .iter()
+        .filter_map(|(name, interval)| interval.map(|interval| format!("{name} {interval}")))
+        .collect::<Vec<_>>()
+        .join(", ");
+        let proxy = self
+            .proxy
+            .as_ref()
+            .map(|proxy| format!(", proxy: {}", proxy.address()))
+            .unwrap_or_default();
+        let common_params = self.common_params_str();
+
+        info!(
+            "Staring benchmark Chaos, streams: {}, producers: {}, consumers: {}, faults: [{faults}], first fault after: {}, \
+            restart downtime: {}, pause duration: {}, max outage: {}{proxy}, {common_params}",
+            self.args.streams(),
+            self.args.producers(),
+            self.args.consumers(),
+            chaos.first_fault_after,
+            chaos.restart_downtime,
+            chaos.pause_duration,
+            chaos.max_outage,
+        );
+    }
+}
@@ -128,6 +128,7 @@ pub fn build_producer_futures(
     let shared_finish_condition =
         BenchmarkFinishCondition::new(args, BenchmarkFinishConditionMode::Shared);
     let rate_limit = rate_limit_per_actor(args.rate_limit(), actors);
+    let open_loop = args.open_loop();
 
     (1..=producers)
         .map(|producer_id| {
@@ -155,6 +156,9 @@ pub fn build_producer_futures(
                     sampling_time,
                     moving_average_window,
                     rate_limit,
+                    open_loop,
+                    None,
+                    None,
                 );
                 producer.run().await
             }
@@ -191,6 +195,7 @@ pub fn build_consumer_futures(
     let global_finish_condition =
         BenchmarkFinishCondition::new(args, BenchmarkFinishConditionMode::Shared);
     let rate_limit = rate_limit_per_actor(args.rate_limit(), actors);
+    let open_loop = args.open_loop();
     let use_high_level_api = args.high_level_api();
 
     (1..=consumers)
@@ -226,6 +231,7 @@ pub fn build_consumer_futures(
                     moving_average_window,
                     polling_kind,
                     rate_limit,
+                    open_loop,
                     origin_timestamp_latency_calculation,
                     use_high_level_api,
                 );
@@ -286,6 +292,7 @@ pub fn build_producing_consumers_futures(
                     args_clone.sampling_time(),
                     args_clone.moving_average_window(),
                     rate_limit,
+                    args_clone.open_loop(),
                     polling_kind,
                 );
                 actor.run().await
@@ -384,6 +391,7 @@ pub fn build_producing_consumer_groups_futures(
                     args_clone.sampling_time(),
                     args_clone.moving_average_window(),
                     rate_limit,
+                    args_clone.open_loop(),
                     polling_kind,
                 );
                 actor.run().await
@@ -22,11 +22,13 @@ pub mod common;
 pub mod balanced_consumer_group;
 pub mod balanced_producer;
 pub mod balanced_producer_and_consumer_group;
+pub mod chaos;
 pub mod end_to_end_producing_consumer;
 pub mod end_to_end_producing_consumer_group;
 pub mod pinned_consumer;
 pub mod pinned_producer;
 pub mod pinned_producer_and_consumer;
+pub mod scenario;
 
 pub const CONSUMER_GROUP_BASE_ID: u32 = 0;
 pub const CONSUMER_GROUP_NAME_PREFIX: &str = "cg";
@@ -0,0 +1,320 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::benchmark::Benchmarkable;
+use super::{CONSUMER_GROUP_BASE_ID, CONSUMER_GROUP_NAME_PREFIX};
+use crate::{
+    actors::{consumer::BenchmarkConsumer, producer::BenchmarkProducer},
+    args::{
+        common::IggyBenchArgs,
+        kinds::scenario::definition::{BenchmarkScenario, ScenarioGroup, ScenarioPartitioning},
+    },
+    benchmarks::common::rate_limit_per_actor,
+    utils::{
+        client_factory::create_group_client_factory, finish_condition::BenchmarkFinishCondition,
+        key_distribution::BenchmarkKeyDistribution, load_profile::BenchmarkLoadProfile,
+    },
+};
+use async_trait::async_trait;
+use bench_report::{benchmark_kind::BenchmarkKind, individual_metrics::BenchmarkIndividualMetrics};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{error, info};
+
+/// Runs the groups of a scenario file side by side. Every group owns a consecutive range of
+/// streams (one topic each) and, when it has consumers, one consumer group per stream.
+pub struct ScenarioBenchmark {
+    args: Arc<IggyBenchArgs>,
+    client_factory: Arc<dyn ClientFactory>,
+}
+
+impl ScenarioBenchmark {
+    pub fn new(args: Arc<IggyBenchArgs>, client_factory: Arc<dyn ClientFactory>) -> Self {
+        Self {
+            args,
+            client_factory,
+        }
+    }
+
+    fn scenario(&self) -> &BenchmarkScenario {
+        self.args
+            .scenario()
+            .expect("Scenario benchmark requires a scenario file")
+    }
+
+    /// Returns the groups along with the id of the first stream of every group.
+    fn groups_with_first_stream_id(&self) -> Vec<(&ScenarioGroup, u32)> {
+        let mut next_stream_id = self.args.start_stream_id() + 1;
+        self.scenario()
+            .groups
+            .iter()
+            .map(|group| {
+                let first_stream_id = next_stream_id;
+                next_stream_id += group.streams;
+                (group, first_stream_id)
+            })
+            .collect()
+    }
+
+    async fn init_group(
+        &self,
+        group: &ScenarioGroup,
+        first_stream_id: u32,
+        client_factory: &Arc<dyn ClientFactory>,
+    ) -> Result<(), IggyError> {
+        let topic_id: u32 = 1;
+        let client = client_factory.create_client().await;
+        let client = IggyClient::create(client, None, None);
+        login_root(&client).await;
+        let streams = client.get_streams().await?;
+        for stream_id in first_stream_id..first_stream_id + group.streams {
+            if streams.iter().all(|s| s.id != stream_id) {
+                info!(
+                    "Creating the test stream {} for scenario group {}",
+                    stream_id, group.name
+                );
+                let name = format!("stream {stream_id}");
+                client.create_stream(&name, Some(stream_id)).await?;
+                let name = format!("topic {topic_id}");
+                let max_topic_size = group
+                    .max_topic_size
+                    .map_or(MaxTopicSize::Unlimited, MaxTopicSize::Custom);
+
+                info!(
+                    "Creating the test topic {} for stream {} with {} partitions and max topic size: {:?}",
+                    topic_id, stream_id, group.partitions, max_topic_size
+                );
+
+                client
+                    .create_topic(
+                        &stream_id.try_into()?,
+                        &name,
+                        group.partitions,
+                        CompressionAlgorithm::default(),
+                        None,
+                        None,
+                        IggyExpiry::NeverExpire,
+                        max_topic_size,
+                    )
+                    .await?;
+            }
+
+            if group.consumers == 0 {
+                continue;
+            }
+
+            let consumer_group_id = self.consumer_group_id(stream_id);
+            let consumer_group_name = format!("{CONSUMER_GROUP_NAME_PREFIX}-{consumer_group_id}");
+            info!(
+                "Creating test consumer group: name={}, id={}, stream={}, topic={}",
+                consumer_group_name, consumer_group_id, stream_id, topic_id
+            );
+            match client
+                .create_consumer_group(
+                    &stream_id.try_into()?,
+                    &topic_id.try_into()?,
+                    &consumer_group_name,
+                    Some(consumer_group_id),
+                )
+                .await
+            {
+                Err(IggyError::ConsumerGroupIdAlreadyExists(_, _)) => {
+                    info!(
+                        "Consumer group with id {} already exists",
+                        consumer_group_id
+                    );
+                }
+                Err(err) => {
+                    error!("Error when creating consumer group {consumer_group_id}: {err}");
+                }
+                Ok(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn consumer_group_id(&self, stream_id: u32) -> u32 {
+        CONSUMER_GROUP_BASE_ID + stream_id - self.args.start_stream_id()
+    }
+
+    /// Spawns the producers and consumers of the group, starting the actor ids after
+    /// the ids already taken by the previous groups.
+    fn spawn_group(
+        &self,
+        tasks: &mut JoinSet<Result<BenchmarkIndividualMetrics, IggyError>>,
+        group: &ScenarioGroup,
+        first_stream_id: u32,
+        first_actor_id: u32,
+    ) {
+        let args = &self.args;
+        let scenario = self.scenario();
+        let client_factory = create_group_client_factory(args, group);
+        let kind = self.kind();
+        let messages_per_batch = group
+            .messages_per_batch
+            .unwrap_or_else(|| args.messages_per_batch());
+        let message_size = group.message_size.unwrap_or_else(|| args.message_size());
+        let warmup_time = args.warmup_time();
+        let sampling_time = args.sampling_time();
+        let moving_average_window = args.moving_average_window();
+        let duration = scenario.duration_of(group);
+        let finish_condition = BenchmarkFinishCondition::new_timed(duration);
+        let load_profile = Arc::new(BenchmarkLoadProfile::new(scenario.phases_of(group)));
+        let producer_rate_limit = rate_limit_per_actor(group.rate, group.producers);
+
+        for i in 0..group.producers {
+            let producer_id = first_actor_id + i;
+            let stream_id = first_stream_id + (i % group.streams);
+            let key_distribution = match group.partitioning {
+                ScenarioPartitioning::Balanced => None,
+                ScenarioPartitioning::MessagesKey {
+                    keys,
+                    distribution,
+                    exponent,
+                } => Some(BenchmarkKeyDistribution::new(distribution, keys, exponent)),
+            };
+            let producer = BenchmarkProducer::new(
+                client_factory.clone(),
+                kind,
+                producer_id,
+                stream_id,
+                group.partitions,
+                messages_per_batch,
+                message_size,
+                finish_condition.clone(),
+                warmup_time,
+                sampling_time,
+                moving_average_window,
+                producer_rate_limit,
+                false,
+                Some(load_profile.clone()),
+                key_distribution,
+            );
+            tasks.spawn(producer.run());
+        }
+
+        for i in 0..group.consumers {
+            let consumer_id = first_actor_id + group.producers + i;
+            let stream_id = first_stream_id + (i % group.streams);
+            let rate_limit = if i < group.slow_consumers {
+                group.slow_consumer_rate
+            } else {
+                None
+            };
+            let consumer = BenchmarkConsumer::new(
+                client_factory.clone(),
+                kind,
+                consumer_id,
+                Some(self.consumer_group_id(stream_id)),
+                stream_id,
+                messages_per_batch,
+                finish_condition.clone(),
+                warmup_time,
+                sampling_time,
+                moving_average_window,
+                PollingKind::Next,
+                rate_limit,
+                false,
+                group.producers > 0,
+                args.high_level_api(),
+            );
+            tasks.spawn(consumer.run());
+        }
+    }
+}
+
+#[async_trait]
+impl Benchmarkable for ScenarioBenchmark {
+    async fn run(
+        &mut self,
+    ) -> Result<JoinSet<Result<BenchmarkIndividualMetrics, IggyError>>, IggyError> {
+        self.init_streams().await?;
+        let mut tasks: JoinSet<_> = JoinSet::new();
+
+        let mut first_actor_id = 1;
+        for (group, first_stream_id) in self.groups_with_first_stream_id() {
+            self.spawn_group(&mut tasks, group, first_stream_id, first_actor_id);
+            first_actor_id += group.producers + group.consumers;
+        }
+
+        Ok(tasks)
+    }
+
+    async fn init_streams(&self) -> Result<(), IggyError> {
+        for (group, first_stream_id) in self.groups_with_first_stream_id() {
+            let client_factory = create_group_client_factory(&self.args, group);
+            self.init_group(group, first_stream_id, &client_factory)
+                .await?;
+        }
+        Ok(())
+    }
+
+    fn kind(&self) -> BenchmarkKind {
+        self.args.kind()
+    }
+
+    fn args(&self) -> &IggyBenchArgs {
+        &self.args
+    }
+
+    fn client_factory(&self) -> &Arc<dyn ClientFactory> {
+        &self.client_factory
+    }
+
+    fn print_info(&self) {
+        let scenario = self.scenario();
+        let groups = self
+            .groups_with_first_stream_id()
+            .into_iter()
+            .map(|(group, first_stream_id)| {
+                let rate = group
+                    .rate
+                    .map(|rate| format!(", rate: {rate}/s"))
+                    .unwrap_or_default();
+                let phases = scenario
+                    .phases_of(group)
+                    .iter()
+                    .map(|phase| format!("{} {}", phase.kind, phase.duration))
+                    .collect::<Vec<_>>()
+                    .join(" → ");
+                format!(
+                    "[{}: streams {}..={}, partitions: {}, producers: {}, consumers: {} ({} slow){}, phases: {}]",
+                    group.name,
+                    first_stream_id,
+                    first_stream_id + group.streams - 1,
+                    group.partitions,
+                    group.producers,
+                    group.consumers,
+                    group.slow_consumers,
+                    rate,
+                    phases
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        info!(
+            "Staring benchmark Scenario '{}' from {}, duration: {} s, groups: {groups}",
+            scenario.name,
+            scenario.path,
+            scenario.duration().as_secs()
+        );
+    }
+}
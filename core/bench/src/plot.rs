@@ -28,6 +28,7 @@ use tracing::info;
 pub enum ChartType {
     Throughput,
     Latency,
+    ServerResources,
 }
 
 impl ChartType {
@@ -35,13 +36,18 @@ impl ChartType {
         match self {
             Self::Throughput => "throughput",
             Self::Latency => "latency",
+            Self::ServerResources => "server_resources",
         }
     }
 
-    fn create_chart(&self) -> fn(&BenchmarkReport, bool, bool) -> Chart {
+    /// Returns `None` when the report has no data for the chart.
+    fn create_chart(&self, report: &BenchmarkReport, dark: bool) -> Option<Chart> {
         match self {
-            Self::Throughput => bench_report::create_throughput_chart,
-            Self::Latency => bench_report::create_latency_chart,
+            Self::Throughput => Some(bench_report::create_throughput_chart(report, dark, false)),
+            Self::Latency => Some(bench_report::create_latency_chart(report, dark, false)),
+            Self::ServerResources => {
+                bench_report::create_server_resources_chart(report, dark, false)
+            }
         }
     }
 
@@ -58,6 +64,10 @@ impl ChartType {
                 .filter(|m| !m.latency_ts.points.is_empty())
                 .map(|m| m.latency_ts.points.len())
                 .sum(),
+            Self::ServerResources => report
+                .server_resources
+                .as_ref()
+                .map_or(0, |r| r.cpu_usage_ts.points.len()),
         }
     }
 }
@@ -89,7 +99,11 @@ pub fn plot_chart(
     should_open_in_browser: bool,
 ) -> std::io::Result<()> {
     let data_processing_start = Instant::now();
-    let chart = (chart_type.create_chart())(report, true, false); // Use dark theme by default
+    // Use dark theme by default
+    let Some(chart) = chart_type.create_chart(report, true) else {
+        info!("Skipping {} plot, no data in report", chart_type.name());
+        return Ok(());
+    };
     let data_processing_time = data_processing_start.elapsed();
 
     let chart_render_start = Instant::now();
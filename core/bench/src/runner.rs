@@ -17,9 +17,11 @@
  */
 
 use crate::analytics::report_builder::BenchmarkReportBuilder;
+use crate::analytics::server_profiler::BenchmarkServerProfiler;
 use crate::args::common::IggyBenchArgs;
 use crate::benchmarks::benchmark::Benchmarkable;
 use crate::plot::{ChartType, plot_chart};
+use crate::utils::chaos_injector::BenchmarkChaosInjector;
 use crate::utils::cpu_name::append_cpu_name_lowercase;
 use crate::utils::server_starter::start_server_if_needed;
 use crate::utils::{collect_server_logs_and_save_to_file, params_from_args_and_metrics};
@@ -44,7 +46,7 @@ impl BenchmarkRunner {
         }
     }
 
-    #[allow(clippy::cognitive_complexity)]
+    #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
     pub async fn run(mut self) -> Result<(), IggyError> {
         let args = self.args.take().unwrap();
         let should_open_charts = args.open_charts();
@@ -54,9 +56,31 @@ impl BenchmarkRunner {
         let server_addr = args.server_address();
         info!("Starting to benchmark: {transport} with server: {server_addr}",);
 
+        if let Some(chaos) = args.chaos()
+            && chaos.requires_local_server()
+            && self.test_server.is_none()
+        {
+            error!(
+                "Server restarts and pauses require the server to be started by the benchmark, use a local server address without --skip-server-start"
+            );
+            return Err(IggyError::InvalidConfiguration);
+        }
+
         let mut benchmark: Box<dyn Benchmarkable> = args.into();
         benchmark.print_info();
+        let server_profiler = BenchmarkServerProfiler::start(
+            benchmark.client_factory(),
+            benchmark.args().server_profiling_interval(),
+            self.test_server.as_ref().map(TestServer::pid),
+        );
         let mut join_handles = benchmark.run().await?;
+        let chaos_injector = benchmark.args().chaos().map(|chaos| {
+            BenchmarkChaosInjector::start(
+                chaos,
+                self.test_server.take(),
+                benchmark.chaos_proxy().cloned(),
+            )
+        });
 
         let mut individual_metrics = Vec::new();
 
@@ -69,6 +93,20 @@ impl BenchmarkRunner {
         }
 
         info!("All actors joined!");
+        let chaos = match chaos_injector {
+            Some(chaos_injector) => {
+                let (test_server, faults) = chaos_injector.stop().await;
+                self.test_server = test_server;
+                benchmark
+                    .chaos_stats()
+                    .map(|stats| stats.to_metrics(faults))
+            }
+            None => None,
+        };
+        let server_resources = match server_profiler {
+            Some(server_profiler) => server_profiler.stop().await,
+            None => None,
+        };
         let hardware =
             BenchmarkHardware::get_system_info_with_identifier(benchmark.args().identifier());
         let params = params_from_args_and_metrics(benchmark.args(), &individual_metrics);
@@ -79,6 +117,8 @@ impl BenchmarkRunner {
             individual_metrics,
             benchmark.args().moving_average_window(),
             benchmark.client_factory(),
+            server_resources,
+            chaos,
         )
         .await;
 
@@ -129,6 +169,16 @@ impl BenchmarkRunner {
                 error!("Failed to generate plots: {e}");
                 IggyError::CannotWriteToFile
             })?;
+            plot_chart(
+                &report,
+                &full_output_path,
+                &ChartType::ServerResources,
+                should_open_charts,
+            )
+            .map_err(|e| {
+                error!("Failed to generate plots: {e}");
+                IggyError::CannotWriteToFile
+            })?;
         }
 
         Ok(())
@@ -0,0 +1,172 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::chaos_proxy::BenchmarkChaosProxy;
+use super::server_starter::restart_server;
+use crate::args::kinds::chaos::faults::ChaosArgs;
+use bench_report::chaos::{BenchmarkChaosFault, ChaosFaultKind};
+use integration::test_server::TestServer;
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    sync::oneshot,
+    task::JoinHandle,
+    time::{Instant, sleep_until},
+};
+use tracing::{info, warn};
+
+/// Injects the faults of the chaos benchmark on their intervals in the background until stopped.
+/// Owns the local server while running, because restarting it replaces its process.
+pub struct BenchmarkChaosInjector {
+    stop: oneshot::Sender<()>,
+    handle: JoinHandle<(Option<TestServer>, Vec<BenchmarkChaosFault>)>,
+}
+
+struct ScheduledFault {
+    kind: ChaosFaultKind,
+    interval: Duration,
+    next: Instant,
+}
+
+impl BenchmarkChaosInjector {
+    pub fn start(
+        args: &ChaosArgs,
+        test_server: Option<TestServer>,
+        proxy: Option<Arc<BenchmarkChaosProxy>>,
+    ) -> Self {
+        let start = Instant::now();
+        let first_fault = start + args.first_fault_after.get_duration();
+        let mut schedule = [
+            (ChaosFaultKind::ServerRestart, args.restart_every),
+            (
+                ChaosFaultKind::ConnectionReset,
+                args.reset_connections_every,
+            ),
+            (ChaosFaultKind::ServerPause, args.pause_every),
+        ]
+        .into_iter()
+        .filter_map(|(kind, interval)| {
+            interval.map(|interval| ScheduledFault {
+                kind,
+                interval: interval.get_duration(),
+                next: first_fault,
+            })
+        })
+        .collect::<Vec<_>>();
+        let restart_downtime = args.restart_downtime.get_duration();
+        let pause_duration = args.pause_duration.get_duration();
+
+        info!(
+            "Starting chaos injection in {}, faults: {}",
+            args.first_fault_after,
+            schedule
+                .iter()
+                .map(|fault| format!("{} every {} ms", fault.kind, fault.interval.as_millis()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let (stop, mut stop_receiver) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let mut test_server = test_server;
+            let mut faults = Vec::new();
+            while let Some(index) = (0..schedule.len()).min_by_key(|&index| schedule[index].next) {
+                let fault = &mut schedule[index];
+                tokio::select! {
+                    _ = &mut stop_receiver => break,
+                    () = sleep_until(fault.next) => {}
+                }
+
+                // The fault is always completed, so that the server isn't left killed or paused
+                let started_at_secs = start.elapsed().as_secs_f64();
+                let duration = match fault.kind {
+                    ChaosFaultKind::ServerRestart => {
+                        let Some(mut server) = test_server.take() else {
+                            warn!(
+                                "Cannot restart the server, it wasn't started by the benchmark, skipping the restarts"
+                            );
+                            schedule.swap_remove(index);
+                            continue;
+                        };
+                        let (server, unavailable) = tokio::task::spawn_blocking(move || {
+                            let unavailable = restart_server(&mut server, restart_downtime);
+                            (server, unavailable)
+                        })
+                        .await
+                        .expect("Failed to restart the server");
+                        test_server = Some(server);
+                        unavailable
+                    }
+                    ChaosFaultKind::ConnectionReset => {
+                        if let Some(proxy) = &proxy {
+                            let connections = proxy.reset_connections();
+                            info!("Reset {connections} client connections");
+                        }
+                        Duration::ZERO
+                    }
+                    ChaosFaultKind::ServerPause => {
+                        let Some(server) = &test_server else {
+                            warn!(
+                                "Cannot pause the server, it wasn't started by the benchmark, skipping the pauses"
+                            );
+                            schedule.swap_remove(index);
+                            continue;
+                        };
+                        pause_server(server, pause_duration).await
+                    }
+                };
+                faults.push(BenchmarkChaosFault {
+                    kind: fault.kind,
+                    started_at_secs,
+                    duration_ms: duration.as_secs_f64() * 1000.0,
+                });
+                // Faults which took longer than their interval are not made up for
+                fault.next = (fault.next + fault.interval).max(Instant::now());
+            }
+            (test_server, faults)
+        });
+
+        Self { stop, handle }
+    }
+
+    /// Stops injecting the faults, returns the local server and the injected faults.
+    pub async fn stop(self) -> (Option<TestServer>, Vec<BenchmarkChaosFault>) {
+        let _ = self.stop.send(());
+        self.handle
+            .await
+            .expect("Chaos injector failed, the state of the server is unknown")
+    }
+}
+
+#[cfg(unix)]
+async fn pause_server(test_server: &TestServer, pause_duration: Duration) -> Duration {
+    let now = Instant::now();
+    test_server.suspend();
+    info!(
+        "Paused test iggy-server, pid: {}, resuming in {} ms",
+        test_server.pid(),
+        pause_duration.as_millis()
+    );
+    tokio::time::sleep(pause_duration).await;
+    test_server.resume();
+    info!("Resumed test iggy-server, pid: {}", test_server.pid());
+    now.elapsed()
+}
+
+#[cfg(not(unix))]
+async fn pause_server(_test_server: &TestServer, _pause_duration: Duration) -> Duration {
+    unreachable!("Server pauses are validated to be supported only on Unix")
+}
@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{io, net::SocketAddr, time::Duration};
+use tokio::{
+    io::copy_bidirectional,
+    net::{TcpListener, TcpStream},
+    sync::broadcast,
+    task::JoinHandle,
+};
+use tracing::{error, info, warn};
+
+/// TCP proxy in front of the server, the clients of the chaos benchmark connect through it,
+/// so that all their connections can be reset at once without touching the server.
+pub struct BenchmarkChaosProxy {
+    address: SocketAddr,
+    reset: broadcast::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl BenchmarkChaosProxy {
+    /// Starts the proxy on a random local port, forwarding the connections to the server address.
+    pub fn start(server_address: &str) -> io::Result<Self> {
+        let server_address: SocketAddr = server_address
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let listener = std::net::TcpListener::bind((server_address.ip(), 0))?;
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+        let address = listener.local_addr()?;
+        let (reset, _) = broadcast::channel(1);
+
+        info!("Started chaos proxy on {address}, forwarding to server: {server_address}");
+        let connection_reset = reset.clone();
+        let handle = tokio::spawn(async move {
+            loop {
+                let client = match listener.accept().await {
+                    Ok((client, _)) => client,
+                    Err(e) => {
+                        error!("Chaos proxy failed to accept connection: {e}");
+                        continue;
+                    }
+                };
+                let reset = connection_reset.subscribe();
+                tokio::spawn(forward(client, server_address, reset));
+            }
+        });
+
+        Ok(Self {
+            address,
+            reset,
+            handle,
+        })
+    }
+
+    pub const fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Resets (RST) all the connections currently open through the proxy,
+    /// returns the number of the connections.
+    pub fn reset_connections(&self) -> usize {
+        self.reset.send(()).unwrap_or_default()
+    }
+}
+
+impl Drop for BenchmarkChaosProxy {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn forward(
+    mut client: TcpStream,
+    server_address: SocketAddr,
+    mut reset: broadcast::Receiver<()>,
+) {
+    let mut server = match TcpStream::connect(server_address).await {
+        Ok(server) => server,
+        Err(e) => {
+            warn!("Chaos proxy failed to connect to server: {server_address}: {e}");
+            return;
+        }
+    };
+    let _ = client.set_nodelay(true);
+    let _ = server.set_nodelay(true);
+
+    tokio::select! {
+        _ = copy_bidirectional(&mut client, &mut server) => {}
+        _ = reset.recv() => {
+            // Zero linger makes closing the sockets send RST instead of FIN on both sides
+            let _ = client.set_linger(Some(Duration::ZERO));
+            let _ = server.set_linger(Some(Duration::ZERO));
+        }
+    }
+}
@@ -17,6 +17,10 @@
  */
 
 use crate::args::common::IggyBenchArgs;
+use crate::args::defaults::{
+    DEFAULT_HTTP_SERVER_ADDRESS, DEFAULT_QUIC_SERVER_ADDRESS, DEFAULT_TCP_SERVER_ADDRESS,
+};
+use crate::args::kinds::scenario::definition::ScenarioGroup;
 use crate::args::transport::BenchmarkTransportCommand;
 use integration::http_client::HttpClientFactory;
 use integration::quic_client::QuicClientFactory;
@@ -25,15 +29,22 @@ use integration::test_server::{ClientFactory, Transport};
 use std::sync::Arc;
 
 pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
+    create_client_factory_with_address(args, args.server_address().to_owned())
+}
+
+/// Creates the client factory of the benchmark connecting to the given address instead of
+/// the server address, e.g. to a proxy in front of the server.
+pub fn create_client_factory_with_address(
+    args: &IggyBenchArgs,
+    server_addr: String,
+) -> Arc<dyn ClientFactory> {
     match &args.transport() {
-        Transport::Http => Arc::new(HttpClientFactory {
-            server_addr: args.server_address().to_owned(),
-        }),
+        Transport::Http => Arc::new(HttpClientFactory { server_addr }),
         Transport::Tcp => {
             let transport_command = args.transport_command();
             if let BenchmarkTransportCommand::Tcp(tcp_args) = transport_command {
                 Arc::new(TcpClientFactory {
-                    server_addr: args.server_address().to_owned(),
+                    server_addr,
                     nodelay: args.nodelay(),
                     tls_enabled: tcp_args.tls,
                     tls_domain: tcp_args.tls_domain.clone(),
@@ -44,8 +55,54 @@ pub fn create_client_factory(args: &IggyBenchArgs) -> Arc<dyn ClientFactory> {
                 unreachable!("Transport is TCP but transport command is not TcpArgs")
             }
         }
-        Transport::Quic => Arc::new(QuicClientFactory {
-            server_addr: args.server_address().to_owned(),
-        }),
+        Transport::Quic => Arc::new(QuicClientFactory { server_addr }),
+    }
+}
+
+/// Creates the client factory of the scenario group, which can override the transport and
+/// the server address of the benchmark command. TLS settings are used only when the
+/// benchmark command uses TCP as well.
+pub fn create_group_client_factory(
+    args: &IggyBenchArgs,
+    group: &ScenarioGroup,
+) -> Arc<dyn ClientFactory> {
+    let transport = group
+        .transport
+        .map_or_else(|| *args.transport(), Transport::from);
+    if transport == *args.transport() && group.server_address.is_none() {
+        return create_client_factory(args);
+    }
+
+    let server_addr = match &group.server_address {
+        Some(server_address) => server_address.clone(),
+        None if transport == *args.transport() => args.server_address().to_owned(),
+        None => match transport {
+            Transport::Http => DEFAULT_HTTP_SERVER_ADDRESS.to_owned(),
+            Transport::Tcp => DEFAULT_TCP_SERVER_ADDRESS.to_owned(),
+            Transport::Quic => DEFAULT_QUIC_SERVER_ADDRESS.to_owned(),
+        },
+    };
+
+    match transport {
+        Transport::Http => Arc::new(HttpClientFactory { server_addr }),
+        Transport::Tcp => match args.transport_command() {
+            BenchmarkTransportCommand::Tcp(tcp_args) => Arc::new(TcpClientFactory {
+                server_addr,
+                nodelay: tcp_args.nodelay,
+                tls_enabled: tcp_args.tls,
+                tls_domain: tcp_args.tls_domain.clone(),
+                tls_ca_file: tcp_args.tls_ca_file.clone(),
+                tls_validate_certificate: tcp_args.tls_validate_certificate,
+            }),
+            _ => Arc::new(TcpClientFactory {
+                server_addr,
+                nodelay: false,
+                tls_enabled: false,
+                tls_domain: "localhost".to_owned(),
+                tls_ca_file: None,
+                tls_validate_certificate: false,
+            }),
+        },
+        Transport::Quic => Arc::new(QuicClientFactory { server_addr }),
     }
 }
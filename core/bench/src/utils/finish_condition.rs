@@ -23,6 +23,7 @@ use std::{
         Arc,
         atomic::{AtomicI64, Ordering},
     },
+    time::{Duration, Instant},
 };
 
 const MINIMUM_MSG_PAYLOAD_SIZE: usize = 20;
@@ -44,12 +45,16 @@ pub enum BenchmarkFinishConditionMode {
 
     /// Per-actor condition for producing consumers (both send/receive)
     PerProducingConsumer,
+
+    /// Condition shared by the actors of a scenario group, finishes after its phases
+    Timed,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum BenchmarkFinishConditionType {
     ByTotalData,
     ByMessageBatchesCount,
+    UntilDeadline,
 }
 
 pub struct BenchmarkFinishCondition {
@@ -57,6 +62,7 @@ pub struct BenchmarkFinishCondition {
     total: u64,
     left_total: Arc<AtomicI64>,
     mode: BenchmarkFinishConditionMode,
+    deadline: Option<Instant>,
 }
 
 impl BenchmarkFinishCondition {
@@ -81,17 +87,18 @@ impl BenchmarkFinishCondition {
             BenchmarkFinishConditionMode::PerProducer => args.producers(),
             BenchmarkFinishConditionMode::PerConsumer => args.consumers(),
             BenchmarkFinishConditionMode::PerProducingConsumer => args.producers() * 2,
+            BenchmarkFinishConditionMode::Timed => unreachable!("Use new_timed() instead"),
         };
 
         let total_data_multiplier = match args.benchmark_kind {
             BenchmarkKindCommand::PinnedProducer(_)
             | BenchmarkKindCommand::BalancedProducer(_)
+            | BenchmarkKindCommand::Chaos(_)
             | BenchmarkKindCommand::BalancedProducerAndConsumerGroup(_) => args.producers(),
             BenchmarkKindCommand::PinnedConsumer(_)
             | BenchmarkKindCommand::BalancedConsumerGroup(_) => args.consumers(),
-            BenchmarkKindCommand::PinnedProducerAndConsumer(_) => {
-                args.producers() + args.consumers()
-            }
+            BenchmarkKindCommand::PinnedProducerAndConsumer(_)
+            | BenchmarkKindCommand::Scenario(_) => args.producers() + args.consumers(),
             BenchmarkKindCommand::EndToEndProducingConsumer(_)
             | BenchmarkKindCommand::EndToEndProducingConsumerGroup(_) => args.producers() * 2,
             BenchmarkKindCommand::Examples => unreachable!(),
@@ -106,6 +113,7 @@ impl BenchmarkFinishCondition {
                     total: u64::from(count_per_actor),
                     left_total: Arc::new(AtomicI64::new(i64::from(count_per_actor))),
                     mode,
+                    deadline: None,
                 }
             }
             (Some(size), None) => {
@@ -118,6 +126,7 @@ impl BenchmarkFinishCondition {
                         i64::try_from(bytes_per_actor).unwrap_or(i64::MAX),
                     )),
                     mode,
+                    deadline: None,
                 }
             }
             _ => unreachable!(),
@@ -132,6 +141,20 @@ impl BenchmarkFinishCondition {
             total: 0,
             left_total: Arc::new(AtomicI64::new(0)),
             mode: BenchmarkFinishConditionMode::Shared,
+            deadline: None,
+        })
+    }
+
+    /// Creates a benchmark finish condition that is satisfied once the duration elapses,
+    /// regardless of the amount of processed data.
+    pub fn new_timed(duration: Duration) -> Arc<Self> {
+        let total = u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+        Arc::new(Self {
+            kind: BenchmarkFinishConditionType::UntilDeadline,
+            total,
+            left_total: Arc::new(AtomicI64::new(i64::try_from(total).unwrap_or(i64::MAX))),
+            mode: BenchmarkFinishConditionMode::Timed,
+            deadline: Some(Instant::now() + duration),
         })
     }
 
@@ -146,6 +169,7 @@ impl BenchmarkFinishCondition {
             BenchmarkFinishConditionType::ByMessageBatchesCount => {
                 self.left_total.fetch_sub(1, Ordering::AcqRel);
             }
+            BenchmarkFinishConditionType::UntilDeadline => return self.is_done(),
         }
         self.left_total.load(Ordering::Acquire) <= 0
     }
@@ -171,11 +195,26 @@ impl BenchmarkFinishCondition {
             BenchmarkFinishConditionType::ByMessageBatchesCount => {
                 format!("{} batches ({})", self.total.human_count_bare(), self.mode)
             }
+
+            BenchmarkFinishConditionType::UntilDeadline => {
+                format!("for {} s ({})", self.total / 1_000_000, self.mode)
+            }
         }
     }
 
+    /// Left data, batches or microseconds (timed condition) to process.
     pub fn left(&self) -> i64 {
-        self.left_total.load(Ordering::Relaxed)
+        self.deadline.map_or_else(
+            || self.left_total.load(Ordering::Relaxed),
+            |deadline| {
+                i64::try_from(
+                    deadline
+                        .saturating_duration_since(Instant::now())
+                        .as_micros(),
+                )
+                .unwrap_or(i64::MAX)
+            },
+        )
     }
 
     pub fn status(&self) -> String {
@@ -198,15 +237,28 @@ impl BenchmarkFinishCondition {
                     self.mode
                 )
             }
+            BenchmarkFinishConditionType::UntilDeadline => {
+                format!(
+                    "{}/{} s ({})",
+                    done / 1_000_000,
+                    total / 1_000_000,
+                    self.mode
+                )
+            }
         }
     }
 
     pub fn max_capacity(&self) -> usize {
         let value = self.left_total.load(Ordering::Relaxed);
-        if self.kind == BenchmarkFinishConditionType::ByTotalData {
-            usize::try_from(value).unwrap_or(0) / MINIMUM_MSG_PAYLOAD_SIZE
-        } else {
-            usize::try_from(value).unwrap_or(0)
+        match self.kind {
+            BenchmarkFinishConditionType::ByTotalData => {
+                usize::try_from(value).unwrap_or(0) / MINIMUM_MSG_PAYLOAD_SIZE
+            }
+            BenchmarkFinishConditionType::ByMessageBatchesCount => {
+                usize::try_from(value).unwrap_or(0)
+            }
+            // Number of batches sent within the duration is unknown upfront
+            BenchmarkFinishConditionType::UntilDeadline => 0,
         }
     }
 }
@@ -221,6 +273,7 @@ impl Display for BenchmarkFinishConditionMode {
             Self::PerProducingConsumer => {
                 write!(f, "per-producing-consumer")
             }
+            Self::Timed => write!(f, "timed"),
         }
     }
 }
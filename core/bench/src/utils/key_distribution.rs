@@ -0,0 +1,69 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::args::kinds::scenario::definition::ScenarioKeyDistribution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Generates message keys from `1` to `keys`, either uniformly or following the Zipf's law,
+/// where the key `k` is chosen with probability proportional to `1 / k^exponent`.
+pub struct BenchmarkKeyDistribution {
+    rng: StdRng,
+    kind: BenchmarkKeyDistributionKind,
+}
+
+enum BenchmarkKeyDistributionKind {
+    Uniform(u64),
+    Zipf(Vec<f64>),
+}
+
+impl BenchmarkKeyDistribution {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(distribution: ScenarioKeyDistribution, keys: u64, exponent: f64) -> Self {
+        let kind = match distribution {
+            ScenarioKeyDistribution::Uniform => BenchmarkKeyDistributionKind::Uniform(keys),
+            ScenarioKeyDistribution::Zipf => {
+                let mut total = 0.0;
+                let mut cumulative = Vec::with_capacity(usize::try_from(keys).unwrap_or(0));
+                for key in 1..=keys {
+                    total += 1.0 / (key as f64).powf(exponent);
+                    cumulative.push(total);
+                }
+                for weight in &mut cumulative {
+                    *weight /= total;
+                }
+                BenchmarkKeyDistributionKind::Zipf(cumulative)
+            }
+        };
+
+        Self {
+            rng: StdRng::from_os_rng(),
+            kind,
+        }
+    }
+
+    pub fn next_key(&mut self) -> u64 {
+        match &self.kind {
+            BenchmarkKeyDistributionKind::Uniform(keys) => self.rng.random_range(1..=*keys),
+            BenchmarkKeyDistributionKind::Zipf(cumulative) => {
+                let value = self.rng.random::<f64>();
+                let index = cumulative.partition_point(|weight| *weight < value);
+                index.min(cumulative.len() - 1) as u64 + 1
+            }
+        }
+    }
+}
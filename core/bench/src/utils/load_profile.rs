@@ -0,0 +1,75 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::args::kinds::scenario::definition::{ScenarioPhase, ScenarioPhaseKind};
+use std::time::Duration;
+use tokio::time::{Instant, sleep};
+
+/// Lowest rate multiplier at the very beginning of the ramp-up phase.
+const MIN_RAMP_UP_RATE_MULTIPLIER: f64 = 0.01;
+
+/// Shapes the send rate of the producers of a scenario group over its consecutive phases,
+/// measured from the moment the profile is created.
+pub struct BenchmarkLoadProfile {
+    start: Instant,
+    phases: Vec<ScenarioPhase>,
+}
+
+impl BenchmarkLoadProfile {
+    pub fn new(phases: &[ScenarioPhase]) -> Self {
+        Self {
+            start: Instant::now(),
+            phases: phases.to_vec(),
+        }
+    }
+
+    /// Returns the current phase and the time elapsed since its beginning, or `None` when
+    /// all the phases are over.
+    fn current_phase(&self) -> Option<(&ScenarioPhase, Duration)> {
+        let mut elapsed = self.start.elapsed();
+        for phase in &self.phases {
+            let duration = phase.duration.get_duration();
+            if elapsed < duration {
+                return Some((phase, elapsed));
+            }
+            elapsed -= duration;
+        }
+        None
+    }
+
+    /// Multiplier of the group rate in the current phase.
+    pub fn rate_multiplier(&self) -> f64 {
+        match self.current_phase() {
+            Some((phase, elapsed)) if phase.kind == ScenarioPhaseKind::RampUp => {
+                (elapsed.as_secs_f64() / phase.duration.get_duration().as_secs_f64())
+                    .max(MIN_RAMP_UP_RATE_MULTIPLIER)
+            }
+            Some((phase, _)) => phase.rate_multiplier(),
+            None => 1.0,
+        }
+    }
+
+    /// Waits until the end of the failover phases, returns immediately in other phases.
+    pub async fn wait_while_paused(&self) {
+        while let Some((phase, elapsed)) = self.current_phase() {
+            if phase.kind != ScenarioPhaseKind::Failover {
+                return;
+            }
+            sleep(phase.duration.get_duration().saturating_sub(elapsed)).await;
+        }
+    }
+}
@@ -36,12 +36,18 @@ use crate::args::{
         DEFAULT_PINNED_NUMBER_OF_STREAMS, DEFAULT_QUIC_SERVER_ADDRESS, DEFAULT_TCP_SERVER_ADDRESS,
         DEFAULT_TOTAL_MESSAGES_SIZE, DEFAULT_WARMUP_TIME,
     },
+    kinds::chaos::faults::ChaosArgs,
 };
 
 pub mod batch_generator;
+pub mod chaos_injector;
+pub mod chaos_proxy;
 pub mod client_factory;
 pub mod cpu_name;
 pub mod finish_condition;
+pub mod key_distribution;
+pub mod load_profile;
+pub mod open_loop_scheduler;
 pub mod rate_limiter;
 pub mod server_starter;
 
@@ -123,8 +129,15 @@ pub fn params_from_args_and_metrics(
     let extra_info = args.extra_info();
     let gitref = args.gitref();
     let gitref_date = args.gitref_date();
-    let messages_per_batch = args.messages_per_batch();
-    let message_size = args.message_size();
+    let (messages_per_batch, message_size) = args.scenario().map_or_else(
+        || (args.messages_per_batch(), args.message_size()),
+        |scenario| {
+            (
+                scenario.messages_per_batch_span(args.messages_per_batch()),
+                scenario.message_size_span(args.message_size()),
+            )
+        },
+    );
     let message_batches = message_batches_from_metrics(metrics);
     let producers = args.producers();
     let consumers = args.consumers();
@@ -132,6 +145,7 @@ pub fn params_from_args_and_metrics(
     let partitions = args.number_of_partitions();
     let consumer_groups = args.number_of_consumer_groups();
     let rate_limit = args.rate_limit().map(|limit| limit.to_string());
+    let open_loop = args.open_loop();
     let pretty_name = args.generate_pretty_name();
     let bench_command = recreate_bench_command(args);
 
@@ -156,7 +170,18 @@ pub fn params_from_args_and_metrics(
         consumer_groups.to_string(),
     ];
 
-    let params_identifier = params_identifier.join("_");
+    let mut params_identifier = params_identifier.join("_");
+    if open_loop {
+        params_identifier.push_str("_open_loop");
+    }
+    if let Some(scenario) = args.scenario() {
+        params_identifier.push('_');
+        params_identifier.push_str(&scenario.name.replace(' ', "_"));
+    }
+    if let Some(chaos) = args.chaos() {
+        params_identifier.push('_');
+        params_identifier.push_str(&chaos.faults_identifier());
+    }
 
     BenchmarkParams {
         benchmark_kind,
@@ -175,6 +200,7 @@ pub fn params_from_args_and_metrics(
         partitions,
         consumer_groups,
         rate_limit,
+        open_loop,
         pretty_name,
         bench_command,
         params_identifier,
@@ -240,6 +266,10 @@ fn add_basic_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
         parts.push(format!("--rate-limit \'{rate_limit}\'"));
     }
 
+    if args.open_loop() {
+        parts.push("--open-loop".to_string());
+    }
+
     if args.warmup_time().to_string() != DEFAULT_WARMUP_TIME {
         parts.push(format!("--warmup-time \'{}\'", args.warmup_time()));
     }
@@ -255,6 +285,8 @@ fn add_benchmark_kind_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
         BenchmarkKind::BalancedProducerAndConsumerGroup => "balanced-producer-and-consumer-group",
         BenchmarkKind::EndToEndProducingConsumer => "end-to-end-producing-consumer",
         BenchmarkKind::EndToEndProducingConsumerGroup => "end-to-end-producing-consumer-group",
+        BenchmarkKind::Scenario => "scenario",
+        BenchmarkKind::Chaos => "chaos",
     };
     parts.push(kind_str.to_string());
 
@@ -299,10 +331,69 @@ fn add_actor_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
                 parts.push(format!("--consumer-groups {number_of_consumer_groups}"));
             }
         }
+        BenchmarkKind::Scenario => {
+            if let Some(scenario) = args.scenario() {
+                parts.push(format!("--file \'{}\'", scenario.path));
+            }
+        }
+        BenchmarkKind::Chaos => {
+            if producers != DEFAULT_NUMBER_OF_PRODUCERS.get() {
+                parts.push(format!("--producers {producers}"));
+            }
+            if let Some(chaos) = args.chaos() {
+                add_chaos_arguments(parts, chaos);
+            }
+        }
+    }
+}
+
+fn add_chaos_arguments(parts: &mut Vec<String>, chaos: &ChaosArgs) {
+    if let Some(restart_every) = chaos.restart_every {
+        parts.push(format!("--restart-every {restart_every}"));
+        parts.push(format!("--restart-downtime {}", chaos.restart_downtime));
+    }
+    if let Some(reset_connections_every) = chaos.reset_connections_every {
+        parts.push(format!(
+            "--reset-connections-every {reset_connections_every}"
+        ));
+    }
+    if let Some(pause_every) = chaos.pause_every {
+        parts.push(format!("--pause-every {pause_every}"));
+        parts.push(format!("--pause-duration {}", chaos.pause_duration));
+    }
+    parts.push(format!("--first-fault-after {}", chaos.first_fault_after));
+    if let Some(max_topic_size) = chaos.max_topic_size {
+        parts.push(format!("--max-topic-size \'{max_topic_size}\'"));
     }
 }
 
 fn add_infrastructure_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
+    // Streams, partitions and consumer groups of a scenario are defined by its file,
+    // the chaos benchmark has one stream with a single partition per producer
+    if !matches!(
+        args.benchmark_kind.as_simple_kind(),
+        BenchmarkKind::Scenario | BenchmarkKind::Chaos
+    ) {
+        add_topology_arguments(parts, args);
+    }
+
+    let transport = args.transport().to_string().to_lowercase();
+    parts.push(transport.clone());
+
+    let server_address = args.server_address();
+    let default_address = match transport.as_str() {
+        "tcp" => DEFAULT_TCP_SERVER_ADDRESS,
+        "quic" => DEFAULT_QUIC_SERVER_ADDRESS,
+        "http" => DEFAULT_HTTP_SERVER_ADDRESS,
+        _ => "",
+    };
+
+    if server_address != default_address {
+        parts.push(format!("--server-address {server_address}"));
+    }
+}
+
+fn add_topology_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
     let streams = args.streams();
     let default_streams = match args.benchmark_kind.as_simple_kind() {
         BenchmarkKind::BalancedProducerAndConsumerGroup
@@ -336,21 +427,6 @@ fn add_infrastructure_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
     if let Some(max_topic_size) = args.max_topic_size() {
         parts.push(format!("--max-topic-size \'{max_topic_size}\'"));
     }
-
-    let transport = args.transport().to_string().to_lowercase();
-    parts.push(transport.clone());
-
-    let server_address = args.server_address();
-    let default_address = match transport.as_str() {
-        "tcp" => DEFAULT_TCP_SERVER_ADDRESS,
-        "quic" => DEFAULT_QUIC_SERVER_ADDRESS,
-        "http" => DEFAULT_HTTP_SERVER_ADDRESS,
-        _ => "",
-    };
-
-    if server_address != default_address {
-        parts.push(format!("--server-address {server_address}"));
-    }
 }
 
 fn add_output_arguments(parts: &mut Vec<String>, args: &IggyBenchArgs) {
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use hdrhistogram::Histogram;
+use iggy::prelude::IggyByteSize;
+use std::time::Duration;
+use tokio::time::{Instant, sleep_until};
+
+/// Highest latency trackable by the histogram, higher values are clamped.
+const MAX_TRACKABLE_LATENCY_US: u64 = 3_600_000_000;
+const SIGNIFICANT_DIGITS: u8 = 3;
+
+/// Schedules requests of the open-loop benchmark at fixed intervals derived from the rate limit,
+/// independently of how long the previous requests took.
+///
+/// Requests are not sent concurrently, the actor awaits each of them before the next one.
+/// Latency is measured from the intended send time instead, so when the server stalls, the requests
+/// that should have been sent in the meantime are sent late and accounted with the time they spent
+/// waiting (coordinated omission correction). Latencies are recorded into the HDR histogram.
+pub struct BenchmarkOpenLoopScheduler {
+    bytes_per_second: u64,
+    next_send_time: Instant,
+    histogram: Histogram<u64>,
+}
+
+impl BenchmarkOpenLoopScheduler {
+    pub fn new(bytes_per_second: IggyByteSize) -> Self {
+        Self {
+            bytes_per_second: bytes_per_second.as_bytes_u64().max(1),
+            next_send_time: Instant::now(),
+            histogram: Histogram::new_with_bounds(1, MAX_TRACKABLE_LATENCY_US, SIGNIFICANT_DIGITS)
+                .expect("Invalid histogram bounds"),
+        }
+    }
+
+    /// Waits until the next scheduled send time and returns it. If the actor is behind
+    /// the schedule, returns immediately with the (past) intended send time.
+    pub async fn wait_for_next_send(&self) -> Instant {
+        sleep_until(self.next_send_time).await;
+        self.next_send_time
+    }
+
+    /// Moves the schedule forward by the time needed to transfer `bytes` at the configured rate.
+    pub fn schedule_next(&mut self, bytes: u64) {
+        let interval_ns = u128::from(bytes) * 1_000_000_000 / u128::from(self.bytes_per_second);
+        self.next_send_time += Duration::from_nanos(u64::try_from(interval_ns).unwrap_or(u64::MAX));
+    }
+
+    /// Records the latency corrected for coordinated omission.
+    pub fn record_latency(&mut self, latency: Duration) {
+        let latency_us = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        self.histogram
+            .saturating_record(latency_us.clamp(1, MAX_TRACKABLE_LATENCY_US));
+    }
+
+    pub const fn histogram(&self) -> &Histogram<u64> {
+        &self.histogram
+    }
+}
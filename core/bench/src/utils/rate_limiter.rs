@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::utils::load_profile::BenchmarkLoadProfile;
 use governor::{
     Quota, RateLimiter as GovernorRateLimiter,
     clock::DefaultClock,
@@ -22,9 +23,12 @@ use governor::{
 };
 use iggy::prelude::IggyByteSize;
 use std::num::NonZeroU32;
+use std::sync::Arc;
 
 pub struct BenchmarkRateLimiter {
     rate_limiter: GovernorRateLimiter<NotKeyed, InMemoryState, DefaultClock>,
+    bytes_per_second: NonZeroU32,
+    load_profile: Option<Arc<BenchmarkLoadProfile>>,
 }
 
 impl BenchmarkRateLimiter {
@@ -37,13 +41,47 @@ impl BenchmarkRateLimiter {
         // Fill the bucket to avoid burst
         let _ = rate_limiter.check_n(bytes_per_second);
 
-        Self { rate_limiter }
+        Self {
+            rate_limiter,
+            bytes_per_second,
+            load_profile: None,
+        }
     }
 
+    /// Scales the rate by the multiplier of the current phase of the load profile.
+    pub fn with_load_profile(mut self, load_profile: Arc<BenchmarkLoadProfile>) -> Self {
+        self.load_profile = Some(load_profile);
+        self
+    }
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
     pub async fn wait_until_necessary(&self, bytes: u64) {
-        self.rate_limiter
-            .until_n_ready(NonZeroU32::new(u32::try_from(bytes).unwrap_or(u32::MAX)).unwrap())
-            .await
-            .unwrap();
+        // Bucket can't hold more than one second of the rate, so wait in chunks and pick up
+        // the multiplier of the load profile again for every chunk
+        let capacity = f64::from(self.bytes_per_second.get());
+        let mut bytes = bytes as f64;
+        loop {
+            let multiplier = self.rate_multiplier();
+            // Saturates to 0 once all the bytes are waited for
+            let chunk = (bytes / multiplier).ceil().min(capacity) as u32;
+            let Some(chunk) = NonZeroU32::new(chunk) else {
+                break;
+            };
+            self.rate_limiter.until_n_ready(chunk).await.unwrap();
+            bytes -= f64::from(chunk.get()) * multiplier;
+        }
+    }
+
+    /// Paused phases (multiplier 0) are awaited by the actors, so they don't slow down the rate.
+    fn rate_multiplier(&self) -> f64 {
+        self.load_profile
+            .as_ref()
+            .map(|load_profile| load_profile.rate_multiplier())
+            .filter(|multiplier| *multiplier > 0.0)
+            .unwrap_or(1.0)
     }
 }
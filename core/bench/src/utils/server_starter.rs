@@ -20,7 +20,7 @@ use crate::args::common::IggyBenchArgs;
 use integration::test_server::{IpAddrKind, SYSTEM_PATH_ENV_VAR, TestServer, Transport};
 use serde::Deserialize;
 use std::net::SocketAddr;
-use std::{collections::HashMap, time::Instant};
+use std::{collections::HashMap, thread, time::Duration, time::Instant};
 use tokio::net::{TcpStream, UdpSocket};
 use tracing::{info, warn};
 
@@ -91,6 +91,27 @@ pub async fn start_server_if_needed(args: &IggyBenchArgs) -> Option<TestServer>
     }
 }
 
+/// Kills the local server with SIGKILL, keeping its data, and starts it again after the downtime.
+/// Blocks until the server is bound again, returns how long it was unavailable.
+pub fn restart_server(test_server: &mut TestServer, downtime: Duration) -> Duration {
+    let now = Instant::now();
+    let pid = test_server.pid();
+    test_server.kill();
+    info!(
+        "Killed test iggy-server, pid: {pid}, restarting in {} ms",
+        downtime.as_millis()
+    );
+    thread::sleep(downtime);
+    test_server.restart();
+    let elapsed = now.elapsed();
+    info!(
+        "Test iggy-server restarted, pid: {}, unavailable for {} ms",
+        test_server.pid(),
+        elapsed.as_millis()
+    );
+    elapsed
+}
+
 async fn evaluate_server_start_condition(args: &IggyBenchArgs) -> (bool, HashMap<String, String>) {
     let default_config: ServerConfig =
         toml::from_str(include_str!("../../../configs/server.toml")).unwrap();
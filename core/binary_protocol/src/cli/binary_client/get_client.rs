@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::{Table, presets::ASCII_NO_BORDERS};
@@ -42,7 +42,11 @@ impl CliCommand for GetClientCmd {
         format!("get client with ID: {}", self.get_client.client_id)
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let client_details = client
             .get_client(self.get_client.client_id)
             .await
@@ -53,6 +57,10 @@ impl CliCommand for GetClientCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&client_details);
+        }
+
         if client_details.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Client with ID: {} was not found", self.get_client.client_id);
             return Ok(());
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::Identifier;
@@ -65,8 +65,12 @@ impl CliCommand for CreateConsumerGroupCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let consumer_group = client
             .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name, self.create_consumer_group.group_id)
             .await
             .with_context(|| {
@@ -76,6 +80,10 @@ impl CliCommand for CreateConsumerGroupCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&consumer_group);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Consumer group: {}, name: {} created for topic with ID: {} and stream with ID: {}",
             self.get_group_id_info(),
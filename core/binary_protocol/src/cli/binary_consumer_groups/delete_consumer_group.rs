@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::delete_consumer_group::DeleteConsumerGroup;
+use iggy_common::{DELETE_CONSUMER_GROUP, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct DeleteConsumerGroupCmd {
@@ -51,7 +52,11 @@ impl CliCommand for DeleteConsumerGroupCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .delete_consumer_group(&self.delete_consumer_group.stream_id, &self.delete_consumer_group.topic_id, &self.delete_consumer_group.group_id)
             .await
@@ -62,6 +67,17 @@ impl CliCommand for DeleteConsumerGroupCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                DELETE_CONSUMER_GROUP,
+                json!({
+                    "stream_id": self.delete_consumer_group.stream_id.to_string(),
+                    "topic_id": self.delete_consumer_group.topic_id.to_string(),
+                    "group_id": self.delete_consumer_group.group_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Consumer group with ID: {} deleted for topic with ID: {} and stream with ID: {}",
             self.delete_consumer_group.group_id,
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::{Table, presets::ASCII_NO_BORDERS};
@@ -52,7 +52,11 @@ impl CliCommand for GetConsumerGroupCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let consumer_group = client
             .get_consumer_group(&self.get_consumer_group.stream_id, &self.get_consumer_group.topic_id, &self.get_consumer_group.group_id)
             .await
@@ -63,6 +67,10 @@ impl CliCommand for GetConsumerGroupCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&consumer_group);
+        }
+
         if consumer_group.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Consumer group with ID: {} was not found", self.get_consumer_group.group_id);
             return Ok(());
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -72,7 +72,11 @@ impl CliCommand for GetConsumerGroupsCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let consumer_groups = client
             .get_consumer_groups(
                 &self.get_consumer_groups.stream_id,
@@ -86,6 +90,10 @@ impl CliCommand for GetConsumerGroupsCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&consumer_groups);
+        }
+
         match self.output {
             GetConsumerGroupsOutput::Table => {
                 let mut table = Table::new();
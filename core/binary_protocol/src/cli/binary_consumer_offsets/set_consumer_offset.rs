@@ -17,12 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
-use iggy_common::{Consumer, ConsumerKind};
+use iggy_common::{Consumer, ConsumerKind, Identifier, STORE_CONSUMER_OFFSET};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct SetConsumerOffsetCmd {
@@ -65,7 +65,11 @@ impl CliCommand for SetConsumerOffsetCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .store_consumer_offset(&self.set_consumer_offset.consumer, &self.set_consumer_offset.stream_id, &self.set_consumer_offset.topic_id, self.set_consumer_offset.partition_id, self.set_consumer_offset.offset)
             .await
@@ -76,6 +80,19 @@ impl CliCommand for SetConsumerOffsetCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                STORE_CONSUMER_OFFSET,
+                json!({
+                    "consumer_id": self.set_consumer_offset.consumer.id.to_string(),
+                    "stream_id": self.set_consumer_offset.stream_id.to_string(),
+                    "topic_id": self.set_consumer_offset.topic_id.to_string(),
+                    "partition_id": self.set_consumer_offset.partition_id,
+                    "offset": self.set_consumer_offset.offset,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Consumer offset for consumer with ID: {} for stream with ID: {} and topic with ID: {} and partition with ID: {} set to {}",
             self.set_consumer_offset.consumer.id,
@@ -18,13 +18,20 @@
 
 use async_trait::async_trait;
 use comfy_table::Table;
+use serde::Serialize;
 use tracing::{Level, event};
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 
 use super::common::ContextManager;
 
+#[derive(Debug, Serialize)]
+struct ContextInfo<'a> {
+    name: &'a str,
+    active: bool,
+}
+
 pub enum GetContextsOutput {
     Table,
     List,
@@ -74,11 +81,27 @@ impl CliCommand for GetContextsCmd {
         false
     }
 
-    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        _client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let mut context_mgr = ContextManager::default();
         let contexts_map = context_mgr.get_contexts().await?;
         let active_context_key = context_mgr.get_active_context_key().await?;
 
+        if format.is_structured() {
+            let mut contexts = contexts_map
+                .keys()
+                .map(|name| ContextInfo {
+                    name,
+                    active: name.eq(&active_context_key),
+                })
+                .collect::<Vec<_>>();
+            contexts.sort_by_key(|context| context.name);
+            return format.print(&contexts);
+        }
+
         match self.output {
             GetContextsOutput::Table => {
                 let mut table = Table::new();
@@ -17,10 +17,11 @@
  */
 
 use async_trait::async_trait;
+use serde_json::json;
 use tracing::{Level, event};
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 
 use super::common::{ContextManager, DEFAULT_CONTEXT_NAME};
 
@@ -57,13 +58,26 @@ impl CliCommand for UseContextCmd {
         false
     }
 
-    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        _client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let mut context_mgr = ContextManager::default();
 
         context_mgr
             .set_active_context_key(&self.context_name)
             .await?;
 
+        if format.is_structured() {
+            return format.print_status(
+                "context.use",
+                json!({
+                    "context_name": self.context_name,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "active context set to '{}'", self.context_name);
 
         return Ok(());
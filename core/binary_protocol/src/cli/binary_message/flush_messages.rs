@@ -17,10 +17,11 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::{Context, Error};
 use async_trait::async_trait;
-use iggy_common::Identifier;
+use iggy_common::{FLUSH_UNSAVED_BUFFER, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct FlushMessagesCmd {
@@ -62,7 +63,11 @@ impl CliCommand for FlushMessagesCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), Error> {
         client
             .flush_unsaved_buffer(
                 &self.stream_id,
@@ -78,6 +83,18 @@ impl CliCommand for FlushMessagesCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                FLUSH_UNSAVED_BUFFER,
+                json!({
+                    "stream_id": self.stream_id.to_string(),
+                    "topic_id": self.topic_id.to_string(),
+                    "partition_id": self.partition_id,
+                    "fsync": self.fsync,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Flushed messages from topic with ID: {} and stream with ID: {} (partition with ID: {}) {}",
             self.topic_id,
@@ -19,3 +19,4 @@
 pub mod flush_messages;
 pub mod poll_messages;
 pub mod send_messages;
+pub mod tail_messages;
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::{Cell, CellAlignment, Row, Table};
@@ -169,7 +169,11 @@ impl CliCommand for PollMessagesCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let start = std::time::Instant::now();
         let polled_messages = client
             .poll_messages(
@@ -232,6 +236,8 @@ impl CliCommand for PollMessagesCmd {
 
             let saved_size_str = saved_size.as_human_string();
             event!(target: PRINT_TARGET, Level::INFO, "Stored {message_count_message} of total size {saved_size_str} to {output_file} binary file");
+        } else if format.is_structured() {
+            format.print(&polled_messages)?;
         } else {
             let message_header_keys = self.create_message_header_keys(&polled_messages.messages);
 
@@ -17,13 +17,15 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use bytes::Bytes;
 use iggy_common::{
-    BytesSerializable, HeaderKey, HeaderValue, Identifier, IggyMessage, Partitioning, Sizeable,
+    BytesSerializable, HeaderKey, HeaderValue, Identifier, IggyMessage, Partitioning,
+    SEND_MESSAGES, Sizeable,
 };
+use serde_json::json;
 use std::collections::HashMap;
 use std::io::{self, Read};
 use tokio::io::AsyncReadExt;
@@ -92,7 +94,11 @@ impl CliCommand for SendMessagesCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let mut messages = if let Some(input_file) = &self.input_file {
             let mut file = tokio::fs::OpenOptions::new()
                 .read(true)
@@ -179,6 +185,17 @@ impl CliCommand for SendMessagesCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                SEND_MESSAGES,
+                json!({
+                    "stream_id": self.stream_id.to_string(),
+                    "topic_id": self.topic_id.to_string(),
+                    "messages_count": messages.len(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Sent messages to topic with ID: {} and stream with ID: {}",
             self.topic_id,
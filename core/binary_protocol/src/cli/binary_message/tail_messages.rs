@@ -0,0 +1,379 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::{
+    Consumer, HeaderKey, Identifier, IggyDuration, IggyMessage, IggyTimestamp, PollingStrategy,
+};
+use serde::Serialize;
+use std::time::Instant;
+use tracing::{Level, event};
+
+/// Format in which the payloads of the tailed messages are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TailMessagesFormat {
+    #[default]
+    Text,
+    Json,
+    Hex,
+}
+
+pub struct TailMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    consumer_group: Option<Identifier>,
+    consumer: Identifier,
+    strategy: Option<PollingStrategy>,
+    format: TailMessagesFormat,
+    show_headers: bool,
+    filters: Vec<(HeaderKey, String)>,
+    message_count: Option<u64>,
+    duration: Option<IggyDuration>,
+    commit: bool,
+    batch_size: u32,
+    poll_interval: IggyDuration,
+}
+
+/// Tailed message printed as a separate document for the JSON and YAML output.
+#[derive(Debug, Serialize)]
+struct TailedMessage<'a> {
+    partition_id: u32,
+    message: &'a IggyMessage,
+}
+
+/// Position of the tail in the single partition, or in the partitions assigned to the consumer group member.
+struct TailCursor {
+    partition_id: Option<u32>,
+    strategy: PollingStrategy,
+}
+
+impl TailMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+        consumer_group: Option<Identifier>,
+        consumer: Identifier,
+        offset: Option<u64>,
+        timestamp: Option<u64>,
+        first: bool,
+        next: bool,
+        format: TailMessagesFormat,
+        show_headers: bool,
+        filters: Vec<(HeaderKey, String)>,
+        message_count: Option<u64>,
+        duration: Option<IggyDuration>,
+        commit: bool,
+        batch_size: u32,
+        poll_interval: IggyDuration,
+    ) -> Self {
+        let strategy = match (offset, timestamp, first, next) {
+            (Some(offset), None, false, false) => Some(PollingStrategy::offset(offset)),
+            (None, Some(timestamp), false, false) => {
+                Some(PollingStrategy::timestamp(IggyTimestamp::from(timestamp)))
+            }
+            (None, None, true, false) => Some(PollingStrategy::first()),
+            (None, None, false, true) => Some(PollingStrategy::next()),
+            _ => None,
+        };
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer_group,
+            consumer,
+            strategy,
+            format,
+            show_headers,
+            filters,
+            message_count,
+            duration,
+            commit,
+            batch_size,
+            poll_interval,
+        }
+    }
+
+    /// Creates the cursor for each followed partition, the last message strategy (default) is resolved
+    /// to the offset following the current one, so that only the messages appended afterward are printed.
+    async fn create_cursors(&self, client: &dyn Client) -> anyhow::Result<Vec<TailCursor>> {
+        if self.consumer_group.is_some() {
+            if self.strategy.is_some() {
+                anyhow::bail!(
+                    "Start options (offset, timestamp, first, next) cannot be used with consumer group, \
+                     the group member continues from the offsets stored for the consumer group"
+                );
+            }
+            return Ok(vec![TailCursor {
+                partition_id: None,
+                strategy: PollingStrategy::next(),
+            }]);
+        }
+
+        let topic = client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Topic with ID: {} in stream with ID: {} was not found",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        let cursors = topic
+            .partitions
+            .iter()
+            .filter(|partition| self.partition_id.is_none_or(|id| id == partition.id))
+            .map(|partition| TailCursor {
+                partition_id: Some(partition.id),
+                strategy: match self.strategy {
+                    Some(strategy) => strategy,
+                    None if partition.messages_count > 0 => {
+                        PollingStrategy::offset(partition.current_offset + 1)
+                    }
+                    None => PollingStrategy::offset(0),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        if cursors.is_empty() {
+            anyhow::bail!(
+                "Partition with ID: {} was not found in topic with ID: {} and stream with ID: {}",
+                self.partition_id.unwrap_or_default(),
+                self.topic_id,
+                self.stream_id
+            );
+        }
+
+        Ok(cursors)
+    }
+
+    fn get_consumer(&self) -> Consumer {
+        match &self.consumer_group {
+            Some(group_id) => Consumer::group(group_id.clone()),
+            None => Consumer::new(self.consumer.clone()),
+        }
+    }
+
+    fn matches_filters(&self, message: &IggyMessage) -> bool {
+        if self.filters.is_empty() {
+            return true;
+        }
+
+        let Ok(Some(headers)) = message.user_headers_map() else {
+            return false;
+        };
+
+        self.filters.iter().all(|(key, value)| {
+            headers
+                .get(key)
+                .is_some_and(|header| header.value_only_to_string() == *value)
+        })
+    }
+
+    fn format_message(&self, partition_id: u32, message: &IggyMessage) -> String {
+        let mut line = format!(
+            "{partition_id}:{} {}",
+            message.header.offset,
+            IggyTimestamp::from(message.header.timestamp).to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
+        );
+
+        if self.show_headers {
+            let mut headers = message
+                .user_headers_map()
+                .ok()
+                .flatten()
+                .unwrap_or_default()
+                .iter()
+                .map(|(key, value)| format!("{key}={}", value.value_only_to_string()))
+                .collect::<Vec<_>>();
+            headers.sort();
+            line.push_str(&format!(" [{}]", headers.join(", ")));
+        }
+
+        let payload = match self.format {
+            TailMessagesFormat::Text => String::from_utf8_lossy(&message.payload).to_string(),
+            TailMessagesFormat::Json => {
+                match serde_json::from_slice::<serde_json::Value>(&message.payload) {
+                    Ok(value) => format!(
+                        "\n{}",
+                        serde_json::to_string_pretty(&value).unwrap_or_default()
+                    ),
+                    Err(_) => String::from_utf8_lossy(&message.payload).to_string(),
+                }
+            }
+            TailMessagesFormat::Hex => message
+                .payload
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        line.push(' ');
+        line.push_str(&payload);
+        line
+    }
+
+    fn is_finished(&self, printed: u64, started_at: Instant) -> bool {
+        self.message_count.is_some_and(|count| printed >= count)
+            || self
+                .duration
+                .is_some_and(|duration| started_at.elapsed() >= duration.get_duration())
+    }
+}
+
+#[async_trait]
+impl CliCommand for TailMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "tail messages from topic ID: {} and stream with ID: {}",
+            self.topic_id, self.stream_id
+        )
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let started_at = Instant::now();
+        let consumer = self.get_consumer();
+        if let Some(group_id) = &self.consumer_group {
+            client
+                .join_consumer_group(&self.stream_id, &self.topic_id, group_id)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem joining consumer group with ID: {group_id} for topic with ID: {} and stream with ID: {}",
+                        self.topic_id, self.stream_id
+                    )
+                })?;
+            event!(target: PRINT_TARGET, Level::INFO, "Joined consumer group with ID: {group_id}");
+        }
+
+        let mut cursors = self.create_cursors(client).await?;
+        let mut printed = 0u64;
+        'tail: while !self.is_finished(printed, started_at) {
+            let mut polled_any = false;
+            for cursor in cursors.iter_mut() {
+                let polled_messages = client
+                    .poll_messages(
+                        &self.stream_id,
+                        &self.topic_id,
+                        cursor.partition_id,
+                        &consumer,
+                        &cursor.strategy,
+                        self.batch_size,
+                        false,
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem polling messages from topic with ID: {} and stream with ID: {}",
+                            self.topic_id, self.stream_id
+                        )
+                    })?;
+
+                let mut processed_offset = None;
+                for message in &polled_messages.messages {
+                    if self.is_finished(printed, started_at) {
+                        break;
+                    }
+
+                    processed_offset = Some(message.header.offset);
+                    if !self.matches_filters(message) {
+                        continue;
+                    }
+
+                    if format.is_structured() {
+                        format.print(&TailedMessage {
+                            partition_id: polled_messages.partition_id,
+                            message,
+                        })?;
+                    } else {
+                        let line = self.format_message(polled_messages.partition_id, message);
+                        event!(target: PRINT_TARGET, Level::INFO, "{line}");
+                    }
+                    printed += 1;
+                }
+
+                let Some(offset) = processed_offset else {
+                    continue;
+                };
+
+                polled_any = true;
+                if self.consumer_group.is_none() {
+                    cursor.strategy = PollingStrategy::offset(offset + 1);
+                }
+
+                // The consumer group member always commits the offset, otherwise it would keep receiving the same messages.
+                if self.commit || self.consumer_group.is_some() {
+                    client
+                        .store_consumer_offset(
+                            &consumer,
+                            &self.stream_id,
+                            &self.topic_id,
+                            Some(polled_messages.partition_id),
+                            offset,
+                        )
+                        .await
+                        .with_context(|| {
+                            format!(
+                                "Problem storing offset: {offset} for partition with ID: {}",
+                                polled_messages.partition_id
+                            )
+                        })?;
+                }
+
+                if self.is_finished(printed, started_at) {
+                    break 'tail;
+                }
+            }
+
+            if !polled_any {
+                tokio::time::sleep(self.poll_interval.get_duration()).await;
+            }
+        }
+
+        if let Some(group_id) = &self.consumer_group {
+            client
+                .leave_consumer_group(&self.stream_id, &self.topic_id, group_id)
+                .await
+                .with_context(|| format!("Problem leaving consumer group with ID: {group_id}"))?;
+        }
+
+        let message_count_message = match printed {
+            1 => "1 message".into(),
+            count => format!("{count} messages"),
+        };
+        event!(target: PRINT_TARGET, Level::INFO, "Tailed {message_count_message} from topic with ID: {} and stream with ID: {}", self.topic_id, self.stream_id);
+
+        Ok(())
+    }
+}
@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::create_partitions::CreatePartitions;
+use iggy_common::{CREATE_PARTITIONS, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct CreatePartitionsCmd {
@@ -56,7 +57,11 @@ impl CliCommand for CreatePartitionsCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let mut partitions = String::from("partition");
         if self.create_partition.partitions_count > 1 {
             partitions.push('s');
@@ -78,6 +83,17 @@ impl CliCommand for CreatePartitionsCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                CREATE_PARTITIONS,
+                json!({
+                    "stream_id": self.create_partition.stream_id.to_string(),
+                    "topic_id": self.create_partition.topic_id.to_string(),
+                    "partitions_count": self.create_partition.partitions_count,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Created {} {partitions} for topic with ID: {} and stream with ID: {}",
             self.create_partition.partitions_count,
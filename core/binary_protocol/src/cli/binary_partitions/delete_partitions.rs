@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::delete_partitions::DeletePartitions;
+use iggy_common::{DELETE_PARTITIONS, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct DeletePartitionsCmd {
@@ -56,7 +57,11 @@ impl CliCommand for DeletePartitionsCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let mut partitions = String::from("partition");
         if self.delete_partitions.partitions_count > 1 {
             partitions.push('s');
@@ -78,6 +83,17 @@ impl CliCommand for DeletePartitionsCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                DELETE_PARTITIONS,
+                json!({
+                    "stream_id": self.delete_partitions.stream_id.to_string(),
+                    "topic_id": self.delete_partitions.topic_id.to_string(),
+                    "partitions_count": self.delete_partitions.partitions_count,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Deleted {} {partitions} for topic with ID: {} and stream with ID: {}",
             self.delete_partitions.partitions_count,
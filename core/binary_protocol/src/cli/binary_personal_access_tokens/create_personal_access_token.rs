@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::PersonalAccessTokenExpiry;
@@ -70,7 +70,11 @@ impl CliCommand for CreatePersonalAccessTokenCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let token = client
             .create_personal_access_token(&self.create_token.name, self.create_token.expiry)
             .await
@@ -81,6 +85,11 @@ impl CliCommand for CreatePersonalAccessTokenCmd {
                 )
             })?;
 
+        // Stored token is not printed, the same as for the table output
+        if format.is_structured() && !self.store_token {
+            return format.print(&token);
+        }
+
         if self.store_token {
             let server_address = format!("iggy:{}", self.server_address);
             let entry = Entry::new(&server_address, &self.create_token.name)?;
@@ -17,11 +17,13 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
+use iggy_common::DELETE_PERSONAL_ACCESS_TOKEN;
 use iggy_common::delete_personal_access_token::DeletePersonalAccessToken;
 use keyring::Entry;
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct DeletePersonalAccessTokenCmd {
@@ -47,7 +49,11 @@ impl CliCommand for DeletePersonalAccessTokenCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .delete_personal_access_token(&self.delete_token.name)
             .await
@@ -66,6 +72,15 @@ impl CliCommand for DeletePersonalAccessTokenCmd {
             event!(target: PRINT_TARGET, Level::DEBUG, "{}", e);
         };
 
+        if format.is_structured() {
+            return format.print_status(
+                DELETE_PERSONAL_ACCESS_TOKEN,
+                json!({
+                    "name": self.delete_token.name,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Personal access token with name: {} deleted", self.delete_token.name
         );
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -53,12 +53,20 @@ impl CliCommand for GetPersonalAccessTokensCmd {
         format!("list personal access tokens in {mode} mode")
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let tokens = client
             .get_personal_access_tokens()
             .await
             .with_context(|| String::from("Problem getting list of personal access tokens"))?;
 
+        if format.is_structured() {
+            return format.print(&tokens);
+        }
+
         match self.output {
             GetPersonalAccessTokensOutput::Table => {
                 let mut table = Table::new();
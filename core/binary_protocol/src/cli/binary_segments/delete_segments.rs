@@ -16,11 +16,12 @@
  * under the License.
  */
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::delete_segments::DeleteSegments;
+use iggy_common::{DELETE_SEGMENTS, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct DeleteSegmentsCmd {
@@ -62,7 +63,11 @@ impl CliCommand for DeleteSegmentsCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let mut segments = String::from("segment");
         if self.delete_segments.segments_count > 1 {
             segments.push('s');
@@ -86,6 +91,18 @@ impl CliCommand for DeleteSegmentsCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                DELETE_SEGMENTS,
+                json!({
+                    "stream_id": self.delete_segments.stream_id.to_string(),
+                    "topic_id": self.delete_segments.topic_id.to_string(),
+                    "partition_id": self.delete_segments.partition_id,
+                    "segments_count": self.delete_segments.segments_count,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Deleted {} {segments} for topic with ID: {}, stream with ID: {} and partition with ID: {}",
             self.delete_segments.segments_count,
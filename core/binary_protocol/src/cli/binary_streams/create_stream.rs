@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::create_stream::CreateStream;
@@ -52,8 +52,12 @@ impl CliCommand for CreateStreamCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let stream = client
             .create_stream(&self.create_stream.name, self.create_stream.stream_id)
             .await
             .with_context(|| {
@@ -64,6 +68,10 @@ impl CliCommand for CreateStreamCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&stream);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Stream with name: {} and {} created",
             self.create_stream.name,
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -43,7 +43,11 @@ impl CliCommand for GetStreamCmd {
         format!("get stream with ID: {}", self.get_stream.stream_id)
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let stream = client
             .get_stream(&self.get_stream.stream_id)
             .await
@@ -54,6 +58,10 @@ impl CliCommand for GetStreamCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&stream);
+        }
+
         if stream.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} was not found", self.get_stream.stream_id);
             return Ok(());
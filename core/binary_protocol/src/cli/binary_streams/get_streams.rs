@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -62,12 +62,20 @@ impl CliCommand for GetStreamsCmd {
         format!("list streams in {mode} mode")
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let streams = client
             .get_streams()
             .await
             .with_context(|| String::from("Problem getting list of streams"))?;
 
+        if format.is_structured() {
+            return format.print(&streams);
+        }
+
         if streams.is_empty() {
             event!(target: PRINT_TARGET, Level::INFO, "No streams found!");
             return Ok(());
@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::purge_stream::PurgeStream;
+use iggy_common::{Identifier, PURGE_STREAM};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct PurgeStreamCmd {
@@ -42,7 +43,11 @@ impl CliCommand for PurgeStreamCmd {
         format!("purge stream with ID: {}", self.purge_stream.stream_id)
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .purge_stream(&self.purge_stream.stream_id)
             .await
@@ -53,6 +58,15 @@ impl CliCommand for PurgeStreamCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                PURGE_STREAM,
+                json!({
+                    "stream_id": self.purge_stream.stream_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} purged", self.purge_stream.stream_id);
 
         Ok(())
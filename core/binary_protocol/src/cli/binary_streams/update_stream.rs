@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::update_stream::UpdateStream;
+use iggy_common::{Identifier, UPDATE_STREAM};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct UpdateStreamCmd {
@@ -45,7 +46,11 @@ impl CliCommand for UpdateStreamCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .update_stream(&self.update_stream.stream_id, &self.update_stream.name)
             .await
@@ -56,6 +61,16 @@ impl CliCommand for UpdateStreamCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                UPDATE_STREAM,
+                json!({
+                    "stream_id": self.update_stream.stream_id.to_string(),
+                    "name": self.update_stream.name,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Stream with ID: {} updated name: {}",
             self.update_stream.stream_id, self.update_stream.name
@@ -18,11 +18,12 @@
 
 use crate::Client;
 use crate::cli::binary_system::session::ServerSession;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use crate::cli::utils::login_session_expiry::LoginSessionExpiry;
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::SEC_IN_MICRO;
+use iggy_common::{LOGIN_USER, SEC_IN_MICRO};
+use serde_json::json;
 use tracing::{Level, event};
 
 const DEFAULT_LOGIN_SESSION_TIMEOUT: u64 = SEC_IN_MICRO * 15 * 60;
@@ -47,8 +48,22 @@ impl CliCommand for LoginCmd {
         "login command".to_owned()
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         if self.server_session.is_active() {
+            if format.is_structured() {
+                return format.print_status(
+                    LOGIN_USER,
+                    json!({
+                        "server_address": self.server_session.get_server_address(),
+                        "already_logged_in": true,
+                    }),
+                );
+            }
+
             event!(target: PRINT_TARGET, Level::INFO, "Already logged into Iggy server {}", self.server_session.get_server_address());
             return Ok(());
         }
@@ -96,6 +111,16 @@ impl CliCommand for LoginCmd {
 
         self.server_session.store(&token.token)?;
 
+        if format.is_structured() {
+            return format.print_status(
+                LOGIN_USER,
+                json!({
+                    "server_address": self.server_session.get_server_address(),
+                    "already_logged_in": false,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Successfully logged into Iggy server {}",
             self.server_session.get_server_address(),
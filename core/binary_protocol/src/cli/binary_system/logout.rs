@@ -18,9 +18,11 @@
 
 use crate::Client;
 use crate::cli::binary_system::session::ServerSession;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
+use iggy_common::LOGOUT_USER;
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct LogoutCmd {
@@ -41,7 +43,11 @@ impl CliCommand for LogoutCmd {
         "logout command".to_owned()
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         if self.server_session.is_active() {
             client
                 .delete_personal_access_token(&self.server_session.get_token_name())
@@ -55,6 +61,15 @@ impl CliCommand for LogoutCmd {
 
             self.server_session.delete()?;
         }
+        if format.is_structured() {
+            return format.print_status(
+                LOGOUT_USER,
+                json!({
+                    "server_address": self.server_session.get_server_address(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "Successfully logged out from Iggy server {}", self.server_session.get_server_address());
 
         Ok(())
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -46,12 +46,20 @@ impl CliCommand for GetMeCmd {
         "me command".to_owned()
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let client_info = client
             .get_me()
             .await
             .with_context(|| "Problem sending get_me command".to_owned())?;
 
+        if format.is_structured() {
+            return format.print(&client_info);
+        }
+
         let mut table = Table::new();
 
         table.set_header(vec!["Property", "Value"]);
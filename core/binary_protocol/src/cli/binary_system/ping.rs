@@ -17,10 +17,11 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::ping::Ping;
+use serde::Serialize;
 use std::fmt::{Display, Formatter, Result};
 use std::time::Duration;
 use tokio::time::{Instant, sleep};
@@ -44,6 +45,15 @@ struct PingStats {
     samples: Vec<u128>,
 }
 
+#[derive(Debug, Serialize)]
+struct PingSummary {
+    count: usize,
+    min_ms: f64,
+    avg_ms: f64,
+    max_ms: f64,
+    mdev_ms: f64,
+}
+
 impl PingStats {
     fn new() -> Self {
         Self { samples: vec![] }
@@ -86,6 +96,17 @@ impl PingStats {
 
         (min, avg, max, std_dev)
     }
+
+    fn get_summary(&self) -> PingSummary {
+        let (min, avg, max, std_dev) = self.get_stats();
+        PingSummary {
+            count: self.count(),
+            min_ms: nano_to_ms(min),
+            avg_ms: nano_to_ms(avg),
+            max_ms: nano_to_ms(max),
+            mdev_ms: nano_to_ms(std_dev),
+        }
+    }
 }
 
 fn nano_to_ms(nanoseconds: u128) -> f64 {
@@ -116,7 +137,11 @@ impl CliCommand for PingCmd {
         false
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let print_width = (self.count.ilog10() + 1) as usize;
         let mut ping_stats = PingStats::new();
 
@@ -132,6 +157,10 @@ impl CliCommand for PingCmd {
             sleep(Duration::from_secs(1)).await;
         }
 
+        if format.is_structured() {
+            return format.print(&ping_stats.get_summary());
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "");
         event!(target: PRINT_TARGET, Level::INFO, "Ping statistics for {} ping commands", ping_stats.count());
         event!(target: PRINT_TARGET, Level::INFO, "{ping_stats}");
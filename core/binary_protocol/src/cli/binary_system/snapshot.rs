@@ -19,15 +19,22 @@
 use std::path::Path;
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::{SnapshotCompression, SystemSnapshotType};
+use serde::Serialize;
 use tokio::io::AsyncWriteExt;
 use tracing::{Level, event};
 
+#[derive(Debug, Serialize)]
+struct SnapshotFile {
+    file_path: String,
+    file_size: usize,
+}
+
 pub struct GetSnapshotCmd {
     _get_snapshot: GetSnapshot,
     out_dir: String,
@@ -70,7 +77,11 @@ impl CliCommand for GetSnapshotCmd {
         "snapshot command".to_owned()
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let snapshot_data = client
             .snapshot(
                 self._get_snapshot.compression,
@@ -92,6 +103,13 @@ impl CliCommand for GetSnapshotCmd {
             .await
             .with_context(|| "Failed to write snapshot data to file".to_owned())?;
 
+        if format.is_structured() {
+            return format.print(&SnapshotFile {
+                file_path: file_path.to_string_lossy().to_string(),
+                file_size,
+            });
+        }
+
         let mut table = Table::new();
         table.set_header(vec!["Property", "Value"]);
         table.add_row(vec!["File Path", file_path.to_string_lossy().as_ref()]);
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -67,12 +67,20 @@ impl CliCommand for GetStatsCmd {
         "stats command".to_owned()
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let stats = client
             .get_stats()
             .await
             .with_context(|| "Problem sending get_stats command".to_owned())?;
 
+        if format.is_structured() {
+            return format.print(&stats);
+        }
+
         let output = match self.output {
             GetStatsOutput::Table => {
                 let mut table = Table::new();
@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::delete_topic::DeleteTopic;
+use iggy_common::{DELETE_TOPIC, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct DeleteTopicCmd {
@@ -48,7 +49,11 @@ impl CliCommand for DeleteTopicCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .delete_topic(&self.delete_topic.stream_id, &self.delete_topic.topic_id)
             .await
@@ -59,6 +64,16 @@ impl CliCommand for DeleteTopicCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                DELETE_TOPIC,
+                json!({
+                    "stream_id": self.delete_topic.stream_id.to_string(),
+                    "topic_id": self.delete_topic.topic_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Topic with ID: {} in stream with ID: {} deleted",
             self.delete_topic.topic_id, self.delete_topic.stream_id
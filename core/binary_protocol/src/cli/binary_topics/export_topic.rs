@@ -0,0 +1,259 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_topics::topic_archive::{
+    TopicArchiveMetadata, TopicArchivePartition, TopicArchiveWriter, print_progress,
+};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::{Consumer, Identifier, IggyByteSize, IggyTimestamp, PollingStrategy};
+use serde::Serialize;
+use std::path::PathBuf;
+use tracing::{Level, event};
+
+pub struct ExportTopicCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    file: PathBuf,
+    partitions: Vec<u32>,
+    from_offset: Option<u64>,
+    to_offset: Option<u64>,
+    from_timestamp: Option<u64>,
+    to_timestamp: Option<u64>,
+    batch_size: u32,
+    compression_level: i32,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportTopicSummary {
+    file: String,
+    size: IggyByteSize,
+    messages_count: u64,
+    partitions: Vec<TopicArchivePartition>,
+}
+
+impl ExportTopicCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        file: PathBuf,
+        partitions: Vec<u32>,
+        from_offset: Option<u64>,
+        to_offset: Option<u64>,
+        from_timestamp: Option<u64>,
+        to_timestamp: Option<u64>,
+        batch_size: u32,
+        compression_level: i32,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            file,
+            partitions,
+            from_offset,
+            to_offset,
+            from_timestamp,
+            to_timestamp,
+            batch_size,
+            compression_level,
+        }
+    }
+
+    /// Exports the messages from the partition, up to its offset at the beginning of the export.
+    async fn export_partition(
+        &self,
+        client: &dyn Client,
+        writer: &mut TopicArchiveWriter,
+        partition: &TopicArchivePartition,
+        exported: &mut u64,
+        total: u64,
+    ) -> anyhow::Result<()> {
+        let consumer = Consumer::default();
+        let mut strategy = match self.from_timestamp {
+            Some(timestamp) => PollingStrategy::timestamp(IggyTimestamp::from(timestamp)),
+            None => PollingStrategy::offset(partition.from_offset),
+        };
+
+        loop {
+            let polled_messages = client
+                .poll_messages(
+                    &self.stream_id,
+                    &self.topic_id,
+                    Some(partition.partition_id),
+                    &consumer,
+                    &strategy,
+                    self.batch_size,
+                    false,
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Problem polling messages from partition with ID: {} of topic with ID: {} and stream with ID: {}",
+                        partition.partition_id, self.topic_id, self.stream_id
+                    )
+                })?;
+
+            let Some(last_offset) = polled_messages.messages.last().map(|m| m.header.offset) else {
+                return Ok(());
+            };
+
+            for message in &polled_messages.messages {
+                if message.header.offset < partition.from_offset {
+                    continue;
+                }
+
+                if message.header.offset > partition.to_offset
+                    || self
+                        .to_timestamp
+                        .is_some_and(|timestamp| message.header.timestamp > timestamp)
+                {
+                    return Ok(());
+                }
+
+                writer.write(partition.partition_id, message)?;
+                *exported += 1;
+            }
+
+            print_progress("Exporting", *exported, total);
+            if last_offset >= partition.to_offset {
+                return Ok(());
+            }
+
+            strategy = PollingStrategy::offset(last_offset + 1);
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ExportTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "export topic with ID: {} from stream with ID: {} to file: {}",
+            self.topic_id,
+            self.stream_id,
+            self.file.display()
+        )
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let stream = client
+            .get_stream(&self.stream_id)
+            .await
+            .with_context(|| format!("Problem getting stream with ID: {}", self.stream_id))?
+            .with_context(|| format!("Stream with ID: {} was not found", self.stream_id))?;
+        let topic = client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} in stream {}",
+                    self.topic_id, self.stream_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Topic with ID: {} in stream {} was not found",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        if let Some(partition_id) = self
+            .partitions
+            .iter()
+            .find(|id| !topic.partitions.iter().any(|p| p.id == **id))
+        {
+            anyhow::bail!(
+                "Partition with ID: {partition_id} was not found in topic with ID: {}",
+                self.topic_id
+            );
+        }
+
+        let from_offset = self.from_offset.unwrap_or_default();
+        let partitions = topic
+            .partitions
+            .iter()
+            .filter(|partition| partition.messages_count > 0)
+            .filter(|partition| {
+                self.partitions.is_empty() || self.partitions.contains(&partition.id)
+            })
+            .map(|partition| TopicArchivePartition {
+                partition_id: partition.id,
+                from_offset,
+                to_offset: self.to_offset.map_or(partition.current_offset, |offset| {
+                    offset.min(partition.current_offset)
+                }),
+            })
+            .filter(|partition| partition.from_offset <= partition.to_offset)
+            .collect::<Vec<_>>();
+
+        let metadata = TopicArchiveMetadata {
+            created_at: IggyTimestamp::now(),
+            stream_id: stream.id,
+            stream_name: stream.name,
+            topic_id: topic.id,
+            topic_name: topic.name,
+            partitions_count: topic.partitions_count,
+            compression_algorithm: topic.compression_algorithm,
+            from_timestamp: self.from_timestamp,
+            to_timestamp: self.to_timestamp,
+            partitions,
+        };
+
+        let mut writer = TopicArchiveWriter::create(&self.file, &metadata, self.compression_level)?;
+        let total = metadata
+            .partitions
+            .iter()
+            .map(|partition| partition.to_offset - partition.from_offset + 1)
+            .sum();
+        let mut exported = 0;
+        for partition in &metadata.partitions {
+            self.export_partition(client, &mut writer, partition, &mut exported, total)
+                .await?;
+        }
+        let messages_count = writer.finish()?;
+        print_progress("Exporting", total, total);
+
+        let size = IggyByteSize::from(std::fs::metadata(&self.file)?.len());
+        if format.is_structured() {
+            return format.print(&ExportTopicSummary {
+                file: self.file.display().to_string(),
+                size,
+                messages_count,
+                partitions: metadata.partitions,
+            });
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Exported {messages_count} messages from {} partitions of topic with ID: {} and stream with ID: {} to file: {} ({})",
+            metadata.partitions.len(),
+            self.topic_id,
+            self.stream_id,
+            self.file.display(),
+            size.as_human_string(),
+        );
+
+        Ok(())
+    }
+}
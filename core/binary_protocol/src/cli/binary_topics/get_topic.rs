@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -50,7 +50,11 @@ impl CliCommand for GetTopicCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let topic = client
             .get_topic(&self.get_topic.stream_id, &self.get_topic.topic_id)
             .await
@@ -61,6 +65,10 @@ impl CliCommand for GetTopicCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&topic);
+        }
+
         if topic.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Topic with ID: {} in stream {} was not found", self.get_topic.topic_id, self.get_topic.stream_id);
             return Ok(());
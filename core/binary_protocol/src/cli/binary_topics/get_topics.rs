@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -65,7 +65,11 @@ impl CliCommand for GetTopicsCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let topics = client
             .get_topics(&self.get_topics.stream_id)
             .await
@@ -76,6 +80,10 @@ impl CliCommand for GetTopicsCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&topics);
+        }
+
         match self.output {
             GetTopicsOutput::Table => {
                 let mut table = Table::new();
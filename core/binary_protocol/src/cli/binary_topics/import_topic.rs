@@ -0,0 +1,261 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_topics::topic_archive::{TopicArchiveReader, print_progress};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::{Identifier, IggyMessage, IggyTimestamp, Partitioning};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{Level, event};
+
+pub struct ImportTopicCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    file: PathBuf,
+    partition_map: HashMap<u32, u32>,
+    balanced: bool,
+    batch_size: u32,
+    resume: bool,
+}
+
+/// Number of the messages already imported from the archive, stored after each sent batch.
+#[derive(Debug, Serialize, Deserialize)]
+struct ImportCheckpoint {
+    archive_created_at: IggyTimestamp,
+    imported: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportTopicSummary {
+    file: String,
+    messages_count: u64,
+    skipped: u64,
+}
+
+impl ImportTopicCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        file: PathBuf,
+        partition_map: Vec<(u32, u32)>,
+        balanced: bool,
+        batch_size: u32,
+        resume: bool,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            file,
+            partition_map: partition_map.into_iter().collect(),
+            balanced,
+            batch_size,
+            resume,
+        }
+    }
+
+    fn checkpoint_path(&self) -> PathBuf {
+        let mut path = self.file.clone().into_os_string();
+        path.push(".checkpoint");
+        path.into()
+    }
+
+    fn read_checkpoint(&self, archive_created_at: IggyTimestamp) -> anyhow::Result<u64> {
+        let path = self.checkpoint_path();
+        if !self.resume || !path.exists() {
+            return Ok(0);
+        }
+
+        let checkpoint: ImportCheckpoint = serde_json::from_slice(&std::fs::read(&path)?)
+            .with_context(|| format!("Problem reading checkpoint file: {}", path.display()))?;
+        if checkpoint.archive_created_at != archive_created_at {
+            anyhow::bail!(
+                "Checkpoint file: {} was created for a different archive",
+                path.display()
+            );
+        }
+
+        Ok(checkpoint.imported)
+    }
+
+    fn write_checkpoint(
+        &self,
+        archive_created_at: IggyTimestamp,
+        imported: u64,
+    ) -> anyhow::Result<()> {
+        let checkpoint = ImportCheckpoint {
+            archive_created_at,
+            imported,
+        };
+        std::fs::write(self.checkpoint_path(), serde_json::to_vec(&checkpoint)?)
+            .with_context(|| "Problem writing import checkpoint")
+    }
+
+    fn target_partition_id(&self, partition_id: u32) -> u32 {
+        self.partition_map
+            .get(&partition_id)
+            .copied()
+            .unwrap_or(partition_id)
+    }
+
+    fn get_partitioning(&self, partition_id: u32) -> Partitioning {
+        match self.balanced {
+            true => Partitioning::balanced(),
+            false => Partitioning::partition_id(self.target_partition_id(partition_id)),
+        }
+    }
+
+    async fn send(
+        &self,
+        client: &dyn Client,
+        partition_id: u32,
+        messages: &mut Vec<IggyMessage>,
+    ) -> anyhow::Result<()> {
+        client
+            .send_messages(
+                &self.stream_id,
+                &self.topic_id,
+                &self.get_partitioning(partition_id),
+                messages,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem sending messages to topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+        messages.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CliCommand for ImportTopicCmd {
+    fn explain(&self) -> String {
+        format!(
+            "import file: {} to topic with ID: {} and stream with ID: {}",
+            self.file.display(),
+            self.topic_id,
+            self.stream_id
+        )
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let mut reader = TopicArchiveReader::open(&self.file)?;
+        let metadata = reader.metadata().clone();
+        let total = reader.messages_count();
+
+        let topic = client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} in stream {}",
+                    self.topic_id, self.stream_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Topic with ID: {} in stream {} was not found",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+
+        if !self.balanced {
+            for partition in &metadata.partitions {
+                let target = self.target_partition_id(partition.partition_id);
+                if target == 0 || target > topic.partitions_count {
+                    anyhow::bail!(
+                        "Partition with ID: {target} (for the exported partition with ID: {}) was not found in topic with ID: {}, use the partition mapping or balanced partitioning",
+                        partition.partition_id,
+                        self.topic_id
+                    );
+                }
+            }
+        }
+
+        let skipped = self.read_checkpoint(metadata.created_at)?;
+        if skipped > 0 {
+            event!(target: PRINT_TARGET, Level::INFO, "Resuming import after {skipped} already imported messages");
+        }
+
+        let mut processed = 0u64;
+        let mut batch_partition_id = 0;
+        let mut batch = Vec::with_capacity(self.batch_size as usize);
+        while let Some((partition_id, message)) = reader.read()? {
+            processed += 1;
+            if processed <= skipped {
+                continue;
+            }
+
+            if !batch.is_empty()
+                && (batch_partition_id != partition_id || batch.len() >= self.batch_size as usize)
+            {
+                self.send(client, batch_partition_id, &mut batch).await?;
+                self.write_checkpoint(metadata.created_at, processed - 1)?;
+                print_progress("Importing", processed - 1, total);
+            }
+
+            batch_partition_id = partition_id;
+            batch.push(message);
+        }
+
+        if !batch.is_empty() {
+            self.send(client, batch_partition_id, &mut batch).await?;
+        }
+        print_progress("Importing", total, total);
+
+        let checkpoint_path = self.checkpoint_path();
+        if checkpoint_path.exists() {
+            std::fs::remove_file(&checkpoint_path).with_context(|| {
+                format!(
+                    "Problem removing checkpoint file: {}",
+                    checkpoint_path.display()
+                )
+            })?;
+        }
+
+        let messages_count = processed.saturating_sub(skipped);
+        if format.is_structured() {
+            return format.print(&ImportTopicSummary {
+                file: self.file.display().to_string(),
+                messages_count,
+                skipped,
+            });
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Imported {messages_count} messages from file: {} (exported from topic: {} in stream: {}) to topic with ID: {} and stream with ID: {}",
+            self.file.display(),
+            metadata.topic_name,
+            metadata.stream_name,
+            self.topic_id,
+            self.stream_id,
+        );
+
+        Ok(())
+    }
+}
@@ -18,7 +18,10 @@
 
 pub mod create_topic;
 pub mod delete_topic;
+pub mod export_topic;
 pub mod get_topic;
 pub mod get_topics;
+pub mod import_topic;
 pub mod purge_topic;
+pub mod topic_archive;
 pub mod update_topic;
@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::purge_topic::PurgeTopic;
+use iggy_common::{Identifier, PURGE_TOPIC};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct PurgeTopicCmd {
@@ -48,7 +49,11 @@ impl CliCommand for PurgeTopicCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .purge_topic(&self.purge_topic.stream_id, &self.purge_topic.topic_id)
             .await
@@ -59,6 +64,16 @@ impl CliCommand for PurgeTopicCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                PURGE_TOPIC,
+                json!({
+                    "stream_id": self.purge_topic.stream_id.to_string(),
+                    "topic_id": self.purge_topic.topic_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Topic with ID: {} in stream with ID: {} purged",
             self.purge_topic.topic_id, self.purge_topic.stream_id);
@@ -0,0 +1,351 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Topic archive, the portable and self-describing file with the topic messages,
+//! created by the topic export command and replayed by the topic import command.
+//!
+//! The archive consists of:
+//! - magic bytes `IGGYTOPC` and the format version (u32),
+//! - the number of the archived messages (u64),
+//! - the length (u32) of the JSON metadata describing the source topic, followed by the metadata,
+//! - zstd compressed records, each being the partition ID (u32), the message length (u32)
+//!   and the message in the same binary format as used by the `message poll --output-file`.
+//!
+//! All the numbers are stored in little endian.
+
+use anyhow::Context;
+use bytes::Bytes;
+use iggy_common::{
+    BytesSerializable, CompressionAlgorithm, IGGY_MESSAGE_HEADER_SIZE, IggyMessage, IggyTimestamp,
+    MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE,
+};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"IGGYTOPC";
+const VERSION: u32 = 1;
+const MESSAGES_COUNT_POSITION: u64 = 12;
+const MAX_MESSAGE_SIZE: u32 =
+    IGGY_MESSAGE_HEADER_SIZE as u32 + MAX_PAYLOAD_SIZE + MAX_USER_HEADERS_SIZE;
+
+/// Description of the exported topic, stored in the archive header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicArchiveMetadata {
+    pub created_at: IggyTimestamp,
+    pub stream_id: u32,
+    pub stream_name: String,
+    pub topic_id: u32,
+    pub topic_name: String,
+    pub partitions_count: u32,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub from_timestamp: Option<u64>,
+    pub to_timestamp: Option<u64>,
+    pub partitions: Vec<TopicArchivePartition>,
+}
+
+/// Range of the offsets exported from the partition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicArchivePartition {
+    pub partition_id: u32,
+    pub from_offset: u64,
+    pub to_offset: u64,
+}
+
+pub struct TopicArchiveWriter {
+    encoder: zstd::Encoder<'static, BufWriter<File>>,
+    messages_count: u64,
+}
+
+impl TopicArchiveWriter {
+    pub fn create(
+        path: &Path,
+        metadata: &TopicArchiveMetadata,
+        compression_level: i32,
+    ) -> anyhow::Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Problem creating archive file: {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+        let metadata = serde_json::to_vec(metadata)?;
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&0u64.to_le_bytes())?;
+        writer.write_all(&(metadata.len() as u32).to_le_bytes())?;
+        writer.write_all(&metadata)?;
+
+        Ok(Self {
+            encoder: zstd::Encoder::new(writer, compression_level)?,
+            messages_count: 0,
+        })
+    }
+
+    pub fn write(&mut self, partition_id: u32, message: &IggyMessage) -> anyhow::Result<()> {
+        let message = message.to_bytes();
+        self.encoder.write_all(&partition_id.to_le_bytes())?;
+        self.encoder
+            .write_all(&(message.len() as u32).to_le_bytes())?;
+        self.encoder.write_all(&message)?;
+        self.messages_count += 1;
+        Ok(())
+    }
+
+    /// Completes the compressed stream and stores the number of the messages in the header.
+    pub fn finish(self) -> anyhow::Result<u64> {
+        let mut writer = self.encoder.finish()?;
+        writer.seek(SeekFrom::Start(MESSAGES_COUNT_POSITION))?;
+        writer.write_all(&self.messages_count.to_le_bytes())?;
+        writer.flush()?;
+        Ok(self.messages_count)
+    }
+}
+
+pub struct TopicArchiveReader {
+    metadata: TopicArchiveMetadata,
+    messages_count: u64,
+    messages_read: u64,
+    decoder: zstd::Decoder<'static, BufReader<File>>,
+}
+
+impl TopicArchiveReader {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path)
+            .with_context(|| format!("Problem opening archive file: {}", path.display()))?;
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            anyhow::bail!("File: {} is not a topic archive", path.display());
+        }
+
+        let version = read_u32(&mut reader)?;
+        if version != VERSION {
+            anyhow::bail!("Unsupported topic archive version: {version}");
+        }
+
+        let messages_count = read_u64(&mut reader)?;
+        let mut metadata = vec![0u8; read_u32(&mut reader)? as usize];
+        reader.read_exact(&mut metadata)?;
+        let metadata = serde_json::from_slice(&metadata)
+            .with_context(|| "Problem reading topic archive metadata")?;
+
+        Ok(Self {
+            metadata,
+            messages_count,
+            messages_read: 0,
+            decoder: zstd::Decoder::with_buffer(reader)?,
+        })
+    }
+
+    pub fn metadata(&self) -> &TopicArchiveMetadata {
+        &self.metadata
+    }
+
+    pub fn messages_count(&self) -> u64 {
+        self.messages_count
+    }
+
+    /// Reads the next message with the ID of the partition it was exported from.
+    /// The end of the archive is valid only after all the messages counted in the header were read.
+    pub fn read(&mut self) -> anyhow::Result<Option<(u32, IggyMessage)>> {
+        let mut partition_id = [0u8; 4];
+        match self.decoder.read_exact(&mut partition_id) {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => {
+                if self.messages_read != self.messages_count {
+                    anyhow::bail!(
+                        "Topic archive is truncated, read {} of {} messages",
+                        self.messages_read,
+                        self.messages_count
+                    );
+                }
+                return Ok(None);
+            }
+            Err(error) => return Err(error.into()),
+        }
+
+        let length = read_u32(&mut self.decoder)
+            .with_context(|| "Problem reading message length from topic archive")?;
+        if length > MAX_MESSAGE_SIZE {
+            anyhow::bail!(
+                "Invalid message length: {length} in topic archive, maximum is {MAX_MESSAGE_SIZE}"
+            );
+        }
+
+        let mut message = vec![0u8; length as usize];
+        self.decoder
+            .read_exact(&mut message)
+            .with_context(|| "Problem reading message from topic archive")?;
+        let message = IggyMessage::from_bytes(Bytes::from(message))
+            .with_context(|| "Problem reading message from topic archive")?;
+        self.messages_read += 1;
+        Ok(Some((u32::from_le_bytes(partition_id), message)))
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> std::io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Prints the progress bar on the standard error, only if it's a terminal.
+pub(crate) fn print_progress(action: &str, done: u64, total: u64) {
+    const WIDTH: u64 = 40;
+    if !passterm::isatty(passterm::Stream::Stderr) || total == 0 {
+        return;
+    }
+
+    let filled = (done * WIDTH / total) as usize;
+    eprint!(
+        "\r{action} [{}{}] {done}/{total} messages ({}%)",
+        "#".repeat(filled),
+        "-".repeat(WIDTH as usize - filled),
+        done * 100 / total
+    );
+    if done >= total {
+        eprintln!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> TopicArchiveMetadata {
+        TopicArchiveMetadata {
+            created_at: IggyTimestamp::now(),
+            stream_id: 1,
+            stream_name: "stream".to_owned(),
+            topic_id: 2,
+            topic_name: "topic".to_owned(),
+            partitions_count: 2,
+            compression_algorithm: CompressionAlgorithm::None,
+            from_timestamp: None,
+            to_timestamp: None,
+            partitions: vec![TopicArchivePartition {
+                partition_id: 1,
+                from_offset: 0,
+                to_offset: 2,
+            }],
+        }
+    }
+
+    #[test]
+    fn should_read_written_messages() {
+        let path = std::env::temp_dir().join(format!(
+            "iggy_topic_archive_{}.iggy",
+            IggyTimestamp::now().as_micros()
+        ));
+        let mut writer = TopicArchiveWriter::create(&path, &metadata(), 3).unwrap();
+        for (partition_id, payload) in [(1, "first"), (2, "second"), (1, "third")] {
+            let message = IggyMessage::builder()
+                .payload(Bytes::from(payload))
+                .build()
+                .unwrap();
+            writer.write(partition_id, &message).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), 3);
+
+        let mut reader = TopicArchiveReader::open(&path).unwrap();
+        assert_eq!(reader.messages_count(), 3);
+        assert_eq!(reader.metadata().topic_name, "topic");
+        assert_eq!(reader.metadata().partitions[0].to_offset, 2);
+
+        let mut messages = Vec::new();
+        while let Some((partition_id, message)) = reader.read().unwrap() {
+            messages.push((partition_id, message.payload));
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            messages,
+            vec![
+                (1, Bytes::from("first")),
+                (2, Bytes::from("second")),
+                (1, Bytes::from("third")),
+            ]
+        );
+    }
+
+    fn archive_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "iggy_{name}_{}.iggy",
+            IggyTimestamp::now().as_micros()
+        ))
+    }
+
+    fn message(payload: &'static str) -> IggyMessage {
+        IggyMessage::builder()
+            .payload(Bytes::from(payload))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_fail_when_archive_has_fewer_messages_than_header() {
+        let path = archive_path("truncated_topic_archive");
+        let mut writer = TopicArchiveWriter::create(&path, &metadata(), 3).unwrap();
+        writer.write(1, &message("first")).unwrap();
+        writer.messages_count = 2;
+        writer.finish().unwrap();
+
+        let mut reader = TopicArchiveReader::open(&path).unwrap();
+        assert!(reader.read().unwrap().is_some());
+        let result = reader.read();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_for_too_large_message_length() {
+        let path = archive_path("invalid_topic_archive");
+        let mut writer = TopicArchiveWriter::create(&path, &metadata(), 3).unwrap();
+        writer.encoder.write_all(&1u32.to_le_bytes()).unwrap();
+        writer.encoder.write_all(&u32::MAX.to_le_bytes()).unwrap();
+        writer.messages_count = 1;
+        writer.finish().unwrap();
+
+        let mut reader = TopicArchiveReader::open(&path).unwrap();
+        let result = reader.read();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_for_not_archive_file() {
+        let path = std::env::temp_dir().join(format!(
+            "iggy_not_topic_archive_{}.iggy",
+            IggyTimestamp::now().as_micros()
+        ));
+        std::fs::write(&path, b"not an archive file").unwrap();
+        let reader = TopicArchiveReader::open(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(reader.is_err());
+    }
+}
@@ -17,12 +17,13 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use core::fmt;
 use iggy_common::update_topic::UpdateTopic;
-use iggy_common::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use iggy_common::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize, UPDATE_TOPIC};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct UpdateTopicCmd {
@@ -65,7 +66,11 @@ impl CliCommand for UpdateTopicCmd {
         format!("{self}")
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size)
             .await
@@ -79,6 +84,17 @@ impl CliCommand for UpdateTopicCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                UPDATE_TOPIC,
+                json!({
+                    "stream_id": self.update_topic.stream_id.to_string(),
+                    "topic_id": self.update_topic.topic_id.to_string(),
+                    "name": self.update_topic.name,
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {}, updated max topic size: {}, updated replication factor: {} in stream with ID: {}",
             self.update_topic.topic_id,
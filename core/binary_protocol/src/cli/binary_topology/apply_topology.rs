@@ -0,0 +1,120 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_topology::topology::{self, Topology, TopologyChangesSummary};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use tracing::{Level, event};
+
+pub struct ApplyTopologyCmd {
+    file: PathBuf,
+    dry_run: bool,
+    prune: bool,
+}
+
+impl ApplyTopologyCmd {
+    pub fn new(file: PathBuf, dry_run: bool, prune: bool) -> Self {
+        Self {
+            file,
+            dry_run,
+            prune,
+        }
+    }
+}
+
+/// Prints the changes required to reconcile the server with the topology file and applies
+/// them unless it is a dry run.
+pub(crate) async fn reconcile(
+    client: &dyn Client,
+    file: &Path,
+    prune: bool,
+    apply: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let desired = Topology::load(file)?;
+    let current = Topology::fetch(client)
+        .await
+        .with_context(|| "Problem getting current topology of the server")?;
+    let changes = topology::plan(&desired, &current, prune).with_context(|| {
+        format!(
+            "Problem planning changes for topology file: {}",
+            file.display()
+        )
+    })?;
+
+    if !format.is_structured() {
+        if changes.is_empty() {
+            event!(target: PRINT_TARGET, Level::INFO, "Server matches topology from file: {}", file.display());
+            return Ok(());
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Changes required by topology from file: {}", file.display());
+        for change in &changes {
+            event!(target: PRINT_TARGET, Level::INFO, "{change}");
+        }
+    }
+
+    let tokens = match apply && !changes.is_empty() {
+        true => topology::apply(client, &changes, current).await?,
+        false => vec![],
+    };
+
+    if format.is_structured() {
+        return format.print(&TopologyChangesSummary {
+            changes: changes.iter().map(|change| change.to_string()).collect(),
+            applied: apply,
+            personal_access_tokens: tokens,
+        });
+    }
+
+    if !apply {
+        event!(target: PRINT_TARGET, Level::INFO, "Dry run, {} changes not applied", changes.len());
+        return Ok(());
+    }
+
+    event!(target: PRINT_TARGET, Level::INFO, "Applied {} changes from topology file: {}", changes.len(), file.display());
+    for token in tokens {
+        event!(target: PRINT_TARGET, Level::INFO, "Personal access token with name: {} created, token: {}", token.name, token.token);
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl CliCommand for ApplyTopologyCmd {
+    fn explain(&self) -> String {
+        let mode = match (self.dry_run, self.prune) {
+            (true, true) => " (dry run, with pruning)",
+            (true, false) => " (dry run)",
+            (false, true) => " (with pruning)",
+            (false, false) => "",
+        };
+        format!("apply topology from file: {}{mode}", self.file.display())
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        reconcile(client, &self.file, self.prune, !self.dry_run, format).await
+    }
+}
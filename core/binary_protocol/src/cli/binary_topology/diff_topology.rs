@@ -0,0 +1,53 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_topology::apply_topology::reconcile;
+use crate::cli::cli_command::{CliCommand, OutputFormat};
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+pub struct DiffTopologyCmd {
+    file: PathBuf,
+    prune: bool,
+}
+
+impl DiffTopologyCmd {
+    pub fn new(file: PathBuf, prune: bool) -> Self {
+        Self { file, prune }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DiffTopologyCmd {
+    fn explain(&self) -> String {
+        let mode = match self.prune {
+            true => " (with pruning)",
+            false => "",
+        };
+        format!("diff topology from file: {}{mode}", self.file.display())
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        reconcile(client, &self.file, self.prune, false, format).await
+    }
+}
@@ -0,0 +1,80 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::binary_topology::topology::{Topology, TopologyFormat};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tracing::{Level, event};
+
+pub struct ExportTopologyCmd {
+    file: Option<PathBuf>,
+}
+
+impl ExportTopologyCmd {
+    pub fn new(file: Option<PathBuf>) -> Self {
+        Self { file }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ExportTopologyCmd {
+    fn explain(&self) -> String {
+        match &self.file {
+            Some(file) => format!("export topology to file: {}", file.display()),
+            None => "export topology".to_owned(),
+        }
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let topology = Topology::fetch(client)
+            .await
+            .with_context(|| "Problem getting current topology of the server")?;
+
+        let Some(file) = &self.file else {
+            if format.is_structured() {
+                return format.print(&topology);
+            }
+            event!(target: PRINT_TARGET, Level::INFO, "{}", topology.to_string(TopologyFormat::Yaml)?.trim_end());
+            return Ok(());
+        };
+
+        let content = topology.to_string(TopologyFormat::from_path(file))?;
+        tokio::fs::write(file, content)
+            .await
+            .with_context(|| format!("Problem writing topology to file: {}", file.display()))?;
+
+        if !format.is_structured() {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Exported topology with {} streams, {} users and {} personal access tokens to file: {}",
+                topology.streams.len(),
+                topology.users.len(),
+                topology.personal_access_tokens.len(),
+                file.display()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1286 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Declarative model of the server topology (streams, topics, consumer groups, users with
+//! their permissions and personal access tokens), used to export the server state to a file
+//! and to reconcile the server with the file.
+//!
+//! Streams, topics and consumer groups are matched by name, the optional IDs are used only
+//! when the resource is created. The permissions refer to the streams and topics by name
+//! (or ID), so that the same file can be applied to the servers with different IDs.
+
+use crate::Client;
+use ahash::AHashMap;
+use anyhow::{Context, Result, bail};
+use iggy_common::{
+    CompressionAlgorithm, GlobalPermissions, Identifier, IggyExpiry, MaxTopicSize, Permissions,
+    StreamPermissions, TopicPermissions, UserStatus, defaults::DEFAULT_ROOT_USER_ID,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TopologyFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl TopologyFormat {
+    /// Format based on the file extension, YAML is used by default.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => TopologyFormat::Json,
+            Some("toml") => TopologyFormat::Toml,
+            _ => TopologyFormat::Yaml,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Topology {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub streams: Vec<StreamSpec>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<UserSpec>,
+    /// Personal access tokens of the user applying the topology.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub personal_access_tokens: Vec<PersonalAccessTokenSpec>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StreamSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<TopicSpec>,
+}
+
+/// Topic attributes which are not set are left as they are on the server (or use the
+/// server defaults when the topic is created).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TopicSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub partitions: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression_algorithm: Option<CompressionAlgorithm>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "expiry_format"
+    )]
+    pub message_expiry: Option<IggyExpiry>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "size_format")]
+    pub max_topic_size: Option<MaxTopicSize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replication_factor: Option<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumer_groups: Vec<ConsumerGroupSpec>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConsumerGroupSpec {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+}
+
+/// The password is used only when the user is created, the permissions are left as they
+/// are on the server when not set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserSpec {
+    pub username: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub status: UserStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<PermissionsSpec>,
+    /// The root user and the user applying the topology are never deleted.
+    #[serde(skip)]
+    protected: bool,
+}
+
+/// Permissions listed by name (e.g. `read_streams`), for the streams and topics referred
+/// to by name or ID.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PermissionsSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub global: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub streams: BTreeMap<String, StreamPermissionsSpec>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StreamPermissionsSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub topics: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PersonalAccessTokenSpec {
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "expiry_format"
+    )]
+    pub expiry: Option<IggyExpiry>,
+}
+
+impl Topology {
+    /// Reads the topology from the file, `${NAME}` placeholders (e.g. the passwords) are
+    /// replaced with the values of the environment variables.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Problem reading topology file: {}", path.display()))?;
+        let content = expand_env_vars(&content)?;
+        let topology: Topology = match TopologyFormat::from_path(path) {
+            TopologyFormat::Yaml => serde_yml::from_str(&content)?,
+            TopologyFormat::Json => serde_json::from_str(&content)?,
+            TopologyFormat::Toml => toml::from_str(&content)?,
+        };
+        topology.validate()?;
+        Ok(topology)
+    }
+
+    pub fn to_string(&self, format: TopologyFormat) -> Result<String> {
+        Ok(match format {
+            TopologyFormat::Yaml => serde_yml::to_string(self)?,
+            TopologyFormat::Json => serde_json::to_string_pretty(self)?,
+            TopologyFormat::Toml => toml::to_string(self)?,
+        })
+    }
+
+    /// Reads the current topology of the server.
+    pub async fn fetch(client: &dyn Client) -> Result<Self> {
+        let mut topology = Topology::default();
+        let mut streams = client.get_streams().await?;
+        streams.sort_by_key(|stream| stream.id);
+        for stream in streams {
+            let stream_id = Identifier::numeric(stream.id)?;
+            let Some(mut stream) = client.get_stream(&stream_id).await? else {
+                continue;
+            };
+            stream.topics.sort_by_key(|topic| topic.id);
+
+            let mut topics = vec![];
+            for topic in stream.topics {
+                let topic_id = Identifier::numeric(topic.id)?;
+                let mut consumer_groups = client.get_consumer_groups(&stream_id, &topic_id).await?;
+                consumer_groups.sort_by_key(|group| group.id);
+                topics.push(TopicSpec {
+                    name: topic.name,
+                    id: Some(topic.id),
+                    partitions: topic.partitions_count,
+                    compression_algorithm: Some(topic.compression_algorithm),
+                    message_expiry: Some(topic.message_expiry),
+                    max_topic_size: Some(topic.max_topic_size),
+                    replication_factor: Some(topic.replication_factor),
+                    consumer_groups: consumer_groups
+                        .into_iter()
+                        .map(|group| ConsumerGroupSpec {
+                            name: group.name,
+                            id: Some(group.id),
+                        })
+                        .collect(),
+                });
+            }
+
+            topology.streams.push(StreamSpec {
+                name: stream.name,
+                id: Some(stream.id),
+                topics,
+            });
+        }
+
+        let current_user_id = client.get_me().await?.user_id;
+        let mut users = client.get_users().await?;
+        users.sort_by_key(|user| user.id);
+        for user in users {
+            let Some(user) = client.get_user(&Identifier::numeric(user.id)?).await? else {
+                continue;
+            };
+            let permissions = user
+                .permissions
+                .map(|permissions| PermissionsSpec::from_permissions(&permissions, &topology));
+            topology.users.push(UserSpec {
+                username: user.username,
+                password: None,
+                status: user.status,
+                permissions,
+                protected: user.id == DEFAULT_ROOT_USER_ID || Some(user.id) == current_user_id,
+            });
+        }
+
+        topology.personal_access_tokens = client
+            .get_personal_access_tokens()
+            .await?
+            .into_iter()
+            .map(|token| PersonalAccessTokenSpec {
+                name: token.name,
+                expiry: None,
+            })
+            .collect();
+        topology
+            .personal_access_tokens
+            .sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(topology)
+    }
+
+    fn validate(&self) -> Result<()> {
+        ensure_unique("stream", self.streams.iter().map(|stream| &stream.name))?;
+        for stream in &self.streams {
+            ensure_unique("topic", stream.topics.iter().map(|topic| &topic.name))?;
+            for topic in &stream.topics {
+                ensure_unique(
+                    "consumer group",
+                    topic.consumer_groups.iter().map(|group| &group.name),
+                )?;
+            }
+        }
+        ensure_unique("user", self.users.iter().map(|user| &user.username))?;
+        ensure_unique(
+            "personal access token",
+            self.personal_access_tokens.iter().map(|token| &token.name),
+        )?;
+
+        for user in &self.users {
+            if let Some(permissions) = &user.permissions {
+                permissions
+                    .validate()
+                    .with_context(|| format!("Invalid permissions of user: {}", user.username))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn stream(&self, key: &str) -> Option<&StreamSpec> {
+        self.streams.iter().find(|stream| stream.matches(key))
+    }
+}
+
+impl StreamSpec {
+    fn matches(&self, key: &str) -> bool {
+        self.name == key || self.id.is_some_and(|id| id.to_string() == key)
+    }
+
+    fn topic(&self, key: &str) -> Option<&TopicSpec> {
+        self.topics
+            .iter()
+            .find(|topic| topic.name == key || topic.id.is_some_and(|id| id.to_string() == key))
+    }
+}
+
+impl PermissionsSpec {
+    fn from_permissions(permissions: &Permissions, topology: &Topology) -> Self {
+        let mut streams = BTreeMap::new();
+        for (stream_id, stream_permissions) in permissions.streams.iter().flatten() {
+            let stream = topology.stream(&stream_id.to_string());
+            let mut topics = BTreeMap::new();
+            for (topic_id, topic_permissions) in stream_permissions.topics.iter().flatten() {
+                let topic_key = stream
+                    .and_then(|stream| stream.topic(&topic_id.to_string()))
+                    .map(|topic| topic.name.clone())
+                    .unwrap_or_else(|| topic_id.to_string());
+                topics.insert(topic_key, permission_names(topic_permissions));
+            }
+
+            let stream_key = stream
+                .map(|stream| stream.name.clone())
+                .unwrap_or_else(|| stream_id.to_string());
+            streams.insert(
+                stream_key,
+                StreamPermissionsSpec {
+                    permissions: permission_names(stream_permissions),
+                    topics,
+                },
+            );
+        }
+
+        PermissionsSpec {
+            global: permission_names(&permissions.global),
+            streams,
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        permissions_from_names::<GlobalPermissions>(&self.global)?;
+        for stream in self.streams.values() {
+            permissions_from_names::<StreamPermissions>(&stream.permissions)?;
+            for topic in stream.topics.values() {
+                permissions_from_names::<TopicPermissions>(topic)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Permissions with the streams and topics referred to by name (when they exist) and the
+    /// sorted permission names, so that they can be compared with the server permissions.
+    fn normalized(&self, topology: &Topology) -> Self {
+        let mut streams = BTreeMap::new();
+        for (stream_key, stream_permissions) in &self.streams {
+            let stream = topology.stream(stream_key);
+            let topics = stream_permissions
+                .topics
+                .iter()
+                .map(|(topic_key, permissions)| {
+                    let topic_key = stream
+                        .and_then(|stream| stream.topic(topic_key))
+                        .map(|topic| topic.name.clone())
+                        .unwrap_or_else(|| topic_key.clone());
+                    (topic_key, sorted(permissions))
+                })
+                .collect();
+            let stream_key = stream
+                .map(|stream| stream.name.clone())
+                .unwrap_or_else(|| stream_key.clone());
+            streams.insert(
+                stream_key,
+                StreamPermissionsSpec {
+                    permissions: sorted(&stream_permissions.permissions),
+                    topics,
+                },
+            );
+        }
+
+        PermissionsSpec {
+            global: sorted(&self.global),
+            streams,
+        }
+    }
+
+    /// Resolves the streams and topics to their IDs on the server.
+    fn to_permissions(&self, topology: &Topology) -> Result<Permissions> {
+        let mut streams = AHashMap::new();
+        for (stream_key, stream_permissions) in &self.streams {
+            let Some(stream) = topology.stream(stream_key) else {
+                bail!("Stream: {stream_key} referred to in permissions does not exist");
+            };
+            let Some(stream_id) = stream.id else {
+                bail!("Stream: {stream_key} referred to in permissions does not exist");
+            };
+
+            let mut topics = AHashMap::new();
+            for (topic_key, permissions) in &stream_permissions.topics {
+                let Some(topic_id) = stream.topic(topic_key).and_then(|topic| topic.id) else {
+                    bail!(
+                        "Topic: {topic_key} in stream: {stream_key} referred to in permissions does not exist"
+                    );
+                };
+                topics.insert(topic_id, permissions_from_names(permissions)?);
+            }
+
+            let mut permissions: StreamPermissions =
+                permissions_from_names(&stream_permissions.permissions)?;
+            permissions.topics = (!topics.is_empty()).then_some(topics);
+            streams.insert(stream_id, permissions);
+        }
+
+        Ok(Permissions {
+            global: permissions_from_names(&self.global)?,
+            streams: (!streams.is_empty()).then_some(streams),
+        })
+    }
+}
+
+/// Names of the permissions which are granted.
+fn permission_names<T: Serialize>(permissions: &T) -> Vec<String> {
+    match serde_json::to_value(permissions) {
+        Ok(serde_json::Value::Object(fields)) => fields
+            .into_iter()
+            .filter(|(_, value)| value.as_bool() == Some(true))
+            .map(|(name, _)| name)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn permissions_from_names<T: Serialize + DeserializeOwned + Default>(
+    names: &[String],
+) -> Result<T> {
+    let mut value = serde_json::to_value(T::default())?;
+    let Some(fields) = value.as_object_mut() else {
+        bail!("Invalid permissions");
+    };
+    for name in names {
+        match fields.get_mut(name) {
+            Some(serde_json::Value::Bool(granted)) => *granted = true,
+            _ => bail!("Invalid permission: {name}"),
+        }
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+fn sorted(names: &[String]) -> Vec<String> {
+    names
+        .iter()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn ensure_unique<'a>(kind: &str, names: impl Iterator<Item = &'a String>) -> Result<()> {
+    let mut unique = BTreeSet::new();
+    for name in names {
+        if !unique.insert(name) {
+            bail!("Duplicated {kind}: {name} in topology");
+        }
+    }
+    Ok(())
+}
+
+fn expand_env_vars(content: &str) -> Result<String> {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 2..start + end];
+        let value = std::env::var(name)
+            .with_context(|| format!("Environment variable: {name} used in topology is not set"))?;
+        result.push_str(&rest[..start]);
+        result.push_str(&value);
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Single change required to reconcile the server with the topology.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TopologyChange {
+    CreateStream(StreamSpec),
+    DeleteStream {
+        stream: String,
+    },
+    CreateTopic {
+        stream: String,
+        topic: TopicSpec,
+    },
+    UpdateTopic {
+        stream: String,
+        topic: TopicSpec,
+        changes: Vec<String>,
+    },
+    CreatePartitions {
+        stream: String,
+        topic: String,
+        count: u32,
+    },
+    DeletePartitions {
+        stream: String,
+        topic: String,
+        count: u32,
+    },
+    DeleteTopic {
+        stream: String,
+        topic: String,
+    },
+    CreateConsumerGroup {
+        stream: String,
+        topic: String,
+        group: ConsumerGroupSpec,
+    },
+    DeleteConsumerGroup {
+        stream: String,
+        topic: String,
+        group: String,
+    },
+    CreateUser(UserSpec),
+    UpdateUserStatus {
+        username: String,
+        status: UserStatus,
+    },
+    UpdatePermissions {
+        username: String,
+        permissions: PermissionsSpec,
+    },
+    DeleteUser {
+        username: String,
+    },
+    CreatePersonalAccessToken(PersonalAccessTokenSpec),
+    DeletePersonalAccessToken {
+        name: String,
+    },
+}
+
+impl Display for TopologyChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreateStream(stream) => {
+                write!(f, "+ create stream: {}", stream.name)?;
+                if let Some(id) = stream.id {
+                    write!(f, " with ID: {id}")?;
+                }
+                Ok(())
+            }
+            Self::DeleteStream { stream } => write!(f, "- delete stream: {stream}"),
+            Self::CreateTopic { stream, topic } => write!(
+                f,
+                "+ create topic: {} with {} partitions in stream: {stream}",
+                topic.name, topic.partitions
+            ),
+            Self::UpdateTopic {
+                stream,
+                topic,
+                changes,
+            } => write!(
+                f,
+                "~ update topic: {} in stream: {stream} ({})",
+                topic.name,
+                changes.join(", ")
+            ),
+            Self::CreatePartitions {
+                stream,
+                topic,
+                count,
+            } => write!(
+                f,
+                "+ create {count} partitions in topic: {topic} in stream: {stream}"
+            ),
+            Self::DeletePartitions {
+                stream,
+                topic,
+                count,
+            } => write!(
+                f,
+                "- delete {count} partitions from topic: {topic} in stream: {stream}"
+            ),
+            Self::DeleteTopic { stream, topic } => {
+                write!(f, "- delete topic: {topic} in stream: {stream}")
+            }
+            Self::CreateConsumerGroup {
+                stream,
+                topic,
+                group,
+            } => write!(
+                f,
+                "+ create consumer group: {} for topic: {topic} in stream: {stream}",
+                group.name
+            ),
+            Self::DeleteConsumerGroup {
+                stream,
+                topic,
+                group,
+            } => write!(
+                f,
+                "- delete consumer group: {group} for topic: {topic} in stream: {stream}"
+            ),
+            Self::CreateUser(user) => {
+                write!(
+                    f,
+                    "+ create user: {} with status: {}",
+                    user.username, user.status
+                )
+            }
+            Self::UpdateUserStatus { username, status } => {
+                write!(f, "~ update status of user: {username} to: {status}")
+            }
+            Self::UpdatePermissions { username, .. } => {
+                write!(f, "~ update permissions of user: {username}")
+            }
+            Self::DeleteUser { username } => write!(f, "- delete user: {username}"),
+            Self::CreatePersonalAccessToken(token) => {
+                write!(f, "+ create personal access token: {}", token.name)
+            }
+            Self::DeletePersonalAccessToken { name } => {
+                write!(f, "- delete personal access token: {name}")
+            }
+        }
+    }
+}
+
+impl TopologyChange {
+    fn is_user_change(&self) -> bool {
+        matches!(
+            self,
+            Self::CreateUser(_)
+                | Self::UpdateUserStatus { .. }
+                | Self::UpdatePermissions { .. }
+                | Self::DeleteUser { .. }
+        )
+    }
+}
+
+/// Changes required to reconcile the `current` server topology with the `desired` one. The
+/// resources missing in the topology (and the excess partitions) are deleted only when
+/// `prune` is set.
+pub fn plan(desired: &Topology, current: &Topology, prune: bool) -> Result<Vec<TopologyChange>> {
+    let mut changes = vec![];
+
+    if prune {
+        for stream in &current.streams {
+            if !desired.streams.iter().any(|s| s.name == stream.name) {
+                changes.push(TopologyChange::DeleteStream {
+                    stream: stream.name.clone(),
+                });
+            }
+        }
+    }
+
+    for stream in &desired.streams {
+        let Some(current_stream) = current.streams.iter().find(|s| s.name == stream.name) else {
+            changes.push(TopologyChange::CreateStream(StreamSpec {
+                topics: vec![],
+                ..stream.clone()
+            }));
+            for topic in &stream.topics {
+                plan_new_topic(&mut changes, &stream.name, topic);
+            }
+            continue;
+        };
+
+        if prune {
+            for topic in &current_stream.topics {
+                if !stream.topics.iter().any(|t| t.name == topic.name) {
+                    changes.push(TopologyChange::DeleteTopic {
+                        stream: stream.name.clone(),
+                        topic: topic.name.clone(),
+                    });
+                }
+            }
+        }
+
+        for topic in &stream.topics {
+            match current_stream.topics.iter().find(|t| t.name == topic.name) {
+                Some(current_topic) => {
+                    plan_topic(&mut changes, &stream.name, topic, current_topic, prune)
+                }
+                None => plan_new_topic(&mut changes, &stream.name, topic),
+            }
+        }
+    }
+
+    for user in &desired.users {
+        let Some(current_user) = current.users.iter().find(|u| u.username == user.username) else {
+            if user.password.is_none() {
+                bail!("Password is required to create user: {}", user.username);
+            }
+            changes.push(TopologyChange::CreateUser(user.clone()));
+            continue;
+        };
+
+        if user.status != current_user.status {
+            changes.push(TopologyChange::UpdateUserStatus {
+                username: user.username.clone(),
+                status: user.status,
+            });
+        }
+
+        if let Some(permissions) = &user.permissions {
+            let current_permissions = current_user
+                .permissions
+                .as_ref()
+                .map(|permissions| permissions.normalized(current))
+                .unwrap_or_default();
+            if permissions.normalized(current) != current_permissions {
+                changes.push(TopologyChange::UpdatePermissions {
+                    username: user.username.clone(),
+                    permissions: permissions.clone(),
+                });
+            }
+        }
+    }
+
+    if prune {
+        for user in &current.users {
+            if !user.protected && !desired.users.iter().any(|u| u.username == user.username) {
+                changes.push(TopologyChange::DeleteUser {
+                    username: user.username.clone(),
+                });
+            }
+        }
+    }
+
+    for token in &desired.personal_access_tokens {
+        if !current
+            .personal_access_tokens
+            .iter()
+            .any(|t| t.name == token.name)
+        {
+            changes.push(TopologyChange::CreatePersonalAccessToken(token.clone()));
+        }
+    }
+
+    if prune {
+        for token in &current.personal_access_tokens {
+            if !desired
+                .personal_access_tokens
+                .iter()
+                .any(|t| t.name == token.name)
+            {
+                changes.push(TopologyChange::DeletePersonalAccessToken {
+                    name: token.name.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(changes)
+}
+
+fn plan_new_topic(changes: &mut Vec<TopologyChange>, stream: &str, topic: &TopicSpec) {
+    changes.push(TopologyChange::CreateTopic {
+        stream: stream.to_owned(),
+        topic: TopicSpec {
+            consumer_groups: vec![],
+            ..topic.clone()
+        },
+    });
+    for group in &topic.consumer_groups {
+        changes.push(TopologyChange::CreateConsumerGroup {
+            stream: stream.to_owned(),
+            topic: topic.name.clone(),
+            group: group.clone(),
+        });
+    }
+}
+
+fn plan_topic(
+    changes: &mut Vec<TopologyChange>,
+    stream: &str,
+    topic: &TopicSpec,
+    current: &TopicSpec,
+    prune: bool,
+) {
+    let mut updates = vec![];
+    diff_attribute(
+        &mut updates,
+        "compression algorithm",
+        &topic.compression_algorithm,
+        &current.compression_algorithm,
+    );
+    diff_attribute(
+        &mut updates,
+        "message expiry",
+        &topic.message_expiry,
+        &current.message_expiry,
+    );
+    diff_attribute(
+        &mut updates,
+        "max topic size",
+        &topic.max_topic_size,
+        &current.max_topic_size,
+    );
+    diff_attribute(
+        &mut updates,
+        "replication factor",
+        &topic.replication_factor,
+        &current.replication_factor,
+    );
+    if !updates.is_empty() {
+        changes.push(TopologyChange::UpdateTopic {
+            stream: stream.to_owned(),
+            topic: TopicSpec {
+                compression_algorithm: topic
+                    .compression_algorithm
+                    .or(current.compression_algorithm),
+                message_expiry: topic.message_expiry.or(current.message_expiry),
+                max_topic_size: topic.max_topic_size.or(current.max_topic_size),
+                replication_factor: topic.replication_factor.or(current.replication_factor),
+                consumer_groups: vec![],
+                ..topic.clone()
+            },
+            changes: updates,
+        });
+    }
+
+    if topic.partitions > current.partitions {
+        changes.push(TopologyChange::CreatePartitions {
+            stream: stream.to_owned(),
+            topic: topic.name.clone(),
+            count: topic.partitions - current.partitions,
+        });
+    } else if prune && topic.partitions < current.partitions {
+        changes.push(TopologyChange::DeletePartitions {
+            stream: stream.to_owned(),
+            topic: topic.name.clone(),
+            count: current.partitions - topic.partitions,
+        });
+    }
+
+    if prune {
+        for group in &current.consumer_groups {
+            if !topic.consumer_groups.iter().any(|g| g.name == group.name) {
+                changes.push(TopologyChange::DeleteConsumerGroup {
+                    stream: stream.to_owned(),
+                    topic: topic.name.clone(),
+                    group: group.name.clone(),
+                });
+            }
+        }
+    }
+
+    for group in &topic.consumer_groups {
+        if !current.consumer_groups.iter().any(|g| g.name == group.name) {
+            changes.push(TopologyChange::CreateConsumerGroup {
+                stream: stream.to_owned(),
+                topic: topic.name.clone(),
+                group: group.clone(),
+            });
+        }
+    }
+}
+
+fn diff_attribute<T: Display + PartialEq>(
+    updates: &mut Vec<String>,
+    name: &str,
+    desired: &Option<T>,
+    current: &Option<T>,
+) {
+    if let Some(desired) = desired
+        && current.as_ref() != Some(desired)
+    {
+        match current {
+            Some(current) => updates.push(format!("{name}: {current} -> {desired}")),
+            None => updates.push(format!("{name}: {desired}")),
+        }
+    }
+}
+
+/// Applies the changes in order and returns the created personal access tokens. The
+/// permissions are resolved against the server state refreshed after the streams and
+/// topics have been changed, so that they can refer to the newly created ones.
+pub async fn apply(
+    client: &dyn Client,
+    changes: &[TopologyChange],
+    current: Topology,
+) -> Result<Vec<CreatedPersonalAccessToken>> {
+    let mut current = current;
+    let mut refresh_required = false;
+    let mut tokens = vec![];
+    for change in changes {
+        if change.is_user_change() && refresh_required {
+            current = Topology::fetch(client).await?;
+            refresh_required = false;
+        }
+
+        apply_change(client, change, &current, &mut tokens)
+            .await
+            .with_context(|| format!("Problem applying change: {change}"))?;
+        refresh_required |= !change.is_user_change();
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedPersonalAccessToken {
+    pub name: String,
+    pub token: String,
+}
+
+async fn apply_change(
+    client: &dyn Client,
+    change: &TopologyChange,
+    current: &Topology,
+    tokens: &mut Vec<CreatedPersonalAccessToken>,
+) -> Result<()> {
+    match change {
+        TopologyChange::CreateStream(stream) => {
+            client.create_stream(&stream.name, stream.id).await?;
+        }
+        TopologyChange::DeleteStream { stream } => {
+            client.delete_stream(&Identifier::named(stream)?).await?;
+        }
+        TopologyChange::CreateTopic { stream, topic } => {
+            client
+                .create_topic(
+                    &Identifier::named(stream)?,
+                    &topic.name,
+                    topic.partitions,
+                    topic.compression_algorithm.unwrap_or_default(),
+                    topic.replication_factor,
+                    topic.id,
+                    topic.message_expiry.unwrap_or(IggyExpiry::ServerDefault),
+                    topic.max_topic_size.unwrap_or(MaxTopicSize::ServerDefault),
+                )
+                .await?;
+        }
+        TopologyChange::UpdateTopic { stream, topic, .. } => {
+            client
+                .update_topic(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(&topic.name)?,
+                    &topic.name,
+                    topic.compression_algorithm.unwrap_or_default(),
+                    topic.replication_factor,
+                    topic.message_expiry.unwrap_or(IggyExpiry::ServerDefault),
+                    topic.max_topic_size.unwrap_or(MaxTopicSize::ServerDefault),
+                )
+                .await?;
+        }
+        TopologyChange::CreatePartitions {
+            stream,
+            topic,
+            count,
+        } => {
+            client
+                .create_partitions(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    *count,
+                )
+                .await?;
+        }
+        TopologyChange::DeletePartitions {
+            stream,
+            topic,
+            count,
+        } => {
+            client
+                .delete_partitions(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    *count,
+                )
+                .await?;
+        }
+        TopologyChange::DeleteTopic { stream, topic } => {
+            client
+                .delete_topic(&Identifier::named(stream)?, &Identifier::named(topic)?)
+                .await?;
+        }
+        TopologyChange::CreateConsumerGroup {
+            stream,
+            topic,
+            group,
+        } => {
+            client
+                .create_consumer_group(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    &group.name,
+                    group.id,
+                )
+                .await?;
+        }
+        TopologyChange::DeleteConsumerGroup {
+            stream,
+            topic,
+            group,
+        } => {
+            client
+                .delete_consumer_group(
+                    &Identifier::named(stream)?,
+                    &Identifier::named(topic)?,
+                    &Identifier::named(group)?,
+                )
+                .await?;
+        }
+        TopologyChange::CreateUser(user) => {
+            let permissions = user
+                .permissions
+                .as_ref()
+                .map(|permissions| permissions.to_permissions(current))
+                .transpose()?;
+            client
+                .create_user(
+                    &user.username,
+                    user.password.as_deref().unwrap_or_default(),
+                    user.status,
+                    permissions,
+                )
+                .await?;
+        }
+        TopologyChange::UpdateUserStatus { username, status } => {
+            client
+                .update_user(&Identifier::named(username)?, None, Some(*status))
+                .await?;
+        }
+        TopologyChange::UpdatePermissions {
+            username,
+            permissions,
+        } => {
+            client
+                .update_permissions(
+                    &Identifier::named(username)?,
+                    Some(permissions.to_permissions(current)?),
+                )
+                .await?;
+        }
+        TopologyChange::DeleteUser { username } => {
+            client.delete_user(&Identifier::named(username)?).await?;
+        }
+        TopologyChange::CreatePersonalAccessToken(token) => {
+            let raw_token = client
+                .create_personal_access_token(
+                    &token.name,
+                    token.expiry.unwrap_or(IggyExpiry::NeverExpire),
+                )
+                .await?;
+            tokens.push(CreatedPersonalAccessToken {
+                name: token.name.clone(),
+                token: raw_token.token,
+            });
+        }
+        TopologyChange::DeletePersonalAccessToken { name } => {
+            client.delete_personal_access_token(name).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Result of the topology diff or apply, printed for the JSON and YAML output.
+#[derive(Debug, Serialize)]
+pub struct TopologyChangesSummary {
+    pub changes: Vec<String>,
+    pub applied: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub personal_access_tokens: Vec<CreatedPersonalAccessToken>,
+}
+
+/// Human-readable values, which can be parsed back to exactly the same value.
+mod expiry_format {
+    use iggy_common::IggyExpiry;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<IggyExpiry>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_str(&value.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<IggyExpiry>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| IggyExpiry::from_str(&value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+mod size_format {
+    use iggy_common::MaxTopicSize;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<MaxTopicSize>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            // The human-readable size is rounded, the exact bytes are used when it differs
+            Some(MaxTopicSize::Custom(size)) => {
+                let text = size.to_string();
+                match MaxTopicSize::from_str(&text) {
+                    Ok(MaxTopicSize::Custom(parsed)) if parsed == *size => {
+                        serializer.serialize_str(&text)
+                    }
+                    _ => serializer.serialize_str(&format!("{} B", size.as_bytes_u64())),
+                }
+            }
+            Some(value) => serializer.serialize_str(&value.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawSize {
+        Text(String),
+        Bytes(u64),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<MaxTopicSize>, D::Error> {
+        Ok(match Option::<RawSize>::deserialize(deserializer)? {
+            Some(RawSize::Text(value)) => {
+                Some(MaxTopicSize::from_str(&value).map_err(serde::de::Error::custom)?)
+            }
+            Some(RawSize::Bytes(value)) => Some(MaxTopicSize::from(value)),
+            None => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOPOLOGY: &str = r#"
+streams:
+  - name: orders
+    id: 1
+    topics:
+      - name: created
+        partitions: 3
+        message_expiry: 7days
+        max_topic_size: 10 GB
+        consumer_groups:
+          - name: billing
+users:
+  - username: app
+    password: secret
+    permissions:
+      global: [read_streams]
+      streams:
+        orders:
+          permissions: [read_stream]
+          topics:
+            created: [send_messages]
+"#;
+
+    fn topology() -> Topology {
+        let topology: Topology = serde_yml::from_str(TOPOLOGY).unwrap();
+        topology.validate().unwrap();
+        topology
+    }
+
+    #[test]
+    fn should_plan_creating_all_resources_for_empty_server() {
+        let changes = plan(&topology(), &Topology::default(), false).unwrap();
+        let changes = changes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            vec![
+                "+ create stream: orders with ID: 1",
+                "+ create topic: created with 3 partitions in stream: orders",
+                "+ create consumer group: billing for topic: created in stream: orders",
+                "+ create user: app with status: active",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_plan_no_changes_for_matching_server() {
+        let mut current = topology();
+        current.streams[0].topics[0].id = Some(1);
+        current.streams[0].topics[0].compression_algorithm = Some(CompressionAlgorithm::None);
+        current.users[0].password = None;
+        let permissions = current.users[0]
+            .permissions
+            .as_ref()
+            .unwrap()
+            .to_permissions(&current)
+            .unwrap();
+        current.users[0].permissions =
+            Some(PermissionsSpec::from_permissions(&permissions, &current));
+
+        assert!(plan(&topology(), &current, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_plan_updates_and_deletions_only_when_pruning() {
+        let mut current = topology();
+        current.streams[0].topics[0].partitions = 5;
+        current.streams[0].topics[0].message_expiry = Some(IggyExpiry::NeverExpire);
+        current.streams[0].topics[0].consumer_groups[0].name = "legacy".to_owned();
+        current.streams.push(StreamSpec {
+            name: "old".to_owned(),
+            ..Default::default()
+        });
+        current.users[0].status = UserStatus::Inactive;
+
+        let changes = plan(&topology(), &current, false).unwrap();
+        let changes = changes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                "~ update topic: created in stream: orders (message expiry: never_expire -> 7days)",
+                "+ create consumer group: billing for topic: created in stream: orders",
+                "~ update status of user: app to: active",
+            ]
+        );
+
+        let changes = plan(&topology(), &current, true).unwrap();
+        let changes = changes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                "- delete stream: old",
+                "~ update topic: created in stream: orders (message expiry: never_expire -> 7days)",
+                "- delete 2 partitions from topic: created in stream: orders",
+                "- delete consumer group: legacy for topic: created in stream: orders",
+                "+ create consumer group: billing for topic: created in stream: orders",
+                "~ update status of user: app to: active",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_resolve_permissions_by_stream_and_topic_names() {
+        let mut current = topology();
+        current.streams[0].topics[0].id = Some(2);
+        let permissions = current.users[0]
+            .permissions
+            .as_ref()
+            .unwrap()
+            .to_permissions(&current)
+            .unwrap();
+
+        assert!(permissions.global.read_streams);
+        let stream = &permissions.streams.as_ref().unwrap()[&1];
+        assert!(stream.read_stream);
+        assert!(!stream.manage_stream);
+        assert!(stream.topics.as_ref().unwrap()[&2].send_messages);
+    }
+
+    #[test]
+    fn should_fail_for_invalid_permission_name() {
+        let topology: Topology = serde_yml::from_str(
+            "users:\n  - username: app\n    permissions:\n      global: [read_everything]\n",
+        )
+        .unwrap();
+        assert!(topology.validate().is_err());
+    }
+
+    #[test]
+    fn should_serialize_topology_back_to_the_same_value() {
+        let topology = topology();
+        for format in [
+            TopologyFormat::Yaml,
+            TopologyFormat::Json,
+            TopologyFormat::Toml,
+        ] {
+            let content = topology.to_string(format).unwrap();
+            let parsed: Topology = match format {
+                TopologyFormat::Yaml => serde_yml::from_str(&content).unwrap(),
+                TopologyFormat::Json => serde_json::from_str(&content).unwrap(),
+                TopologyFormat::Toml => toml::from_str(&content).unwrap(),
+            };
+            assert_eq!(parsed, topology);
+        }
+    }
+}
@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
+use iggy_common::{CHANGE_PASSWORD, Identifier};
 use passterm::{Stream, isatty, prompt_password_stdin, prompt_password_tty};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct ChangePasswordCmd {
@@ -58,7 +59,11 @@ impl CliCommand for ChangePasswordCmd {
         self.use_tracing()
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let current_password = match &self.current_password {
             Some(password) => password.clone(),
             None => {
@@ -91,6 +96,15 @@ impl CliCommand for ChangePasswordCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                CHANGE_PASSWORD,
+                json!({
+                    "user_id": self.user_id.to_string(),
+                }),
+            );
+        }
+
         if self.use_tracing() {
             event!(target: PRINT_TARGET, Level::INFO, "Password for user with ID: {} changed", self.user_id);
         } else {
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::Permissions;
@@ -56,8 +56,12 @@ impl CliCommand for CreateUserCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
+        let user = client
             .create_user(
                 &self.create_user.username,
                 &self.create_user.password,
@@ -72,6 +76,10 @@ impl CliCommand for CreateUserCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print(&user);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "User with username: {} and password: {} created",
             self.create_user.username, self.create_user.password
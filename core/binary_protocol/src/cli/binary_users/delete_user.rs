@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
 use iggy_common::delete_user::DeleteUser;
+use iggy_common::{DELETE_USER, Identifier};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct DeleteUserCmd {
@@ -42,7 +43,11 @@ impl CliCommand for DeleteUserCmd {
         format!("delete user with ID: {}", self.delete_user.user_id)
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .delete_user(&self.delete_user.user_id)
             .await
@@ -53,6 +58,15 @@ impl CliCommand for DeleteUserCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                DELETE_USER,
+                json!({
+                    "user_id": self.delete_user.user_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "User with ID: {} deleted", self.delete_user.user_id);
 
         Ok(())
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -43,12 +43,20 @@ impl CliCommand for GetUserCmd {
         format!("get user with ID: {}", self.get_user.user_id)
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let user = client
             .get_user(&self.get_user.user_id)
             .await
             .with_context(|| format!("Problem getting user with ID: {}", self.get_user.user_id))?;
 
+        if format.is_structured() {
+            return format.print(&user);
+        }
+
         if user.is_none() {
             event!(
                 target: PRINT_TARGET,
@@ -17,7 +17,7 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
@@ -62,12 +62,20 @@ impl CliCommand for GetUsersCmd {
         format!("list users in {mode} mode")
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         let users = client
             .get_users()
             .await
             .with_context(|| String::from("Problem getting list of users"))?;
 
+        if format.is_structured() {
+            return format.print(&users);
+        }
+
         if users.is_empty() {
             event!(target: PRINT_TARGET, Level::INFO, "No users found!");
             return Ok(());
@@ -17,11 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy_common::update_permissions::UpdatePermissions;
-use iggy_common::{Identifier, Permissions};
+use iggy_common::{Identifier, Permissions, UPDATE_PERMISSIONS};
+use serde_json::json;
 use tracing::{Level, event};
 
 pub struct UpdatePermissionsCmd {
@@ -48,7 +49,11 @@ impl CliCommand for UpdatePermissionsCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .update_permissions(
                 &self.update_permissions.user_id,
@@ -62,6 +67,15 @@ impl CliCommand for UpdatePermissionsCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                UPDATE_PERMISSIONS,
+                json!({
+                    "user_id": self.update_permissions.user_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "Permissions for user with ID: {} updated",
             self.update_permissions.user_id
@@ -17,12 +17,12 @@
  */
 
 use crate::Client;
-use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
-use iggy_common::Identifier;
-use iggy_common::UserStatus;
 use iggy_common::update_user::UpdateUser;
+use iggy_common::{Identifier, UPDATE_USER, UserStatus};
+use serde_json::json;
 use tracing::{Level, event};
 
 #[derive(Debug, Clone)]
@@ -71,7 +71,11 @@ impl CliCommand for UpdateUserCmd {
         )
     }
 
-    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        format: OutputFormat,
+    ) -> anyhow::Result<(), anyhow::Error> {
         client
             .update_user(
                 &self.update_user.user_id,
@@ -87,6 +91,15 @@ impl CliCommand for UpdateUserCmd {
                 )
             })?;
 
+        if format.is_structured() {
+            return format.print_status(
+                UPDATE_USER,
+                json!({
+                    "user_id": self.update_user.user_id.to_string(),
+                }),
+            );
+        }
+
         event!(target: PRINT_TARGET, Level::INFO,
             "User with ID: {} updated with {}",
             self.update_user.user_id, self.get_message()
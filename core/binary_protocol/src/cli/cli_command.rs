@@ -19,8 +19,55 @@
 use crate::Client;
 use anyhow::{Error, Result};
 use async_trait::async_trait;
+use serde::Serialize;
+use tracing::{Level, event};
 
 pub static PRINT_TARGET: &str = "iggy::cli::output";
+pub static PRINT_STRUCTURED_TARGET: &str = "iggy::cli::structured";
+
+/// Format of the command output, either human-readable (table) or machine-readable (JSON, YAML).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+}
+
+impl OutputFormat {
+    pub fn is_structured(&self) -> bool {
+        *self != OutputFormat::Table
+    }
+
+    /// Prints the serde representation of the command result, nothing is printed for the table format.
+    pub fn print<T: Serialize + ?Sized>(&self, value: &T) -> Result<()> {
+        let output = match self {
+            OutputFormat::Table => return Ok(()),
+            OutputFormat::Json => serde_json::to_string_pretty(value)?,
+            OutputFormat::Yaml => format!("---\n{}", serde_yml::to_string(value)?),
+        };
+        event!(target: PRINT_STRUCTURED_TARGET, Level::INFO, "{}", output.trim_end());
+        Ok(())
+    }
+
+    /// Prints the code of the command (e.g. `stream.delete`), its status and the affected IDs,
+    /// for the commands which don't return any entity.
+    pub fn print_status<T: Serialize>(&self, command: &str, details: T) -> Result<()> {
+        self.print(&CommandStatus {
+            command,
+            status: "success",
+            details,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct CommandStatus<'a, T> {
+    command: &'a str,
+    status: &'a str,
+    #[serde(flatten)]
+    details: T,
+}
 
 #[async_trait]
 pub trait CliCommand {
@@ -34,5 +81,6 @@ pub trait CliCommand {
     fn connection_required(&self) -> bool {
         true
     }
-    async fn execute_cmd(&mut self, client: &dyn Client) -> Result<(), Error>;
+    async fn execute_cmd(&mut self, client: &dyn Client, format: OutputFormat)
+    -> Result<(), Error>;
 }
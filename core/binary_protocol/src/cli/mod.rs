@@ -27,6 +27,7 @@ pub mod binary_segments;
 pub mod binary_streams;
 pub mod binary_system;
 pub mod binary_topics;
+pub mod binary_topology;
 pub mod binary_users;
 pub mod cli_command;
 pub mod utils;
@@ -18,8 +18,8 @@
 
 use async_trait::async_trait;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, Health, IggyDuration, IggyError, Snapshot, SnapshotCompression,
+    Stats, SystemSnapshotType,
 };
 
 /// This trait defines the methods to interact with the system module.
@@ -43,6 +43,11 @@ pub trait SystemClient {
     async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self) -> Result<(), IggyError>;
+    /// Get the health of the server, including the results of the readiness checks
+    /// such as the state loading, free disk space or archiver reachability.
+    ///
+    /// Authentication is required.
+    async fn get_health(&self) -> Result<Health, IggyError>;
     async fn heartbeat_interval(&self) -> IggyDuration;
     /// Capture and package the current system state as a snapshot.
     ///
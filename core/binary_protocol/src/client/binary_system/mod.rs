@@ -21,13 +21,14 @@ use crate::utils::mapper;
 use crate::{BinaryClient, SystemClient};
 use iggy_common::get_client::GetClient;
 use iggy_common::get_clients::GetClients;
+use iggy_common::get_health::GetHealth;
 use iggy_common::get_me::GetMe;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::get_stats::GetStats;
 use iggy_common::ping::Ping;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, Health, IggyDuration, IggyError, Snapshot, SnapshotCompression,
+    Stats, SystemSnapshotType,
 };
 
 #[async_trait::async_trait]
@@ -64,6 +65,11 @@ impl<B: BinaryClient> SystemClient for B {
         Ok(())
     }
 
+    async fn get_health(&self) -> Result<Health, IggyError> {
+        let response = self.send_with_response(&GetHealth {}).await?;
+        mapper::map_health(response)
+    }
+
     async fn heartbeat_interval(&self) -> IggyDuration {
         self.get_heartbeat_interval()
     }
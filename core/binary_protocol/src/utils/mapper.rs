@@ -20,9 +20,10 @@ use bytes::Bytes;
 use iggy_common::{
     BytesSerializable, CacheMetrics, CacheMetricsKey, ClientInfo, ClientInfoDetails,
     CompressionAlgorithm, ConsumerGroup, ConsumerGroupDetails, ConsumerGroupInfo,
-    ConsumerGroupMember, ConsumerOffsetInfo, IdentityInfo, IggyByteSize, IggyError, IggyExpiry,
-    MaxTopicSize, Partition, Permissions, PersonalAccessTokenInfo, RawPersonalAccessToken, Stats,
-    Stream, StreamDetails, Topic, TopicDetails, UserInfo, UserInfoDetails, UserStatus,
+    ConsumerGroupMember, ConsumerOffsetInfo, Health, HealthCheck, HealthStatus, IdentityInfo,
+    IggyByteSize, IggyError, IggyExpiry, MaxTopicSize, Partition, Permissions,
+    PersonalAccessTokenInfo, RawPersonalAccessToken, Stats, Stream, StreamDetails, Topic,
+    TopicDetails, UserInfo, UserInfoDetails, UserStatus,
 };
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -368,6 +369,47 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     })
 }
 
+pub fn map_health(payload: Bytes) -> Result<Health, IggyError> {
+    let status = HealthStatus::from_code(payload[0])?;
+    let checks_count = u32::from_le_bytes(
+        payload[1..5]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let mut checks = Vec::with_capacity(checks_count as usize);
+    let mut position = 5;
+    for _ in 0..checks_count {
+        let name_length = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        let name = from_utf8(&payload[position..position + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        position += name_length;
+        let check_status = HealthStatus::from_code(payload[position])?;
+        position += 1;
+        let details_length = u32::from_le_bytes(
+            payload[position..position + 4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 4;
+        let details = from_utf8(&payload[position..position + details_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        position += details_length;
+        checks.push(HealthCheck {
+            name,
+            status: check_status,
+            details,
+        });
+    }
+    Ok(Health { status, checks })
+}
+
 pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyError> {
     let partition_id = u32::from_le_bytes(
         payload[..4]
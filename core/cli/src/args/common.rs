@@ -25,6 +25,7 @@ use iggy_binary_protocol::cli::binary_streams::get_streams::GetStreamsOutput;
 use iggy_binary_protocol::cli::binary_system::stats::GetStatsOutput;
 use iggy_binary_protocol::cli::binary_topics::get_topics::GetTopicsOutput;
 use iggy_binary_protocol::cli::binary_users::get_users::GetUsersOutput;
+use iggy_binary_protocol::cli::cli_command::OutputFormat;
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub(crate) enum ListMode {
@@ -113,3 +114,21 @@ impl From<ListModeExt> for GetStatsOutput {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub(crate) enum OutputFormatArg {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+}
+
+impl From<OutputFormatArg> for OutputFormat {
+    fn from(format: OutputFormatArg) -> Self {
+        match format {
+            OutputFormatArg::Table => OutputFormat::Table,
+            OutputFormatArg::Json => OutputFormat::Json,
+            OutputFormatArg::Yaml => OutputFormat::Yaml,
+        }
+    }
+}
@@ -17,8 +17,9 @@
  */
 
 use clap::builder::NonEmptyStringValueParser;
-use clap::{ArgGroup, Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
 use iggy::prelude::*;
+use iggy_binary_protocol::cli::binary_message::tail_messages::TailMessagesFormat;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -64,6 +65,24 @@ pub(crate) enum MessageAction {
     ///  iggy message flush stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "f")]
     Flush(FlushMessagesArgs),
+    /// Tail messages from given topic ID and given stream ID
+    ///
+    /// Messages are polled from all the partitions of the topic (or the given
+    /// partition), or from the partitions assigned to the consumer group member,
+    /// and printed as they arrive until the command is stopped, the given number
+    /// of messages is printed or the given duration elapses.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message tail 1 2
+    ///  iggy message tail --first --message-count 10 stream topic
+    ///  iggy message tail --timestamp 1718000000000000 --format json stream topic
+    ///  iggy message tail --consumer-group group --duration 1m stream topic
+    ///  iggy message tail --filter source=orders --show-headers stream topic
+    #[clap(verbatim_doc_comment, visible_alias = "t")]
+    Tail(TailMessagesArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -230,6 +249,123 @@ pub(crate) struct FlushMessagesArgs {
     pub(crate) fsync: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub(crate) enum TailFormat {
+    #[default]
+    Text,
+    Json,
+    Hex,
+}
+
+impl From<TailFormat> for TailMessagesFormat {
+    fn from(format: TailFormat) -> Self {
+        match format {
+            TailFormat::Text => TailMessagesFormat::Text,
+            TailFormat::Json => TailMessagesFormat::Json,
+            TailFormat::Hex => TailMessagesFormat::Hex,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("polling_strategy").multiple(false))]
+pub(crate) struct TailMessagesArgs {
+    /// ID of the stream from which messages will be tailed
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which messages will be tailed
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which messages will be tailed
+    ///
+    /// If not specified, messages are tailed from all the partitions
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "consumer_group")]
+    pub(crate) partition_id: Option<u32>,
+    /// Consumer group which will be joined to tail messages
+    ///
+    /// Messages are polled from the partitions assigned to the group member
+    /// starting from the stored offsets, which are committed after each batch.
+    /// Consumer group ID can be specified as a consumer group name or ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short = 'g', long, value_parser = clap::value_parser!(Identifier), conflicts_with = "polling_strategy")]
+    pub(crate) consumer_group: Option<Identifier>,
+    /// Start tailing from the given offset
+    #[clap(short, long, group = "polling_strategy")]
+    pub(crate) offset: Option<u64>,
+    /// Start tailing from the given timestamp
+    ///
+    /// Timestamp must be specified in microseconds since Unix epoch
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, group = "polling_strategy")]
+    pub(crate) timestamp: Option<u64>,
+    /// Start tailing from the first message in the partitions
+    #[clap(short, long, default_value_t = false, group = "polling_strategy")]
+    pub(crate) first: bool,
+    /// Start tailing after the last message in the partitions (default)
+    #[clap(short, long, default_value_t = false, group = "polling_strategy")]
+    pub(crate) last: bool,
+    /// Start tailing after the stored consumer offset
+    #[clap(short, long, default_value_t = false, group = "polling_strategy")]
+    pub(crate) next: bool,
+    /// Regular consumer which will poll messages
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, default_value_t = Identifier::default(), value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer: Identifier,
+    /// Format in which the message payloads are printed
+    ///
+    /// JSON payloads are pretty printed, other payloads are printed as text
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_enum, default_value_t = TailFormat::default())]
+    pub(crate) format: TailFormat,
+    /// Include the message headers in the output
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) show_headers: bool,
+    /// Print only the messages with the header of the given value, as key=value
+    ///
+    /// Option can be specified multiple times, in which case
+    /// all the headers must match.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long = "filter", value_name = "KEY=VALUE", value_parser = parse_header_filter)]
+    pub(crate) filters: Vec<(HeaderKey, String)>,
+    /// Stop after the given number of messages is printed
+    #[clap(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub(crate) message_count: Option<u64>,
+    /// Stop after the given duration, e.g. 30s or 5m
+    #[clap(short, long, value_parser = clap::value_parser!(IggyDuration))]
+    pub(crate) duration: Option<IggyDuration>,
+    /// Commit the offsets of the processed messages
+    ///
+    /// Offsets are stored for the regular consumer in each partition
+    /// after processing the polled messages. Offsets are always committed
+    /// when tailing as the consumer group member.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, default_value_t = false)]
+    pub(crate) commit: bool,
+    /// Maximum number of messages polled from the partition at once
+    #[clap(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Interval between polls when no new messages are available
+    #[clap(long, default_value = "100ms", value_parser = clap::value_parser!(IggyDuration))]
+    pub(crate) poll_interval: IggyDuration,
+}
+
+/// Parse Header Key and expected string Value separated by a '='
+fn parse_header_filter(s: &str) -> Result<(HeaderKey, String), IggyError> {
+    let Some((key, value)) = s.split_once('=') else {
+        return Err(IggyError::InvalidFormat);
+    };
+
+    let key = HeaderKey::from_str(key)?;
+    Ok((key, value.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,4 +428,19 @@ mod tests {
         let result = parse_key_val("key:uint8:69.42");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn parse_header_filter_should_parse_key_and_value() {
+        let result = parse_header_filter("source=orders=eu");
+        assert!(result.is_ok());
+        let (key, value) = result.unwrap();
+        assert_eq!(key, HeaderKey::from_str("source").unwrap());
+        assert_eq!(value, "orders=eu");
+    }
+
+    #[test]
+    fn parse_header_filter_without_value_should_return_err() {
+        let result = parse_header_filter("source");
+        assert!(result.is_err());
+    }
 }
@@ -30,6 +30,7 @@ use system::SnapshotArgs;
 
 use crate::args::{
     client::ClientAction,
+    common::OutputFormatArg,
     consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction,
     context::ContextAction,
@@ -37,8 +38,9 @@ use crate::args::{
     partition::PartitionAction,
     personal_access_token::PersonalAccessTokenAction,
     stream::StreamAction,
-    system::{PingArgs, StatsArgs},
+    system::{PingArgs, StatsArgs, TopArgs},
     topic::TopicAction,
+    topology::{ApplyTopologyArgs, DiffTopologyArgs, ExportTopologyArgs},
 };
 
 #[cfg(feature = "login-session")]
@@ -59,6 +61,7 @@ pub(crate) mod segment;
 pub(crate) mod stream;
 pub(crate) mod system;
 pub(crate) mod topic;
+pub(crate) mod topology;
 pub(crate) mod user;
 
 static CARGO_BIN_NAME: &str = env!("CARGO_BIN_NAME");
@@ -84,6 +87,14 @@ pub(crate) struct CliOptions {
     #[clap(short, long, default_value_t = false)]
     pub(crate) quiet: bool,
 
+    /// Output format of the command results
+    ///
+    /// Table is the human-readable output, while JSON and YAML formats print
+    /// the serialized command results (e.g. stream or topic details) only,
+    /// which is useful for scripting. Commands without results print nothing.
+    #[clap(short, long, value_enum, default_value_t = OutputFormatArg::Table, verbatim_doc_comment)]
+    pub(crate) output: OutputFormatArg,
+
     /// Debug mode (verbose printing to given file)
     #[clap(short, long)]
     pub(crate) debug: Option<PathBuf>,
@@ -161,6 +172,19 @@ pub(crate) enum Command {
     /// Server OS name, version, etc. are also collected.
     #[clap(verbatim_doc_comment)]
     Stats(StatsArgs),
+    /// interactive iggy server dashboard
+    ///
+    /// Display live throughput of streams, topics and partitions computed from
+    /// successive statistics polls, connected clients, consumer groups with their
+    /// members and lag, and segment counts and sizes. Select a stream or topic
+    /// and press enter to drill into it, press t in the topic view to tail its
+    /// messages, c to switch to the connected clients and q to quit.
+    ///
+    /// Examples:
+    ///  iggy top
+    ///  iggy top --interval 5s
+    #[clap(verbatim_doc_comment)]
+    Top(TopArgs),
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
@@ -185,6 +209,43 @@ pub(crate) enum Command {
     /// context operations
     #[command(subcommand, visible_alias = "ctx")]
     Context(ContextAction),
+    /// apply topology file to iggy server
+    ///
+    /// Reconcile the server with the declarative topology file describing streams,
+    /// topics (partitions, compression, message expiry, max size, replication factor),
+    /// consumer groups, users with their permissions and personal access tokens
+    /// of the current user. Resources are matched by name, missing ones are created
+    /// and the differing ones are updated. Resources missing in the file are deleted
+    /// only when pruning is enabled. User passwords are used only to create the users,
+    /// ${NAME} placeholders in the file are replaced with environment variables.
+    ///
+    /// Examples:
+    ///  iggy apply -f topology.yaml
+    ///  iggy apply --dry-run --prune -f topology.yaml
+    #[clap(verbatim_doc_comment)]
+    Apply(ApplyTopologyArgs),
+    /// show changes required to apply topology file
+    ///
+    /// Print the changes required to reconcile the server with the topology file,
+    /// the same as apply with the dry run option.
+    ///
+    /// Examples:
+    ///  iggy diff -f topology.yaml
+    ///  iggy diff --prune -f topology.json
+    #[clap(verbatim_doc_comment)]
+    Diff(DiffTopologyArgs),
+    /// export iggy server topology
+    ///
+    /// Export streams, topics, consumer groups, users with their permissions and
+    /// personal access tokens of the current user to the topology file, which can be
+    /// applied to the other server. User passwords are not exported and must be
+    /// added to the file to create the users.
+    ///
+    /// Examples:
+    ///  iggy export-topology
+    ///  iggy export-topology -f topology.yaml
+    #[clap(verbatim_doc_comment)]
+    ExportTopology(ExportTopologyArgs),
     #[cfg(feature = "login-session")]
     /// login to Iggy server
     ///
@@ -247,6 +308,7 @@ impl IggyMergedConsoleArgs {
     pub fn from_context(context: ContextConfig, args: IggyConsoleArgs) -> Self {
         let merged_cli_options = CliOptions {
             quiet: args.cli.quiet,
+            output: args.cli.output,
             debug: args.cli.debug,
             username: args.cli.username.or(context.username),
             password: args.cli.password.or(context.password),
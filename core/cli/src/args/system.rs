@@ -18,7 +18,7 @@
 
 use crate::args::common::ListModeExt;
 use clap::Args;
-use iggy::prelude::{SnapshotCompression, SystemSnapshotType};
+use iggy::prelude::{IggyDuration, SnapshotCompression, SystemSnapshotType};
 use iggy_binary_protocol::cli::utils::login_session_expiry::LoginSessionExpiry;
 
 #[derive(Debug, Clone, Args)]
@@ -46,6 +46,16 @@ pub(crate) struct StatsArgs {
     pub(crate) output: ListModeExt,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopArgs {
+    /// Interval between the successive server polls, e.g. 1s or 500ms
+    #[clap(short, long, default_value = "1s", value_parser = clap::value_parser!(IggyDuration))]
+    pub(crate) interval: IggyDuration,
+    /// Number of the most recent messages displayed in the topic tail view
+    #[clap(short, long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) tail_count: u32,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct SnapshotArgs {
     /// Specify snapshot compression method.
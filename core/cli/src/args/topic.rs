@@ -17,8 +17,9 @@
  */
 
 use crate::args::common::ListMode;
-use clap::{Args, Subcommand};
-use iggy::prelude::{CompressionAlgorithm, Identifier, IggyExpiry, MaxTopicSize};
+use clap::{ArgGroup, Args, Subcommand};
+use iggy::prelude::{CompressionAlgorithm, Identifier, IggyError, IggyExpiry, MaxTopicSize};
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum TopicAction {
@@ -93,6 +94,38 @@ pub(crate) enum TopicAction {
     ///  iggy topic purge 2 debugs
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(TopicPurgeArgs),
+    /// Export messages from topic with given ID in given stream ID to archive file
+    ///
+    /// Messages (with their IDs, timestamps, headers and payloads) are exported
+    /// from all or selected partitions, in the given offset or time range, into
+    /// the compressed file describing the exported topic. The messages appended
+    /// to the partitions after the export has started are not exported.
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic export 1 1 topic.iggy
+    ///  iggy topic export prod orders orders.iggy --partitions 1,2
+    ///  iggy topic export prod orders orders.iggy --from-offset 100 --to-offset 200
+    ///  iggy topic export prod orders orders.iggy --from-timestamp 1718000000000000
+    #[clap(verbatim_doc_comment, visible_alias = "e")]
+    Export(TopicExportArgs),
+    /// Import messages from archive file to topic with given ID in given stream ID
+    ///
+    /// Messages are sent to the same partitions they were exported from, unless
+    /// the partitions are remapped or the balanced partitioning is used. Progress
+    /// is stored in the checkpoint file (archive file name with .checkpoint suffix)
+    /// after each sent batch, so that the interrupted import can be resumed.
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic import 1 1 topic.iggy
+    ///  iggy topic import test orders orders.iggy --partition-map 1:2,2:1
+    ///  iggy topic import test orders orders.iggy --balanced
+    ///  iggy topic import test orders orders.iggy --resume
+    #[clap(verbatim_doc_comment, visible_alias = "i")]
+    Import(TopicImportArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -215,3 +248,85 @@ pub(crate) struct TopicPurgeArgs {
     #[arg(value_parser = clap::value_parser!(Identifier))]
     pub(crate) topic_id: Identifier,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicExportArgs {
+    /// Stream ID of the exported topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to export
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Path of the archive file to create
+    pub(crate) file: PathBuf,
+    /// Comma separated list of partition IDs to export, all partitions by default
+    #[clap(short, long, value_delimiter = ',', value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partitions: Vec<u32>,
+    /// Offset of the first exported message in each partition
+    #[clap(long)]
+    pub(crate) from_offset: Option<u64>,
+    /// Offset of the last exported message in each partition
+    #[clap(long)]
+    pub(crate) to_offset: Option<u64>,
+    /// Timestamp (microseconds since Unix epoch) of the first exported message
+    #[clap(long)]
+    pub(crate) from_timestamp: Option<u64>,
+    /// Timestamp (microseconds since Unix epoch) of the last exported message
+    #[clap(long)]
+    pub(crate) to_timestamp: Option<u64>,
+    /// Number of messages polled from the partition at once
+    #[clap(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Compression level of the archive file (1-22)
+    #[clap(long, default_value_t = 3, value_parser = clap::value_parser!(i32).range(1..=22))]
+    pub(crate) compression_level: i32,
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("partitioning").multiple(false))]
+pub(crate) struct TopicImportArgs {
+    /// Stream ID of the target topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID of the target topic
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Path of the archive file to import
+    pub(crate) file: PathBuf,
+    /// Comma separated list of exported:target partition IDs
+    ///
+    /// Messages from the partitions which are not listed are sent
+    /// to the partitions with the same ID as the exported ones.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, value_delimiter = ',', value_parser = parse_partition_mapping, group = "partitioning")]
+    pub(crate) partition_map: Vec<(u32, u32)>,
+    /// Send messages using the balanced partitioning instead of preserving partitions
+    #[clap(long, default_value_t = false, group = "partitioning")]
+    pub(crate) balanced: bool,
+    /// Maximum number of messages sent at once
+    #[clap(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Resume the interrupted import from the checkpoint file
+    #[clap(long, default_value_t = false)]
+    pub(crate) resume: bool,
+}
+
+/// Parse exported and target partition IDs separated by a ':'
+fn parse_partition_mapping(s: &str) -> Result<(u32, u32), IggyError> {
+    let Some((source, target)) = s.split_once(':') else {
+        return Err(IggyError::InvalidFormat);
+    };
+
+    match (source.parse::<u32>(), target.parse::<u32>()) {
+        (Ok(source), Ok(target)) if source > 0 && target > 0 => Ok((source, target)),
+        _ => Err(IggyError::InvalidFormat),
+    }
+}
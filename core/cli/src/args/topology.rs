@@ -0,0 +1,54 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ApplyTopologyArgs {
+    /// Path of the topology file (YAML, JSON or TOML based on the extension)
+    #[arg(short, long)]
+    pub(crate) file: PathBuf,
+    /// Print the changes without applying them
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+    /// Delete the resources (and the excess partitions) missing in the topology file
+    ///
+    /// The root user and the user applying the topology are never deleted.
+    #[arg(short, long, default_value_t = false, verbatim_doc_comment)]
+    pub(crate) prune: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct DiffTopologyArgs {
+    /// Path of the topology file (YAML, JSON or TOML based on the extension)
+    #[arg(short, long)]
+    pub(crate) file: PathBuf,
+    /// Include the deletions of the resources (and the excess partitions) missing in the topology file
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) prune: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ExportTopologyArgs {
+    /// Path of the topology file (YAML, JSON or TOML based on the extension)
+    ///
+    /// Topology is printed as YAML on standard output when the file is not set.
+    #[arg(short, long, verbatim_doc_comment)]
+    pub(crate) file: Option<PathBuf>,
+}
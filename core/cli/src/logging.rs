@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use iggy_binary_protocol::cli::cli_command::PRINT_TARGET;
+use iggy_binary_protocol::cli::cli_command::{OutputFormat, PRINT_STRUCTURED_TARGET, PRINT_TARGET};
 use std::path::PathBuf;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
@@ -38,10 +38,20 @@ impl Logging {
         }
     }
 
-    pub(crate) fn init(&mut self, quiet: bool, debug: &Option<PathBuf>) -> &mut Self {
+    pub(crate) fn init(
+        &mut self,
+        quiet: bool,
+        output: OutputFormat,
+        debug: &Option<PathBuf>,
+    ) -> &mut Self {
         let mut layers = vec![];
 
-        let stdout_filter = filter::filter_fn(|metadata| metadata.target().contains(PRINT_TARGET));
+        // Only the serialized command results are printed for the JSON and YAML output
+        let structured = output.is_structured();
+        let stdout_filter = filter::filter_fn(move |metadata| match structured {
+            true => metadata.target() == PRINT_STRUCTURED_TARGET,
+            false => metadata.target().contains(PRINT_TARGET),
+        });
         let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
 
         let stdout_layer = fmt::Layer::default()
@@ -20,6 +20,7 @@ mod args;
 mod credentials;
 mod error;
 mod logging;
+mod top;
 
 use crate::args::{
     Command, IggyConsoleArgs, client::ClientAction, consumer_group::ConsumerGroupAction,
@@ -29,6 +30,7 @@ use crate::args::{
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
 use crate::logging::Logging;
+use crate::top::TopCmd;
 use args::context::ContextAction;
 use args::message::MessageAction;
 use args::partition::PartitionAction;
@@ -43,7 +45,7 @@ use iggy_binary_protocol::cli::binary_context::common::ContextManager;
 use iggy_binary_protocol::cli::binary_context::use_context::UseContextCmd;
 use iggy_binary_protocol::cli::binary_segments::delete_segments::DeleteSegmentsCmd;
 use iggy_binary_protocol::cli::binary_system::snapshot::GetSnapshotCmd;
-use iggy_binary_protocol::cli::cli_command::{CliCommand, PRINT_TARGET};
+use iggy_binary_protocol::cli::cli_command::{CliCommand, OutputFormat, PRINT_TARGET};
 use iggy_binary_protocol::cli::{
     binary_client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
     binary_consumer_groups::{
@@ -57,7 +59,7 @@ use iggy_binary_protocol::cli::{
     binary_context::get_contexts::GetContextsCmd,
     binary_message::{
         flush_messages::FlushMessagesCmd, poll_messages::PollMessagesCmd,
-        send_messages::SendMessagesCmd,
+        send_messages::SendMessagesCmd, tail_messages::TailMessagesCmd,
     },
     binary_partitions::{
         create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
@@ -73,8 +75,13 @@ use iggy_binary_protocol::cli::{
     },
     binary_system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     binary_topics::{
-        create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
-        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
+        create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, export_topic::ExportTopicCmd,
+        get_topic::GetTopicCmd, get_topics::GetTopicsCmd, import_topic::ImportTopicCmd,
+        purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
+    },
+    binary_topology::{
+        apply_topology::ApplyTopologyCmd, diff_topology::DiffTopologyCmd,
+        export_topology::ExportTopologyCmd,
     },
     binary_users::{
         change_password::ChangePasswordCmd,
@@ -154,6 +161,27 @@ fn get_command(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
             )),
+            TopicAction::Export(args) => Box::new(ExportTopicCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.file.clone(),
+                args.partitions.clone(),
+                args.from_offset,
+                args.to_offset,
+                args.from_timestamp,
+                args.to_timestamp,
+                args.batch_size,
+                args.compression_level,
+            )),
+            TopicAction::Import(args) => Box::new(ImportTopicCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.file.clone(),
+                args.partition_map.clone(),
+                args.balanced,
+                args.batch_size,
+                args.resume,
+            )),
         },
         Command::Partition(command) => match command {
             PartitionAction::Create(args) => Box::new(CreatePartitionsCmd::new(
@@ -178,6 +206,7 @@ fn get_command(
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats(args) => Box::new(GetStatsCmd::new(cli_options.quiet, args.output.into())),
+        Command::Top(args) => Box::new(TopCmd::new(args.interval, args.tail_count)),
         Command::Snapshot(args) => Box::new(GetSnapshotCmd::new(
             args.compression,
             args.snapshot_types,
@@ -300,6 +329,25 @@ fn get_command(
                 flush_args.partition_id,
                 flush_args.fsync,
             )),
+            MessageAction::Tail(tail_args) => Box::new(TailMessagesCmd::new(
+                tail_args.stream_id.clone(),
+                tail_args.topic_id.clone(),
+                tail_args.partition_id,
+                tail_args.consumer_group.clone(),
+                tail_args.consumer.clone(),
+                tail_args.offset,
+                tail_args.timestamp,
+                tail_args.first,
+                tail_args.next,
+                tail_args.format.into(),
+                tail_args.show_headers,
+                tail_args.filters.clone(),
+                tail_args.message_count,
+                tail_args.duration,
+                tail_args.commit,
+                tail_args.batch_size,
+                tail_args.poll_interval,
+            )),
         },
         Command::ConsumerOffset(command) => match command {
             ConsumerOffsetAction::Get(get_args) => Box::new(GetConsumerOffsetCmd::new(
@@ -324,6 +372,13 @@ fn get_command(
                 Box::new(UseContextCmd::new(use_args.context_name.clone()))
             }
         },
+        Command::Apply(args) => Box::new(ApplyTopologyCmd::new(
+            args.file.clone(),
+            args.dry_run,
+            args.prune,
+        )),
+        Command::Diff(args) => Box::new(DiffTopologyCmd::new(args.file.clone(), args.prune)),
+        Command::ExportTopology(args) => Box::new(ExportTopologyCmd::new(args.file.clone())),
         #[cfg(feature = "login-session")]
         Command::Login(login_args) => Box::new(LoginCmd::new(
             iggy_args.get_server_address().unwrap(),
@@ -349,7 +404,7 @@ async fn main() -> Result<(), IggyCmdError> {
     }
 
     let mut logging = Logging::new();
-    logging.init(args.cli.quiet, &args.cli.debug);
+    logging.init(args.cli.quiet, args.cli.output.into(), &args.cli.debug);
 
     let command = args.command.clone().unwrap();
 
@@ -385,12 +440,13 @@ async fn main() -> Result<(), IggyCmdError> {
     credentials.set_iggy_client(&client);
     credentials.login_user().await?;
 
+    let output: OutputFormat = cli_options.output.into();
     if command.use_tracing() {
         event!(target: PRINT_TARGET, Level::INFO, "Executing {}", command.explain());
-    } else {
+    } else if !output.is_structured() {
         println!("Executing {}", command.explain());
     }
-    command.execute_cmd(&client).await?;
+    command.execute_cmd(&client, output).await?;
 
     credentials.logout_user().await?;
 
@@ -0,0 +1,142 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod state;
+mod ui;
+
+use crate::top::state::App;
+use anyhow::Context;
+use async_trait::async_trait;
+use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers};
+use futures::StreamExt;
+use iggy::prelude::{Client, IggyDuration};
+use iggy_binary_protocol::cli::cli_command::{CliCommand, OutputFormat};
+use ratatui::DefaultTerminal;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Interactive dashboard with the live server statistics, refreshed every interval.
+pub(crate) struct TopCmd {
+    interval: IggyDuration,
+    tail_count: u32,
+}
+
+impl TopCmd {
+    pub(crate) fn new(interval: IggyDuration, tail_count: u32) -> Self {
+        Self {
+            interval,
+            tail_count,
+        }
+    }
+
+    async fn run(&self, terminal: &mut DefaultTerminal, client: &dyn Client) -> anyhow::Result<()> {
+        let mut app = App::new(self.tail_count);
+        let mut events = EventStream::new();
+        let mut refresh =
+            tokio::time::interval(self.interval.get_duration().max(MIN_REFRESH_INTERVAL));
+        refresh.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        app.refresh(client).await;
+        refresh.reset();
+
+        loop {
+            terminal
+                .draw(|frame| ui::draw(frame, &app))
+                .with_context(|| "Problem drawing the dashboard")?;
+
+            let event = tokio::select! {
+                _ = refresh.tick() => {
+                    if !app.paused {
+                        app.refresh(client).await;
+                    }
+                    continue;
+                }
+                event = events.next() => event,
+            };
+
+            let Some(event) = event else {
+                return Ok(());
+            };
+            let Event::Key(key) = event.with_context(|| "Problem reading terminal input")? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            // Changing the view fetches its data right away
+            let view_changed = match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(());
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    app.select_previous();
+                    false
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    app.select_next();
+                    false
+                }
+                KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => app.drill_down(),
+                KeyCode::Char('t') => app.tail(),
+                KeyCode::Esc | KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                    app.go_back()
+                }
+                KeyCode::Char('c') => {
+                    app.toggle_clients();
+                    true
+                }
+                KeyCode::Char('p') => {
+                    app.paused = !app.paused;
+                    false
+                }
+                KeyCode::Char('r') => true,
+                _ => false,
+            };
+            if view_changed {
+                app.refresh(client).await;
+                refresh.reset();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for TopCmd {
+    fn explain(&self) -> String {
+        format!("top with refresh interval: {}", self.interval)
+    }
+
+    // Tracing output is written asynchronously and would interfere with the dashboard
+    fn use_tracing(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(
+        &mut self,
+        client: &dyn Client,
+        _format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        let mut terminal = ratatui::try_init().with_context(|| "Problem initializing terminal")?;
+        let result = self.run(&mut terminal, client).await;
+        ratatui::restore();
+        result
+    }
+}
@@ -0,0 +1,644 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use ahash::AHashMap;
+use iggy::prelude::{
+    Client, Consumer, Identifier, IggyByteSize, IggyError, IggyTimestamp, PollingStrategy, Stats,
+};
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// Throughput computed from the difference of two successive samples.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Rate {
+    pub(crate) messages_per_second: f64,
+    pub(crate) bytes_per_second: f64,
+}
+
+/// Keeps the last sample of the messages count and size for each entity, so that
+/// the throughput can be computed whenever the next sample arrives.
+#[derive(Debug)]
+pub(crate) struct RateTracker<K> {
+    samples: AHashMap<K, (Instant, u64, u64)>,
+}
+
+impl<K: Hash + Eq> Default for RateTracker<K> {
+    fn default() -> Self {
+        Self {
+            samples: AHashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> RateTracker<K> {
+    pub(crate) fn update(&mut self, key: K, now: Instant, messages: u64, bytes: u64) -> Rate {
+        let rate = match self.samples.get(&key) {
+            Some((previous_time, previous_messages, previous_bytes)) => {
+                let elapsed = now.duration_since(*previous_time).as_secs_f64();
+                if elapsed == 0.0 {
+                    Rate::default()
+                } else {
+                    // Counters going backwards (e.g. purged topic) are reported as no throughput
+                    Rate {
+                        messages_per_second: messages.saturating_sub(*previous_messages) as f64
+                            / elapsed,
+                        bytes_per_second: bytes.saturating_sub(*previous_bytes) as f64 / elapsed,
+                    }
+                }
+            }
+            None => Rate::default(),
+        };
+        self.samples.insert(key, (now, messages, bytes));
+        rate
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub(crate) enum RateKey {
+    Server,
+    Stream(u32),
+    Topic(u32, u32),
+    Partition(u32, u32, u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum View {
+    Streams,
+    Stream { stream_id: u32 },
+    Topic { stream_id: u32, topic_id: u32 },
+    Tail { stream_id: u32, topic_id: u32 },
+    Clients,
+}
+
+#[derive(Debug)]
+pub(crate) struct Screen {
+    pub(crate) view: View,
+    pub(crate) selected: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct StreamRow {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) topics_count: u32,
+    pub(crate) messages_count: u64,
+    pub(crate) size: IggyByteSize,
+    pub(crate) rate: Rate,
+}
+
+#[derive(Debug)]
+pub(crate) struct TopicRow {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) partitions_count: u32,
+    pub(crate) messages_count: u64,
+    pub(crate) size: IggyByteSize,
+    pub(crate) rate: Rate,
+}
+
+#[derive(Debug)]
+pub(crate) struct PartitionRow {
+    pub(crate) id: u32,
+    pub(crate) segments_count: u32,
+    pub(crate) current_offset: u64,
+    pub(crate) messages_count: u64,
+    pub(crate) size: IggyByteSize,
+    pub(crate) rate: Rate,
+}
+
+#[derive(Debug)]
+pub(crate) struct MemberRow {
+    pub(crate) id: u32,
+    pub(crate) partitions: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ConsumerGroupRow {
+    pub(crate) id: u32,
+    pub(crate) name: String,
+    pub(crate) members: Vec<MemberRow>,
+    pub(crate) lag: u64,
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientRow {
+    pub(crate) client_id: u32,
+    pub(crate) user_id: Option<u32>,
+    pub(crate) address: String,
+    pub(crate) transport: String,
+    pub(crate) consumer_groups_count: u32,
+}
+
+#[derive(Debug)]
+pub(crate) struct TailedMessage {
+    pub(crate) partition_id: u32,
+    pub(crate) offset: u64,
+    pub(crate) timestamp: IggyTimestamp,
+    pub(crate) payload: String,
+}
+
+/// Data of the currently displayed view, fetched on each refresh.
+#[derive(Debug, Default)]
+pub(crate) enum Content {
+    #[default]
+    Empty,
+    Streams(Vec<StreamRow>),
+    Stream {
+        name: String,
+        topics: Vec<TopicRow>,
+    },
+    Topic {
+        stream_name: String,
+        name: String,
+        partitions: Vec<PartitionRow>,
+        consumer_groups: Vec<ConsumerGroupRow>,
+    },
+    Tail {
+        stream_name: String,
+        name: String,
+    },
+    Clients(Vec<ClientRow>),
+}
+
+#[derive(Debug)]
+struct TailState {
+    stream_id: u32,
+    topic_id: u32,
+    next_offsets: AHashMap<u32, u64>,
+    messages: VecDeque<TailedMessage>,
+}
+
+pub(crate) struct App {
+    screens: Vec<Screen>,
+    rates: RateTracker<RateKey>,
+    tail: Option<TailState>,
+    tail_count: u32,
+    pub(crate) stats: Option<Stats>,
+    pub(crate) server_rate: Rate,
+    pub(crate) content: Content,
+    pub(crate) error: Option<String>,
+    pub(crate) paused: bool,
+}
+
+impl App {
+    pub(crate) fn new(tail_count: u32) -> Self {
+        Self {
+            screens: vec![Screen {
+                view: View::Streams,
+                selected: 0,
+            }],
+            rates: RateTracker::default(),
+            tail: None,
+            tail_count,
+            stats: None,
+            server_rate: Rate::default(),
+            content: Content::Empty,
+            error: None,
+            paused: false,
+        }
+    }
+
+    pub(crate) fn screen(&self) -> &Screen {
+        self.screens
+            .last()
+            .expect("At least one screen is always present")
+    }
+
+    fn screen_mut(&mut self) -> &mut Screen {
+        self.screens
+            .last_mut()
+            .expect("At least one screen is always present")
+    }
+
+    pub(crate) fn tailed_messages(&self) -> impl Iterator<Item = &TailedMessage> {
+        self.tail.iter().flat_map(|tail| tail.messages.iter())
+    }
+
+    /// Number of the selectable rows in the current view.
+    pub(crate) fn rows_count(&self) -> usize {
+        match &self.content {
+            Content::Empty | Content::Tail { .. } => 0,
+            Content::Streams(streams) => streams.len(),
+            Content::Stream { topics, .. } => topics.len(),
+            Content::Topic { partitions, .. } => partitions.len(),
+            Content::Clients(clients) => clients.len(),
+        }
+    }
+
+    pub(crate) fn select_next(&mut self) {
+        let rows_count = self.rows_count();
+        let screen = self.screen_mut();
+        if screen.selected + 1 < rows_count {
+            screen.selected += 1;
+        }
+    }
+
+    pub(crate) fn select_previous(&mut self) {
+        let screen = self.screen_mut();
+        screen.selected = screen.selected.saturating_sub(1);
+    }
+
+    /// Opens the view of the selected stream or topic, or the tail of the current topic.
+    /// Returns `false` when there is nothing to drill into.
+    pub(crate) fn drill_down(&mut self) -> bool {
+        let selected = self.screen().selected;
+        let view = match (self.screen().view, &self.content) {
+            (View::Streams, Content::Streams(streams)) => match streams.get(selected) {
+                Some(stream) => View::Stream {
+                    stream_id: stream.id,
+                },
+                None => return false,
+            },
+            (View::Stream { stream_id }, Content::Stream { topics, .. }) => {
+                match topics.get(selected) {
+                    Some(topic) => View::Topic {
+                        stream_id,
+                        topic_id: topic.id,
+                    },
+                    None => return false,
+                }
+            }
+            (View::Topic { .. }, _) => return self.tail(),
+            _ => return false,
+        };
+        self.open(view);
+        true
+    }
+
+    /// Opens the tail of the current topic, returns `false` outside of the topic view.
+    pub(crate) fn tail(&mut self) -> bool {
+        let View::Topic {
+            stream_id,
+            topic_id,
+        } = self.screen().view
+        else {
+            return false;
+        };
+
+        self.open(View::Tail {
+            stream_id,
+            topic_id,
+        });
+        true
+    }
+
+    /// Returns to the previous view, returns `false` when already at the top level view.
+    pub(crate) fn go_back(&mut self) -> bool {
+        if self.screens.len() == 1 {
+            return false;
+        }
+
+        self.screens.pop();
+        self.content = Content::Empty;
+        if !matches!(self.screen().view, View::Tail { .. }) {
+            self.tail = None;
+        }
+        true
+    }
+
+    /// Switches between the streams and the connected clients as the top level view.
+    pub(crate) fn toggle_clients(&mut self) {
+        let view = match self.screens[0].view {
+            View::Clients => View::Streams,
+            _ => View::Clients,
+        };
+        self.screens = vec![Screen { view, selected: 0 }];
+        self.content = Content::Empty;
+        self.tail = None;
+    }
+
+    fn open(&mut self, view: View) {
+        self.screens.push(Screen { view, selected: 0 });
+        self.content = Content::Empty;
+    }
+
+    /// Fetches the server statistics and the data of the current view. The errors are
+    /// kept to be displayed instead of stopping the dashboard, e.g. when the displayed
+    /// topic has been deleted in the meantime.
+    pub(crate) async fn refresh(&mut self, client: &dyn Client) {
+        self.error = match self.fetch(client).await {
+            Ok(()) => None,
+            Err(error) => Some(error.to_string()),
+        };
+
+        let rows_count = self.rows_count();
+        let screen = self.screen_mut();
+        screen.selected = screen.selected.min(rows_count.saturating_sub(1));
+    }
+
+    async fn fetch(&mut self, client: &dyn Client) -> Result<(), IggyError> {
+        let stats = client.get_stats().await?;
+        self.server_rate = self.rates.update(
+            RateKey::Server,
+            Instant::now(),
+            stats.messages_count,
+            stats.messages_size_bytes.as_bytes_u64(),
+        );
+        self.stats = Some(stats);
+
+        self.content = match self.screen().view {
+            View::Streams => self.fetch_streams(client).await?,
+            View::Stream { stream_id } => self.fetch_stream(client, stream_id).await?,
+            View::Topic {
+                stream_id,
+                topic_id,
+            } => self.fetch_topic(client, stream_id, topic_id).await?,
+            View::Tail {
+                stream_id,
+                topic_id,
+            } => self.fetch_tail(client, stream_id, topic_id).await?,
+            View::Clients => Content::Clients(
+                client
+                    .get_clients()
+                    .await?
+                    .into_iter()
+                    .map(|client| ClientRow {
+                        client_id: client.client_id,
+                        user_id: client.user_id,
+                        address: client.address,
+                        transport: client.transport,
+                        consumer_groups_count: client.consumer_groups_count,
+                    })
+                    .collect(),
+            ),
+        };
+        Ok(())
+    }
+
+    async fn fetch_streams(&mut self, client: &dyn Client) -> Result<Content, IggyError> {
+        let now = Instant::now();
+        let mut streams = client.get_streams().await?;
+        streams.sort_by_key(|stream| stream.id);
+        Ok(Content::Streams(
+            streams
+                .into_iter()
+                .map(|stream| StreamRow {
+                    rate: self.rates.update(
+                        RateKey::Stream(stream.id),
+                        now,
+                        stream.messages_count,
+                        stream.size.as_bytes_u64(),
+                    ),
+                    id: stream.id,
+                    name: stream.name,
+                    topics_count: stream.topics_count,
+                    messages_count: stream.messages_count,
+                    size: stream.size,
+                })
+                .collect(),
+        ))
+    }
+
+    async fn fetch_stream(
+        &mut self,
+        client: &dyn Client,
+        stream_id: u32,
+    ) -> Result<Content, IggyError> {
+        let now = Instant::now();
+        let Some(mut stream) = client.get_stream(&stream_id.try_into()?).await? else {
+            return Err(IggyError::StreamIdNotFound(stream_id));
+        };
+        stream.topics.sort_by_key(|topic| topic.id);
+        Ok(Content::Stream {
+            name: stream.name,
+            topics: stream
+                .topics
+                .into_iter()
+                .map(|topic| TopicRow {
+                    rate: self.rates.update(
+                        RateKey::Topic(stream_id, topic.id),
+                        now,
+                        topic.messages_count,
+                        topic.size.as_bytes_u64(),
+                    ),
+                    id: topic.id,
+                    name: topic.name,
+                    partitions_count: topic.partitions_count,
+                    messages_count: topic.messages_count,
+                    size: topic.size,
+                })
+                .collect(),
+        })
+    }
+
+    async fn fetch_topic(
+        &mut self,
+        client: &dyn Client,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<Content, IggyError> {
+        let now = Instant::now();
+        let stream_identifier: Identifier = stream_id.try_into()?;
+        let topic_identifier: Identifier = topic_id.try_into()?;
+        let Some(stream) = client.get_stream(&stream_identifier).await? else {
+            return Err(IggyError::StreamIdNotFound(stream_id));
+        };
+        let Some(mut topic) = client
+            .get_topic(&stream_identifier, &topic_identifier)
+            .await?
+        else {
+            return Err(IggyError::TopicIdNotFound(topic_id, stream_id));
+        };
+        topic.partitions.sort_by_key(|partition| partition.id);
+
+        let mut consumer_groups = vec![];
+        for group in client
+            .get_consumer_groups(&stream_identifier, &topic_identifier)
+            .await?
+        {
+            let group_identifier: Identifier = group.id.try_into()?;
+            let Some(details) = client
+                .get_consumer_group(&stream_identifier, &topic_identifier, &group_identifier)
+                .await?
+            else {
+                continue;
+            };
+
+            let consumer = Consumer::group(group_identifier);
+            let mut lag = 0;
+            for partition in &topic.partitions {
+                let offset = client
+                    .get_consumer_offset(
+                        &consumer,
+                        &stream_identifier,
+                        &topic_identifier,
+                        Some(partition.id),
+                    )
+                    .await?;
+                lag += partition_lag(
+                    partition.messages_count,
+                    partition.current_offset,
+                    offset.map(|offset| offset.stored_offset),
+                );
+            }
+
+            consumer_groups.push(ConsumerGroupRow {
+                id: details.id,
+                name: details.name,
+                members: details
+                    .members
+                    .into_iter()
+                    .map(|member| MemberRow {
+                        id: member.id,
+                        partitions: member.partitions,
+                    })
+                    .collect(),
+                lag,
+            });
+        }
+        consumer_groups.sort_by_key(|group| group.id);
+
+        Ok(Content::Topic {
+            stream_name: stream.name,
+            name: topic.name,
+            partitions: topic
+                .partitions
+                .into_iter()
+                .map(|partition| PartitionRow {
+                    rate: self.rates.update(
+                        RateKey::Partition(stream_id, topic_id, partition.id),
+                        now,
+                        partition.messages_count,
+                        partition.size.as_bytes_u64(),
+                    ),
+                    id: partition.id,
+                    segments_count: partition.segments_count,
+                    current_offset: partition.current_offset,
+                    messages_count: partition.messages_count,
+                    size: partition.size,
+                })
+                .collect(),
+            consumer_groups,
+        })
+    }
+
+    async fn fetch_tail(
+        &mut self,
+        client: &dyn Client,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<Content, IggyError> {
+        let stream_identifier: Identifier = stream_id.try_into()?;
+        let topic_identifier: Identifier = topic_id.try_into()?;
+        let Some(stream) = client.get_stream(&stream_identifier).await? else {
+            return Err(IggyError::StreamIdNotFound(stream_id));
+        };
+        let Some(topic) = client
+            .get_topic(&stream_identifier, &topic_identifier)
+            .await?
+        else {
+            return Err(IggyError::TopicIdNotFound(topic_id, stream_id));
+        };
+
+        let tail_count = self.tail_count;
+        let tail = match &mut self.tail {
+            Some(tail) if tail.stream_id == stream_id && tail.topic_id == topic_id => tail,
+            _ => self.tail.insert(TailState {
+                stream_id,
+                topic_id,
+                next_offsets: AHashMap::new(),
+                messages: VecDeque::new(),
+            }),
+        };
+
+        let consumer = Consumer::default();
+        for partition in topic.partitions.iter().filter(|p| p.messages_count > 0) {
+            // The tail starts with the most recent messages of the partition
+            let next_offset = *tail
+                .next_offsets
+                .entry(partition.id)
+                .or_insert((partition.current_offset + 1).saturating_sub(tail_count as u64));
+            if next_offset > partition.current_offset {
+                continue;
+            }
+
+            let polled_messages = client
+                .poll_messages(
+                    &stream_identifier,
+                    &topic_identifier,
+                    Some(partition.id),
+                    &consumer,
+                    &PollingStrategy::offset(next_offset),
+                    tail_count,
+                    false,
+                )
+                .await?;
+            for message in polled_messages.messages {
+                tail.next_offsets
+                    .insert(partition.id, message.header.offset + 1);
+                tail.messages.push_back(TailedMessage {
+                    partition_id: partition.id,
+                    offset: message.header.offset,
+                    timestamp: IggyTimestamp::from(message.header.timestamp),
+                    payload: String::from_utf8_lossy(&message.payload)
+                        .chars()
+                        .map(|c| if c.is_control() { ' ' } else { c })
+                        .collect(),
+                });
+            }
+        }
+
+        while tail.messages.len() > tail_count as usize {
+            tail.messages.pop_front();
+        }
+
+        Ok(Content::Tail {
+            stream_name: stream.name,
+            name: topic.name,
+        })
+    }
+}
+
+/// Number of the messages in the partition not consumed yet by the consumer group,
+/// all the messages are pending when no offset has been stored.
+fn partition_lag(messages_count: u64, current_offset: u64, stored_offset: Option<u64>) -> u64 {
+    match stored_offset {
+        Some(stored_offset) => current_offset.saturating_sub(stored_offset),
+        None => messages_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn rate_should_be_computed_from_successive_samples() {
+        let mut tracker = RateTracker::default();
+        let now = Instant::now();
+
+        assert_eq!(tracker.update(1, now, 100, 1000), Rate::default());
+
+        let rate = tracker.update(1, now + Duration::from_secs(2), 300, 5000);
+        assert_eq!(rate.messages_per_second, 100.0);
+        assert_eq!(rate.bytes_per_second, 2000.0);
+
+        let rate = tracker.update(1, now + Duration::from_secs(3), 10, 100);
+        assert_eq!(rate, Rate::default());
+    }
+
+    #[test]
+    fn lag_should_count_messages_after_stored_offset() {
+        assert_eq!(partition_lag(10, 9, Some(4)), 5);
+        assert_eq!(partition_lag(10, 9, Some(9)), 0);
+        assert_eq!(partition_lag(10, 9, None), 10);
+        assert_eq!(partition_lag(0, 0, None), 0);
+    }
+}
@@ -0,0 +1,306 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::top::state::{App, Content, Rate, View};
+use iggy::prelude::IggyByteSize;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Row, Table, TableState};
+
+const KEYS_HELP: &str =
+    "q quit  ↑/↓ select  enter open  t tail  esc back  c clients  p pause  r refresh";
+
+pub(crate) fn draw(frame: &mut Frame, app: &App) {
+    let [header_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    draw_header(frame, header_area, app);
+    draw_body(frame, body_area, app);
+    draw_footer(frame, footer_area, app);
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
+    let lines = match &app.stats {
+        Some(stats) => vec![
+            Line::from(format!(
+                "{} ({} {}), server: {}, uptime: {}, CPU: {:.2}%, memory: {} / {}",
+                stats.hostname,
+                stats.os_name,
+                stats.os_version,
+                stats.iggy_server_version,
+                stats.run_time,
+                stats.cpu_usage,
+                stats.memory_usage.as_human_string(),
+                stats.total_memory.as_human_string(),
+            )),
+            Line::from(format!(
+                "streams: {}, topics: {}, partitions: {}, segments: {}, clients: {}, consumer groups: {}",
+                stats.streams_count,
+                stats.topics_count,
+                stats.partitions_count,
+                stats.segments_count,
+                stats.clients_count,
+                stats.consumer_groups_count,
+            )),
+            Line::from(format!(
+                "messages: {} ({}), throughput: {}",
+                stats.messages_count,
+                stats.messages_size_bytes.as_human_string(),
+                format_rate(&app.server_rate),
+            )),
+        ],
+        None => vec![Line::from("Fetching server statistics...")],
+    };
+
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::new().title(" iggy top ".bold())),
+        area,
+    );
+}
+
+fn draw_body(frame: &mut Frame, area: Rect, app: &App) {
+    let mut state = TableState::default().with_selected(Some(app.screen().selected));
+    match &app.content {
+        Content::Empty => frame.render_widget(Block::bordered(), area),
+        Content::Streams(streams) => {
+            let rows = streams.iter().map(|stream| {
+                Row::new(vec![
+                    stream.id.to_string(),
+                    stream.name.clone(),
+                    stream.topics_count.to_string(),
+                    stream.messages_count.to_string(),
+                    stream.size.as_human_string(),
+                    format_messages_rate(&stream.rate),
+                    format_bytes_rate(&stream.rate),
+                ])
+            });
+            let table = table(
+                rows,
+                [
+                    "ID", "Name", "Topics", "Messages", "Size", "Msg/s", "Bytes/s",
+                ],
+                "Streams",
+            );
+            frame.render_stateful_widget(table, area, &mut state);
+        }
+        Content::Stream { name, topics } => {
+            let rows = topics.iter().map(|topic| {
+                Row::new(vec![
+                    topic.id.to_string(),
+                    topic.name.clone(),
+                    topic.partitions_count.to_string(),
+                    topic.messages_count.to_string(),
+                    topic.size.as_human_string(),
+                    format_messages_rate(&topic.rate),
+                    format_bytes_rate(&topic.rate),
+                ])
+            });
+            let table = table(
+                rows,
+                [
+                    "ID",
+                    "Name",
+                    "Partitions",
+                    "Messages",
+                    "Size",
+                    "Msg/s",
+                    "Bytes/s",
+                ],
+                &format!("Topics of stream: {name}"),
+            );
+            frame.render_stateful_widget(table, area, &mut state);
+        }
+        Content::Topic {
+            stream_name,
+            name,
+            partitions,
+            consumer_groups,
+        } => {
+            let [partitions_area, groups_area] =
+                Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)])
+                    .areas(area);
+
+            let rows = partitions.iter().map(|partition| {
+                Row::new(vec![
+                    partition.id.to_string(),
+                    partition.segments_count.to_string(),
+                    partition.current_offset.to_string(),
+                    partition.messages_count.to_string(),
+                    partition.size.as_human_string(),
+                    format_messages_rate(&partition.rate),
+                    format_bytes_rate(&partition.rate),
+                ])
+            });
+            let table = table(
+                rows,
+                [
+                    "ID", "Segments", "Offset", "Messages", "Size", "Msg/s", "Bytes/s",
+                ],
+                &format!("Partitions of topic: {name} in stream: {stream_name}"),
+            );
+            frame.render_stateful_widget(table, partitions_area, &mut state);
+
+            let rows = consumer_groups.iter().map(|group| {
+                let members = group
+                    .members
+                    .iter()
+                    .map(|member| format!("{}: {:?}", member.id, member.partitions))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Row::new(vec![
+                    group.id.to_string(),
+                    group.name.clone(),
+                    group.members.len().to_string(),
+                    group.lag.to_string(),
+                    members,
+                ])
+            });
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(6),
+                    Constraint::Fill(1),
+                    Constraint::Length(8),
+                    Constraint::Length(12),
+                    Constraint::Fill(2),
+                ],
+            )
+            .header(header(["ID", "Name", "Members", "Lag", "Partitions"]))
+            .block(Block::bordered().title(" Consumer groups "));
+            frame.render_widget(table, groups_area);
+        }
+        Content::Tail { stream_name, name } => {
+            // The most recent messages are displayed first
+            let rows = app
+                .tailed_messages()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .map(|message| {
+                    Row::new(vec![
+                        message.partition_id.to_string(),
+                        message.offset.to_string(),
+                        message.timestamp.to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
+                        message.payload.clone(),
+                    ])
+                });
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(9),
+                    Constraint::Length(12),
+                    Constraint::Length(26),
+                    Constraint::Fill(1),
+                ],
+            )
+            .header(header(["Partition", "Offset", "Timestamp", "Payload"]))
+            .block(Block::bordered().title(format!(
+                " Messages of topic: {name} in stream: {stream_name} "
+            )));
+            frame.render_widget(table, area);
+        }
+        Content::Clients(clients) => {
+            let rows = clients.iter().map(|client| {
+                Row::new(vec![
+                    client.client_id.to_string(),
+                    client
+                        .user_id
+                        .map(|user_id| user_id.to_string())
+                        .unwrap_or_default(),
+                    client.address.clone(),
+                    client.transport.clone(),
+                    client.consumer_groups_count.to_string(),
+                ])
+            });
+            let table = table(
+                rows,
+                [
+                    "Client ID",
+                    "User ID",
+                    "Address",
+                    "Transport",
+                    "Consumer groups",
+                ],
+                "Connected clients",
+            );
+            frame.render_stateful_widget(table, area, &mut state);
+        }
+    }
+}
+
+fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![Span::raw(KEYS_HELP)];
+    if app.paused {
+        spans.push(Span::styled("  [paused]", Style::new().fg(Color::Yellow)));
+    }
+    if matches!(app.screen().view, View::Tail { .. }) {
+        spans.push(Span::raw(format!(
+            "  {} messages",
+            app.tailed_messages().count()
+        )));
+    }
+    if let Some(error) = &app.error {
+        spans.push(Span::styled(
+            format!("  error: {error}"),
+            Style::new().fg(Color::Red),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn table<'a, const N: usize>(
+    rows: impl IntoIterator<Item = Row<'a>>,
+    columns: [&'a str; N],
+    title: &str,
+) -> Table<'a> {
+    let mut widths = vec![Constraint::Length(10); N];
+    widths[1] = Constraint::Fill(1);
+    Table::new(rows, widths)
+        .header(header(columns))
+        .block(Block::bordered().title(format!(" {title} ")))
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+}
+
+fn header<'a, const N: usize>(columns: [&'a str; N]) -> Row<'a> {
+    Row::new(columns).style(Style::new().add_modifier(Modifier::BOLD))
+}
+
+fn format_rate(rate: &Rate) -> String {
+    format!(
+        "{} msg/s, {}",
+        format_messages_rate(rate),
+        format_bytes_rate(rate)
+    )
+}
+
+fn format_messages_rate(rate: &Rate) -> String {
+    format!("{:.1}", rate.messages_per_second)
+}
+
+fn format_bytes_rate(rate: &Rate) -> String {
+    format!(
+        "{}/s",
+        IggyByteSize::from(rate.bytes_per_second.round() as u64).as_human_string()
+    )
+}
@@ -18,6 +18,7 @@
 
 pub mod get_client;
 pub mod get_clients;
+pub mod get_health;
 pub mod get_me;
 pub mod get_snapshot;
 pub mod get_stats;
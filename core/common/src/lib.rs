@@ -69,6 +69,7 @@ pub use types::consumer::consumer_group::*;
 pub use types::consumer::consumer_kind::*;
 pub use types::consumer::consumer_offset_info::*;
 pub use types::diagnostic::diagnostic_event::DiagnosticEvent;
+pub use types::health::*;
 pub use types::identifier::*;
 pub use types::message::*;
 pub use types::partition::*;
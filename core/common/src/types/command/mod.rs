@@ -31,6 +31,8 @@ pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const GET_HEALTH: &str = "health";
+pub const GET_HEALTH_CODE: u32 = 12;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -122,6 +124,7 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
         PING_CODE => Ok(PING),
         GET_STATS_CODE => Ok(GET_STATS),
+        GET_HEALTH_CODE => Ok(GET_HEALTH),
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
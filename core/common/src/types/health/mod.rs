@@ -0,0 +1,161 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `Health` represents the readiness of the server along with the results of the performed checks.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Health {
+    /// The overall status, `up` unless any of the checks is `down`.
+    pub status: HealthStatus,
+    /// The results of the performed checks.
+    pub checks: Vec<HealthCheck>,
+}
+
+/// `HealthCheck` represents the result of a single readiness check.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct HealthCheck {
+    /// The name of the check, e.g. `state` or `disk`.
+    pub name: String,
+    /// The status of the check.
+    pub status: HealthStatus,
+    /// The human-readable details of the check.
+    pub details: String,
+}
+
+/// `HealthStatus` represents the status of the server or a single check.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// The check has passed.
+    #[default]
+    Up,
+    /// The check has failed.
+    Down,
+    /// The check is not applicable, e.g. the checked component is disabled.
+    Disabled,
+}
+
+impl Health {
+    /// Creates the health from the checks, the status is `down` if any of the checks is `down`.
+    pub fn new(checks: Vec<HealthCheck>) -> Self {
+        let status = if checks
+            .iter()
+            .any(|check| check.status == HealthStatus::Down)
+        {
+            HealthStatus::Down
+        } else {
+            HealthStatus::Up
+        };
+        Self { status, checks }
+    }
+
+    /// Returns `true` if the server is ready to handle the requests.
+    pub fn is_ready(&self) -> bool {
+        self.status != HealthStatus::Down
+    }
+
+    /// Returns the overall status only, without the checks which expose the server details.
+    pub fn without_checks(&self) -> Self {
+        Self {
+            status: self.status,
+            checks: Vec::new(),
+        }
+    }
+}
+
+impl HealthCheck {
+    /// Creates a new health check result.
+    pub fn new(name: &str, status: HealthStatus, details: impl Into<String>) -> Self {
+        Self {
+            name: name.to_owned(),
+            status,
+            details: details.into(),
+        }
+    }
+}
+
+impl Display for HealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthStatus::Up => write!(f, "up"),
+            HealthStatus::Down => write!(f, "down"),
+            HealthStatus::Disabled => write!(f, "disabled"),
+        }
+    }
+}
+
+impl HealthStatus {
+    /// Returns the code of the health status.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            HealthStatus::Up => 1,
+            HealthStatus::Down => 2,
+            HealthStatus::Disabled => 3,
+        }
+    }
+
+    /// Returns the health status from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(HealthStatus::Up),
+            2 => Ok(HealthStatus::Down),
+            3 => Ok(HealthStatus::Disabled),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_down_if_any_check_is_down() {
+        let health = Health::new(vec![
+            HealthCheck::new("state", HealthStatus::Up, "loaded"),
+            HealthCheck::new("archiver", HealthStatus::Disabled, "disabled"),
+            HealthCheck::new("disk", HealthStatus::Down, "no space left"),
+        ]);
+        assert_eq!(health.status, HealthStatus::Down);
+        assert!(!health.is_ready());
+    }
+
+    #[test]
+    fn should_be_up_if_no_check_is_down() {
+        let health = Health::new(vec![
+            HealthCheck::new("state", HealthStatus::Up, "loaded"),
+            HealthCheck::new("archiver", HealthStatus::Disabled, "disabled"),
+        ]);
+        assert_eq!(health.status, HealthStatus::Up);
+        assert!(health.is_ready());
+    }
+
+    #[test]
+    fn should_keep_status_without_checks() {
+        let health = Health::new(vec![HealthCheck::new(
+            "disk",
+            HealthStatus::Down,
+            "no space left",
+        )])
+        .without_checks();
+        assert_eq!(health.status, HealthStatus::Down);
+        assert!(health.checks.is_empty());
+    }
+}
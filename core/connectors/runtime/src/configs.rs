@@ -20,6 +20,7 @@ use crate::api::config::HttpApiConfig;
 use iggy_connector_sdk::{Schema, transforms::TransformType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use strum::Display;
 
 #[derive(
@@ -45,6 +46,8 @@ pub struct RuntimeConfig {
     pub sinks: HashMap<String, SinkConfig>,
     pub sources: HashMap<String, SourceConfig>,
     pub state: StateConfig,
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,6 +74,10 @@ pub struct StreamConsumerConfig {
     pub stream: String,
     pub topics: Vec<String>,
     pub schema: Schema,
+    /// Inline definition of the payload schema, used by the `avro` schema.
+    pub schema_definition: Option<String>,
+    /// Path to the file with the payload schema, used by the `avro` schema.
+    pub schema_path: Option<PathBuf>,
     pub batch_length: Option<u32>,
     pub poll_interval: Option<String>,
     pub consumer_group: Option<String>,
@@ -81,6 +88,10 @@ pub struct StreamProducerConfig {
     pub stream: String,
     pub topic: String,
     pub schema: Schema,
+    /// Inline definition of the payload schema, used by the `avro` schema.
+    pub schema_definition: Option<String>,
+    /// Path to the file with the payload schema, used by the `avro` schema.
+    pub schema_path: Option<PathBuf>,
     pub batch_length: Option<u32>,
     pub linger_time: Option<String>,
 }
@@ -94,6 +105,14 @@ pub struct SourceConfig {
     pub streams: Vec<StreamProducerConfig>,
     pub config_format: Option<ConfigFormat>,
     pub config: Option<serde_json::Value>,
+    pub scale_out: Option<ScaleOutConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScaleOutConfig {
+    pub enabled: bool,
+    pub stream: Option<String>,
+    pub refresh_interval: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -109,5 +128,63 @@ pub struct SharedTransformConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StateConfig {
+    pub storage: Option<StateStorageKind>,
     pub path: String,
+    pub stream: Option<String>,
+    pub topic: Option<String>,
+    pub expiry: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Display)]
+#[serde(rename_all = "lowercase")]
+pub enum StateStorageKind {
+    #[default]
+    #[strum(to_string = "file")]
+    File,
+    #[strum(to_string = "iggy")]
+    Iggy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    pub service_name: String,
+    pub traces: TelemetryTracesConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryTracesConfig {
+    pub transport: TelemetryTransport,
+    pub endpoint: String,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Display)]
+#[serde(rename_all = "lowercase")]
+pub enum TelemetryTransport {
+    #[default]
+    #[strum(to_string = "grpc")]
+    Grpc,
+    #[strum(to_string = "http")]
+    Http,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            service_name: "iggy-connectors".to_owned(),
+            traces: TelemetryTracesConfig::default(),
+        }
+    }
+}
+
+impl Default for TelemetryTracesConfig {
+    fn default() -> Self {
+        Self {
+            transport: TelemetryTransport::Grpc,
+            endpoint: "http://localhost:4317".to_owned(),
+        }
+    }
 }
@@ -0,0 +1,539 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use dashmap::DashMap;
+use iggy::prelude::{
+    ConsumerGroupClient, Identifier, IggyClient, IggyDuration, IggyError, IggyExpiry, MaxTopicSize,
+    PartitionClient, StreamClient, SystemClient, TopicClient,
+};
+use iggy_connector_sdk::{ConnectorState, WorkUnit};
+use once_cell::sync::Lazy;
+use std::{str::FromStr, sync::Arc, time::Duration};
+use tracing::{debug, error, info, warn};
+
+use crate::{
+    RuntimeError,
+    configs::ScaleOutConfig,
+    state::{IggyStateStore, StateRecord},
+};
+
+pub const DEFAULT_COORDINATION_STREAM: &str = "connectors_coordination";
+const COORDINATION_GROUP: &str = "runtime";
+const DEFAULT_REFRESH_INTERVAL: &str = "5s";
+
+pub type AssignFn = extern "C" fn(id: u32, units_ptr: *const u8, units_len: usize) -> i32;
+
+static WORK_UNITS: Lazy<DashMap<u32, Vec<String>>> = Lazy::new(DashMap::new);
+
+/// Splits the work units of the source connector across the runtime instances.
+///
+/// Each work unit is mapped to the partition of the coordination topic (one per source connector),
+/// and all the runtime instances join the same consumer group on that topic. The server assigns
+/// the partitions to the group members and rebalances them whenever the instance joins or
+/// disconnects, so the coordinator only has to follow the current assignment.
+///
+/// The progress of each work unit is stored in the Iggy state storage along with its owner, and the newly
+/// assigned unit is taken over only once its previous owner has released it (after saving its final progress)
+/// or has left the group, so the same unit is never processed by the multiple instances at once.
+pub struct WorkCoordinator {
+    plugin_id: u32,
+    key: String,
+    client: Arc<IggyClient>,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    group_id: Identifier,
+    units: Vec<String>,
+    assigned: Option<Vec<String>>,
+    assign: AssignFn,
+    refresh_interval: Duration,
+    state_store: Arc<IggyStateStore>,
+}
+
+impl WorkCoordinator {
+    pub async fn init(
+        plugin_id: u32,
+        key: &str,
+        config: &ScaleOutConfig,
+        client: Arc<IggyClient>,
+        units: Vec<String>,
+        assign: AssignFn,
+        state_store: Arc<IggyStateStore>,
+    ) -> Result<Self, RuntimeError> {
+        let stream = config
+            .stream
+            .as_deref()
+            .unwrap_or(DEFAULT_COORDINATION_STREAM);
+        let refresh_interval = IggyDuration::from_str(
+            config
+                .refresh_interval
+                .as_deref()
+                .unwrap_or(DEFAULT_REFRESH_INTERVAL),
+        )
+        .map_err(|error| {
+            RuntimeError::InvalidConfiguration(format!(
+                "Invalid scale-out refresh interval for source: {key}. {error}"
+            ))
+        })?;
+
+        let mut coordinator = WorkCoordinator {
+            plugin_id,
+            key: key.to_owned(),
+            client,
+            stream_id: Identifier::named(stream)?,
+            topic_id: Identifier::named(key)?,
+            group_id: Identifier::named(COORDINATION_GROUP)?,
+            units,
+            assigned: None,
+            assign,
+            refresh_interval: refresh_interval.get_duration(),
+            state_store,
+        };
+        coordinator.init_topic(stream).await?;
+        coordinator.join().await?;
+        coordinator.refresh().await?;
+        Ok(coordinator)
+    }
+
+    /// Periodically checks the current assignment, and passes it to the source connector whenever it changes.
+    pub async fn run(mut self) {
+        let mut interval = tokio::time::interval(self.refresh_interval);
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.refresh().await {
+                error!(
+                    "Failed to refresh work units assignment for source connector with ID: {} ({}). {error}",
+                    self.plugin_id, self.key
+                );
+            }
+        }
+    }
+
+    async fn init_topic(&self, stream: &str) -> Result<(), IggyError> {
+        let partitions_count = self.units.len() as u32;
+        if self.client.get_stream(&self.stream_id).await?.is_none() {
+            info!("Creating coordination stream: {stream}");
+            self.client.create_stream(stream, None).await?;
+        }
+
+        match self
+            .client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await?
+        {
+            None => {
+                info!(
+                    "Creating coordination topic: {} with {partitions_count} partitions in stream: {stream}",
+                    self.key
+                );
+                self.client
+                    .create_topic(
+                        &self.stream_id,
+                        &self.key,
+                        partitions_count,
+                        Default::default(),
+                        None,
+                        None,
+                        IggyExpiry::NeverExpire,
+                        MaxTopicSize::ServerDefault,
+                    )
+                    .await?;
+            }
+            Some(topic) if topic.partitions_count < partitions_count => {
+                info!(
+                    "Adding {} partitions to coordination topic: {}",
+                    partitions_count - topic.partitions_count,
+                    self.key
+                );
+                self.client
+                    .create_partitions(
+                        &self.stream_id,
+                        &self.topic_id,
+                        partitions_count - topic.partitions_count,
+                    )
+                    .await?;
+            }
+            Some(topic) if topic.partitions_count > partitions_count => {
+                info!(
+                    "Deleting {} partitions from coordination topic: {}",
+                    topic.partitions_count - partitions_count,
+                    self.key
+                );
+                self.client
+                    .delete_partitions(
+                        &self.stream_id,
+                        &self.topic_id,
+                        topic.partitions_count - partitions_count,
+                    )
+                    .await?;
+            }
+            Some(_) => {}
+        }
+
+        if self
+            .client
+            .get_consumer_group(&self.stream_id, &self.topic_id, &self.group_id)
+            .await?
+            .is_none()
+        {
+            match self
+                .client
+                .create_consumer_group(&self.stream_id, &self.topic_id, COORDINATION_GROUP, None)
+                .await
+            {
+                Ok(_) | Err(IggyError::ConsumerGroupNameAlreadyExists(_, _)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    async fn join(&self) -> Result<(), IggyError> {
+        self.client
+            .join_consumer_group(&self.stream_id, &self.topic_id, &self.group_id)
+            .await?;
+        info!(
+            "Joined coordination group for source connector with ID: {} ({})",
+            self.plugin_id, self.key
+        );
+        Ok(())
+    }
+
+    async fn refresh(&mut self) -> Result<(), RuntimeError> {
+        let client_id = self.client.get_me().await?.client_id;
+        let Some(group) = self
+            .client
+            .get_consumer_group(&self.stream_id, &self.topic_id, &self.group_id)
+            .await?
+        else {
+            warn!(
+                "Coordination group for source connector with ID: {} ({}) was not found.",
+                self.plugin_id, self.key
+            );
+            return Ok(());
+        };
+
+        let Some(member) = group.members.iter().find(|member| member.id == client_id) else {
+            // The membership is lost e.g. after reconnecting to the server, so the work units might be
+            // taken over by the other instances already, and they're released before rejoining.
+            warn!(
+                "Runtime is not a member of the coordination group for source connector with ID: {} ({}), rejoining...",
+                self.plugin_id, self.key
+            );
+            if self
+                .assigned
+                .as_ref()
+                .is_some_and(|units| !units.is_empty())
+            {
+                self.assign(vec![])?;
+            }
+            self.join().await?;
+            return Ok(());
+        };
+
+        let members = group
+            .members
+            .iter()
+            .map(|member| member.id)
+            .collect::<Vec<_>>();
+        let processed = self.assigned.clone().unwrap_or_default();
+        let mut units = Vec::new();
+        for unit in assigned_units(&self.units, &member.partitions) {
+            if processed.contains(&unit) {
+                units.push(WorkUnit {
+                    name: unit,
+                    state: None,
+                });
+                continue;
+            }
+
+            let key = work_unit_key(&self.key, &unit);
+            let record = self.state_store.load_record(&key).await?;
+            if !can_acquire(record.as_ref(), client_id, &members) {
+                info!(
+                    "Work unit: {unit} of source connector with ID: {} ({}) is not released by its previous owner yet.",
+                    self.plugin_id, self.key
+                );
+                continue;
+            }
+
+            let state = record
+                .map(|record| record.state)
+                .filter(|state| !state.is_empty());
+            self.state_store
+                .save_record(
+                    &key,
+                    StateRecord {
+                        state: state.clone().unwrap_or_default(),
+                        owner: Some(client_id),
+                        released: false,
+                    },
+                )
+                .await?;
+            units.push(WorkUnit {
+                name: unit,
+                state: state.map(ConnectorState),
+            });
+        }
+
+        let assigned = units
+            .iter()
+            .map(|unit| unit.name.clone())
+            .collect::<Vec<_>>();
+        if self.assigned.as_ref() == Some(&assigned) {
+            debug!(
+                "Work units assignment for source connector with ID: {} ({}) has not changed.",
+                self.plugin_id, self.key
+            );
+            return Ok(());
+        }
+
+        self.assign(units)
+    }
+
+    fn assign(&mut self, units: Vec<WorkUnit>) -> Result<(), RuntimeError> {
+        let assigned = units
+            .iter()
+            .map(|unit| unit.name.clone())
+            .collect::<Vec<_>>();
+        let units = postcard::to_allocvec(&units)
+            .map_err(|_| RuntimeError::InvalidConfiguration("Invalid work units".to_owned()))?;
+        let result = (self.assign)(self.plugin_id, units.as_ptr(), units.len());
+        if result != 0 {
+            error!(
+                "Source connector with ID: {} ({}) failed to accept work units: {assigned:?}",
+                self.plugin_id, self.key
+            );
+            return Ok(());
+        }
+
+        info!(
+            "Assigned work units: {assigned:?} to source connector with ID: {} ({})",
+            self.plugin_id, self.key
+        );
+        self.assigned = Some(assigned);
+        Ok(())
+    }
+}
+
+/// Returns the key under which the progress of the work unit is stored.
+pub fn work_unit_key(key: &str, unit: &str) -> String {
+    format!("{key}/{unit}")
+}
+
+/// Maps the partitions assigned to the group member to the work units, in the order of the partitions.
+fn assigned_units(units: &[String], partitions: &[u32]) -> Vec<String> {
+    let mut partitions = partitions.to_vec();
+    partitions.sort_unstable();
+    partitions
+        .into_iter()
+        .filter_map(|partition_id| {
+            (partition_id as usize)
+                .checked_sub(1)
+                .and_then(|index| units.get(index).cloned())
+        })
+        .collect()
+}
+
+/// Checks whether the work unit can be taken over, i.e. it has no owner yet, or it was released,
+/// or its owner is no longer the member of the coordination group (e.g. its connection was closed).
+fn can_acquire(record: Option<&StateRecord>, client_id: u32, members: &[u32]) -> bool {
+    match record
+        .filter(|record| !record.released)
+        .and_then(|record| record.owner)
+    {
+        None => true,
+        Some(owner) => owner == client_id || !members.contains(&owner),
+    }
+}
+
+/// Returns the work units exposed by the source connector.
+pub fn take_work_units(plugin_id: u32) -> Vec<String> {
+    WORK_UNITS
+        .remove(&plugin_id)
+        .map(|(_, units)| units)
+        .unwrap_or_default()
+}
+
+pub extern "C" fn handle_work_units(plugin_id: u32, units_ptr: *const u8, units_len: usize) {
+    let units = unsafe { std::slice::from_raw_parts(units_ptr, units_len) };
+    let Ok(units) = postcard::from_bytes::<Vec<String>>(units) else {
+        error!("Failed to deserialize work units for source connector with ID: {plugin_id}");
+        return;
+    };
+    WORK_UNITS.insert(plugin_id, units);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::prelude::{Client, IggyExpiry};
+    use integration::{
+        tcp_client::TcpClientFactory,
+        test_server::{ClientFactory, TestServer, login_root},
+    };
+
+    const KEY: &str = "postgres";
+
+    static ASSIGNED: Lazy<DashMap<u32, Vec<WorkUnit>>> = Lazy::new(DashMap::new);
+
+    extern "C" fn assign(plugin_id: u32, units_ptr: *const u8, units_len: usize) -> i32 {
+        let units = unsafe { std::slice::from_raw_parts(units_ptr, units_len) };
+        ASSIGNED.insert(plugin_id, postcard::from_bytes(units).unwrap());
+        0
+    }
+
+    fn assigned(plugin_id: u32) -> Vec<String> {
+        ASSIGNED
+            .get(&plugin_id)
+            .map(|units| units.iter().map(|unit| unit.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn units() -> Vec<String> {
+        vec!["first".to_owned(), "second".to_owned()]
+    }
+
+    async fn connect(test_server: &TestServer) -> Arc<IggyClient> {
+        let client_factory = TcpClientFactory {
+            server_addr: test_server.get_raw_tcp_addr().unwrap(),
+            ..Default::default()
+        };
+        let client = IggyClient::create(client_factory.create_client().await, None, None);
+        client.connect().await.unwrap();
+        login_root(&client).await;
+        Arc::new(client)
+    }
+
+    async fn init_state_store(test_server: &TestServer) -> Arc<IggyStateStore> {
+        let store = IggyStateStore::new(
+            connect(test_server).await,
+            "connectors_state",
+            "state",
+            IggyExpiry::NeverExpire,
+        )
+        .unwrap();
+        store.init().await.unwrap();
+        Arc::new(store)
+    }
+
+    async fn init_coordinator(
+        plugin_id: u32,
+        client: Arc<IggyClient>,
+        state_store: Arc<IggyStateStore>,
+    ) -> WorkCoordinator {
+        WorkCoordinator::init(
+            plugin_id,
+            KEY,
+            &ScaleOutConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            client,
+            units(),
+            assign,
+            state_store,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn assigned_units_should_follow_partitions_order() {
+        assert_eq!(assigned_units(&units(), &[2, 1]), units());
+        assert_eq!(assigned_units(&units(), &[2]), vec!["second".to_owned()]);
+        assert!(assigned_units(&units(), &[0, 3]).is_empty());
+    }
+
+    #[test]
+    fn can_acquire_should_allow_unit_without_active_owner() {
+        let record = |owner, released| StateRecord {
+            state: vec![],
+            owner,
+            released,
+        };
+
+        assert!(can_acquire(None, 1, &[1, 2]));
+        assert!(can_acquire(Some(&record(None, false)), 1, &[1, 2]));
+        assert!(can_acquire(Some(&record(Some(1), false)), 1, &[1, 2]));
+        assert!(can_acquire(Some(&record(Some(2), true)), 1, &[1, 2]));
+        assert!(can_acquire(Some(&record(Some(3), false)), 1, &[1, 2]));
+        assert!(!can_acquire(Some(&record(Some(2), false)), 1, &[1, 2]));
+    }
+
+    #[tokio::test]
+    async fn refresh_should_hand_over_unit_once_released_by_previous_owner() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let state_store = init_state_store(&test_server).await;
+        let mut first = init_coordinator(1, connect(&test_server).await, state_store.clone()).await;
+        assert_eq!(assigned(1), units());
+
+        // The unit assigned to the second instance is still owned by the first one.
+        let mut second =
+            init_coordinator(2, connect(&test_server).await, state_store.clone()).await;
+        assert!(assigned(2).is_empty());
+
+        first.refresh().await.unwrap();
+        let kept = assigned(1);
+        assert_eq!(kept.len(), 1);
+        let released = units()
+            .into_iter()
+            .find(|unit| !kept.contains(unit))
+            .unwrap();
+
+        second.refresh().await.unwrap();
+        assert!(assigned(2).is_empty());
+
+        // The source reports the released unit along with its final progress.
+        state_store
+            .save_progress(&work_unit_key(KEY, &released), Some(b"100".to_vec()), true)
+            .await
+            .unwrap();
+        second.refresh().await.unwrap();
+
+        let units = ASSIGNED.get(&2).unwrap();
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].name, released);
+        assert_eq!(units[0].state.as_ref().unwrap().0, b"100".to_vec());
+    }
+
+    #[tokio::test]
+    async fn refresh_should_take_over_units_of_disconnected_owner() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let state_store = init_state_store(&test_server).await;
+        let first_client = connect(&test_server).await;
+        init_coordinator(3, first_client.clone(), state_store.clone()).await;
+        assert_eq!(assigned(3), units());
+
+        let mut second =
+            init_coordinator(4, connect(&test_server).await, state_store.clone()).await;
+        assert!(assigned(4).is_empty());
+
+        first_client.disconnect().await.unwrap();
+        for _ in 0..50 {
+            second.refresh().await.unwrap();
+            if assigned(4).len() == units().len() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(assigned(4), units());
+    }
+}
@@ -17,39 +17,46 @@
  */
 
 use config::{Config, Environment, File};
-use configs::{ConfigFormat, RuntimeConfig};
+use configs::{ConfigFormat, RuntimeConfig, StateStorageKind};
+use coordinator::WorkCoordinator;
 use dlopen2::wrapper::{Container, WrapperApi};
 use dotenvy::dotenv;
 use error::RuntimeError;
 use figlet_rs::FIGfont;
-use iggy::prelude::{Client, IggyConsumer, IggyProducer};
+use iggy::prelude::{Client, IggyConsumer, IggyExpiry, IggyProducer};
 use iggy_connector_sdk::{
     StreamDecoder, StreamEncoder,
     sink::ConsumeCallback,
-    source::{HandleCallback, SendCallback},
+    source::{HandleCallback, SendCallback, WorkUnitsCallback},
     transforms::Transform,
 };
+#[cfg(not(test))]
 use mimalloc::MiMalloc;
-use state::StateStorage;
+use state::{IggyStateStore, StateStorage};
 use std::{
     collections::HashMap,
     env,
+    str::FromStr,
     sync::{Arc, atomic::AtomicU32},
 };
-use tracing::{debug, info};
+use tracing::{debug, error, info};
 use tracing_subscriber::{EnvFilter, Registry, layer::SubscriberExt, util::SubscriberInitExt};
 
 mod api;
 pub(crate) mod configs;
 pub(crate) mod context;
+mod coordinator;
 pub(crate) mod error;
 mod manager;
 mod sink;
 mod source;
 mod state;
 mod stream;
+mod telemetry;
 mod transform;
 
+// The tests run against the server from the integration crate, which brings its own allocator.
+#[cfg(not(test))]
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
@@ -68,6 +75,8 @@ struct SourceApi {
     ) -> i32,
     handle: extern "C" fn(id: u32, callback: SendCallback) -> i32,
     close: extern "C" fn(id: u32) -> i32,
+    work_units: extern "C" fn(id: u32, callback: WorkUnitsCallback) -> i32,
+    assign: extern "C" fn(id: u32, units_ptr: *const u8, units_len: usize) -> i32,
 }
 
 #[derive(WrapperApi)]
@@ -103,14 +112,8 @@ async fn main() -> Result<(), RuntimeError> {
         );
     }
 
-    Registry::default()
-        .with(tracing_subscriber::fmt::layer())
-        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
-        .init();
-
     let config_path =
         env::var("IGGY_CONNECTORS_CONFIG_PATH").unwrap_or_else(|_| "config".to_string());
-    info!("Starting Iggy Connectors Runtime, loading configuration from: {config_path}...");
     let builder = Config::builder()
         .add_source(File::with_name(&config_path))
         .add_source(Environment::with_prefix("IGGY_CONNECTORS").separator("_"));
@@ -121,18 +124,90 @@ async fn main() -> Result<(), RuntimeError> {
         .try_deserialize()
         .expect("Failed to deserialize runtime config");
 
+    // The config is loaded first, as the telemetry layer depends on it.
+    let (tracer_provider, telemetry_layer) = telemetry::init(&config.telemetry).unzip();
+    Registry::default()
+        .with(telemetry_layer)
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+    info!("Starting Iggy Connectors Runtime, loaded configuration from: {config_path}");
+    if config.telemetry.enabled {
+        info!(
+            "Exporting traces to: {} ({})",
+            config.telemetry.traces.endpoint, config.telemetry.traces.transport
+        );
+    }
+
     std::fs::create_dir_all(&config.state.path).expect("Failed to create state directory");
 
-    info!("State will be stored in: {}", config.state.path);
+    let state_storage = config.state.storage.unwrap_or_default();
+    let scale_out = config.sources.values().any(|source| {
+        source.enabled
+            && source
+                .scale_out
+                .as_ref()
+                .is_some_and(|scale_out| scale_out.enabled)
+    });
+    let iggy_clients = stream::init(config.iggy.clone(), state_storage, scale_out).await?;
+    let state_store = match state_storage {
+        StateStorageKind::File => {
+            info!("State will be stored in: {}", config.state.path);
+            None
+        }
+        StateStorageKind::Iggy => {
+            let stream = config
+                .state
+                .stream
+                .as_deref()
+                .unwrap_or(source::DEFAULT_STATE_STREAM);
+            let topic = config
+                .state
+                .topic
+                .as_deref()
+                .unwrap_or(source::DEFAULT_STATE_TOPIC);
+            let expiry = IggyExpiry::from_str(
+                config
+                    .state
+                    .expiry
+                    .as_deref()
+                    .unwrap_or(source::DEFAULT_STATE_EXPIRY),
+            )
+            .map_err(|error| {
+                RuntimeError::InvalidConfiguration(format!("Invalid state expiry. {error}"))
+            })?;
+            let state_store = match &iggy_clients.state {
+                Some(client) => {
+                    let state_store =
+                        Arc::new(IggyStateStore::new(client.clone(), stream, topic, expiry)?);
+                    state_store.init().await?;
+                    tokio::spawn(state_store.clone().run_refresh());
+                    Some(state_store)
+                }
+                None => None,
+            };
+            info!(
+                "State will be stored in Iggy stream: {stream}, topic: {topic} with message expiry: {expiry}"
+            );
+            state_store
+        }
+    };
 
-    let iggy_clients = stream::init(config.iggy.clone()).await?;
     let sources = source::init(
         config.sources.clone(),
         &iggy_clients.producer,
-        &config.state.path,
+        &config.state,
+        state_store.as_ref(),
+        iggy_clients.coordination.as_ref(),
+        config.telemetry.enabled,
+    )
+    .await?;
+    let sinks = sink::init(
+        config.sinks.clone(),
+        &iggy_clients.consumer,
+        config.telemetry.enabled,
     )
     .await?;
-    let sinks = sink::init(config.sinks.clone(), &iggy_clients.consumer).await?;
 
     let mut sink_wrappers = vec![];
     let mut sink_with_plugins = HashMap::new();
@@ -213,6 +288,18 @@ async fn main() -> Result<(), RuntimeError> {
 
     iggy_clients.producer.shutdown().await?;
     iggy_clients.consumer.shutdown().await?;
+    if let Some(state) = &iggy_clients.state {
+        state.shutdown().await?;
+    }
+    if let Some(coordination) = &iggy_clients.coordination {
+        coordination.shutdown().await?;
+    }
+
+    if let Some(tracer_provider) = tracer_provider
+        && let Err(error) = tracer_provider.shutdown()
+    {
+        error!("Failed to flush the traces. {error}");
+    }
 
     info!("All connectors closed. Runtime shutdown complete.");
     Ok(())
@@ -280,6 +367,7 @@ struct SourceConnectorPlugin {
     transforms: Vec<Arc<dyn Transform>>,
     producer: Option<SourceConnectorProducer>,
     state_storage: StateStorage,
+    coordinator: Option<WorkCoordinator>,
 }
 
 struct SourceConnectorProducer {
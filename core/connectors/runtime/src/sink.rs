@@ -36,11 +36,12 @@ use std::{
     sync::{Arc, atomic::Ordering},
     time::Instant,
 };
-use tracing::{error, info, warn};
+use tracing::{Instrument, error, info, info_span, warn};
 
 pub async fn init(
     sink_configs: HashMap<String, SinkConfig>,
     iggy_client: &IggyClient,
+    trace_context_propagation: bool,
 ) -> Result<HashMap<String, SinkConnector>, RuntimeError> {
     let mut sink_connectors: HashMap<String, SinkConnector> = HashMap::new();
     for (key, config) in sink_configs {
@@ -128,20 +129,27 @@ pub async fn init(
                 .unwrap_or(&default_consumer_group);
             let batch_length = stream.batch_length.unwrap_or(1000);
             for topic in stream.topics.iter() {
-                let mut consumer = iggy_client
+                let consumer = iggy_client
                     .consumer_group(consumer_group, &stream.stream, topic)?
                     .auto_commit(AutoCommit::When(AutoCommitWhen::PollingMessages))
                     .create_consumer_group_if_not_exists()
                     .auto_join_consumer_group()
                     .polling_strategy(PollingStrategy::next())
                     .poll_interval(poll_interval)
-                    .batch_length(batch_length)
-                    .build();
+                    .batch_length(batch_length);
+                let mut consumer = if trace_context_propagation {
+                    consumer.trace_context_propagation().build()
+                } else {
+                    consumer.build()
+                };
 
                 consumer.init().await?;
                 plugin.consumers.push(SinkConnectorConsumer {
                     consumer,
-                    decoder: stream.schema.decoder(),
+                    decoder: stream.schema.decoder(
+                        stream.schema_definition.as_deref(),
+                        stream.schema_path.as_deref(),
+                    )?,
                     batch_size: batch_length,
                     transforms: transforms.clone(),
                 });
@@ -195,6 +203,7 @@ async fn consume_messages(
     info!("Started consuming messages for sink connector with ID: {plugin_id}");
     let batch_size = batch_size as usize;
     let mut batch = Vec::with_capacity(batch_size);
+    let mut spans = Vec::with_capacity(batch_size);
     let topic_metadata = TopicMetadata {
         stream: consumer.stream().to_string(),
         topic: consumer.topic().to_string(),
@@ -210,12 +219,25 @@ async fn consume_messages(
         let current_offset = message.current_offset;
         let message_offset = message.message.header.offset;
         batch.push(message.message);
+        spans.push(message.span);
         if current_offset != message_offset && batch.len() < batch_size {
             continue;
         }
 
         let messages = std::mem::take(&mut batch);
         let messages_count = messages.len();
+        // The spans of the consumed messages, linked with their producers, end once the batch is processed.
+        let message_spans = std::mem::take(&mut spans);
+        let span = info_span!(
+            "iggy.connector.sink.consume",
+            otel.kind = "consumer",
+            iggy.connector.plugin_id = plugin_id,
+            messaging.destination.name = %topic_metadata.topic,
+            messaging.batch.message_count = messages_count,
+        );
+        for message_span in &message_spans {
+            span.follows_from(message_span);
+        }
         let messages_metadata = MessagesMetadata {
             partition_id,
             current_offset,
@@ -235,6 +257,7 @@ async fn consume_messages(
             &transforms,
             &decoder,
         )
+        .instrument(span)
         .await
         {
             error!(
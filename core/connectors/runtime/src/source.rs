@@ -23,8 +23,8 @@ use iggy::prelude::{
     DirectConfig, HeaderKey, HeaderValue, IggyClient, IggyDuration, IggyError, IggyMessage,
 };
 use iggy_connector_sdk::{
-    ConnectorState, DecodedMessage, Error, ProducedMessages, StreamEncoder, TopicMetadata,
-    transforms::Transform,
+    ConnectorState, DecodedMessage, Error, ProducedMessages, Schema, StreamEncoder, TopicMetadata,
+    WorkUnitState, transforms::Transform,
 };
 use once_cell::sync::Lazy;
 use std::{
@@ -37,18 +37,26 @@ use tracing::{debug, error, info, warn};
 use crate::{
     PLUGIN_ID, RuntimeError, SourceApi, SourceConnector, SourceConnectorPlugin,
     SourceConnectorProducer, SourceConnectorWrapper,
-    configs::SourceConfig,
+    configs::{ScaleOutConfig, SourceConfig, StateConfig, StateStorageKind},
+    coordinator::{WorkCoordinator, handle_work_units, take_work_units},
     resolve_plugin_path,
-    state::{FileStateProvider, StateProvider, StateStorage},
+    state::{FileStateProvider, IggyStateProvider, IggyStateStore, StateProvider, StateStorage},
     transform,
 };
 
+pub const DEFAULT_STATE_STREAM: &str = "connectors_state";
+pub const DEFAULT_STATE_TOPIC: &str = "state";
+pub const DEFAULT_STATE_EXPIRY: &str = "7 days";
+
 pub static SOURCE_SENDERS: Lazy<DashMap<u32, Sender<ProducedMessages>>> = Lazy::new(DashMap::new);
 
 pub async fn init(
     source_configs: HashMap<String, SourceConfig>,
     iggy_client: &IggyClient,
-    state_path: &str,
+    state_config: &StateConfig,
+    state_store: Option<&Arc<IggyStateStore>>,
+    coordination_client: Option<&Arc<IggyClient>>,
+    trace_context_propagation: bool,
 ) -> Result<HashMap<String, SourceConnector>, RuntimeError> {
     let mut source_connectors: HashMap<String, SourceConnector> = HashMap::new();
     for (key, config) in source_configs {
@@ -61,10 +69,7 @@ pub async fn init(
         let plugin_id = PLUGIN_ID.load(Ordering::Relaxed);
         let path = resolve_plugin_path(&config.path);
         info!("Initializing source container with name: {name} ({key}), plugin: {path}",);
-        let state_storage = get_state_storage(state_path, &key);
-        let state = match &state_storage {
-            StateStorage::File(file) => file.load().await?,
-        };
+        let (state_storage, state) = init_state_storage(state_config, state_store, &key).await?;
         if let Some(container) = source_connectors.get_mut(&path) {
             info!("Source container for plugin: {path} is already loaded.",);
             init_source(
@@ -82,6 +87,7 @@ pub async fn init(
                 producer: None,
                 transforms: vec![],
                 state_storage,
+                coordinator: None,
             });
         } else {
             let container: Container<SourceApi> =
@@ -106,6 +112,7 @@ pub async fn init(
                         producer: None,
                         transforms: vec![],
                         state_storage,
+                        coordinator: None,
                     }],
                 },
             );
@@ -139,26 +146,52 @@ pub async fn init(
             .find(|p| p.id == plugin_id)
             .expect("Failed to get source plugin");
 
+        if let Some(scale_out) = config.scale_out.as_ref().filter(|c| c.enabled) {
+            plugin.coordinator = init_coordinator(
+                &connector.container,
+                plugin_id,
+                &key,
+                scale_out,
+                coordination_client,
+                state_store,
+            )
+            .await?;
+        }
+
         for stream in config.streams.iter() {
             let linger_time =
                 IggyDuration::from_str(stream.linger_time.as_deref().unwrap_or("5ms"))
                     .expect("Invalid send interval");
             let batch_length = stream.batch_length.unwrap_or(1000);
-            let producer = iggy_client
-                .producer(&stream.stream, &stream.topic)?
-                .direct(
-                    DirectConfig::builder()
-                        .batch_length(batch_length)
-                        .linger_time(linger_time)
-                        .build(),
-                )
-                .build();
+            let producer = iggy_client.producer(&stream.stream, &stream.topic)?.direct(
+                DirectConfig::builder()
+                    .batch_length(batch_length)
+                    .linger_time(linger_time)
+                    .build(),
+            );
+            // Messages produced with the trace context in their headers keep it.
+            let producer = if trace_context_propagation {
+                producer.trace_context_propagation().build()
+            } else {
+                producer.build()
+            };
+
+            let encoder = stream.schema.encoder(
+                stream.schema_definition.as_deref(),
+                stream.schema_path.as_deref(),
+            )?;
+            if stream.schema == Schema::Avro
+                && stream.schema_definition.is_none()
+                && stream.schema_path.is_none()
+            {
+                warn!(
+                    "Avro schema is not configured for stream: {}, topic: {}, only the Avro payloads will be sent.",
+                    stream.stream, stream.topic
+                );
+            }
 
             producer.init().await?;
-            plugin.producer = Some(SourceConnectorProducer {
-                producer,
-                encoder: stream.schema.encoder(),
-            });
+            plugin.producer = Some(SourceConnectorProducer { producer, encoder });
             plugin.transforms = transforms.clone();
         }
     }
@@ -166,6 +199,50 @@ pub async fn init(
     Ok(source_connectors)
 }
 
+async fn init_coordinator(
+    container: &Container<SourceApi>,
+    plugin_id: u32,
+    key: &str,
+    config: &ScaleOutConfig,
+    client: Option<&Arc<IggyClient>>,
+    state_store: Option<&Arc<IggyStateStore>>,
+) -> Result<Option<WorkCoordinator>, RuntimeError> {
+    let Some(client) = client else {
+        return Err(RuntimeError::InvalidConfiguration(
+            "Iggy client for the scale-out coordination is not initialized".to_owned(),
+        ));
+    };
+
+    // The progress of the work units has to be shared between the runtime instances.
+    let Some(state_store) = state_store else {
+        return Err(RuntimeError::InvalidConfiguration(format!(
+            "Scale-out of source: {key} requires the Iggy state storage"
+        )));
+    };
+
+    (container.work_units)(plugin_id, handle_work_units);
+    let units = take_work_units(plugin_id);
+    if units.is_empty() {
+        warn!(
+            "Source connector with ID: {plugin_id} ({key}) does not expose any work units, scale-out is disabled."
+        );
+        return Ok(None);
+    }
+
+    info!("Source connector with ID: {plugin_id} ({key}) exposes work units: {units:?}");
+    let coordinator = WorkCoordinator::init(
+        plugin_id,
+        key,
+        config,
+        client.clone(),
+        units,
+        container.assign,
+        state_store.clone(),
+    )
+    .await?;
+    Ok(Some(coordinator))
+}
+
 fn init_source(
     container: &Container<SourceApi>,
     config: &serde_json::Value,
@@ -178,15 +255,37 @@ fn init_source(
     (container.open)(id, config.as_ptr(), config.len(), state_ptr, state_len);
 }
 
-fn get_state_storage(state_path: &str, key: &str) -> StateStorage {
-    let path = format!("{state_path}/source_{key}.state");
-    StateStorage::File(FileStateProvider::new(path))
+async fn init_state_storage(
+    config: &StateConfig,
+    store: Option<&Arc<IggyStateStore>>,
+    key: &str,
+) -> Result<(StateStorage, Option<ConnectorState>), RuntimeError> {
+    let path = format!("{}/source_{key}.state", config.path);
+    match (config.storage.unwrap_or_default(), store) {
+        (StateStorageKind::File, _) => {
+            let file = FileStateProvider::new(path);
+            let state = file.load().await?;
+            Ok((StateStorage::File(file), state))
+        }
+        (StateStorageKind::Iggy, Some(store)) => {
+            let iggy = IggyStateProvider::new(key.to_owned(), store.clone());
+            let state = iggy.migrate_file_state(&path).await?;
+            Ok((StateStorage::Iggy(iggy), state))
+        }
+        (StateStorageKind::Iggy, None) => Err(RuntimeError::InvalidConfiguration(
+            "Iggy state storage is not initialized".to_owned(),
+        )),
+    }
 }
 
 pub fn handle(sources: Vec<SourceConnectorWrapper>) {
     for source in sources {
         for plugin in source.plugins {
+            let mut plugin = plugin;
             let plugin_id = plugin.id;
+            if let Some(coordinator) = plugin.coordinator.take() {
+                tokio::spawn(coordinator.run());
+            }
             info!("Starting handler for source connector with ID: {plugin_id}...");
             let handle = source.callback;
             tokio::task::spawn_blocking(move || {
@@ -253,6 +352,13 @@ pub fn handle(sources: Vec<SourceConnectorWrapper>) {
                             producer.stream(),
                             producer.topic()
                         );
+                        save_work_units(
+                            plugin_id,
+                            &plugin.state_storage,
+                            produced_messages.work_units,
+                            false,
+                        )
+                        .await;
                         continue;
                     };
 
@@ -262,6 +368,13 @@ pub fn handle(sources: Vec<SourceConnectorWrapper>) {
                             producer.stream(),
                             producer.topic(),
                         );
+                        save_work_units(
+                            plugin_id,
+                            &plugin.state_storage,
+                            produced_messages.work_units,
+                            false,
+                        )
+                        .await;
                         continue;
                     }
 
@@ -271,28 +384,77 @@ pub fn handle(sources: Vec<SourceConnectorWrapper>) {
                         producer.topic()
                     );
 
+                    save_work_units(
+                        plugin_id,
+                        &plugin.state_storage,
+                        produced_messages.work_units,
+                        true,
+                    )
+                    .await;
                     let Some(state) = produced_messages.state else {
                         debug!("No state provided for source connector with ID: {plugin_id}");
                         continue;
                     };
 
-                    match &plugin.state_storage {
-                        StateStorage::File(file) => {
-                            if let Err(error) = file.save(state).await {
-                                error!(
-                                    "Failed to save state for source connector with ID: {plugin_id}. {error}"
-                                );
-                                continue;
-                            }
-                            debug!("State saved for source connector with ID: {plugin_id}");
-                        }
+                    if let Err(error) = plugin.state_storage.save(state).await {
+                        error!(
+                            "Failed to save state for source connector with ID: {plugin_id}. {error}"
+                        );
+                        continue;
                     }
+                    debug!(
+                        "State saved in {} storage for source connector with ID: {plugin_id}",
+                        plugin.state_storage
+                    );
                 }
             });
         }
     }
 }
 
+/// Saves the progress of the work units once the messages produced along with it are sent. Otherwise, the released
+/// work units keep their last saved progress, so the next owner produces the unsent messages again.
+async fn save_work_units(
+    plugin_id: u32,
+    state_storage: &StateStorage,
+    units: Vec<WorkUnitState>,
+    sent: bool,
+) {
+    if units.is_empty() {
+        return;
+    }
+
+    let StateStorage::Iggy(iggy) = state_storage else {
+        error!(
+            "Cannot save progress of work units for source connector with ID: {plugin_id} without Iggy state storage."
+        );
+        return;
+    };
+
+    for unit in units.into_iter().filter(|unit| sent || unit.released) {
+        let state = if sent { unit.state } else { None };
+        if let Err(error) = iggy.save_work_unit(&unit.name, state, unit.released).await {
+            error!(
+                "Failed to save progress of work unit: {} for source connector with ID: {plugin_id}. {error}",
+                unit.name
+            );
+            continue;
+        }
+
+        if unit.released {
+            info!(
+                "Released work unit: {} of source connector with ID: {plugin_id}",
+                unit.name
+            );
+        } else {
+            debug!(
+                "Saved progress of work unit: {} for source connector with ID: {plugin_id}",
+                unit.name
+            );
+        }
+    }
+}
+
 fn process_messages(
     id: u32,
     encoder: &Arc<dyn StreamEncoder>,
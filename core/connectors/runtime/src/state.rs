@@ -15,27 +15,69 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::io::SeekFrom;
+use std::{
+    collections::HashMap,
+    io::SeekFrom,
+    path::Path,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use dashmap::DashMap;
+use iggy::prelude::{
+    Consumer, ConsumerOffsetClient, HeaderKey, HeaderValue, Identifier, IggyClient, IggyError,
+    IggyExpiry, IggyMessage, IggyTimestamp, MaxTopicSize, MessageClient, Partitioning,
+    PolledMessages, PollingStrategy, StreamClient, TopicClient,
+};
 use iggy_connector_sdk::{ConnectorState, Error};
+
+use crate::coordinator::work_unit_key;
 use strum::Display;
 use tokio::{
     fs::{File, OpenOptions},
     io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
     sync::Mutex,
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 pub trait StateProvider {
     async fn load(&self) -> Result<Option<ConnectorState>, Error>;
     async fn save(&self, state: ConnectorState) -> Result<(), Error>;
 }
 
+const STATE_PARTITION_ID: u32 = 1;
+const STATE_MIN_POLL_BATCH: u64 = 10;
+const STATE_MAX_POLL_BATCH: u64 = 1000;
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+const CONNECTOR_KEY_HEADER: &str = "connector_key";
+const OWNER_HEADER: &str = "owner";
+const RELEASED_HEADER: &str = "released";
+const EMPTY_STATE_HEADER: &str = "empty_state";
+
 #[non_exhaustive]
 #[derive(Debug, Display)]
 pub enum StateStorage {
     #[strum(to_string = "file")]
     File(FileStateProvider),
+    #[strum(to_string = "iggy")]
+    Iggy(IggyStateProvider),
+}
+
+impl StateProvider for StateStorage {
+    async fn load(&self) -> Result<Option<ConnectorState>, Error> {
+        match self {
+            StateStorage::File(file) => file.load().await,
+            StateStorage::Iggy(iggy) => iggy.load().await,
+        }
+    }
+
+    async fn save(&self, state: ConnectorState) -> Result<(), Error> {
+        match self {
+            StateStorage::File(file) => file.save(state).await,
+            StateStorage::Iggy(iggy) => iggy.save(state).await,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -104,3 +146,767 @@ impl StateProvider for FileStateProvider {
         Ok(())
     }
 }
+
+/// Stores the connector state snapshots in the internal stream and topic on the connected server.
+///
+/// Each snapshot is appended to the single partition topic with the connector key in the headers,
+/// and the most recent snapshot for the given key is the current state. The topic is append-only
+/// (there's no compaction), so its size is bounded by the message expiry, while the snapshots saved
+/// by this runtime are saved again before they could expire. The offset of the most recent snapshot
+/// is stored as the consumer offset of the consumer named after the key, so the state can be loaded
+/// without scanning the topic.
+#[derive(Debug)]
+pub struct IggyStateStore {
+    client: Arc<IggyClient>,
+    stream: String,
+    topic: String,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    expiry: IggyExpiry,
+    snapshots: DashMap<String, Arc<Mutex<Option<Snapshot>>>>,
+}
+
+#[derive(Debug)]
+struct Snapshot {
+    record: StateRecord,
+    saved_at: Instant,
+}
+
+/// The state snapshot, along with the owner (the ID of the coordination client) of the work unit it belongs to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateRecord {
+    pub state: Vec<u8>,
+    pub owner: Option<u32>,
+    pub released: bool,
+}
+
+impl StateRecord {
+    fn from_message(message: &IggyMessage) -> Self {
+        let header = |name: &str| {
+            HeaderKey::new(name)
+                .ok()
+                .and_then(|key| message.get_user_header(&key).ok().flatten())
+        };
+        let empty_state =
+            header(EMPTY_STATE_HEADER).is_some_and(|value| value.as_bool().unwrap_or(false));
+        StateRecord {
+            state: if empty_state {
+                vec![]
+            } else {
+                message.payload.to_vec()
+            },
+            owner: header(OWNER_HEADER).and_then(|value| value.as_uint32().ok()),
+            released: header(RELEASED_HEADER).is_some_and(|value| value.as_bool().unwrap_or(false)),
+        }
+    }
+}
+
+impl IggyStateStore {
+    pub fn new(
+        client: Arc<IggyClient>,
+        stream: &str,
+        topic: &str,
+        expiry: IggyExpiry,
+    ) -> Result<Self, IggyError> {
+        Ok(IggyStateStore {
+            client,
+            stream: stream.to_owned(),
+            topic: topic.to_owned(),
+            stream_id: Identifier::named(stream)?,
+            topic_id: Identifier::named(topic)?,
+            expiry,
+            snapshots: DashMap::new(),
+        })
+    }
+
+    /// Creates the state stream and topic if they don't exist yet, or updates the message expiry of the existing topic.
+    pub async fn init(&self) -> Result<(), IggyError> {
+        if self.client.get_stream(&self.stream_id).await?.is_none() {
+            info!("Creating state stream: {}", self.stream);
+            self.client.create_stream(&self.stream, None).await?;
+        }
+
+        match self
+            .client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await?
+        {
+            None => {
+                info!(
+                    "Creating state topic: {} in stream: {} with message expiry: {}",
+                    self.topic, self.stream, self.expiry
+                );
+                self.client
+                    .create_topic(
+                        &self.stream_id,
+                        &self.topic,
+                        1,
+                        Default::default(),
+                        None,
+                        None,
+                        self.expiry,
+                        MaxTopicSize::ServerDefault,
+                    )
+                    .await?;
+            }
+            Some(topic) if topic.message_expiry != self.expiry => {
+                info!(
+                    "Updating message expiry of state topic: {} in stream: {} to: {}",
+                    self.topic, self.stream, self.expiry
+                );
+                self.client
+                    .update_topic(
+                        &self.stream_id,
+                        &self.topic_id,
+                        &self.topic,
+                        topic.compression_algorithm,
+                        Some(topic.replication_factor),
+                        self.expiry,
+                        topic.max_topic_size,
+                    )
+                    .await?;
+            }
+            Some(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Periodically saves again the snapshots which could expire otherwise, e.g. when the connector is idle.
+    pub async fn run_refresh(self: Arc<Self>) {
+        let IggyExpiry::ExpireDuration(expiry) = self.expiry else {
+            return;
+        };
+
+        let max_age = expiry.get_duration() / 2;
+        let mut interval = tokio::time::interval((max_age / 2).max(MIN_REFRESH_INTERVAL));
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            self.refresh(max_age).await;
+        }
+    }
+
+    async fn refresh(&self, max_age: Duration) {
+        let snapshots = self
+            .snapshots
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        for (key, snapshot) in snapshots {
+            let mut snapshot = snapshot.lock().await;
+            let Some(record) = snapshot
+                .as_ref()
+                .filter(|snapshot| snapshot.saved_at.elapsed() >= max_age)
+                .map(|snapshot| snapshot.record.clone())
+            else {
+                continue;
+            };
+
+            if let Err(error) = self.append(&key, record, &mut snapshot).await {
+                error!("Failed to refresh state for connector: {key}. {error}");
+                continue;
+            }
+            debug!("Refreshed state in Iggy for connector: {key}");
+        }
+    }
+
+    /// Loads the current state for the given key, which will be refreshed from now on.
+    pub async fn load(&self, key: &str) -> Result<Option<ConnectorState>, Error> {
+        let Some(message) = self.find(key).await? else {
+            info!("No state stored in Iggy for connector: {key}");
+            return Ok(None);
+        };
+
+        info!(
+            "Loaded state for connector: {key} from Iggy at offset: {}",
+            message.header.offset
+        );
+        let age = IggyTimestamp::now()
+            .as_micros()
+            .saturating_sub(message.header.timestamp);
+        let saved_at = Instant::now()
+            .checked_sub(Duration::from_micros(age))
+            .unwrap_or_else(Instant::now);
+        let record = StateRecord::from_message(&message);
+        let state = record.state.clone();
+        self.snapshots.insert(
+            key.to_owned(),
+            Arc::new(Mutex::new(Some(Snapshot { record, saved_at }))),
+        );
+        Ok(Some(ConnectorState(state)))
+    }
+
+    /// Loads the current record for the given key, without refreshing it, e.g. to check the owner of the work unit.
+    pub async fn load_record(&self, key: &str) -> Result<Option<StateRecord>, Error> {
+        Ok(self
+            .find(key)
+            .await?
+            .map(|message| StateRecord::from_message(&message)))
+    }
+
+    pub async fn save(&self, key: &str, state: Vec<u8>) -> Result<(), Error> {
+        self.save_record(
+            key,
+            StateRecord {
+                state,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    pub async fn save_record(&self, key: &str, record: StateRecord) -> Result<(), Error> {
+        let snapshot = self.snapshots.entry(key.to_owned()).or_default().clone();
+        let mut snapshot = snapshot.lock().await;
+        self.append(key, record, &mut snapshot).await
+    }
+
+    /// Saves the progress of the work unit owned by this runtime, or the last saved one if there's no progress.
+    /// Once the work unit is released, its record is no longer refreshed, as it's up to the next owner now.
+    pub async fn save_progress(
+        &self,
+        key: &str,
+        state: Option<Vec<u8>>,
+        released: bool,
+    ) -> Result<(), Error> {
+        let snapshot = self.snapshots.entry(key.to_owned()).or_default().clone();
+        let mut snapshot = snapshot.lock().await;
+        let previous = snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.record.clone())
+            .unwrap_or_default();
+        let record = StateRecord {
+            state: state.unwrap_or(previous.state),
+            owner: previous.owner,
+            released,
+        };
+        self.append(key, record, &mut snapshot).await?;
+        if released {
+            self.snapshots.remove(key);
+        }
+        Ok(())
+    }
+
+    async fn append(
+        &self,
+        key: &str,
+        record: StateRecord,
+        snapshot: &mut Option<Snapshot>,
+    ) -> Result<(), Error> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new(CONNECTOR_KEY_HEADER).map_err(|_| Error::CannotWriteState)?,
+            HeaderValue::from_str(key).map_err(|_| Error::CannotWriteState)?,
+        );
+        if let Some(owner) = record.owner {
+            headers.insert(
+                HeaderKey::new(OWNER_HEADER).map_err(|_| Error::CannotWriteState)?,
+                HeaderValue::from_uint32(owner).map_err(|_| Error::CannotWriteState)?,
+            );
+        }
+        if record.released {
+            headers.insert(
+                HeaderKey::new(RELEASED_HEADER).map_err(|_| Error::CannotWriteState)?,
+                HeaderValue::from_bool(true).map_err(|_| Error::CannotWriteState)?,
+            );
+        }
+
+        // The message payload cannot be empty, e.g. when the work unit has no progress yet.
+        let payload = if record.state.is_empty() {
+            headers.insert(
+                HeaderKey::new(EMPTY_STATE_HEADER).map_err(|_| Error::CannotWriteState)?,
+                HeaderValue::from_bool(true).map_err(|_| Error::CannotWriteState)?,
+            );
+            vec![0]
+        } else {
+            record.state.clone()
+        };
+
+        let message = IggyMessage::builder()
+            .payload(payload.into())
+            .user_headers(headers)
+            .build()
+            .map_err(|error| {
+                error!("Cannot build state message for connector: {key}. {error}");
+                Error::CannotWriteState
+            })?;
+
+        self.client
+            .send_messages(
+                &self.stream_id,
+                &self.topic_id,
+                &Partitioning::partition_id(STATE_PARTITION_ID),
+                &mut [message],
+            )
+            .await
+            .map_err(|error| {
+                error!("Cannot save state for connector: {key}. {error}");
+                Error::CannotWriteState
+            })?;
+
+        snapshot.replace(Snapshot {
+            record,
+            saved_at: Instant::now(),
+        });
+        debug!("Saved state in Iggy for connector: {key}");
+        Ok(())
+    }
+
+    async fn store_index(&self, key: &str, offset: u64) -> Result<(), IggyError> {
+        let consumer = Consumer::new(Identifier::named(key)?);
+        self.client
+            .store_consumer_offset(
+                &consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(STATE_PARTITION_ID),
+                offset,
+            )
+            .await
+    }
+
+    /// Finds the latest snapshot for the key and indexes it, the index is not updated on save
+    /// so it points to the snapshot found by the previous lookup and serves as the scan boundary.
+    async fn find(&self, key: &str) -> Result<Option<IggyMessage>, Error> {
+        let indexed_offset = self.get_index(key).await?;
+        let mut message = self.find_latest(key, indexed_offset).await?;
+        if message.is_none() && indexed_offset.is_some() {
+            // The indexed snapshot might have expired, or the index might belong to another key with the same hash.
+            message = self.find_latest(key, None).await?;
+        }
+
+        if let Some(message) = &message
+            && indexed_offset != Some(message.header.offset)
+            && let Err(error) = self.store_index(key, message.header.offset).await
+        {
+            warn!("Failed to index state for connector: {key}. {error}");
+        }
+        Ok(message)
+    }
+
+    async fn get_index(&self, key: &str) -> Result<Option<u64>, Error> {
+        let consumer = Consumer::new(Identifier::named(key).map_err(|_| Error::CannotReadState)?);
+        let offset = self
+            .client
+            .get_consumer_offset(
+                &consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(STATE_PARTITION_ID),
+            )
+            .await
+            .map_err(|error| {
+                error!("Cannot get state index for connector: {key}. {error}");
+                Error::CannotReadState
+            })?;
+        Ok(offset.map(|offset| offset.stored_offset))
+    }
+
+    /// Scans the partition backwards, as the latest snapshot for the key is the current one.
+    /// The scan stops at the given offset, as no older snapshot can be the latest one.
+    async fn find_latest(
+        &self,
+        key: &str,
+        min_offset: Option<u64>,
+    ) -> Result<Option<IggyMessage>, Error> {
+        let topic = self
+            .client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .map_err(|error| {
+                error!("Cannot get state topic for connector: {key}. {error}");
+                Error::CannotReadState
+            })?
+            .ok_or_else(|| {
+                error!("State topic not found for connector: {key}");
+                Error::CannotReadState
+            })?;
+
+        let Some(partition) = topic
+            .partitions
+            .iter()
+            .find(|partition| partition.id == STATE_PARTITION_ID)
+        else {
+            error!("State partition not found for connector: {key}");
+            return Err(Error::CannotReadState);
+        };
+
+        if partition.messages_count == 0 {
+            return Ok(None);
+        }
+
+        // The scan ends at the oldest retained snapshot, as the older ones might have expired already.
+        let Some(first_offset) = self
+            .client
+            .poll_messages(
+                &self.stream_id,
+                &self.topic_id,
+                Some(STATE_PARTITION_ID),
+                &Consumer::default(),
+                &PollingStrategy::first(),
+                1,
+                false,
+            )
+            .await
+            .map_err(|error| {
+                error!("Cannot poll state for connector: {key}. {error}");
+                Error::CannotReadState
+            })?
+            .messages
+            .first()
+            .map(|message| message.header.offset)
+        else {
+            return Ok(None);
+        };
+
+        let first_offset = first_offset.max(min_offset.unwrap_or_default());
+        let mut end_offset = partition.current_offset;
+        let mut batch = STATE_MIN_POLL_BATCH;
+        while end_offset >= first_offset {
+            let start_offset = end_offset.saturating_sub(batch - 1).max(first_offset);
+            let polled_messages = self
+                .poll(start_offset, (end_offset - start_offset + 1) as u32)
+                .await
+                .inspect_err(|error| error!("Cannot poll state for connector: {key}. {error}"))?;
+
+            if let Some(message) = polled_messages
+                .messages
+                .into_iter()
+                .rev()
+                .filter(|message| message.header.offset <= end_offset)
+                .find(|message| is_state_of(message, key))
+            {
+                return Ok(Some(message));
+            }
+
+            if start_offset == 0 {
+                break;
+            }
+            end_offset = start_offset - 1;
+            batch = (batch * 10).min(STATE_MAX_POLL_BATCH);
+        }
+        Ok(None)
+    }
+
+    async fn poll(&self, offset: u64, count: u32) -> Result<PolledMessages, Error> {
+        self.client
+            .poll_messages(
+                &self.stream_id,
+                &self.topic_id,
+                Some(STATE_PARTITION_ID),
+                &Consumer::default(),
+                &PollingStrategy::offset(offset),
+                count,
+                false,
+            )
+            .await
+            .map_err(|_| Error::CannotReadState)
+    }
+}
+
+fn is_state_of(message: &IggyMessage, key: &str) -> bool {
+    let Ok(header_key) = HeaderKey::new(CONNECTOR_KEY_HEADER) else {
+        return false;
+    };
+
+    match message.get_user_header(&header_key) {
+        Ok(Some(value)) => value.as_str().is_ok_and(|value| value == key),
+        _ => false,
+    }
+}
+
+/// Stores the state of the single connector in the Iggy state storage.
+#[derive(Debug)]
+pub struct IggyStateProvider {
+    key: String,
+    store: Arc<IggyStateStore>,
+}
+
+impl IggyStateProvider {
+    pub fn new(key: String, store: Arc<IggyStateStore>) -> Self {
+        IggyStateProvider { key, store }
+    }
+
+    /// Moves the existing state file (if any) into Iggy, unless the state for the connector is already stored there.
+    pub async fn migrate_file_state(&self, path: &str) -> Result<Option<ConnectorState>, Error> {
+        if let Some(state) = self.load().await? {
+            return Ok(Some(state));
+        }
+
+        if !Path::new(path).exists() {
+            return Ok(None);
+        }
+
+        let state = FileStateProvider::new(path.to_owned()).load().await?;
+        let Some(state) = state else {
+            return Ok(None);
+        };
+
+        self.save(ConnectorState(state.0.clone())).await?;
+        info!(
+            "Migrated state file: {path} to Iggy state storage for connector: {}",
+            self.key
+        );
+        Ok(Some(state))
+    }
+}
+
+impl IggyStateProvider {
+    /// Saves the progress of the work unit of the scaled out connector.
+    pub async fn save_work_unit(
+        &self,
+        unit: &str,
+        state: Option<ConnectorState>,
+        released: bool,
+    ) -> Result<(), Error> {
+        self.store
+            .save_progress(
+                &work_unit_key(&self.key, unit),
+                state.map(|state| state.0),
+                released,
+            )
+            .await
+    }
+}
+
+impl StateProvider for IggyStateProvider {
+    async fn load(&self) -> Result<Option<ConnectorState>, Error> {
+        self.store.load(&self.key).await
+    }
+
+    async fn save(&self, state: ConnectorState) -> Result<(), Error> {
+        self.store.save(&self.key, state.0).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::prelude::Client;
+    use integration::{
+        tcp_client::TcpClientFactory,
+        test_server::{ClientFactory, TestServer, login_root},
+    };
+
+    const STREAM: &str = "connectors_state";
+    const TOPIC: &str = "state";
+
+    async fn init_store(test_server: &TestServer, expiry: IggyExpiry) -> Arc<IggyStateStore> {
+        let client_factory = TcpClientFactory {
+            server_addr: test_server.get_raw_tcp_addr().unwrap(),
+            ..Default::default()
+        };
+        let client = IggyClient::create(client_factory.create_client().await, None, None);
+        client.connect().await.unwrap();
+        login_root(&client).await;
+        let store = IggyStateStore::new(Arc::new(client), STREAM, TOPIC, expiry).unwrap();
+        store.init().await.unwrap();
+        Arc::new(store)
+    }
+
+    async fn messages_count(store: &IggyStateStore) -> u64 {
+        store
+            .client
+            .get_topic(&store.stream_id, &store.topic_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .messages_count
+    }
+
+    #[tokio::test]
+    async fn load_should_return_latest_state_for_each_connector() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        let first = IggyStateProvider::new("first".to_owned(), store.clone());
+        let second = IggyStateProvider::new("second".to_owned(), store.clone());
+        let third = IggyStateProvider::new("third".to_owned(), store.clone());
+
+        first.save(ConnectorState(vec![1])).await.unwrap();
+        second.save(ConnectorState(vec![2])).await.unwrap();
+        first.save(ConnectorState(vec![3])).await.unwrap();
+
+        assert_eq!(first.load().await.unwrap().unwrap().0, vec![3]);
+        assert_eq!(second.load().await.unwrap().unwrap().0, vec![2]);
+        assert!(third.load().await.unwrap().is_none());
+    }
+
+    async fn index(store: &IggyStateStore, key: &str) -> Option<u64> {
+        store
+            .client
+            .get_consumer_offset(
+                &Consumer::new(Identifier::named(key).unwrap()),
+                &store.stream_id,
+                &store.topic_id,
+                Some(STATE_PARTITION_ID),
+            )
+            .await
+            .unwrap()
+            .map(|offset| offset.stored_offset)
+    }
+
+    #[tokio::test]
+    async fn load_should_scan_topic_given_missing_index() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        store.save("first", vec![1]).await.unwrap();
+        for value in 0..50 {
+            store.save("second", vec![value]).await.unwrap();
+        }
+        assert_eq!(index(&store, "first").await, None);
+
+        assert_eq!(store.load("first").await.unwrap().unwrap().0, vec![1]);
+        assert_eq!(index(&store, "first").await, Some(0));
+    }
+
+    #[tokio::test]
+    async fn load_should_return_state_saved_after_indexed_one() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        store.save("first", vec![1]).await.unwrap();
+        assert_eq!(store.load("first").await.unwrap().unwrap().0, vec![1]);
+        store.save("second", vec![2]).await.unwrap();
+        store.save("first", vec![3]).await.unwrap();
+        assert_eq!(index(&store, "first").await, Some(0));
+
+        assert_eq!(store.load("first").await.unwrap().unwrap().0, vec![3]);
+        assert_eq!(index(&store, "first").await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn refresh_should_save_again_only_stale_snapshots() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        store.save("first", vec![1]).await.unwrap();
+
+        store.refresh(Duration::from_secs(3600)).await;
+        assert_eq!(messages_count(&store).await, 1);
+
+        store.refresh(Duration::ZERO).await;
+        assert_eq!(messages_count(&store).await, 2);
+        assert_eq!(store.load("first").await.unwrap().unwrap().0, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn save_progress_should_keep_owner_and_last_saved_state() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        let key = "first/table";
+        store
+            .save_record(
+                key,
+                StateRecord {
+                    state: vec![],
+                    owner: Some(1),
+                    released: false,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            store.load_record(key).await.unwrap().unwrap(),
+            StateRecord {
+                state: vec![],
+                owner: Some(1),
+                released: false,
+            }
+        );
+
+        store.save_progress(key, Some(vec![2]), false).await.unwrap();
+        store.save_progress(key, None, true).await.unwrap();
+
+        assert_eq!(
+            store.load_record(key).await.unwrap().unwrap(),
+            StateRecord {
+                state: vec![2],
+                owner: Some(1),
+                released: true,
+            }
+        );
+        assert!(!store.snapshots.contains_key(key));
+    }
+
+    #[tokio::test]
+    async fn init_should_update_expiry_of_existing_topic() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let expiry = IggyExpiry::from_str("1 hour").unwrap();
+        init_store(&test_server, IggyExpiry::NeverExpire).await;
+        let store = init_store(&test_server, expiry).await;
+
+        let topic = store
+            .client
+            .get_topic(&store.stream_id, &store.topic_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(topic.message_expiry, expiry);
+    }
+
+    #[test]
+    fn new_should_fail_given_invalid_stream_name() {
+        let client = Arc::new(IggyClient::default());
+        assert!(IggyStateStore::new(client, "", TOPIC, IggyExpiry::NeverExpire).is_err());
+    }
+
+    #[tokio::test]
+    async fn migrate_file_state_should_move_state_file_into_iggy() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("source_first.state");
+        std::fs::write(&path, [1, 2, 3]).unwrap();
+
+        let provider = IggyStateProvider::new("first".to_owned(), store.clone());
+        let state = provider
+            .migrate_file_state(path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(state.unwrap().0, vec![1, 2, 3]);
+        assert_eq!(provider.load().await.unwrap().unwrap().0, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn migrate_file_state_should_keep_state_already_stored_in_iggy() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("source_first.state");
+        std::fs::write(&path, [1, 2, 3]).unwrap();
+
+        let provider = IggyStateProvider::new("first".to_owned(), store.clone());
+        provider.save(ConnectorState(vec![4])).await.unwrap();
+        let state = provider
+            .migrate_file_state(path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(state.unwrap().0, vec![4]);
+        assert_eq!(messages_count(&store).await, 1);
+    }
+
+    #[tokio::test]
+    async fn migrate_file_state_should_return_none_given_no_state() {
+        let mut test_server = TestServer::default();
+        test_server.start();
+        let store = init_store(&test_server, IggyExpiry::NeverExpire).await;
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("source_first.state");
+
+        let provider = IggyStateProvider::new("first".to_owned(), store);
+        let state = provider
+            .migrate_file_state(path.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert!(state.is_none());
+    }
+}
@@ -15,20 +15,45 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
 use iggy::prelude::{Client, IggyClient, IggyClientBuilder};
 use tracing::{error, info};
 
-use crate::{configs::IggyConfig, error::RuntimeError};
+use crate::{
+    configs::{IggyConfig, StateStorageKind},
+    error::RuntimeError,
+};
 
 pub struct IggyClients {
     pub producer: IggyClient,
     pub consumer: IggyClient,
+    pub state: Option<Arc<IggyClient>>,
+    pub coordination: Option<Arc<IggyClient>>,
 }
 
-pub async fn init(config: IggyConfig) -> Result<IggyClients, RuntimeError> {
+pub async fn init(
+    config: IggyConfig,
+    state_storage: StateStorageKind,
+    scale_out: bool,
+) -> Result<IggyClients, RuntimeError> {
     let consumer = create_client(&config).await?;
     let producer = create_client(&config).await?;
-    let iggy_clients = IggyClients { producer, consumer };
+    let state = match state_storage {
+        StateStorageKind::File => None,
+        StateStorageKind::Iggy => Some(Arc::new(create_client(&config).await?)),
+    };
+    let coordination = if scale_out {
+        Some(Arc::new(create_client(&config).await?))
+    } else {
+        None
+    };
+    let iggy_clients = IggyClients {
+        producer,
+        consumer,
+        state,
+        coordination,
+    };
     Ok(iggy_clients)
 }
 
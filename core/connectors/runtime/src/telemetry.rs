@@ -0,0 +1,67 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::{TelemetryConfig, TelemetryTransport};
+use opentelemetry::KeyValue;
+use opentelemetry::global;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::Registry;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Exports the spans of the runtime, including the ones of sending and consuming the messages,
+/// whose trace context is propagated through the message headers.
+pub fn init(
+    config: &TelemetryConfig,
+) -> Option<(SdkTracerProvider, OpenTelemetryLayer<Registry, SdkTracer>)> {
+    if !config.enabled {
+        return None;
+    }
+
+    let resource = Resource::builder()
+        .with_service_name(config.service_name.clone())
+        .with_attribute(KeyValue::new("service.version", VERSION))
+        .build();
+    let exporter = match config.traces.transport {
+        TelemetryTransport::Grpc => SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(config.traces.endpoint.clone())
+            .build()
+            .expect("Failed to initialize gRPC tracer."),
+        TelemetryTransport::Http => SpanExporter::builder()
+            .with_http()
+            .with_endpoint(config.traces.endpoint.clone())
+            .with_protocol(opentelemetry_otlp::Protocol::HttpBinary)
+            .build()
+            .expect("Failed to initialize HTTP tracer."),
+    };
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_resource(resource)
+        .with_batch_exporter(exporter)
+        .build();
+
+    let tracer = tracer_provider.tracer(config.service_name.clone());
+    global::set_tracer_provider(tracer_provider.clone());
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    Some((tracer_provider, OpenTelemetryLayer::new(tracer)))
+}
@@ -0,0 +1,260 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Error, Payload, Schema, StreamDecoder};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::error;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvroConfig {
+    /// Path to the Avro schema (`.avsc`) file used to decode the single datum payloads.
+    pub schema_path: Option<PathBuf>,
+    /// Inline Avro schema in JSON format, takes precedence over `schema_path`.
+    pub schema: Option<String>,
+    pub extract_as_json: bool,
+}
+
+impl Default for AvroConfig {
+    fn default() -> Self {
+        Self {
+            schema_path: None,
+            schema: None,
+            extract_as_json: true,
+        }
+    }
+}
+
+/// Decodes Avro payloads into JSON.
+///
+/// When the schema is configured, the payload is expected to be a single datum encoded with
+/// that schema, otherwise it's expected to be an object container file with embedded schema.
+/// Payloads which cannot be decoded without the schema are passed through as `Payload::Avro`.
+pub struct AvroStreamDecoder {
+    config: AvroConfig,
+    schema: Option<apache_avro::Schema>,
+}
+
+impl AvroStreamDecoder {
+    /// Creates the decoder, fails if the configured schema cannot be loaded.
+    pub fn new(config: AvroConfig) -> Result<Self, Error> {
+        let schema = load_schema(config.schema.as_deref(), config.schema_path.as_deref())
+            .inspect_err(|e| error!("Failed to load Avro schema during decoder creation: {e}"))?;
+        Ok(Self { config, schema })
+    }
+
+    /// Creates the decoder without the schema, expecting the object container files.
+    pub fn new_default() -> Self {
+        Self {
+            config: AvroConfig::default(),
+            schema: None,
+        }
+    }
+
+    fn decode_datum(&self, schema: &apache_avro::Schema, data: &[u8]) -> Result<Payload, Error> {
+        let value = apache_avro::from_avro_datum(schema, &mut &data[..], None).map_err(|e| {
+            error!("Failed to decode Avro datum: {e}");
+            Error::CannotDecode(Schema::Avro)
+        })?;
+        Ok(Payload::Json(avro_to_json(value)?))
+    }
+
+    fn decode_container(&self, data: Vec<u8>) -> Result<Payload, Error> {
+        let Ok(reader) = apache_avro::Reader::new(data.as_slice()) else {
+            return Ok(Payload::Avro(data));
+        };
+
+        let mut values = reader
+            .map(|value| {
+                value
+                    .map_err(|e| {
+                        error!("Failed to decode Avro container value: {e}");
+                        Error::CannotDecode(Schema::Avro)
+                    })
+                    .and_then(avro_to_json)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if values.len() == 1 {
+            return Ok(Payload::Json(values.remove(0)));
+        }
+        Ok(Payload::Json(simd_json::OwnedValue::Array(Box::new(
+            values,
+        ))))
+    }
+}
+
+impl StreamDecoder for AvroStreamDecoder {
+    fn schema(&self) -> Schema {
+        Schema::Avro
+    }
+
+    fn decode(&self, payload: Vec<u8>) -> Result<Payload, Error> {
+        if payload.is_empty() {
+            return Err(Error::InvalidPayloadType);
+        }
+
+        if !self.config.extract_as_json {
+            return Ok(Payload::Avro(payload));
+        }
+
+        match &self.schema {
+            Some(schema) => self.decode_datum(schema, &payload),
+            None => self.decode_container(payload),
+        }
+    }
+}
+
+impl Default for AvroStreamDecoder {
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
+/// Loads the Avro schema from the inline definition or the file, if any was provided.
+pub fn load_schema(
+    schema: Option<&str>,
+    schema_path: Option<&Path>,
+) -> Result<Option<apache_avro::Schema>, Error> {
+    let definition = match (schema, schema_path) {
+        (Some(schema), _) => schema.to_owned(),
+        (None, Some(path)) => std::fs::read_to_string(path).map_err(|e| {
+            Error::InitError(format!("Failed to read Avro schema file: {path:?}. {e}"))
+        })?,
+        (None, None) => return Ok(None),
+    };
+
+    apache_avro::Schema::parse_str(&definition)
+        .map(Some)
+        .map_err(|e| Error::InitError(format!("Failed to parse Avro schema: {e}")))
+}
+
+pub(crate) fn avro_to_json(
+    value: apache_avro::types::Value,
+) -> Result<simd_json::OwnedValue, Error> {
+    let json = serde_json::Value::try_from(value).map_err(|e| {
+        error!("Failed to convert Avro value to JSON: {e}");
+        Error::CannotDecode(Schema::Avro)
+    })?;
+    serde_json::from_value(json).map_err(|_| Error::InvalidJsonPayload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apache_avro::types::Record;
+
+    const USER_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"},
+            {"name": "email", "type": ["null", "string"], "default": null}
+        ]
+    }"#;
+
+    fn user_record(schema: &apache_avro::Schema) -> apache_avro::types::Value {
+        let mut record = Record::new(schema).unwrap();
+        record.put("id", 1i64);
+        record.put("name", "John");
+        record.put(
+            "email",
+            apache_avro::types::Value::Union(1, Box::new("john@example.com".into())),
+        );
+        record.into()
+    }
+
+    #[test]
+    fn decode_should_return_json_given_datum_and_schema() {
+        let decoder = AvroStreamDecoder::new(AvroConfig {
+            schema: Some(USER_SCHEMA.to_string()),
+            ..AvroConfig::default()
+        })
+        .unwrap();
+        let schema = apache_avro::Schema::parse_str(USER_SCHEMA).unwrap();
+        let datum = apache_avro::to_avro_datum(&schema, user_record(&schema)).unwrap();
+
+        let result = decoder.decode(datum).unwrap();
+
+        let Payload::Json(simd_json::OwnedValue::Object(map)) = result else {
+            panic!("Expected JSON object");
+        };
+        assert_eq!(map["id"], 1);
+        assert_eq!(map["name"], "John");
+        assert_eq!(map["email"], "john@example.com");
+    }
+
+    #[test]
+    fn decode_should_return_json_given_container_file_without_schema() {
+        let decoder = AvroStreamDecoder::default();
+        let schema = apache_avro::Schema::parse_str(USER_SCHEMA).unwrap();
+        let mut writer = apache_avro::Writer::new(&schema, Vec::new());
+        writer.append(user_record(&schema)).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let result = decoder.decode(data).unwrap();
+
+        let Payload::Json(simd_json::OwnedValue::Object(map)) = result else {
+            panic!("Expected JSON object");
+        };
+        assert_eq!(map["name"], "John");
+    }
+
+    #[test]
+    fn decode_should_pass_through_datum_without_schema() {
+        let decoder = AvroStreamDecoder::default();
+        let result = decoder.decode(vec![2, 8, 74, 111, 104, 110]).unwrap();
+        assert!(matches!(result, Payload::Avro(data) if data.len() == 6));
+    }
+
+    #[test]
+    fn decode_should_fail_given_invalid_datum() {
+        let decoder = AvroStreamDecoder::new(AvroConfig {
+            schema: Some(USER_SCHEMA.to_string()),
+            ..AvroConfig::default()
+        })
+        .unwrap();
+        let result = decoder.decode(vec![255]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_should_fail_given_empty_payload() {
+        let decoder = AvroStreamDecoder::default();
+        assert!(decoder.decode(vec![]).is_err());
+    }
+
+    #[test]
+    fn new_should_fail_given_invalid_schema() {
+        let result = AvroStreamDecoder::new(AvroConfig {
+            schema: Some("{\"type\": \"unknown\"}".to_string()),
+            ..AvroConfig::default()
+        });
+        assert!(matches!(result, Err(Error::InitError(_))));
+    }
+
+    #[test]
+    fn new_should_fail_given_missing_schema_file() {
+        let result = AvroStreamDecoder::new(AvroConfig {
+            schema_path: Some(PathBuf::from("missing.avsc")),
+            ..AvroConfig::default()
+        });
+        assert!(matches!(result, Err(Error::InitError(_))));
+    }
+}
@@ -16,8 +16,10 @@
  * under the License.
  */
 
+pub mod avro;
 pub mod flatbuffer;
 pub mod json;
+pub mod msgpack;
 pub mod proto;
 pub mod raw;
 pub mod text;
@@ -0,0 +1,131 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Error, Payload, Schema, StreamDecoder};
+use base64::Engine;
+use simd_json::{OwnedValue, ValueBuilder};
+use tracing::error;
+
+/// Decodes MessagePack payloads into JSON.
+/// Binary and extension values are represented as base64 encoded strings.
+pub struct MsgPackStreamDecoder;
+
+impl StreamDecoder for MsgPackStreamDecoder {
+    fn schema(&self) -> Schema {
+        Schema::MsgPack
+    }
+
+    fn decode(&self, payload: Vec<u8>) -> Result<Payload, Error> {
+        let value = rmpv::decode::read_value(&mut payload.as_slice()).map_err(|error| {
+            error!("Failed to decode MessagePack payload: {error}");
+            Error::CannotDecode(self.schema())
+        })?;
+        Ok(Payload::Json(msgpack_to_json(value)))
+    }
+}
+
+pub(crate) fn msgpack_to_json(value: rmpv::Value) -> OwnedValue {
+    match value {
+        rmpv::Value::Nil => OwnedValue::null(),
+        rmpv::Value::Boolean(value) => OwnedValue::from(value),
+        rmpv::Value::Integer(value) => match (value.as_i64(), value.as_u64()) {
+            (Some(value), _) => OwnedValue::from(value),
+            (None, Some(value)) => OwnedValue::from(value),
+            (None, None) => OwnedValue::null(),
+        },
+        rmpv::Value::F32(value) => OwnedValue::from(value as f64),
+        rmpv::Value::F64(value) => OwnedValue::from(value),
+        rmpv::Value::String(value) => match value.into_str() {
+            Some(value) => OwnedValue::from(value),
+            None => OwnedValue::null(),
+        },
+        rmpv::Value::Binary(value) => {
+            OwnedValue::from(base64::engine::general_purpose::STANDARD.encode(value))
+        }
+        rmpv::Value::Array(values) => {
+            OwnedValue::Array(Box::new(values.into_iter().map(msgpack_to_json).collect()))
+        }
+        rmpv::Value::Map(entries) => {
+            let mut map = simd_json::owned::Object::with_capacity(entries.len());
+            for (key, value) in entries {
+                let key = match key {
+                    rmpv::Value::String(key) => key.into_str().unwrap_or_default(),
+                    other => other.to_string(),
+                };
+                map.insert(key, msgpack_to_json(value));
+            }
+            OwnedValue::Object(Box::new(map))
+        }
+        rmpv::Value::Ext(kind, data) => simd_json::json!({
+            "type": kind,
+            "data": base64::engine::general_purpose::STANDARD.encode(data),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(value: &rmpv::Value) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        rmpv::encode::write_value(&mut buffer, value).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn decode_should_return_json_given_msgpack_map() {
+        let value = rmpv::Value::Map(vec![
+            (rmpv::Value::from("id"), rmpv::Value::from(1)),
+            (rmpv::Value::from("name"), rmpv::Value::from("John")),
+            (rmpv::Value::from("active"), rmpv::Value::from(true)),
+            (
+                rmpv::Value::from("tags"),
+                rmpv::Value::Array(vec![rmpv::Value::from("a"), rmpv::Value::from("b")]),
+            ),
+            (rmpv::Value::from("score"), rmpv::Value::F64(1.5)),
+            (rmpv::Value::from("empty"), rmpv::Value::Nil),
+        ]);
+
+        let result = MsgPackStreamDecoder.decode(encode(&value)).unwrap();
+
+        let Payload::Json(OwnedValue::Object(map)) = result else {
+            panic!("Expected JSON object");
+        };
+        assert_eq!(map["id"], 1);
+        assert_eq!(map["name"], "John");
+        assert_eq!(map["active"], true);
+        assert_eq!(map["tags"][1], "b");
+        assert_eq!(map["score"], 1.5);
+        assert_eq!(map["empty"], ());
+    }
+
+    #[test]
+    fn decode_should_encode_binary_as_base64() {
+        let value = rmpv::Value::Binary(vec![1, 2, 3]);
+        let result = MsgPackStreamDecoder.decode(encode(&value)).unwrap();
+        assert!(matches!(result, Payload::Json(value) if value == "AQID"));
+    }
+
+    #[test]
+    fn decode_should_fail_given_truncated_payload() {
+        let mut data = encode(&rmpv::Value::from("truncated string"));
+        data.truncate(4);
+        assert!(MsgPackStreamDecoder.decode(data).is_err());
+    }
+}
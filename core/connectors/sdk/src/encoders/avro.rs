@@ -0,0 +1,207 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::decoders::avro::load_schema;
+use crate::{Error, Payload, Schema, StreamEncoder};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::error;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AvroEncoderConfig {
+    /// Path to the Avro schema (`.avsc`) file used to encode the JSON payloads.
+    pub schema_path: Option<PathBuf>,
+    /// Inline Avro schema in JSON format, takes precedence over `schema_path`.
+    pub schema: Option<String>,
+    /// Whether to write an object container file (with embedded schema) instead of a single datum.
+    pub container: bool,
+}
+
+pub struct AvroStreamEncoder {
+    config: AvroEncoderConfig,
+    schema: Option<apache_avro::Schema>,
+}
+
+impl AvroStreamEncoder {
+    /// Creates the encoder, fails if the configured schema cannot be loaded.
+    pub fn new(config: AvroEncoderConfig) -> Result<Self, Error> {
+        let schema = load_schema(config.schema.as_deref(), config.schema_path.as_deref())
+            .inspect_err(|e| error!("Failed to load Avro schema during encoder creation: {e}"))?;
+        Ok(Self { config, schema })
+    }
+
+    /// Creates the encoder without the schema, which only passes through the Avro payloads.
+    pub fn new_default() -> Self {
+        Self {
+            config: AvroEncoderConfig::default(),
+            schema: None,
+        }
+    }
+
+    fn encode_json(&self, value: simd_json::OwnedValue) -> Result<Vec<u8>, Error> {
+        let Some(schema) = &self.schema else {
+            error!("Avro schema is required to encode JSON payload.");
+            return Err(Error::CannotEncode(Schema::Avro));
+        };
+
+        let value = json_to_avro(value)?.resolve(schema).map_err(|e| {
+            error!("JSON payload does not match Avro schema: {e}");
+            Error::CannotEncode(Schema::Avro)
+        })?;
+
+        if !self.config.container {
+            return apache_avro::to_avro_datum(schema, value).map_err(|e| {
+                error!("Failed to encode Avro datum: {e}");
+                Error::CannotEncode(Schema::Avro)
+            });
+        }
+
+        let mut writer = apache_avro::Writer::new(schema, Vec::new());
+        writer
+            .append(value)
+            .and_then(|_| writer.into_inner())
+            .map_err(|e| {
+                error!("Failed to write Avro container: {e}");
+                Error::CannotEncode(Schema::Avro)
+            })
+    }
+}
+
+impl StreamEncoder for AvroStreamEncoder {
+    fn schema(&self) -> Schema {
+        Schema::Avro
+    }
+
+    fn encode(&self, payload: Payload) -> Result<Vec<u8>, Error> {
+        match payload {
+            Payload::Json(value) => self.encode_json(value),
+            Payload::Avro(data) | Payload::Raw(data) => Ok(data),
+            _ => Err(Error::InvalidPayloadType),
+        }
+    }
+}
+
+impl Default for AvroStreamEncoder {
+    fn default() -> Self {
+        Self::new_default()
+    }
+}
+
+pub(crate) fn json_to_avro(
+    value: simd_json::OwnedValue,
+) -> Result<apache_avro::types::Value, Error> {
+    let json = serde_json::to_value(value).map_err(|_| Error::InvalidJsonPayload)?;
+    Ok(apache_avro::types::Value::from(json))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StreamDecoder;
+    use crate::decoders::avro::{AvroConfig, AvroStreamDecoder};
+
+    const USER_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"},
+            {"name": "email", "type": ["null", "string"], "default": null}
+        ]
+    }"#;
+
+    fn json(value: &str) -> simd_json::OwnedValue {
+        let mut bytes = value.as_bytes().to_vec();
+        simd_json::to_owned_value(&mut bytes).unwrap()
+    }
+
+    #[test]
+    fn encode_should_produce_datum_decodable_with_schema() {
+        let encoder = AvroStreamEncoder::new(AvroEncoderConfig {
+            schema: Some(USER_SCHEMA.to_string()),
+            ..AvroEncoderConfig::default()
+        })
+        .unwrap();
+        let decoder = AvroStreamDecoder::new(AvroConfig {
+            schema: Some(USER_SCHEMA.to_string()),
+            ..AvroConfig::default()
+        })
+        .unwrap();
+
+        let encoded = encoder
+            .encode(Payload::Json(json(
+                r#"{"id": 1, "name": "John", "email": "john@example.com"}"#,
+            )))
+            .unwrap();
+        let decoded = decoder.decode(encoded).unwrap();
+
+        let Payload::Json(simd_json::OwnedValue::Object(map)) = decoded else {
+            panic!("Expected JSON object");
+        };
+        assert_eq!(map["id"], 1);
+        assert_eq!(map["name"], "John");
+        assert_eq!(map["email"], "john@example.com");
+    }
+
+    #[test]
+    fn encode_should_produce_container_file_decodable_without_schema() {
+        let encoder = AvroStreamEncoder::new(AvroEncoderConfig {
+            schema: Some(USER_SCHEMA.to_string()),
+            container: true,
+            ..AvroEncoderConfig::default()
+        })
+        .unwrap();
+
+        let encoded = encoder
+            .encode(Payload::Json(json(
+                r#"{"id": 2, "name": "Jane", "email": null}"#,
+            )))
+            .unwrap();
+        let decoded = AvroStreamDecoder::default().decode(encoded).unwrap();
+
+        let Payload::Json(simd_json::OwnedValue::Object(map)) = decoded else {
+            panic!("Expected JSON object");
+        };
+        assert_eq!(map["name"], "Jane");
+    }
+
+    #[test]
+    fn encode_should_fail_given_json_not_matching_schema() {
+        let encoder = AvroStreamEncoder::new(AvroEncoderConfig {
+            schema: Some(USER_SCHEMA.to_string()),
+            ..AvroEncoderConfig::default()
+        })
+        .unwrap();
+        let result = encoder.encode(Payload::Json(json(r#"{"name": 1}"#)));
+        assert!(matches!(result, Err(Error::CannotEncode(Schema::Avro))));
+    }
+
+    #[test]
+    fn encode_should_fail_given_json_without_schema() {
+        let encoder = AvroStreamEncoder::default();
+        let result = encoder.encode(Payload::Json(json(r#"{"id": 1}"#)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn encode_should_pass_through_avro_payload() {
+        let encoder = AvroStreamEncoder::default();
+        let result = encoder.encode(Payload::Avro(vec![1, 2, 3])).unwrap();
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+}
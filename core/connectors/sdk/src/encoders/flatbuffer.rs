@@ -235,7 +235,9 @@ impl StreamEncoder for FlatBufferStreamEncoder {
         match transformed_payload {
             Payload::Json(json_value) => self.encode_json_to_flatbuffer(json_value),
             Payload::Text(text) => self.encode_text_to_flatbuffer(text),
-            Payload::Raw(data) => self.encode_raw_to_flatbuffer(data),
+            Payload::Raw(data) | Payload::Avro(data) | Payload::MsgPack(data) => {
+                self.encode_raw_to_flatbuffer(data)
+            }
             Payload::FlatBuffer(data) => Ok(data),
             Payload::Proto(text) => self.encode_text_to_flatbuffer(text),
         }
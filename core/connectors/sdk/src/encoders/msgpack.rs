@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{Error, Payload, Schema, StreamEncoder};
+use simd_json::{OwnedValue, StaticNode};
+use tracing::error;
+
+pub struct MsgPackStreamEncoder;
+
+impl MsgPackStreamEncoder {
+    fn write(&self, value: &rmpv::Value) -> Result<Vec<u8>, Error> {
+        let mut buffer = Vec::new();
+        rmpv::encode::write_value(&mut buffer, value).map_err(|error| {
+            error!("Failed to encode MessagePack payload: {error}");
+            Error::CannotEncode(self.schema())
+        })?;
+        Ok(buffer)
+    }
+}
+
+impl StreamEncoder for MsgPackStreamEncoder {
+    fn schema(&self) -> Schema {
+        Schema::MsgPack
+    }
+
+    fn encode(&self, payload: Payload) -> Result<Vec<u8>, Error> {
+        match payload {
+            Payload::Json(value) => self.write(&json_to_msgpack(value)),
+            Payload::Text(value) => self.write(&rmpv::Value::from(value)),
+            Payload::MsgPack(value) => Ok(value),
+            _ => Err(Error::InvalidPayloadType),
+        }
+    }
+}
+
+pub(crate) fn json_to_msgpack(value: OwnedValue) -> rmpv::Value {
+    match value {
+        OwnedValue::Static(StaticNode::Null) => rmpv::Value::Nil,
+        OwnedValue::Static(StaticNode::Bool(value)) => rmpv::Value::from(value),
+        OwnedValue::Static(StaticNode::I64(value)) => rmpv::Value::from(value),
+        OwnedValue::Static(StaticNode::U64(value)) => rmpv::Value::from(value),
+        OwnedValue::Static(StaticNode::F64(value)) => rmpv::Value::F64(value),
+        OwnedValue::String(value) => rmpv::Value::from(value),
+        OwnedValue::Array(values) => {
+            rmpv::Value::Array(values.into_iter().map(json_to_msgpack).collect())
+        }
+        OwnedValue::Object(map) => rmpv::Value::Map(
+            map.into_iter()
+                .map(|(key, value)| (rmpv::Value::from(key), json_to_msgpack(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StreamDecoder;
+    use crate::decoders::msgpack::MsgPackStreamDecoder;
+
+    #[test]
+    fn encode_should_produce_payload_decodable_back_to_same_json() {
+        let mut bytes = br#"{"id": 1, "name": "John", "tags": ["a", "b"], "score": 1.5, "email": null, "big": 18446744073709551615}"#.to_vec();
+        let json = simd_json::to_owned_value(&mut bytes).unwrap();
+
+        let encoded = MsgPackStreamEncoder
+            .encode(Payload::Json(json.clone()))
+            .unwrap();
+        let decoded = MsgPackStreamDecoder.decode(encoded).unwrap();
+
+        assert!(matches!(decoded, Payload::Json(value) if value == json));
+    }
+
+    #[test]
+    fn encode_should_write_text_as_msgpack_string() {
+        let encoded = MsgPackStreamEncoder
+            .encode(Payload::Text("hello".to_string()))
+            .unwrap();
+        let value = rmpv::decode::read_value(&mut encoded.as_slice()).unwrap();
+        assert_eq!(value.as_str(), Some("hello"));
+    }
+
+    #[test]
+    fn encode_should_fail_given_raw_payload() {
+        let result = MsgPackStreamEncoder.encode(Payload::Raw(vec![1]));
+        assert!(matches!(result, Err(Error::InvalidPayloadType)));
+    }
+}
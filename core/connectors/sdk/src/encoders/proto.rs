@@ -370,6 +370,9 @@ impl ProtoStreamEncoder {
                 "flatbuffer_size": data.len(),
                 "data": general_purpose::STANDARD.encode(&data)
             }),
+            Payload::Avro(data) | Payload::MsgPack(data) => simd_json::json!({
+                "data": general_purpose::STANDARD.encode(&data)
+            }),
         };
 
         if let simd_json::OwnedValue::Object(json_map) = json_value {
@@ -617,7 +620,7 @@ impl ProtoStreamEncoder {
                 ),
                 text.into_bytes(),
             ),
-            Payload::FlatBuffer(data) => (
+            Payload::FlatBuffer(data) | Payload::Avro(data) | Payload::MsgPack(data) => (
                 format!(
                     "{}/google.protobuf.BytesValue",
                     self.config.format_options.type_url_prefix
@@ -642,7 +645,7 @@ impl ProtoStreamEncoder {
             Payload::Text(text) => Ok(text.into_bytes()),
             Payload::Raw(data) => Ok(data),
             Payload::Proto(text) => Ok(text.into_bytes()),
-            Payload::FlatBuffer(data) => Ok(data),
+            Payload::FlatBuffer(data) | Payload::Avro(data) | Payload::MsgPack(data) => Ok(data),
         }
     }
 
@@ -19,17 +19,28 @@
 use async_trait::async_trait;
 use base64::{self, Engine};
 use decoders::{
-    flatbuffer::FlatBufferStreamDecoder, json::JsonStreamDecoder, proto::ProtoStreamDecoder,
-    raw::RawStreamDecoder, text::TextStreamDecoder,
+    avro::{AvroConfig, AvroStreamDecoder},
+    flatbuffer::FlatBufferStreamDecoder,
+    json::JsonStreamDecoder,
+    msgpack::MsgPackStreamDecoder,
+    proto::ProtoStreamDecoder,
+    raw::RawStreamDecoder,
+    text::TextStreamDecoder,
 };
 use encoders::{
-    flatbuffer::FlatBufferStreamEncoder, json::JsonStreamEncoder, proto::ProtoStreamEncoder,
-    raw::RawStreamEncoder, text::TextStreamEncoder,
+    avro::{AvroEncoderConfig, AvroStreamEncoder},
+    flatbuffer::FlatBufferStreamEncoder,
+    json::JsonStreamEncoder,
+    msgpack::MsgPackStreamEncoder,
+    proto::ProtoStreamEncoder,
+    raw::RawStreamEncoder,
+    text::TextStreamEncoder,
 };
 use iggy::prelude::{HeaderKey, HeaderValue};
 use once_cell::sync::OnceCell;
 use prost::Message;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::{collections::HashMap, sync::Arc};
 use strum_macros::{Display, IntoStaticStr};
 use thiserror::Error;
@@ -52,6 +63,22 @@ pub fn get_runtime() -> &'static Runtime {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConnectorState(pub Vec<u8>);
 
+/// The unit of work assigned to the source, along with its progress saved by the previous owner (if any).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkUnit {
+    pub name: String,
+    pub state: Option<ConnectorState>,
+}
+
+/// The progress of the work unit, saved by the runtime once the messages produced along with it are sent.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkUnitState {
+    pub name: String,
+    pub state: Option<ConnectorState>,
+    /// The work unit is no longer processed by the source, so it can be taken over by its next owner.
+    pub released: bool,
+}
+
 /// The Source trait defines the interface for a source connector, responsible for producing the messages to the configured stream and topic.
 /// Once the messages are produced (e.g. fetched from an external API), they will be sent further to the specified destination.
 #[async_trait]
@@ -64,6 +91,20 @@ pub trait Source: Send + Sync {
 
     /// Invoked when the source is closed, allowing it to perform any necessary cleanup.
     async fn close(&mut self) -> Result<(), Error>;
+
+    /// Returns the units of work (e.g. tables, files or shards), which can be split across the multiple runtime instances.
+    /// The empty list (default) means that the source doesn't support the scale-out.
+    fn work_units(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Invoked when the work units are (re)assigned to the source, which should only process the assigned ones from now on.
+    /// The newly assigned units come with the progress saved by their previous owner, while the units which are no longer
+    /// assigned must be reported as released (with their final progress) in the next produced messages,
+    /// as their next owner waits for it before taking them over.
+    async fn assign_work_units(&self, _units: Vec<WorkUnit>) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 /// The Sink trait defines the interface for a sink connector, responsible for consuming the messages from the configured topics.
@@ -92,6 +133,8 @@ pub enum Payload {
     Text(String),
     Proto(String),
     FlatBuffer(Vec<u8>),
+    Avro(Vec<u8>),
+    MsgPack(Vec<u8>),
 }
 
 impl Payload {
@@ -104,6 +147,8 @@ impl Payload {
             Payload::Text(text) => Ok(text.into_bytes()),
             Payload::Proto(text) => Ok(text.into_bytes()),
             Payload::FlatBuffer(value) => Ok(value),
+            Payload::Avro(value) => Ok(value),
+            Payload::MsgPack(value) => Ok(value),
         }
     }
 }
@@ -120,6 +165,8 @@ impl std::fmt::Display for Payload {
             Payload::Text(text) => write!(f, "Text({text})"),
             Payload::Proto(text) => write!(f, "Proto({text})"),
             Payload::FlatBuffer(value) => write!(f, "FlatBuffer({} bytes)", value.len()),
+            Payload::Avro(value) => write!(f, "Avro({} bytes)", value.len()),
+            Payload::MsgPack(value) => write!(f, "MsgPack({} bytes)", value.len()),
         }
     }
 }
@@ -140,6 +187,10 @@ pub enum Schema {
     Proto,
     #[strum(to_string = "flatbuffer")]
     FlatBuffer,
+    #[strum(to_string = "avro")]
+    Avro,
+    #[strum(to_string = "msgpack")]
+    MsgPack,
 }
 
 impl Schema {
@@ -163,27 +214,56 @@ impl Schema {
                 Err(_) => Ok(Payload::Raw(value)),
             },
             Schema::FlatBuffer => Ok(Payload::FlatBuffer(value)),
+            Schema::Avro => Ok(Payload::Avro(value)),
+            Schema::MsgPack => match rmpv::decode::read_value(&mut value.as_slice()) {
+                Ok(msgpack) => Ok(Payload::Json(decoders::msgpack::msgpack_to_json(msgpack))),
+                Err(_) => Ok(Payload::MsgPack(value)),
+            },
         }
     }
 
-    pub fn decoder(self) -> Arc<dyn StreamDecoder> {
-        match self {
+    /// Creates the stream decoder, the schema definition (inline or loaded from the file)
+    /// is used by the `avro` decoder, and ignored by the other ones.
+    pub fn decoder(
+        self,
+        definition: Option<&str>,
+        path: Option<&Path>,
+    ) -> Result<Arc<dyn StreamDecoder>, Error> {
+        Ok(match self {
             Schema::Json => Arc::new(JsonStreamDecoder),
             Schema::Raw => Arc::new(RawStreamDecoder),
             Schema::Text => Arc::new(TextStreamDecoder),
             Schema::Proto => Arc::new(ProtoStreamDecoder::default()),
             Schema::FlatBuffer => Arc::new(FlatBufferStreamDecoder::default()),
-        }
+            Schema::Avro => Arc::new(AvroStreamDecoder::new(AvroConfig {
+                schema: definition.map(ToOwned::to_owned),
+                schema_path: path.map(ToOwned::to_owned),
+                ..AvroConfig::default()
+            })?),
+            Schema::MsgPack => Arc::new(MsgPackStreamDecoder),
+        })
     }
 
-    pub fn encoder(self) -> Arc<dyn StreamEncoder> {
-        match self {
+    /// Creates the stream encoder, the schema definition (inline or loaded from the file)
+    /// is used by the `avro` encoder, and ignored by the other ones.
+    pub fn encoder(
+        self,
+        definition: Option<&str>,
+        path: Option<&Path>,
+    ) -> Result<Arc<dyn StreamEncoder>, Error> {
+        Ok(match self {
             Schema::Json => Arc::new(JsonStreamEncoder),
             Schema::Raw => Arc::new(RawStreamEncoder),
             Schema::Text => Arc::new(TextStreamEncoder),
             Schema::Proto => Arc::new(ProtoStreamEncoder::default()),
             Schema::FlatBuffer => Arc::new(FlatBufferStreamEncoder::default()),
-        }
+            Schema::Avro => Arc::new(AvroStreamEncoder::new(AvroEncoderConfig {
+                schema: definition.map(ToOwned::to_owned),
+                schema_path: path.map(ToOwned::to_owned),
+                ..AvroEncoderConfig::default()
+            })?),
+            Schema::MsgPack => Arc::new(MsgPackStreamEncoder),
+        })
     }
 }
 
@@ -220,6 +300,7 @@ pub struct ProducedMessages {
     pub schema: Schema,
     pub messages: Vec<ProducedMessage>,
     pub state: Option<ConnectorState>,
+    pub work_units: Vec<WorkUnitState>,
 }
 
 #[repr(C)]
@@ -306,6 +387,8 @@ pub enum Error {
     InvalidTextPayload,
     #[error("Cannot decode schema {0}")]
     CannotDecode(Schema),
+    #[error("Cannot encode schema {0}")]
+    CannotEncode(Schema),
     #[error("Invalid protobuf payload.")]
     InvalidProtobufPayload,
     #[error("Cannot open state file")]
@@ -314,6 +397,14 @@ pub enum Error {
     CannotReadStateFile,
     #[error("Cannot write state file")]
     CannotWriteStateFile,
+    #[error("Cannot read state")]
+    CannotReadState,
+    #[error("Cannot write state")]
+    CannotWriteState,
     #[error("Invalid state")]
     InvalidState,
+    #[error("Invalid script: {0}")]
+    InvalidScript(String),
+    #[error("Script failed: {0}")]
+    ScriptFailed(String),
 }
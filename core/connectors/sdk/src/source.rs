@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::{ConnectorState, Error, Source, get_runtime};
+use crate::{ConnectorState, Error, Source, WorkUnit, get_runtime};
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
 use tokio::{sync::watch, task::JoinHandle};
@@ -36,6 +36,8 @@ pub type HandleCallback = extern "C" fn(plugin_id: u32, callback: SendCallback)
 
 pub type SendCallback = extern "C" fn(plugin_id: u32, messages_ptr: *const u8, messages_len: usize);
 
+pub type WorkUnitsCallback = extern "C" fn(plugin_id: u32, units_ptr: *const u8, units_len: usize);
+
 #[derive(Debug)]
 pub struct SourceContainer<T: Source + std::fmt::Debug> {
     id: u32,
@@ -140,6 +142,60 @@ impl<T: Source + std::fmt::Debug + 'static> SourceContainer<T> {
         0
     }
 
+    /// # Safety
+    /// Do not copy the pointer to the work units.
+    pub unsafe fn work_units(&self, callback: WorkUnitsCallback) -> i32 {
+        let Some(source) = self.source.as_ref() else {
+            error!(
+                "Source connector with ID: {} is not initialized - cannot get work units.",
+                self.id
+            );
+            return -1;
+        };
+
+        let Ok(units) = postcard::to_allocvec(&source.work_units()) else {
+            error!(
+                "Failed to serialize work units for source connector with ID: {}",
+                self.id
+            );
+            return -1;
+        };
+
+        callback(self.id, units.as_ptr(), units.len());
+        0
+    }
+
+    /// # Safety
+    /// Do not copy the pointer to the work units.
+    pub unsafe fn assign(&self, units_ptr: *const u8, units_len: usize) -> i32 {
+        let Some(source) = self.source.as_ref() else {
+            error!(
+                "Source connector with ID: {} is not initialized - cannot assign work units.",
+                self.id
+            );
+            return -1;
+        };
+
+        let units = unsafe { std::slice::from_raw_parts(units_ptr, units_len) };
+        let Ok(units) = postcard::from_bytes::<Vec<WorkUnit>>(units) else {
+            error!(
+                "Failed to deserialize work units for source connector with ID: {}",
+                self.id
+            );
+            return -1;
+        };
+
+        let runtime = get_runtime();
+        if let Err(error) = runtime.block_on(source.assign_work_units(units)) {
+            error!(
+                "Failed to assign work units for source connector with ID: {}. {error}",
+                self.id
+            );
+            return 1;
+        }
+        0
+    }
+
     /// # Safety
     /// Do not copy the pointer to the messages.
     pub unsafe fn handle(&mut self, callback: SendCallback) -> i32 {
@@ -208,6 +264,7 @@ macro_rules! source_connector {
         use once_cell::sync::Lazy;
         use $crate::source::SendCallback;
         use $crate::source::SourceContainer;
+        use $crate::source::WorkUnitsCallback;
 
         static INSTANCES: Lazy<DashMap<u32, SourceContainer<$type>>> = Lazy::new(DashMap::new);
 
@@ -245,6 +302,30 @@ macro_rules! source_connector {
             instance.handle(callback)
         }
 
+        #[cfg(not(test))]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn work_units(id: u32, callback: WorkUnitsCallback) -> i32 {
+            let Some(instance) = INSTANCES.get(&id) else {
+                tracing::error!(
+                    "Source connector with ID: {id} was not found and cannot provide work units."
+                );
+                return -1;
+            };
+            instance.work_units(callback)
+        }
+
+        #[cfg(not(test))]
+        #[unsafe(no_mangle)]
+        unsafe extern "C" fn assign(id: u32, units_ptr: *const u8, units_len: usize) -> i32 {
+            let Some(instance) = INSTANCES.get(&id) else {
+                tracing::error!(
+                    "Source connector with ID: {id} was not found and cannot be assigned work units."
+                );
+                return -1;
+            };
+            instance.assign(units_ptr, units_len)
+        }
+
         #[cfg(not(test))]
         #[unsafe(no_mangle)]
         unsafe extern "C" fn close(id: u32) -> i32 {
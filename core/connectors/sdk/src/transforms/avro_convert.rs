@@ -0,0 +1,206 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use super::{Transform, TransformType};
+use crate::decoders::avro::{AvroConfig, AvroStreamDecoder};
+use crate::encoders::avro::{AvroEncoderConfig, AvroStreamEncoder};
+use crate::{DecodedMessage, Error, Payload, Schema, TopicMetadata};
+use crate::{StreamDecoder, StreamEncoder};
+use tracing::error;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvroConvertConfig {
+    pub source_format: Schema,
+    pub target_format: Schema,
+    pub schema_path: Option<PathBuf>,
+    pub schema: Option<String>,
+    #[serde(default)]
+    pub container: bool,
+}
+
+impl Default for AvroConvertConfig {
+    fn default() -> Self {
+        Self {
+            source_format: Schema::Avro,
+            target_format: Schema::Json,
+            schema_path: None,
+            schema: None,
+            container: false,
+        }
+    }
+}
+
+/// Transform converting the payloads between Avro and JSON formats.
+#[derive(Default)]
+pub struct AvroConvert {
+    config: AvroConvertConfig,
+    decoder: AvroStreamDecoder,
+    encoder: AvroStreamEncoder,
+}
+
+impl AvroConvert {
+    /// Creates the transform, fails if the configured schema cannot be loaded,
+    /// or if it's missing for the conversion from JSON to Avro.
+    pub fn new(config: AvroConvertConfig) -> Result<Self, Error> {
+        if config.source_format == Schema::Json
+            && config.target_format == Schema::Avro
+            && config.schema.is_none()
+            && config.schema_path.is_none()
+        {
+            error!("Avro convert transform requires either inline schema or path to the file.");
+            return Err(Error::InvalidConfig);
+        }
+
+        let decoder = AvroStreamDecoder::new(AvroConfig {
+            schema_path: config.schema_path.clone(),
+            schema: config.schema.clone(),
+            extract_as_json: true,
+        })?;
+        let encoder = AvroStreamEncoder::new(AvroEncoderConfig {
+            schema_path: config.schema_path.clone(),
+            schema: config.schema.clone(),
+            container: config.container,
+        })?;
+        Ok(Self {
+            config,
+            decoder,
+            encoder,
+        })
+    }
+}
+
+impl Transform for AvroConvert {
+    fn r#type(&self) -> TransformType {
+        TransformType::AvroConvert
+    }
+
+    fn transform(
+        &self,
+        _metadata: &TopicMetadata,
+        mut message: DecodedMessage,
+    ) -> Result<Option<DecodedMessage>, Error> {
+        message.payload = match (&self.config.source_format, &self.config.target_format) {
+            (Schema::Json, Schema::Avro) => {
+                let Payload::Json(_) = message.payload else {
+                    return Err(Error::InvalidPayloadType);
+                };
+                Payload::Avro(self.encoder.encode(message.payload)?)
+            }
+            (Schema::Avro, Schema::Json) => match message.payload {
+                Payload::Avro(bytes) | Payload::Raw(bytes) => self.decoder.decode(bytes)?,
+                Payload::Json(value) => Payload::Json(value),
+                _ => return Err(Error::InvalidPayloadType),
+            },
+            _ => {
+                // For unsupported conversions, pass through unchanged
+                message.payload
+            }
+        };
+
+        Ok(Some(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transforms::json::test_utils::{
+        create_test_message, create_test_topic_metadata, extract_json_object,
+    };
+
+    const USER_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"}
+        ]
+    }"#;
+
+    fn converter(source_format: Schema, target_format: Schema) -> AvroConvert {
+        AvroConvert::new(AvroConvertConfig {
+            source_format,
+            target_format,
+            schema: Some(USER_SCHEMA.to_string()),
+            ..AvroConvertConfig::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn should_convert_json_to_avro_and_back() {
+        let to_avro = converter(Schema::Json, Schema::Avro);
+        let to_json = converter(Schema::Avro, Schema::Json);
+        let msg = create_test_message(r#"{"id": 7, "name": "John"}"#);
+
+        let avro = to_avro
+            .transform(&create_test_topic_metadata(), msg)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(avro.payload, Payload::Avro(_)));
+
+        let json = to_json
+            .transform(&create_test_topic_metadata(), avro)
+            .unwrap()
+            .unwrap();
+        let json_obj = extract_json_object(&json).unwrap();
+        assert_eq!(json_obj["id"], 7);
+        assert_eq!(json_obj["name"], "John");
+    }
+
+    #[test]
+    fn should_fail_given_json_not_matching_schema() {
+        let to_avro = converter(Schema::Json, Schema::Avro);
+        let msg = create_test_message(r#"{"unknown": true}"#);
+        let result = to_avro.transform(&create_test_topic_metadata(), msg);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_pass_through_unsupported_conversion() {
+        let transform = converter(Schema::Json, Schema::Text);
+        let msg = create_test_message(r#"{"id": 1}"#);
+        let result = transform
+            .transform(&create_test_topic_metadata(), msg)
+            .unwrap()
+            .unwrap();
+        assert_eq!(extract_json_object(&result).unwrap()["id"], 1);
+    }
+
+    #[test]
+    fn should_fail_to_create_given_json_to_avro_without_schema() {
+        let result = AvroConvert::new(AvroConvertConfig {
+            source_format: Schema::Json,
+            target_format: Schema::Avro,
+            ..AvroConvertConfig::default()
+        });
+        assert!(matches!(result, Err(Error::InvalidConfig)));
+    }
+
+    #[test]
+    fn should_fail_to_create_given_invalid_schema() {
+        let result = AvroConvert::new(AvroConvertConfig {
+            schema: Some("{\"type\": \"unknown\"}".to_string()),
+            ..AvroConvertConfig::default()
+        });
+        assert!(matches!(result, Err(Error::InitError(_))));
+    }
+}
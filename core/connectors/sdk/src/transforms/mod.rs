@@ -17,21 +17,27 @@
  */
 
 mod add_fields;
+pub mod avro_convert;
 mod delete_fields;
 mod filter_fields;
 pub mod flatbuffer_convert;
 pub mod json;
+pub mod msgpack_convert;
 pub mod proto_convert;
+mod script;
 mod update_fields;
 use crate::{DecodedMessage, Error, TopicMetadata};
 pub use add_fields::{AddFields, AddFieldsConfig, Field as AddField};
+pub use avro_convert::{AvroConvert, AvroConvertConfig};
 pub use delete_fields::{DeleteFields, DeleteFieldsConfig};
 pub use filter_fields::{
     FilterFields, FilterFieldsConfig, FilterPattern, KeyPattern as FilterKeyPattern,
     ValuePattern as FilterValuePattern,
 };
 pub use flatbuffer_convert::{FlatBufferConvert, FlatBufferConvertConfig};
+pub use msgpack_convert::{MsgPackConvert, MsgPackConvertConfig};
 pub use proto_convert::{ProtoConvert, ProtoConvertConfig};
+pub use script::{Script, ScriptConfig, ScriptErrorPolicy};
 use serde::{Deserialize, Serialize};
 use simd_json::OwnedValue;
 use std::sync::Arc;
@@ -86,6 +92,9 @@ pub enum TransformType {
     UpdateFields,
     ProtoConvert,
     FlatBufferConvert,
+    Script,
+    AvroConvert,
+    MsgPackConvert,
 }
 
 pub fn from_config(
@@ -123,5 +132,20 @@ pub fn from_config(
                 serde_json::from_value(raw.clone()).map_err(|_| Error::InvalidConfig)?;
             Ok(Arc::new(FlatBufferConvert::new(cfg)))
         }
+        TransformType::Script => {
+            let cfg: ScriptConfig =
+                serde_json::from_value(raw.clone()).map_err(|_| Error::InvalidConfig)?;
+            Ok(Arc::new(Script::new(cfg)?))
+        }
+        TransformType::AvroConvert => {
+            let cfg: AvroConvertConfig =
+                serde_json::from_value(raw.clone()).map_err(|_| Error::InvalidConfig)?;
+            Ok(Arc::new(AvroConvert::new(cfg)?))
+        }
+        TransformType::MsgPackConvert => {
+            let cfg: MsgPackConvertConfig =
+                serde_json::from_value(raw.clone()).map_err(|_| Error::InvalidConfig)?;
+            Ok(Arc::new(MsgPackConvert::new(cfg)))
+        }
     }
 }
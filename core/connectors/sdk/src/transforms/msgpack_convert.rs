@@ -0,0 +1,133 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+use super::{Transform, TransformType};
+use crate::decoders::msgpack::MsgPackStreamDecoder;
+use crate::encoders::msgpack::MsgPackStreamEncoder;
+use crate::{DecodedMessage, Error, Payload, Schema, TopicMetadata};
+use crate::{StreamDecoder, StreamEncoder};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MsgPackConvertConfig {
+    pub source_format: Schema,
+    pub target_format: Schema,
+}
+
+impl Default for MsgPackConvertConfig {
+    fn default() -> Self {
+        Self {
+            source_format: Schema::MsgPack,
+            target_format: Schema::Json,
+        }
+    }
+}
+
+/// Transform converting the payloads between MessagePack and JSON formats.
+pub struct MsgPackConvert {
+    config: MsgPackConvertConfig,
+}
+
+impl MsgPackConvert {
+    pub fn new(config: MsgPackConvertConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Transform for MsgPackConvert {
+    fn r#type(&self) -> TransformType {
+        TransformType::MsgPackConvert
+    }
+
+    fn transform(
+        &self,
+        _metadata: &TopicMetadata,
+        mut message: DecodedMessage,
+    ) -> Result<Option<DecodedMessage>, Error> {
+        message.payload = match (&self.config.source_format, &self.config.target_format) {
+            (Schema::Json, Schema::MsgPack) => match message.payload {
+                Payload::Json(_) | Payload::Text(_) => {
+                    Payload::MsgPack(MsgPackStreamEncoder.encode(message.payload)?)
+                }
+                _ => return Err(Error::InvalidPayloadType),
+            },
+            (Schema::MsgPack, Schema::Json) => match message.payload {
+                Payload::MsgPack(bytes) | Payload::Raw(bytes) => {
+                    MsgPackStreamDecoder.decode(bytes)?
+                }
+                Payload::Json(value) => Payload::Json(value),
+                _ => return Err(Error::InvalidPayloadType),
+            },
+            _ => {
+                // For unsupported conversions, pass through unchanged
+                message.payload
+            }
+        };
+
+        Ok(Some(message))
+    }
+}
+
+impl Default for MsgPackConvert {
+    fn default() -> Self {
+        Self::new(MsgPackConvertConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transforms::json::test_utils::{
+        create_raw_test_message, create_test_message, create_test_topic_metadata,
+        extract_json_object,
+    };
+
+    #[test]
+    fn should_convert_json_to_msgpack_and_back() {
+        let to_msgpack = MsgPackConvert::new(MsgPackConvertConfig {
+            source_format: Schema::Json,
+            target_format: Schema::MsgPack,
+        });
+        let to_json = MsgPackConvert::default();
+        let msg = create_test_message(r#"{"id": 7, "name": "John", "tags": ["a"]}"#);
+
+        let msgpack = to_msgpack
+            .transform(&create_test_topic_metadata(), msg)
+            .unwrap()
+            .unwrap();
+        assert!(matches!(msgpack.payload, Payload::MsgPack(_)));
+
+        let json = to_json
+            .transform(&create_test_topic_metadata(), msgpack)
+            .unwrap()
+            .unwrap();
+        let json_obj = extract_json_object(&json).unwrap();
+        assert_eq!(json_obj["id"], 7);
+        assert_eq!(json_obj["name"], "John");
+        assert_eq!(json_obj["tags"][0], "a");
+    }
+
+    #[test]
+    fn should_fail_given_invalid_msgpack_bytes() {
+        let transform = MsgPackConvert::default();
+        let msg = create_raw_test_message(vec![0xa5, b'a']);
+        let result = transform.transform(&create_test_topic_metadata(), msg);
+        assert!(result.is_err());
+    }
+}
@@ -470,6 +470,7 @@ impl ProtoConvert {
                     Err(Error::InvalidPayloadType)
                 }
             }
+            Schema::Avro | Schema::MsgPack => Err(Error::InvalidPayloadType),
         }
     }
 
@@ -487,6 +488,7 @@ impl ProtoConvert {
                     Err(Error::InvalidPayloadType)
                 }
             }
+            Schema::Avro | Schema::MsgPack => Err(Error::InvalidPayloadType),
         }
     }
 
@@ -0,0 +1,548 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use super::{Transform, TransformType};
+use crate::{DecodedMessage, Error, Payload, TopicMetadata};
+use iggy::prelude::{HeaderKey, HeaderKind, HeaderValue};
+use rhai::{AST, Blob, Dynamic, Engine, Map, Scope};
+use serde::{Deserialize, Serialize};
+use simd_json::OwnedValue;
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    path::PathBuf,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+use strum_macros::{Display, IntoStaticStr};
+use tracing::{error, warn};
+
+const DEFAULT_MAX_OPERATIONS: u64 = 100_000;
+const DEFAULT_MAX_CALL_LEVELS: usize = 32;
+const DEFAULT_MAX_STRING_SIZE: usize = 1024 * 1024;
+const DEFAULT_MAX_COLLECTION_SIZE: usize = 10_000;
+const DEFAULT_TIMEOUT: &str = "100ms";
+const TIMEOUT_CHECK_INTERVAL: u64 = 1024;
+
+thread_local! {
+    static SCRIPT_STARTED_AT: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Configuration for the Script transform
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScriptConfig {
+    /// Inline Rhai script, takes precedence over `path`.
+    pub script: Option<String>,
+    /// Path to the file containing Rhai script.
+    pub path: Option<PathBuf>,
+    /// Maximum number of operations performed by a single script invocation.
+    pub max_operations: Option<u64>,
+    /// Maximum wall clock time of a single script invocation, e.g. `10ms`.
+    pub timeout: Option<String>,
+    #[serde(default)]
+    pub on_error: ScriptErrorPolicy,
+}
+
+/// The behavior of the transform when the script fails (including exceeding the limits)
+#[derive(
+    Debug, Copy, Clone, Default, Eq, PartialEq, Serialize, Deserialize, Display, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptErrorPolicy {
+    /// Return the error, failing the whole batch.
+    #[default]
+    #[strum(to_string = "fail")]
+    Fail,
+    /// Drop the message which could not be processed.
+    #[strum(to_string = "drop")]
+    Drop,
+    /// Pass the original message through unchanged.
+    #[strum(to_string = "skip")]
+    Skip,
+}
+
+/// Transform that runs a sandboxed Rhai script against each message.
+///
+/// The script can read and modify `payload` and `headers` variables, and read `metadata`
+/// (stream, topic, id, offset, timestamps). Returning `false` drops the message.
+pub struct Script {
+    engine: Engine,
+    ast: AST,
+    on_error: ScriptErrorPolicy,
+}
+
+impl Script {
+    pub fn new(cfg: ScriptConfig) -> Result<Self, Error> {
+        let source = match (cfg.script, cfg.path) {
+            (Some(script), _) => script,
+            (None, Some(path)) => std::fs::read_to_string(&path).map_err(|error| {
+                error!("Failed to read script file: {path:?}. {error}");
+                Error::InvalidScript(format!("Cannot read file: {}", path.display()))
+            })?,
+            (None, None) => {
+                error!("Script transform requires either inline script or path to the file.");
+                return Err(Error::InvalidConfig);
+            }
+        };
+
+        let timeout = cfg.timeout.unwrap_or(DEFAULT_TIMEOUT.to_string());
+        let timeout = humantime::Duration::from_str(&timeout).map_err(|error| {
+            error!("Invalid script timeout: {timeout}. {error}");
+            Error::InvalidConfig
+        })?;
+        let engine = Self::create_engine(
+            cfg.max_operations.unwrap_or(DEFAULT_MAX_OPERATIONS),
+            *timeout,
+        );
+        let ast = engine.compile(&source).map_err(|error| {
+            error!("Failed to compile script. {error}");
+            Error::InvalidScript(error.to_string())
+        })?;
+
+        Ok(Self {
+            engine,
+            ast,
+            on_error: cfg.on_error,
+        })
+    }
+
+    fn create_engine(max_operations: u64, timeout: Duration) -> Engine {
+        let mut engine = Engine::new();
+        engine.set_max_operations(max_operations);
+        engine.set_max_call_levels(DEFAULT_MAX_CALL_LEVELS);
+        engine.set_max_string_size(DEFAULT_MAX_STRING_SIZE);
+        engine.set_max_array_size(DEFAULT_MAX_COLLECTION_SIZE);
+        engine.set_max_map_size(DEFAULT_MAX_COLLECTION_SIZE);
+        engine.disable_symbol("eval");
+        engine.on_print(|_| {});
+        engine.on_debug(|_, _, _| {});
+        engine.on_progress(move |operations| {
+            if operations % TIMEOUT_CHECK_INTERVAL != 0 {
+                return None;
+            }
+
+            let started_at = SCRIPT_STARTED_AT.with(|started_at| started_at.get())?;
+            if started_at.elapsed() > timeout {
+                return Some(Dynamic::from("timeout"));
+            }
+            None
+        });
+        engine
+    }
+
+    fn run(
+        &self,
+        metadata: &TopicMetadata,
+        message: &DecodedMessage,
+    ) -> Result<Option<DecodedMessage>, Error> {
+        let mut scope = Scope::new();
+        scope.push("payload", payload_to_dynamic(&message.payload)?);
+        scope.push("headers", headers_to_dynamic(message.headers.as_ref()));
+        scope.push_constant("metadata", metadata_to_dynamic(metadata, message));
+
+        SCRIPT_STARTED_AT.with(|started_at| started_at.set(Some(Instant::now())));
+        let result = self
+            .engine
+            .eval_ast_with_scope::<Dynamic>(&mut scope, &self.ast);
+        SCRIPT_STARTED_AT.with(|started_at| started_at.set(None));
+        let result = result.map_err(|error| Error::ScriptFailed(error.to_string()))?;
+
+        if result.as_bool().is_ok_and(|keep| !keep) {
+            return Ok(None);
+        }
+
+        let payload = scope
+            .get_value::<Dynamic>("payload")
+            .ok_or(Error::ScriptFailed("Missing payload".to_owned()))?;
+        let headers = scope
+            .get_value::<Dynamic>("headers")
+            .ok_or(Error::ScriptFailed("Missing headers".to_owned()))?;
+
+        Ok(Some(DecodedMessage {
+            id: message.id,
+            offset: message.offset,
+            checksum: message.checksum,
+            timestamp: message.timestamp,
+            origin_timestamp: message.origin_timestamp,
+            headers: dynamic_to_headers(headers, message.headers.as_ref())?,
+            payload: dynamic_to_payload(payload, &message.payload)?,
+        }))
+    }
+}
+
+impl Transform for Script {
+    fn r#type(&self) -> TransformType {
+        TransformType::Script
+    }
+
+    fn transform(
+        &self,
+        metadata: &TopicMetadata,
+        message: DecodedMessage,
+    ) -> Result<Option<DecodedMessage>, Error> {
+        match self.run(metadata, &message) {
+            Ok(message) => Ok(message),
+            Err(error) => match self.on_error {
+                ScriptErrorPolicy::Fail => {
+                    error!("Script transform failed. {error}");
+                    Err(error)
+                }
+                ScriptErrorPolicy::Drop => {
+                    warn!("Script transform failed, dropping message. {error}");
+                    Ok(None)
+                }
+                ScriptErrorPolicy::Skip => {
+                    warn!("Script transform failed, passing message unchanged. {error}");
+                    Ok(Some(message))
+                }
+            },
+        }
+    }
+}
+
+fn payload_to_dynamic(payload: &Payload) -> Result<Dynamic, Error> {
+    match payload {
+        Payload::Json(value) => rhai::serde::to_dynamic(value).map_err(|error| {
+            error!("Failed to convert JSON payload for script. {error}");
+            Error::InvalidJsonPayload
+        }),
+        Payload::Text(text) | Payload::Proto(text) => Ok(Dynamic::from(text.clone())),
+        Payload::Raw(bytes)
+        | Payload::FlatBuffer(bytes)
+        | Payload::Avro(bytes)
+        | Payload::MsgPack(bytes) => Ok(Dynamic::from_blob(bytes.clone())),
+    }
+}
+
+fn dynamic_to_payload(value: Dynamic, original: &Payload) -> Result<Payload, Error> {
+    let type_name = value.type_name();
+    match original {
+        Payload::Json(_) => rhai::serde::from_dynamic::<OwnedValue>(&value)
+            .map(Payload::Json)
+            .map_err(|error| Error::ScriptFailed(format!("Invalid JSON payload. {error}"))),
+        Payload::Text(_) => value
+            .into_string()
+            .map(Payload::Text)
+            .map_err(|_| Error::ScriptFailed(format!("Expected string payload, got {type_name}"))),
+        Payload::Proto(_) => value
+            .into_string()
+            .map(Payload::Proto)
+            .map_err(|_| Error::ScriptFailed(format!("Expected string payload, got {type_name}"))),
+        Payload::Raw(_) => value
+            .into_blob()
+            .map(Payload::Raw)
+            .map_err(|_| Error::ScriptFailed(format!("Expected blob payload, got {type_name}"))),
+        Payload::FlatBuffer(_) => value
+            .into_blob()
+            .map(Payload::FlatBuffer)
+            .map_err(|_| Error::ScriptFailed(format!("Expected blob payload, got {type_name}"))),
+        Payload::Avro(_) => value
+            .into_blob()
+            .map(Payload::Avro)
+            .map_err(|_| Error::ScriptFailed(format!("Expected blob payload, got {type_name}"))),
+        Payload::MsgPack(_) => value
+            .into_blob()
+            .map(Payload::MsgPack)
+            .map_err(|_| Error::ScriptFailed(format!("Expected blob payload, got {type_name}"))),
+    }
+}
+
+fn header_to_dynamic(value: &HeaderValue) -> Dynamic {
+    match value.kind {
+        HeaderKind::Raw => Dynamic::from_blob(value.value.to_vec()),
+        HeaderKind::Bool => value.as_bool().map(Dynamic::from).unwrap_or_default(),
+        _ => Dynamic::from(value.value_only_to_string()),
+    }
+}
+
+fn headers_to_dynamic(headers: Option<&HashMap<HeaderKey, HeaderValue>>) -> Dynamic {
+    let mut map = Map::new();
+    for (key, value) in headers.into_iter().flatten() {
+        map.insert(key.as_str().into(), header_to_dynamic(value));
+    }
+    Dynamic::from_map(map)
+}
+
+/// Converts the headers map back, preserving the original kind of the unchanged headers,
+/// and reusing the original kind (when possible) for the modified ones.
+fn dynamic_to_headers(
+    value: Dynamic,
+    original: Option<&HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<HashMap<HeaderKey, HeaderValue>>, Error> {
+    let type_name = value.type_name();
+    let map = value
+        .try_cast::<Map>()
+        .ok_or_else(|| Error::ScriptFailed(format!("Expected headers map, got {type_name}")))?;
+    if map.is_empty() {
+        return Ok(None);
+    }
+
+    let mut headers = HashMap::with_capacity(map.len());
+    for (key, value) in map {
+        let key = HeaderKey::new(key.as_str())
+            .map_err(|_| Error::ScriptFailed(format!("Invalid header key: {key}")))?;
+        let original_value = original.and_then(|headers| headers.get(&key));
+        if let Some(original_value) = original_value
+            && header_to_dynamic(original_value).to_string() == value.to_string()
+            && header_to_dynamic(original_value).type_name() == value.type_name()
+        {
+            headers.insert(key, original_value.clone());
+            continue;
+        }
+
+        let header_value = dynamic_to_header(&value, original_value.map(|value| value.kind))
+            .ok_or_else(|| {
+                Error::ScriptFailed(format!(
+                    "Invalid value of type {} for header: {key}",
+                    value.type_name()
+                ))
+            })?;
+        headers.insert(key, header_value);
+    }
+    Ok(Some(headers))
+}
+
+fn dynamic_to_header(value: &Dynamic, kind: Option<HeaderKind>) -> Option<HeaderValue> {
+    if let Some(kind) = kind
+        && kind != HeaderKind::Raw
+        && !value.is_blob()
+        && let Ok(header) = HeaderValue::from_kind_and_value_str(kind, &value.to_string())
+    {
+        return Some(header);
+    }
+
+    if let Ok(value) = value.as_bool() {
+        return HeaderValue::from_bool(value).ok();
+    }
+    if let Ok(value) = value.as_int() {
+        return HeaderValue::from_int64(value).ok();
+    }
+    if let Ok(value) = value.as_float() {
+        return HeaderValue::from_float64(value).ok();
+    }
+    if value.is_blob() {
+        let blob = value.clone().try_cast::<Blob>()?;
+        return HeaderValue::from_raw(&blob).ok();
+    }
+    HeaderValue::from_str(&value.to_string()).ok()
+}
+
+fn metadata_to_dynamic(metadata: &TopicMetadata, message: &DecodedMessage) -> Dynamic {
+    let mut map = Map::new();
+    map.insert("stream".into(), Dynamic::from(metadata.stream.clone()));
+    map.insert("topic".into(), Dynamic::from(metadata.topic.clone()));
+    map.insert(
+        "id".into(),
+        message
+            .id
+            .map(|id| Dynamic::from(id.to_string()))
+            .unwrap_or_default(),
+    );
+    map.insert("offset".into(), optional_int(message.offset));
+    map.insert("timestamp".into(), optional_int(message.timestamp));
+    map.insert(
+        "origin_timestamp".into(),
+        optional_int(message.origin_timestamp),
+    );
+    Dynamic::from_map(map)
+}
+
+fn optional_int(value: Option<u64>) -> Dynamic {
+    value
+        .map(|value| Dynamic::from_int(value as i64))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transforms::json::test_utils::{
+        create_raw_test_message, create_test_message, create_test_topic_metadata,
+        extract_json_object,
+    };
+
+    fn script(source: &str) -> Script {
+        Script::new(ScriptConfig {
+            script: Some(source.to_string()),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn should_derive_json_field() {
+        let transform = script(r#"payload.full_name = payload.first + " " + payload.last;"#);
+        let msg = create_test_message(r#"{"first": "John", "last": "Doe"}"#);
+        let result = transform
+            .transform(&create_test_topic_metadata(), msg)
+            .unwrap()
+            .unwrap();
+        let json_obj = extract_json_object(&result).unwrap();
+        assert_eq!(json_obj.len(), 3);
+        assert_eq!(json_obj["full_name"], "John Doe");
+    }
+
+    #[test]
+    fn should_drop_message_when_script_returns_false() {
+        let transform = script("payload.amount > 100");
+        let result = transform
+            .transform(
+                &create_test_topic_metadata(),
+                create_test_message(r#"{"amount": 10}"#),
+            )
+            .unwrap();
+        assert!(result.is_none());
+
+        let result = transform
+            .transform(
+                &create_test_topic_metadata(),
+                create_test_message(r#"{"amount": 1000}"#),
+            )
+            .unwrap();
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn should_expose_topic_metadata() {
+        let transform = script(r#"payload.route = metadata.stream + "/" + metadata.topic;"#);
+        let result = transform
+            .transform(&create_test_topic_metadata(), create_test_message("{}"))
+            .unwrap()
+            .unwrap();
+        let json_obj = extract_json_object(&result).unwrap();
+        assert_eq!(json_obj["route"], "test-stream/test-topic");
+    }
+
+    #[test]
+    fn should_add_and_modify_headers() {
+        let transform = script(
+            r#"
+            headers.region = "eu";
+            headers.priority = 5;
+            headers.existing = headers.existing + "-updated";
+            "#,
+        );
+        let mut msg = create_test_message(r#"{"a": 1}"#);
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new("existing").unwrap(),
+            HeaderValue::from_str("value").unwrap(),
+        );
+        headers.insert(
+            HeaderKey::new("untouched").unwrap(),
+            HeaderValue::from_uint64(42).unwrap(),
+        );
+        msg.headers = Some(headers);
+
+        let result = transform
+            .transform(&create_test_topic_metadata(), msg)
+            .unwrap()
+            .unwrap();
+        let headers = result.headers.unwrap();
+        assert_eq!(headers.len(), 4);
+        let get = |key: &str| headers.get(&HeaderKey::new(key).unwrap()).unwrap();
+        assert_eq!(get("region").as_str().unwrap(), "eu");
+        assert_eq!(get("priority").as_int64().unwrap(), 5);
+        assert_eq!(get("existing").as_str().unwrap(), "value-updated");
+        assert_eq!(get("untouched").as_uint64().unwrap(), 42);
+    }
+
+    #[test]
+    fn should_modify_raw_payload() {
+        let transform = script("payload.push(4);");
+        let result = transform
+            .transform(
+                &create_test_topic_metadata(),
+                create_raw_test_message(vec![1, 2, 3]),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(matches!(result.payload, Payload::Raw(bytes) if bytes == vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn should_fail_when_max_operations_are_exceeded() {
+        let transform = Script::new(ScriptConfig {
+            script: Some("loop {}".to_string()),
+            max_operations: Some(1000),
+            ..Default::default()
+        })
+        .unwrap();
+        let result = transform.transform(&create_test_topic_metadata(), create_test_message("{}"));
+        assert!(matches!(result, Err(Error::ScriptFailed(_))));
+    }
+
+    #[test]
+    fn should_fail_when_timeout_is_exceeded() {
+        let transform = Script::new(ScriptConfig {
+            script: Some("loop {}".to_string()),
+            max_operations: Some(0),
+            timeout: Some("10ms".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        let result = transform.transform(&create_test_topic_metadata(), create_test_message("{}"));
+        assert!(matches!(result, Err(Error::ScriptFailed(_))));
+    }
+
+    #[test]
+    fn should_apply_error_policy() {
+        let config = ScriptConfig {
+            script: Some("payload.missing.field = 1;".to_string()),
+            ..Default::default()
+        };
+        let drop = Script::new(ScriptConfig {
+            on_error: ScriptErrorPolicy::Drop,
+            ..config.clone()
+        })
+        .unwrap();
+        let result = drop
+            .transform(&create_test_topic_metadata(), create_test_message("{}"))
+            .unwrap();
+        assert!(result.is_none());
+
+        let skip = Script::new(ScriptConfig {
+            on_error: ScriptErrorPolicy::Skip,
+            ..config
+        })
+        .unwrap();
+        let result = skip
+            .transform(
+                &create_test_topic_metadata(),
+                create_test_message(r#"{"a": 1}"#),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(extract_json_object(&result).unwrap()["a"], 1);
+    }
+
+    #[test]
+    fn should_reject_invalid_script() {
+        let result = Script::new(ScriptConfig {
+            script: Some("let x = ;".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(result, Err(Error::InvalidScript(_))));
+    }
+
+    #[test]
+    fn should_reject_config_without_script() {
+        let result = Script::new(ScriptConfig::default());
+        assert!(matches!(result, Err(Error::InvalidConfig)));
+    }
+}
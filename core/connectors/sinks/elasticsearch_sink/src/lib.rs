@@ -0,0 +1,740 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use iggy_connector_sdk::{
+    ConsumedMessage, Error, MessagesMetadata, Payload, Sink, TopicMetadata, sink_connector,
+};
+use serde::{Deserialize, Serialize};
+use simd_json::OwnedValue;
+use std::{str::FromStr, time::Duration};
+use tokio::{sync::Mutex, time::sleep};
+use tracing::{error, info, warn};
+
+sink_connector!(ElasticsearchSink);
+
+const DEFAULT_DATE_FORMAT: &str = "%Y.%m.%d";
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_RETRY_INTERVAL: &str = "1s";
+const RETRYABLE_STATUS_CODES: [u16; 4] = [429, 502, 503, 504];
+
+#[derive(Debug)]
+pub struct ElasticsearchSink {
+    id: u32,
+    config: ElasticsearchSinkConfig,
+    client: reqwest::Client,
+    retry_interval: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElasticsearchSinkConfig {
+    pub url: String,
+    /// Index name template, supports `{stream}`, `{topic}`, `{partition}` and `{date}` placeholders.
+    pub index: String,
+    pub date_format: Option<String>,
+    pub document_id: Option<DocumentId>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub api_key: Option<String>,
+    pub index_template: Option<IndexTemplateConfig>,
+    pub max_retries: Option<u32>,
+    pub retry_interval: Option<String>,
+    pub fail_on_item_error: Option<bool>,
+}
+
+/// The source of the `_id` assigned to each indexed document.
+/// When not configured, the document IDs are generated by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentId {
+    MessageId,
+    Field(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexTemplateConfig {
+    pub name: String,
+    pub body: String,
+}
+
+#[derive(Debug)]
+struct State {
+    messages_processed: u64,
+    indexing_errors: u64,
+}
+
+#[derive(Debug)]
+struct BulkDocument {
+    index: String,
+    id: Option<String>,
+    body: String,
+}
+
+impl ElasticsearchSink {
+    pub fn new(id: u32, config: ElasticsearchSinkConfig) -> Self {
+        let retry_interval = config
+            .retry_interval
+            .clone()
+            .unwrap_or(DEFAULT_RETRY_INTERVAL.to_string());
+        let retry_interval = humantime::Duration::from_str(&retry_interval).unwrap_or(
+            humantime::Duration::from_str(DEFAULT_RETRY_INTERVAL)
+                .expect("Failed to parse retry interval"),
+        );
+        ElasticsearchSink {
+            id,
+            config,
+            client: reqwest::Client::new(),
+            retry_interval: *retry_interval,
+            state: Mutex::new(State {
+                messages_processed: 0,
+                indexing_errors: 0,
+            }),
+        }
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}/{path}", self.config.url.trim_end_matches('/'));
+        let request = self.client.request(method, url);
+        if let Some(api_key) = &self.config.api_key {
+            return request.header("authorization", format!("ApiKey {api_key}"));
+        }
+
+        if let Some(username) = &self.config.username {
+            return request.basic_auth(username, self.config.password.as_ref());
+        }
+
+        request
+    }
+
+    async fn create_index_template(&self, template: &IndexTemplateConfig) -> Result<(), Error> {
+        info!("Creating index template: {}", template.name);
+        let response = self
+            .request(
+                reqwest::Method::PUT,
+                &format!("_index_template/{}", template.name),
+            )
+            .header("content-type", "application/json")
+            .body(template.body.to_owned())
+            .send()
+            .await
+            .map_err(|error| {
+                error!(
+                    "Failed to send HTTP request to create index template: {}. {error}",
+                    template.name
+                );
+                Error::HttpRequestFailed(error.to_string())
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let reason = response.text().await.unwrap_or_default();
+            error!(
+                "Received an invalid HTTP response when creating index template: {}. Status code: {status}, reason: {reason}",
+                template.name
+            );
+            return Err(Error::InitError(format!(
+                "Failed to create index template: {}. {reason}",
+                template.name
+            )));
+        }
+
+        info!("Created index template: {}", template.name);
+        Ok(())
+    }
+
+    fn index_name(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        timestamp: u64,
+    ) -> String {
+        let mut index = self
+            .config
+            .index
+            .replace("{stream}", &topic_metadata.stream)
+            .replace("{topic}", &topic_metadata.topic)
+            .replace("{partition}", &messages_metadata.partition_id.to_string());
+        if index.contains("{date}") {
+            let date_format = self
+                .config
+                .date_format
+                .as_deref()
+                .unwrap_or(DEFAULT_DATE_FORMAT);
+            let date = DateTime::from_timestamp_micros(timestamp as i64).unwrap_or_else(Utc::now);
+            index = index.replace("{date}", &date.format(date_format).to_string());
+        }
+        index.to_lowercase()
+    }
+
+    fn document_id(&self, message: &ConsumedMessage, value: &OwnedValue) -> Option<String> {
+        match self.config.document_id.as_ref()? {
+            DocumentId::MessageId => Some(message.id.to_string()),
+            DocumentId::Field(field) => {
+                let OwnedValue::Object(map) = value else {
+                    return None;
+                };
+                match map.get(field.as_str())? {
+                    OwnedValue::String(value) => Some(value.to_owned()),
+                    OwnedValue::Static(simd_json::StaticNode::Null) => None,
+                    OwnedValue::Static(value) => Some(value.to_string()),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn prepare_documents(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: &MessagesMetadata,
+        messages: Vec<ConsumedMessage>,
+    ) -> Vec<BulkDocument> {
+        let mut documents = Vec::with_capacity(messages.len());
+        for message in messages {
+            let Payload::Json(ref value) = message.payload else {
+                warn!("Unsupported payload format: {}", messages_metadata.schema);
+                continue;
+            };
+
+            let id = self.document_id(&message, value);
+            if id.is_none() && matches!(self.config.document_id, Some(DocumentId::Field(_))) {
+                warn!(
+                    "Document ID field is missing for message with ID: {}, offset: {}. ID will be generated by the server.",
+                    message.id, message.offset
+                );
+            }
+
+            let Ok(body) = simd_json::to_string(value) else {
+                error!(
+                    "Failed to serialize payload for message with ID: {}, offset: {}",
+                    message.id, message.offset
+                );
+                continue;
+            };
+
+            documents.push(BulkDocument {
+                index: self.index_name(topic_metadata, messages_metadata, message.timestamp),
+                id,
+                body,
+            });
+        }
+        documents
+    }
+
+    fn build_bulk_body(documents: &[&BulkDocument]) -> String {
+        let mut body = String::new();
+        for document in documents {
+            let action = match &document.id {
+                Some(id) => serde_json::json!({ "index": { "_index": document.index, "_id": id } }),
+                None => serde_json::json!({ "index": { "_index": document.index } }),
+            };
+            body.push_str(&action.to_string());
+            body.push('\n');
+            body.push_str(&document.body);
+            body.push('\n');
+        }
+        body
+    }
+
+    /// Sends the documents using the `_bulk` API and returns the positions of the documents
+    /// that failed with a retryable status code, along with the count of permanently failed ones.
+    async fn bulk(&self, documents: &[&BulkDocument]) -> Result<(Vec<usize>, u64), Error> {
+        let response = self
+            .request(reqwest::Method::POST, "_bulk")
+            .header("content-type", "application/x-ndjson")
+            .body(Self::build_bulk_body(documents))
+            .send()
+            .await
+            .map_err(|error| {
+                error!(
+                    "Failed to send HTTP bulk request for Elasticsearch sink with ID: {}. {error}",
+                    self.id
+                );
+                Error::HttpRequestFailed(error.to_string())
+            })?;
+
+        let status = response.status();
+        if RETRYABLE_STATUS_CODES.contains(&status.as_u16()) {
+            warn!("Received a retryable HTTP response for bulk request. Status code: {status}");
+            return Ok(((0..documents.len()).collect(), 0));
+        }
+
+        let text = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            error!(
+                "Received an invalid HTTP response for bulk request. Status code: {status}, reason: {text}"
+            );
+            return Err(Error::HttpRequestFailed(format!(
+                "Status code: {status}, reason: {text}"
+            )));
+        }
+
+        let response: BulkResponse = serde_json::from_str(&text).map_err(|error| {
+            error!("Failed to parse bulk response: {error}");
+            Error::HttpRequestFailed(format!("Invalid bulk response: {error}"))
+        })?;
+        if !response.errors {
+            return Ok((vec![], 0));
+        }
+
+        let mut retryable = Vec::new();
+        let mut failed = 0;
+        for (position, item) in response.items.iter().enumerate() {
+            let Some(result) = item.values().next() else {
+                continue;
+            };
+            if result.error.is_none() {
+                continue;
+            }
+
+            if RETRYABLE_STATUS_CODES.contains(&result.status) {
+                retryable.push(position);
+                continue;
+            }
+
+            failed += 1;
+            let document = documents.get(position);
+            error!(
+                "Failed to index document with ID: {} in index: {}. Status code: {}, error: {}",
+                document
+                    .and_then(|document| document.id.as_deref())
+                    .unwrap_or("-"),
+                document
+                    .map(|document| document.index.as_str())
+                    .unwrap_or("-"),
+                result.status,
+                result
+                    .error
+                    .as_ref()
+                    .map(|e| e.to_string())
+                    .unwrap_or_default()
+            );
+        }
+        Ok((retryable, failed))
+    }
+
+    async fn index_documents(&self, documents: Vec<BulkDocument>) -> Result<u64, Error> {
+        let max_retries = self.config.max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+        let mut pending = documents.iter().collect::<Vec<_>>();
+        let mut failed = 0;
+        let mut attempt = 0;
+        loop {
+            let (retryable, permanently_failed) = self.bulk(&pending).await?;
+            failed += permanently_failed;
+            if retryable.is_empty() {
+                break;
+            }
+
+            if attempt >= max_retries {
+                error!(
+                    "Failed to index {} documents after {max_retries} retries for Elasticsearch sink with ID: {}",
+                    retryable.len(),
+                    self.id
+                );
+                failed += retryable.len() as u64;
+                break;
+            }
+
+            attempt += 1;
+            warn!(
+                "Retrying {} documents for Elasticsearch sink with ID: {} (attempt {attempt}/{max_retries})...",
+                retryable.len(),
+                self.id
+            );
+            pending = retryable
+                .into_iter()
+                .filter_map(|position| pending.get(position).copied())
+                .collect();
+            sleep(self.retry_interval).await;
+        }
+        Ok(failed)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkResponse {
+    errors: bool,
+    #[serde(default)]
+    items: Vec<std::collections::HashMap<String, BulkItemResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkItemResult {
+    status: u16,
+    error: Option<serde_json::Value>,
+}
+
+#[async_trait]
+impl Sink for ElasticsearchSink {
+    async fn open(&mut self) -> Result<(), Error> {
+        info!(
+            "Opened Elasticsearch sink connector with ID: {} for URL: {}",
+            self.id, self.config.url
+        );
+        if let Some(template) = &self.config.index_template {
+            self.create_index_template(template).await?;
+        }
+        Ok(())
+    }
+
+    async fn consume(
+        &self,
+        topic_metadata: &TopicMetadata,
+        messages_metadata: MessagesMetadata,
+        messages: Vec<ConsumedMessage>,
+    ) -> Result<(), Error> {
+        let messages_count = messages.len();
+        info!(
+            "Elasticsearch sink with ID: {} received: {messages_count} messages, format: {}",
+            self.id, messages_metadata.schema
+        );
+
+        let documents = self.prepare_documents(topic_metadata, &messages_metadata, messages);
+        if documents.is_empty() {
+            return Ok(());
+        }
+
+        let documents_count = documents.len();
+        let failed = self.index_documents(documents).await?;
+        let mut state = self.state.lock().await;
+        state.messages_processed += messages_count as u64;
+        state.indexing_errors += failed;
+        drop(state);
+
+        if failed > 0 {
+            error!(
+                "Elasticsearch sink with ID: {} failed to index {failed} of {documents_count} documents",
+                self.id
+            );
+            if self.config.fail_on_item_error.unwrap_or(false) {
+                return Err(Error::HttpRequestFailed(format!(
+                    "Failed to index {failed} of {documents_count} documents"
+                )));
+            }
+            return Ok(());
+        }
+
+        info!(
+            "Elasticsearch sink with ID: {} indexed {documents_count} documents",
+            self.id
+        );
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<(), Error> {
+        let state = self.state.lock().await;
+        info!(
+            "Elasticsearch sink connector with ID: {} is closed. Processed {} messages with {} errors",
+            self.id, state.messages_processed, state.indexing_errors
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        Router, extract::State as AxumState, http::StatusCode, routing::post, routing::put,
+    };
+    use iggy_connector_sdk::Schema;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use tokio::{net::TcpListener, sync::Mutex as AsyncMutex};
+
+    #[derive(Clone, Default)]
+    struct MockServer {
+        requests: Arc<AsyncMutex<Vec<String>>>,
+        templates: Arc<AsyncMutex<Vec<(String, String)>>>,
+        responses: Arc<AsyncMutex<Vec<(StatusCode, String)>>>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    async fn bulk_handler(
+        AxumState(server): AxumState<MockServer>,
+        body: String,
+    ) -> (StatusCode, String) {
+        server.calls.fetch_add(1, Ordering::SeqCst);
+        server.requests.lock().await.push(body);
+        let mut responses = server.responses.lock().await;
+        if responses.is_empty() {
+            return (
+                StatusCode::OK,
+                r#"{"took":1,"errors":false,"items":[]}"#.to_string(),
+            );
+        }
+        responses.remove(0)
+    }
+
+    async fn template_handler(
+        AxumState(server): AxumState<MockServer>,
+        axum::extract::Path(name): axum::extract::Path<String>,
+        body: String,
+    ) -> StatusCode {
+        server.templates.lock().await.push((name, body));
+        StatusCode::OK
+    }
+
+    async fn start_mock_server(responses: Vec<(StatusCode, String)>) -> (String, MockServer) {
+        let server = MockServer::default();
+        *server.responses.lock().await = responses;
+        let app = Router::new()
+            .route("/_bulk", post(bulk_handler))
+            .route("/_index_template/{name}", put(template_handler))
+            .with_state(server.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        (format!("http://{address}"), server)
+    }
+
+    fn config(url: &str) -> ElasticsearchSinkConfig {
+        ElasticsearchSinkConfig {
+            url: url.to_string(),
+            index: "iggy-{stream}-{topic}".to_string(),
+            date_format: None,
+            document_id: None,
+            username: None,
+            password: None,
+            api_key: None,
+            index_template: None,
+            max_retries: Some(1),
+            retry_interval: Some("1ms".to_string()),
+            fail_on_item_error: None,
+        }
+    }
+
+    fn topic_metadata() -> TopicMetadata {
+        TopicMetadata {
+            stream: "Orders".to_string(),
+            topic: "events".to_string(),
+        }
+    }
+
+    fn messages_metadata() -> MessagesMetadata {
+        MessagesMetadata {
+            partition_id: 1,
+            current_offset: 0,
+            schema: Schema::Json,
+        }
+    }
+
+    fn message(id: u128, json: &str) -> ConsumedMessage {
+        let mut bytes = json.as_bytes().to_vec();
+        ConsumedMessage {
+            id,
+            offset: id as u64,
+            checksum: 0,
+            // 2025-01-02T00:00:00Z
+            timestamp: 1_735_776_000_000_000,
+            origin_timestamp: 0,
+            headers: None,
+            payload: Payload::Json(simd_json::to_owned_value(&mut bytes).unwrap()),
+        }
+    }
+
+    fn lines(body: &str) -> Vec<serde_json::Value> {
+        body.lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn should_send_bulk_request_with_resolved_index_name() {
+        let (url, server) = start_mock_server(vec![]).await;
+        let mut config = config(&url);
+        config.index = "iggy-{stream}-{topic}-{partition}-{date}".to_string();
+        let sink = ElasticsearchSink::new(1, config);
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            vec![message(1, r#"{"name":"a"}"#), message(2, r#"{"name":"b"}"#)],
+        )
+        .await
+        .unwrap();
+
+        let requests = server.requests.lock().await;
+        assert_eq!(requests.len(), 1);
+        let lines = lines(&requests[0]);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[0]["index"]["_index"],
+            "iggy-orders-events-1-2025.01.02"
+        );
+        assert!(lines[0]["index"].get("_id").is_none());
+        assert_eq!(lines[1]["name"], "a");
+        assert_eq!(lines[3]["name"], "b");
+    }
+
+    #[tokio::test]
+    async fn should_use_message_id_as_document_id() {
+        let (url, server) = start_mock_server(vec![]).await;
+        let mut config = config(&url);
+        config.document_id = Some(DocumentId::MessageId);
+        let sink = ElasticsearchSink::new(1, config);
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            vec![message(42, r#"{"name":"a"}"#)],
+        )
+        .await
+        .unwrap();
+
+        let requests = server.requests.lock().await;
+        let lines = lines(&requests[0]);
+        assert_eq!(lines[0]["index"]["_id"], "42");
+    }
+
+    #[tokio::test]
+    async fn should_use_json_field_as_document_id() {
+        let (url, server) = start_mock_server(vec![]).await;
+        let mut config = config(&url);
+        config.document_id = Some(DocumentId::Field("user_id".to_string()));
+        let sink = ElasticsearchSink::new(1, config);
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            vec![
+                message(1, r#"{"user_id":"u-1"}"#),
+                message(2, r#"{"user_id":7}"#),
+                message(3, r#"{"other":true}"#),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let requests = server.requests.lock().await;
+        let lines = lines(&requests[0]);
+        assert_eq!(lines[0]["index"]["_id"], "u-1");
+        assert_eq!(lines[2]["index"]["_id"], "7");
+        assert!(lines[4]["index"].get("_id").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_retry_only_failed_retryable_items() {
+        let partial_failure = r#"{"took":1,"errors":true,"items":[
+            {"index":{"_index":"iggy-orders-events","status":201}},
+            {"index":{"_index":"iggy-orders-events","status":429,"error":{"type":"es_rejected_execution_exception"}}}
+        ]}"#;
+        let (url, server) =
+            start_mock_server(vec![(StatusCode::OK, partial_failure.to_string())]).await;
+        let mut config = config(&url);
+        config.document_id = Some(DocumentId::MessageId);
+        let sink = ElasticsearchSink::new(1, config);
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            vec![message(1, r#"{"name":"a"}"#), message(2, r#"{"name":"b"}"#)],
+        )
+        .await
+        .unwrap();
+
+        let requests = server.requests.lock().await;
+        assert_eq!(requests.len(), 2);
+        let retried = lines(&requests[1]);
+        assert_eq!(retried.len(), 2);
+        assert_eq!(retried[0]["index"]["_id"], "2");
+        assert_eq!(sink.state.lock().await.indexing_errors, 0);
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_item_errors_are_not_retryable_and_fail_on_item_error_is_set() {
+        let failure = r#"{"took":1,"errors":true,"items":[
+            {"index":{"_index":"iggy-orders-events","status":400,"error":{"type":"mapper_parsing_exception"}}}
+        ]}"#;
+        let (url, server) = start_mock_server(vec![(StatusCode::OK, failure.to_string())]).await;
+        let mut config = config(&url);
+        config.fail_on_item_error = Some(true);
+        let sink = ElasticsearchSink::new(1, config);
+
+        let result = sink
+            .consume(
+                &topic_metadata(),
+                messages_metadata(),
+                vec![message(1, r#"{"name":"a"}"#)],
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(server.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(sink.state.lock().await.indexing_errors, 1);
+    }
+
+    #[tokio::test]
+    async fn should_give_up_after_max_retries() {
+        let (url, server) = start_mock_server(vec![
+            (StatusCode::TOO_MANY_REQUESTS, String::new()),
+            (StatusCode::TOO_MANY_REQUESTS, String::new()),
+        ])
+        .await;
+        let sink = ElasticsearchSink::new(1, config(&url));
+
+        sink.consume(
+            &topic_metadata(),
+            messages_metadata(),
+            vec![message(1, r#"{"name":"a"}"#)],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(server.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(sink.state.lock().await.indexing_errors, 1);
+    }
+
+    #[tokio::test]
+    async fn should_create_index_template_on_open() {
+        let (url, server) = start_mock_server(vec![]).await;
+        let mut config = config(&url);
+        config.index_template = Some(IndexTemplateConfig {
+            name: "iggy".to_string(),
+            body: r#"{"index_patterns":["iggy-*"]}"#.to_string(),
+        });
+        let mut sink = ElasticsearchSink::new(1, config);
+
+        sink.open().await.unwrap();
+
+        let templates = server.templates.lock().await;
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].0, "iggy");
+        assert_eq!(templates[0].1, r#"{"index_patterns":["iggy-*"]}"#);
+    }
+
+    #[tokio::test]
+    async fn should_skip_non_json_payloads() {
+        let (url, server) = start_mock_server(vec![]).await;
+        let sink = ElasticsearchSink::new(1, config(&url));
+        let mut raw = message(1, r#"{"name":"a"}"#);
+        raw.payload = Payload::Raw(vec![1, 2, 3]);
+
+        sink.consume(&topic_metadata(), messages_metadata(), vec![raw])
+            .await
+            .unwrap();
+
+        assert_eq!(server.calls.load(Ordering::SeqCst), 0);
+    }
+}
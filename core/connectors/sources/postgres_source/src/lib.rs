@@ -21,7 +21,8 @@ use std::{collections::HashMap, str::FromStr, time::Duration};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use iggy_connector_sdk::{
-    ConnectorState, Error, ProducedMessage, ProducedMessages, Schema, Source, source_connector,
+    ConnectorState, Error, ProducedMessage, ProducedMessages, Schema, Source, WorkUnit,
+    WorkUnitState, source_connector,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::{Column, Pool, Postgres, Row, TypeInfo, postgres::PgPoolOptions};
@@ -63,6 +64,8 @@ struct State {
     last_poll_time: DateTime<Utc>,
     tracking_offsets: HashMap<String, String>,
     processed_rows: u64,
+    assigned_tables: Option<Vec<String>>,
+    released_tables: Vec<(String, Option<String>)>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -75,15 +78,25 @@ pub struct DatabaseRecord {
 }
 
 impl PostgresSource {
-    pub fn new(id: u32, config: PostgresSourceConfig, _state: Option<ConnectorState>) -> Self {
+    pub fn new(id: u32, config: PostgresSourceConfig, state: Option<ConnectorState>) -> Self {
+        // The state holds the tracking offsets of the polled tables.
+        let tracking_offsets = state
+            .and_then(|state| {
+                serde_json::from_slice::<HashMap<String, String>>(&state.0)
+                    .inspect_err(|e| error!("Failed to restore PostgreSQL source state: {e}"))
+                    .ok()
+            })
+            .unwrap_or_default();
         PostgresSource {
             id,
             pool: None,
             config,
             state: Mutex::new(State {
                 last_poll_time: Utc::now(),
-                tracking_offsets: HashMap::new(),
+                tracking_offsets,
                 processed_rows: 0,
+                assigned_tables: None,
+                released_tables: vec![],
             }),
         }
     }
@@ -388,7 +401,12 @@ impl PostgresSource {
         let batch_size = self.config.batch_size.unwrap_or(1000);
         let tracking_column = self.config.tracking_column.as_deref().unwrap_or("id");
 
-        for table in &self.config.tables {
+        // When scaled out, only the tables assigned to this instance are polled.
+        let tables = state
+            .assigned_tables
+            .clone()
+            .unwrap_or_else(|| self.config.tables.clone());
+        for table in &tables {
             let last_offset = state.tracking_offsets.get(table).cloned();
 
             let query = if let Some(custom_query) = &self.config.custom_query {
@@ -647,6 +665,7 @@ impl Source for PostgresSource {
         let poll_interval = self.get_poll_interval();
         tokio::time::sleep(poll_interval).await;
 
+        let previous_offsets = self.state.lock().await.tracking_offsets.clone();
         let messages = match self.config.mode.as_str() {
             "polling" => self.poll_tables().await?,
             "cdc" => self.poll_cdc().await?,
@@ -656,7 +675,7 @@ impl Source for PostgresSource {
             }
         };
 
-        let state = self.state.lock().await;
+        let mut state = self.state.lock().await;
         info!(
             "PostgreSQL source connector ID: {} produced {} messages. Total processed: {}",
             self.id,
@@ -664,13 +683,100 @@ impl Source for PostgresSource {
             state.processed_rows
         );
 
+        let changed_offsets = state
+            .tracking_offsets
+            .iter()
+            .filter(|(table, offset)| previous_offsets.get(*table) != Some(*offset))
+            .map(|(table, offset)| (table.clone(), offset.clone()))
+            .collect::<Vec<_>>();
+        let mut work_units = state
+            .released_tables
+            .drain(..)
+            .map(|(table, offset)| WorkUnitState {
+                name: table,
+                state: offset.map(|offset| ConnectorState(offset.into_bytes())),
+                released: true,
+            })
+            .collect::<Vec<_>>();
+
+        // When scaled out, the progress is tracked per table, as the tables move between the instances.
+        let connector_state =
+            if state.assigned_tables.is_some() {
+                work_units.extend(changed_offsets.into_iter().map(|(table, offset)| {
+                    WorkUnitState {
+                        name: table,
+                        state: Some(ConnectorState(offset.into_bytes())),
+                        released: false,
+                    }
+                }));
+                None
+            } else if !changed_offsets.is_empty() {
+                serde_json::to_vec(&state.tracking_offsets)
+                    .ok()
+                    .map(ConnectorState)
+            } else {
+                None
+            };
+
         Ok(ProducedMessages {
             schema: Schema::Json,
             messages,
-            state: None,
+            state: connector_state,
+            work_units,
         })
     }
 
+    fn work_units(&self) -> Vec<String> {
+        // The CDC mode reads the single replication slot, so it cannot be split across instances.
+        match self.config.mode.as_str() {
+            "polling" if self.config.custom_query.is_none() => self.config.tables.clone(),
+            _ => vec![],
+        }
+    }
+
+    async fn assign_work_units(&self, units: Vec<WorkUnit>) -> Result<(), Error> {
+        let tables = units
+            .iter()
+            .map(|unit| unit.name.clone())
+            .collect::<Vec<_>>();
+        info!(
+            "PostgreSQL source connector ID: {} assigned tables: {:?}",
+            self.id, tables
+        );
+
+        let mut state = self.state.lock().await;
+        let previous_tables = state
+            .assigned_tables
+            .replace(tables.clone())
+            .unwrap_or_default();
+        // The tables which are no longer assigned are released with their final offsets in the next poll.
+        for table in previous_tables
+            .iter()
+            .filter(|table| !tables.contains(table))
+        {
+            let offset = state.tracking_offsets.remove(table);
+            state.released_tables.push((table.clone(), offset));
+        }
+
+        // The tables which were already assigned keep their current offsets.
+        for unit in units
+            .into_iter()
+            .filter(|unit| !previous_tables.contains(&unit.name))
+        {
+            let Some(unit_state) = unit.state else {
+                continue;
+            };
+
+            match String::from_utf8(unit_state.0) {
+                Ok(offset) => {
+                    state.tracking_offsets.insert(unit.name, offset);
+                }
+                Err(e) => error!("Invalid offset of table '{}': {e}", unit.name),
+            }
+        }
+        Ok(())
+    }
+
     async fn close(&mut self) -> Result<(), Error> {
         if let Some(pool) = self.pool.take() {
             pool.close().await;
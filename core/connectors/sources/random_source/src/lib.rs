@@ -155,6 +155,7 @@ impl Source for RandomSource {
                     schema: Schema::Json,
                     messages: vec![],
                     state: Some(ConnectorState(state.current_number.to_le_bytes().to_vec())),
+                    work_units: vec![],
                 });
             }
         }
@@ -170,6 +171,7 @@ impl Source for RandomSource {
             schema: Schema::Json,
             messages,
             state: Some(ConnectorState(state.current_number.to_le_bytes().to_vec())),
+            work_units: vec![],
         })
     }
 
@@ -80,6 +80,9 @@ enum ServerProtocolAddr {
 
     #[display("QUIC_UDP:{_0}")]
     QuicUdp(SocketAddr),
+
+    #[display("METRICS_TCP:{_0}")]
+    MetricsTcp(SocketAddr),
 }
 
 #[derive(Debug)]
@@ -171,7 +174,49 @@ impl TestServer {
     pub fn start(&mut self) {
         self.set_server_addrs_from_env();
         self.cleanup();
+        self.spawn();
+    }
+
+    /// Starts the server again on its existing data, e.g. after it was killed.
+    pub fn restart(&mut self) {
+        if self.is_started() {
+            self.kill();
+        }
+        self.spawn();
+    }
 
+    /// Kills the server process with SIGKILL, keeping its data, to simulate a crash.
+    pub fn kill(&mut self) {
+        if let Some(mut child_handle) = self.child_handle.take() {
+            if let Err(error) = child_handle.kill() {
+                eprintln!("Failed to kill the server process: {error}");
+            }
+            let _ = child_handle.wait();
+        }
+    }
+
+    /// Pauses the server process with SIGSTOP until it's resumed.
+    #[cfg(unix)]
+    pub fn suspend(&self) {
+        self.send_signal(libc::SIGSTOP);
+    }
+
+    /// Resumes the server process paused with `suspend`.
+    #[cfg(unix)]
+    pub fn resume(&self) {
+        self.send_signal(libc::SIGCONT);
+    }
+
+    #[cfg(unix)]
+    fn send_signal(&self, signal: libc::c_int) {
+        if let Some(child_handle) = &self.child_handle {
+            unsafe {
+                libc::kill(child_handle.id() as libc::pid_t, signal);
+            }
+        }
+    }
+
+    fn spawn(&mut self) {
         // Remove the config file if it exists from a previous run.
         // Without this, starting the server on existing data will not work, because
         // port detection mechanism will use port from previous runtime.
@@ -297,6 +342,9 @@ impl TestServer {
                 ServerProtocolAddr::QuicUdp(addr) => {
                     ("IGGY_QUIC_ADDRESS".to_string(), addr.to_string())
                 }
+                ServerProtocolAddr::MetricsTcp(addr) => {
+                    ("IGGY_METRICS_ADDRESS".to_string(), addr.to_string())
+                }
             };
 
             self.envs.entry(key.0).or_insert(key.1);
@@ -346,6 +394,12 @@ impl TestServer {
             self.server_addrs.push(ServerProtocolAddr::HttpTcp(
                 config.http.address.parse().unwrap(),
             ));
+
+            if config.metrics.enabled {
+                self.server_addrs.push(ServerProtocolAddr::MetricsTcp(
+                    config.metrics.address.parse().unwrap(),
+                ));
+            }
         } else {
             panic!(
                 "Failed to load config from file {config_path} in {MAX_PORT_WAIT_DURATION_S} s!"
@@ -408,6 +462,15 @@ impl TestServer {
         None
     }
 
+    pub fn get_metrics_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::MetricsTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
     pub fn get_server_ip_addr(&self) -> Option<String> {
         if let Some(server_address) = self
             .get_raw_tcp_addr()
@@ -18,5 +18,6 @@
 
 mod test_help_command;
 mod test_missing_credentials;
+mod test_output_format;
 mod test_overview_command;
 mod test_quiet_mode;
@@ -40,6 +40,7 @@ Commands:
   ping             ping iggy server
   me               get current client info
   stats            get iggy server statistics
+  top              interactive iggy server dashboard
   snapshot         collect iggy server troubleshooting data
   pat              personal access token operations
   user             user operations [aliases: u]
@@ -48,6 +49,9 @@ Commands:
   consumer-offset  consumer offset operations [aliases: o]
   message          message operations [aliases: m]
   context          context operations [aliases: ctx]
+  apply            apply topology file to iggy server
+  diff             show changes required to apply topology file
+  export-topology  export iggy server topology
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
   help             Print this message or the help of the given subcommand(s)
@@ -177,6 +181,16 @@ Options:
   -q, --quiet
           Quiet mode (disabled stdout printing)
 
+  -o, --output <OUTPUT>
+          Output format of the command results
+{CLAP_INDENT}
+          Table is the human-readable output, while JSON and YAML formats print
+          the serialized command results (e.g. stream or topic details) only,
+          which is useful for scripting. Commands without results print nothing.
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, json, yaml]
+
   -d, --debug <DEBUG>
           Debug mode (verbose printing to given file)
 
@@ -0,0 +1,159 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestOutputFormatCmd {
+    stream_id: u32,
+    name: String,
+    format: String,
+}
+
+impl TestOutputFormatCmd {
+    fn new(stream_id: u32, name: String, format: String) -> Self {
+        Self {
+            stream_id,
+            name,
+            format,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestOutputFormatCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream(&self.name, Some(self.stream_id)).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opts(vec!["--output", self.format.as_str()])
+            .arg("stream")
+            .arg("get")
+            .arg(format!("{}", self.stream_id))
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state.success().stdout(contains("Executing").not());
+
+        match self.format.as_str() {
+            "json" => {
+                let stream: serde_json::Value =
+                    serde_json::from_slice(&command_state.get_output().stdout)
+                        .expect("Failed to parse JSON output");
+                assert_eq!(stream["id"], self.stream_id);
+                assert_eq!(stream["name"], self.name.as_str());
+                assert_eq!(stream["topics_count"], 0);
+                assert!(stream["topics"].is_array());
+            }
+            _ => {
+                command_state
+                    .stdout(starts_with("---\n"))
+                    .stdout(contains(format!("id: {}", self.stream_id)))
+                    .stdout(contains(format!("name: {}", self.name)));
+            }
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+struct TestOutputFormatStatusCmd {
+    stream_id: u32,
+    name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestOutputFormatStatusCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream(&self.name, Some(self.stream_id)).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opts(vec!["--output", "json"])
+            .arg("stream")
+            .arg("delete")
+            .arg(format!("{}", self.stream_id))
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state.success().stdout(contains("deleted").not());
+        let status: serde_json::Value = serde_json::from_slice(&command_state.get_output().stdout)
+            .expect("Failed to parse JSON output");
+        assert_eq!(status["command"], "stream.delete");
+        assert_eq!(status["status"], "success");
+        assert_eq!(status["stream_id"], self.stream_id.to_string());
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client.get_stream(&self.stream_id.try_into().unwrap()).await;
+        assert!(stream.is_ok_and(|stream| stream.is_none()));
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_print_structured_output() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatCmd::new(
+            1,
+            String::from("production"),
+            String::from("json"),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatCmd::new(
+            2,
+            String::from("testing"),
+            String::from("yaml"),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_print_structured_status_of_command_without_result() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestOutputFormatStatusCmd {
+            stream_id: 3,
+            name: String::from("staging"),
+        })
+        .await;
+}
@@ -51,6 +51,7 @@ Commands:
   ping             ping iggy server
   me               get current client info
   stats            get iggy server statistics
+  top              interactive iggy server dashboard
   snapshot         collect iggy server troubleshooting data
   pat              personal access token operations
   user             user operations [aliases: u]
@@ -59,6 +60,9 @@ Commands:
   consumer-offset  consumer offset operations [aliases: o]
   message          message operations [aliases: m]
   context          context operations [aliases: ctx]
+  apply            apply topology file to iggy server
+  diff             show changes required to apply topology file
+  export-topology  export iggy server topology
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
   help             Print this message or the help of the given subcommand(s)
@@ -23,3 +23,4 @@ mod test_message_poll_to_file_command;
 mod test_message_reply_via_file;
 mod test_message_send_command;
 mod test_message_send_from_file_command;
+mod test_message_tail_command;
@@ -36,6 +36,7 @@ Commands:
   send   Send messages to given topic ID and given stream ID [aliases: s]
   poll   Poll messages from given topic ID and given stream ID [aliases: p]
   flush  Flush messages from given topic ID and given stream ID [aliases: f]
+  tail   Tail messages from given topic ID and given stream ID [aliases: t]
   help   Print this message or the help of the given subcommand(s)
 
 Options:
@@ -0,0 +1,452 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId,
+    TestTopicId, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::prelude::*;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const SOURCE_HEADER: &str = "source";
+
+struct TestMessageTailCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    messages: Vec<(String, String)>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    filter: Option<String>,
+    show_headers: bool,
+}
+
+impl TestMessageTailCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        messages: &[(String, String)],
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        filter: Option<String>,
+        show_headers: bool,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            messages: messages.to_owned(),
+            using_stream_id,
+            using_topic_id,
+            filter,
+            show_headers,
+        }
+    }
+
+    fn expected_messages(&self) -> Vec<&String> {
+        self.messages
+            .iter()
+            .filter(|(_, source)| self.filter.as_ref().is_none_or(|filter| filter == source))
+            .map(|(message, _)| message)
+            .collect()
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![
+            "--first".into(),
+            "--message-count".into(),
+            format!("{}", self.expected_messages().len()),
+        ];
+
+        if let Some(filter) = &self.filter {
+            command.extend(vec!["--filter".into(), format!("{SOURCE_HEADER}={filter}")]);
+        }
+
+        if self.show_headers {
+            command.push("--show-headers".into());
+        }
+
+        command.push(match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageTailCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                self.partitions_count,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        for (index, (message, source)) in self.messages.iter().enumerate() {
+            let mut messages = vec![
+                IggyMessage::builder()
+                    .payload(Bytes::from(message.as_bytes().to_vec()))
+                    .user_headers(HashMap::from([(
+                        HeaderKey::from_str(SOURCE_HEADER).unwrap(),
+                        HeaderValue::from_str(source).unwrap(),
+                    )]))
+                    .build()
+                    .expect("Failed to create message with headers"),
+            ];
+            let partition_id = index as u32 % self.partitions_count + 1;
+            let send_status = client
+                .send_messages(
+                    &self.stream_id.try_into().unwrap(),
+                    &self.topic_id.try_into().unwrap(),
+                    &Partitioning::partition_id(partition_id),
+                    &mut messages,
+                )
+                .await;
+            assert!(send_status.is_ok());
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("tail")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let expected_messages = self.expected_messages();
+        let tailed_status = match expected_messages.len() {
+            1 => "Tailed 1 message".into(),
+            count => format!("Tailed {count} messages"),
+        };
+
+        let mut status = command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing tail messages from topic ID: {topic_id} and stream with ID: {stream_id}\n"
+            )))
+            .stdout(contains(format!(
+                "{tailed_status} from topic with ID: {topic_id} and stream with ID: {stream_id}"
+            )));
+
+        status = self.messages.iter().fold(status, |status, (message, _)| {
+            if expected_messages.contains(&message) {
+                status.stdout(contains(message.as_str()))
+            } else {
+                status.stdout(contains(message.as_str()).not())
+            }
+        });
+
+        if self.show_headers {
+            status.stdout(contains(format!("[{SOURCE_HEADER}=")).count(expected_messages.len()));
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<(String, String)> = vec![
+        ("Lorem ipsum dolor sit amet".into(), "orders".into()),
+        ("consectetur adipiscing elit".into(), "payments".into()),
+        ("sed do eiusmod tempor incididunt".into(), "orders".into()),
+        ("ut labore et dolore magna aliqua".into(), "payments".into()),
+        ("Ut enim ad minim veniam".into(), "orders".into()),
+        ("quis nostrud exercitation ullamco".into(), "orders".into()),
+    ];
+
+    let test_parameters: Vec<(TestStreamId, TestTopicId, Option<String>, bool)> = vec![
+        (TestStreamId::Numeric, TestTopicId::Numeric, None, false),
+        (TestStreamId::Named, TestTopicId::Named, None, true),
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+            Some("orders".into()),
+            true,
+        ),
+        (
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+            Some("payments".into()),
+            false,
+        ),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id, filter, show_headers) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessageTailCmd::new(
+                1,
+                String::from("stream"),
+                2,
+                String::from("topic"),
+                3,
+                &test_messages,
+                using_stream_id,
+                using_topic_id,
+                filter,
+                show_headers,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "tail", "--help"],
+            format!(
+                r#"Tail messages from given topic ID and given stream ID
+
+Messages are polled from all the partitions of the topic (or the given
+partition), or from the partitions assigned to the consumer group member,
+and printed as they arrive until the command is stopped, the given number
+of messages is printed or the given duration elapses.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy message tail 1 2
+ iggy message tail --first --message-count 10 stream topic
+ iggy message tail --timestamp 1718000000000000 --format json stream topic
+ iggy message tail --consumer-group group --duration 1m stream topic
+ iggy message tail --filter source=orders --show-headers stream topic
+
+{USAGE_PREFIX} message tail [OPTIONS] <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>
+          ID of the stream from which messages will be tailed
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          ID of the topic from which messages will be tailed
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          Partition ID from which messages will be tailed
+{CLAP_INDENT}
+          If not specified, messages are tailed from all the partitions
+
+  -g, --consumer-group <CONSUMER_GROUP>
+          Consumer group which will be joined to tail messages
+{CLAP_INDENT}
+          Messages are polled from the partitions assigned to the group member
+          starting from the stored offsets, which are committed after each batch.
+          Consumer group ID can be specified as a consumer group name or ID
+
+  -o, --offset <OFFSET>
+          Start tailing from the given offset
+
+  -t, --timestamp <TIMESTAMP>
+          Start tailing from the given timestamp
+{CLAP_INDENT}
+          Timestamp must be specified in microseconds since Unix epoch
+
+  -f, --first
+          Start tailing from the first message in the partitions
+
+  -l, --last
+          Start tailing after the last message in the partitions (default)
+
+  -n, --next
+          Start tailing after the stored consumer offset
+
+  -c, --consumer <CONSUMER>
+          Regular consumer which will poll messages
+{CLAP_INDENT}
+          Consumer ID can be specified as a consumer name or ID
+{CLAP_INDENT}
+          [default: 1]
+
+      --format <FORMAT>
+          Format in which the message payloads are printed
+{CLAP_INDENT}
+          JSON payloads are pretty printed, other payloads are printed as text
+{CLAP_INDENT}
+          [default: text]
+          [possible values: text, json, hex]
+
+  -s, --show-headers
+          Include the message headers in the output
+
+      --filter <KEY=VALUE>
+          Print only the messages with the header of the given value, as key=value
+{CLAP_INDENT}
+          Option can be specified multiple times, in which case
+          all the headers must match.
+
+  -m, --message-count <MESSAGE_COUNT>
+          Stop after the given number of messages is printed
+
+  -d, --duration <DURATION>
+          Stop after the given duration, e.g. 30s or 5m
+
+      --commit
+          Commit the offsets of the processed messages
+{CLAP_INDENT}
+          Offsets are stored for the regular consumer in each partition
+          after processing the polled messages. Offsets are always committed
+          when tailing as the consumer group member.
+
+      --batch-size <BATCH_SIZE>
+          Maximum number of messages polled from the partition at once
+{CLAP_INDENT}
+          [default: 100]
+
+      --poll-interval <POLL_INTERVAL>
+          Interval between polls when no new messages are available
+{CLAP_INDENT}
+          [default: 100ms]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "tail", "-h"],
+            format!(
+                r#"Tail messages from given topic ID and given stream ID
+
+{USAGE_PREFIX} message tail [OPTIONS] <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>  ID of the stream from which messages will be tailed
+  <TOPIC_ID>   ID of the topic from which messages will be tailed
+
+Options:
+  -p, --partition-id <PARTITION_ID>
+          Partition ID from which messages will be tailed
+  -g, --consumer-group <CONSUMER_GROUP>
+          Consumer group which will be joined to tail messages
+  -o, --offset <OFFSET>
+          Start tailing from the given offset
+  -t, --timestamp <TIMESTAMP>
+          Start tailing from the given timestamp
+  -f, --first
+          Start tailing from the first message in the partitions
+  -l, --last
+          Start tailing after the last message in the partitions (default)
+  -n, --next
+          Start tailing after the stored consumer offset
+  -c, --consumer <CONSUMER>
+          Regular consumer which will poll messages [default: 1]
+      --format <FORMAT>
+          Format in which the message payloads are printed [default: text] [possible values: text, json, hex]
+  -s, --show-headers
+          Include the message headers in the output
+      --filter <KEY=VALUE>
+          Print only the messages with the header of the given value, as key=value
+  -m, --message-count <MESSAGE_COUNT>
+          Stop after the given number of messages is printed
+  -d, --duration <DURATION>
+          Stop after the given duration, e.g. 30s or 5m
+      --commit
+          Commit the offsets of the processed messages
+      --batch-size <BATCH_SIZE>
+          Maximum number of messages polled from the partition at once [default: 100]
+      --poll-interval <POLL_INTERVAL>
+          Interval between polls when no new messages are available [default: 100ms]
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
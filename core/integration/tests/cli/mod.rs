@@ -28,4 +28,5 @@ mod personal_access_token;
 mod stream;
 mod system;
 mod topic;
+mod topology;
 mod user;
@@ -31,3 +31,4 @@ mod test_me_command;
 mod test_ping_command;
 mod test_snapshot_cmd;
 mod test_stats_command;
+mod test_top_command;
@@ -0,0 +1,85 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{CLAP_INDENT, IggyCmdTest, TestHelpCmd, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["top", "--help"],
+            format!(
+                r#"interactive iggy server dashboard
+
+Display live throughput of streams, topics and partitions computed from
+successive statistics polls, connected clients, consumer groups with their
+members and lag, and segment counts and sizes. Select a stream or topic
+and press enter to drill into it, press t in the topic view to tail its
+messages, c to switch to the connected clients and q to quit.
+
+Examples:
+ iggy top
+ iggy top --interval 5s
+
+{USAGE_PREFIX} top [OPTIONS]
+
+Options:
+  -i, --interval <INTERVAL>
+          Interval between the successive server polls, e.g. 1s or 500ms
+{CLAP_INDENT}
+          [default: 1s]
+
+  -t, --tail-count <TAIL_COUNT>
+          Number of the most recent messages displayed in the topic tail view
+{CLAP_INDENT}
+          [default: 100]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["top", "-h"],
+            format!(
+                r#"interactive iggy server dashboard
+
+{USAGE_PREFIX} top [OPTIONS]
+
+Options:
+  -i, --interval <INTERVAL>      Interval between the successive server polls, e.g. 1s or 500ms [default: 1s]
+  -t, --tail-count <TAIL_COUNT>  Number of the most recent messages displayed in the topic tail view [default: 100]
+  -h, --help                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,282 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::prelude::*;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+
+const STREAM_ID: u32 = 1;
+const SOURCE_TOPIC: &str = "source";
+const TARGET_TOPIC: &str = "target";
+
+struct TestTopicExportCmd {
+    file: PathBuf,
+    messages: Vec<(u32, String)>,
+}
+
+impl TestTopicExportCmd {
+    fn new(file: PathBuf, messages: &[(u32, String)]) -> Self {
+        Self {
+            file,
+            messages: messages.to_owned(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicExportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream("stream", Some(STREAM_ID)).await;
+        assert!(stream.is_ok());
+
+        for (topic_id, name) in [(1, SOURCE_TOPIC), (2, TARGET_TOPIC)] {
+            let topic = client
+                .create_topic(
+                    &STREAM_ID.try_into().unwrap(),
+                    name,
+                    2,
+                    Default::default(),
+                    None,
+                    Some(topic_id),
+                    IggyExpiry::NeverExpire,
+                    MaxTopicSize::ServerDefault,
+                )
+                .await;
+            assert!(topic.is_ok());
+        }
+
+        for (partition_id, payload) in &self.messages {
+            let mut messages = vec![
+                IggyMessage::builder()
+                    .payload(Bytes::from(payload.clone()))
+                    .build()
+                    .expect("Failed to create message"),
+            ];
+            let send_status = client
+                .send_messages(
+                    &STREAM_ID.try_into().unwrap(),
+                    &Identifier::named(SOURCE_TOPIC).unwrap(),
+                    &Partitioning::partition_id(*partition_id),
+                    &mut messages,
+                )
+                .await;
+            assert!(send_status.is_ok());
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("topic")
+            .arg("export")
+            .arg(format!("{STREAM_ID}"))
+            .arg(SOURCE_TOPIC)
+            .arg(self.file.to_string_lossy())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing export topic with ID: {SOURCE_TOPIC} from stream with ID: {STREAM_ID} to file: {}\n",
+                self.file.display()
+            )))
+            .stdout(contains(format!(
+                "Exported {} messages from 2 partitions of topic with ID: {SOURCE_TOPIC}",
+                self.messages.len()
+            )));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {
+        assert!(self.file.exists());
+    }
+}
+
+struct TestTopicImportCmd {
+    file: PathBuf,
+    messages: Vec<(u32, String)>,
+}
+
+impl TestTopicImportCmd {
+    fn new(file: PathBuf, messages: &[(u32, String)]) -> Self {
+        Self {
+            file,
+            messages: messages.to_owned(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicImportCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("topic")
+            .arg("import")
+            .arg(format!("{STREAM_ID}"))
+            .arg(TARGET_TOPIC)
+            .arg(self.file.to_string_lossy())
+            .args(vec!["--partition-map", "1:2,2:1"])
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(contains(format!(
+            "Imported {} messages from file: {} (exported from topic: {SOURCE_TOPIC} in stream: stream)",
+            self.messages.len(),
+            self.file.display()
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        // Partitions are swapped by the partition mapping
+        for (source_partition_id, target_partition_id) in [(1, 2), (2, 1)] {
+            let polled_messages = client
+                .poll_messages(
+                    &STREAM_ID.try_into().unwrap(),
+                    &Identifier::named(TARGET_TOPIC).unwrap(),
+                    Some(target_partition_id),
+                    &Consumer::default(),
+                    &PollingStrategy::first(),
+                    100,
+                    false,
+                )
+                .await
+                .unwrap();
+
+            let expected_payloads = self
+                .messages
+                .iter()
+                .filter(|(partition_id, _)| *partition_id == source_partition_id)
+                .map(|(_, payload)| payload.as_bytes())
+                .collect::<Vec<_>>();
+            let payloads = polled_messages
+                .messages
+                .iter()
+                .map(|message| message.payload.as_ref())
+                .collect::<Vec<_>>();
+            assert_eq!(payloads, expected_payloads);
+        }
+
+        assert!(!self.file.with_extension("iggy.checkpoint").exists());
+
+        let stream = client.delete_stream(&STREAM_ID.try_into().unwrap()).await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let directory = tempfile::tempdir().unwrap();
+    let file = directory.path().join("topic.iggy");
+
+    let messages: Vec<(u32, String)> = vec![
+        (1, "Lorem ipsum dolor sit amet".into()),
+        (2, "consectetur adipiscing elit".into()),
+        (1, "sed do eiusmod tempor incididunt".into()),
+        (1, "ut labore et dolore magna aliqua".into()),
+        (2, "Ut enim ad minim veniam".into()),
+    ];
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestTopicExportCmd::new(file.clone(), &messages))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicImportCmd::new(file, &messages))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_export_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "export", "-h"],
+            format!(
+                r#"Export messages from topic with given ID in given stream ID to archive file
+
+{USAGE_PREFIX} topic export [OPTIONS] <STREAM_ID> <TOPIC_ID> <FILE>
+
+Arguments:
+  <STREAM_ID>  Stream ID of the exported topic
+  <TOPIC_ID>   Topic ID to export
+  <FILE>       Path of the archive file to create
+
+Options:
+  -p, --partitions <PARTITIONS>
+          Comma separated list of partition IDs to export, all partitions by default
+      --from-offset <FROM_OFFSET>
+          Offset of the first exported message in each partition
+      --to-offset <TO_OFFSET>
+          Offset of the last exported message in each partition
+      --from-timestamp <FROM_TIMESTAMP>
+          Timestamp (microseconds since Unix epoch) of the first exported message
+      --to-timestamp <TO_TIMESTAMP>
+          Timestamp (microseconds since Unix epoch) of the last exported message
+      --batch-size <BATCH_SIZE>
+          Number of messages polled from the partition at once [default: 1000]
+      --compression-level <COMPRESSION_LEVEL>
+          Compression level of the archive file (1-22) [default: 3]
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_import_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "import", "-h"],
+            format!(
+                r#"Import messages from archive file to topic with given ID in given stream ID
+
+{USAGE_PREFIX} topic import [OPTIONS] <STREAM_ID> <TOPIC_ID> <FILE>
+
+Arguments:
+  <STREAM_ID>  Stream ID of the target topic
+  <TOPIC_ID>   Topic ID of the target topic
+  <FILE>       Path of the archive file to import
+
+Options:
+      --partition-map <PARTITION_MAP>  Comma separated list of exported:target partition IDs
+      --balanced                       Send messages using the balanced partitioning instead of preserving partitions
+      --batch-size <BATCH_SIZE>        Maximum number of messages sent at once [default: 1000]
+      --resume                         Resume the interrupted import from the checkpoint file
+  -h, --help                           Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
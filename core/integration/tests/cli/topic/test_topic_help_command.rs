@@ -39,6 +39,8 @@ Commands:
   get     Get topic detail for given topic ID and stream ID [aliases: g]
   list    List all topics in given stream ID [aliases: l]
   purge   Purge topic with given ID in given stream ID [aliases: p]
+  export  Export messages from topic with given ID in given stream ID to archive file [aliases: e]
+  import  Import messages from archive file to topic with given ID in given stream ID [aliases: i]
   help    Print this message or the help of the given subcommand(s)
 
 Options:
@@ -0,0 +1,41 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod test_apply_command;
+mod test_diff_command;
+mod test_export_topology_command;
+
+pub(crate) const TOPOLOGY: &str = r#"
+streams:
+  - name: orders
+    topics:
+      - name: created
+        partitions: 2
+        consumer_groups:
+          - name: billing
+users:
+  - username: app
+    password: app-password
+    permissions:
+      global: [read_streams]
+      streams:
+        orders:
+          permissions: [read_stream]
+          topics:
+            created: [send_messages]
+"#;
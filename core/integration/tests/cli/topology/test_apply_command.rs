@@ -0,0 +1,211 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX,
+};
+use crate::cli::topology::TOPOLOGY;
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::PathBuf;
+
+struct TestApplyCmd {
+    file: PathBuf,
+    prune: bool,
+}
+
+impl TestApplyCmd {
+    fn new(file: PathBuf, prune: bool) -> Self {
+        Self { file, prune }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestApplyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        if !self.prune {
+            let stream = client.create_stream("legacy", None).await;
+            assert!(stream.is_ok());
+            std::fs::write(&self.file, TOPOLOGY).unwrap();
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new().arg("apply");
+        let command = match self.prune {
+            true => command.arg("--prune"),
+            false => command,
+        };
+        command
+            .arg("-f")
+            .arg(self.file.to_string_lossy())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let file = self.file.display();
+        match self.prune {
+            false => command_state
+                .success()
+                .stdout(starts_with(format!(
+                    "Executing apply topology from file: {file}\n"
+                )))
+                .stdout(contains(format!(
+                    "Changes required by topology from file: {file}
++ create stream: orders
++ create topic: created with 2 partitions in stream: orders
++ create consumer group: billing for topic: created in stream: orders
++ create user: app with status: active
+Applied 4 changes from topology file: {file}
+"
+                ))),
+            true => command_state.success().stdout(contains(format!(
+                "Changes required by topology from file: {file}
+- delete stream: legacy
+Applied 1 changes from topology file: {file}
+"
+            ))),
+        };
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let streams = client.get_streams().await.unwrap();
+        assert_eq!(
+            streams.iter().any(|stream| stream.name == "legacy"),
+            !self.prune
+        );
+
+        let stream_id = Identifier::named("orders").unwrap();
+        let topic_id = Identifier::named("created").unwrap();
+        let stream = client.get_stream(&stream_id).await.unwrap().unwrap();
+        let topic = client
+            .get_topic(&stream_id, &topic_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(topic.partitions_count, 2);
+
+        let consumer_groups = client
+            .get_consumer_groups(&stream_id, &topic_id)
+            .await
+            .unwrap();
+        assert_eq!(consumer_groups.len(), 1);
+        assert_eq!(consumer_groups[0].name, "billing");
+
+        let user_id = Identifier::named("app").unwrap();
+        let user = client.get_user(&user_id).await.unwrap().unwrap();
+        let permissions = user.permissions.unwrap();
+        assert!(permissions.global.read_streams);
+        let stream_permissions = &permissions.streams.as_ref().unwrap()[&stream.id];
+        assert!(stream_permissions.read_stream);
+        assert!(stream_permissions.topics.as_ref().unwrap()[&topic.id].send_messages);
+
+        if self.prune {
+            assert!(client.delete_user(&user_id).await.is_ok());
+            assert!(client.delete_stream(&stream_id).await.is_ok());
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let directory = tempfile::tempdir().unwrap();
+    let file = directory.path().join("topology.yaml");
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestApplyCmd::new(file.clone(), false))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestApplyCmd::new(file, true))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["apply", "--help"],
+            format!(
+                r#"apply topology file to iggy server
+
+Reconcile the server with the declarative topology file describing streams,
+topics (partitions, compression, message expiry, max size, replication factor),
+consumer groups, users with their permissions and personal access tokens
+of the current user. Resources are matched by name, missing ones are created
+and the differing ones are updated. Resources missing in the file are deleted
+only when pruning is enabled. User passwords are used only to create the users,
+${{NAME}} placeholders in the file are replaced with environment variables.
+
+Examples:
+ iggy apply -f topology.yaml
+ iggy apply --dry-run --prune -f topology.yaml
+
+{USAGE_PREFIX} apply [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>
+          Path of the topology file (YAML, JSON or TOML based on the extension)
+
+  -d, --dry-run
+          Print the changes without applying them
+
+  -p, --prune
+          Delete the resources (and the excess partitions) missing in the topology file
+{CLAP_INDENT}
+          The root user and the user applying the topology are never deleted.
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["apply", "-h"],
+            format!(
+                r#"apply topology file to iggy server
+
+{USAGE_PREFIX} apply [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>  Path of the topology file (YAML, JSON or TOML based on the extension)
+  -d, --dry-run      Print the changes without applying them
+  -p, --prune        Delete the resources (and the excess partitions) missing in the topology file
+  -h, --help         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,158 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use crate::cli::topology::TOPOLOGY;
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::path::PathBuf;
+
+struct TestDiffCmd {
+    file: PathBuf,
+}
+
+impl TestDiffCmd {
+    fn new(file: PathBuf) -> Self {
+        Self { file }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestDiffCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream("orders", None).await;
+        assert!(stream.is_ok());
+        let stream = client.create_stream("legacy", None).await;
+        assert!(stream.is_ok());
+        std::fs::write(&self.file, TOPOLOGY).unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("diff")
+            .arg("--prune")
+            .arg("-f")
+            .arg(self.file.to_string_lossy())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let file = self.file.display();
+        command_state.success().stdout(diff(format!(
+            "Executing diff topology from file: {file} (with pruning)
+Changes required by topology from file: {file}
+- delete stream: legacy
++ create topic: created with 2 partitions in stream: orders
++ create consumer group: billing for topic: created in stream: orders
++ create user: app with status: active
+Dry run, 4 changes not applied
+"
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let streams = client.get_streams().await.unwrap();
+        assert_eq!(streams.len(), 2);
+        assert!(streams.iter().all(|stream| stream.topics_count == 0));
+        assert!(
+            client
+                .get_user(&Identifier::named("app").unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        for stream in streams {
+            let stream_id = Identifier::numeric(stream.id).unwrap();
+            assert!(client.delete_stream(&stream_id).await.is_ok());
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let directory = tempfile::tempdir().unwrap();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestDiffCmd::new(directory.path().join("topology.yaml")))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["diff", "--help"],
+            format!(
+                r#"show changes required to apply topology file
+
+Print the changes required to reconcile the server with the topology file,
+the same as apply with the dry run option.
+
+Examples:
+ iggy diff -f topology.yaml
+ iggy diff --prune -f topology.json
+
+{USAGE_PREFIX} diff [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>
+          Path of the topology file (YAML, JSON or TOML based on the extension)
+
+  -p, --prune
+          Include the deletions of the resources (and the excess partitions) missing in the topology file
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["diff", "-h"],
+            format!(
+                r#"show changes required to apply topology file
+
+{USAGE_PREFIX} diff [OPTIONS] --file <FILE>
+
+Options:
+  -f, --file <FILE>  Path of the topology file (YAML, JSON or TOML based on the extension)
+  -p, --prune        Include the deletions of the resources (and the excess partitions) missing in the topology file
+  -h, --help         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,172 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::*;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::path::PathBuf;
+
+struct TestExportTopologyCmd {
+    file: PathBuf,
+}
+
+impl TestExportTopologyCmd {
+    fn new(file: PathBuf) -> Self {
+        Self { file }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestExportTopologyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream("orders", Some(3)).await;
+        assert!(stream.is_ok());
+        let topic = client
+            .create_topic(
+                &Identifier::numeric(3).unwrap(),
+                "created",
+                2,
+                CompressionAlgorithm::Gzip,
+                None,
+                Some(1),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::Unlimited,
+            )
+            .await;
+        assert!(topic.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("export-topology")
+            .arg("-f")
+            .arg(self.file.to_string_lossy())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let file = self.file.display();
+        command_state.success().stdout(diff(format!(
+            "Executing export topology to file: {file}
+Exported topology with 1 streams, 1 users and 0 personal access tokens to file: {file}
+"
+        )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topology: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&self.file).unwrap()).unwrap();
+        assert_eq!(
+            topology["streams"],
+            serde_json::json!([{
+                "name": "orders",
+                "id": 3,
+                "topics": [{
+                    "name": "created",
+                    "id": 1,
+                    "partitions": 2,
+                    "compression_algorithm": "gzip",
+                    "message_expiry": "never_expire",
+                    "max_topic_size": "unlimited",
+                    "replication_factor": 1
+                }]
+            }])
+        );
+        assert_eq!(topology["users"][0]["username"], DEFAULT_ROOT_USERNAME);
+
+        let stream = client.delete_stream(&Identifier::numeric(3).unwrap()).await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let directory = tempfile::tempdir().unwrap();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestExportTopologyCmd::new(
+            directory.path().join("topology.json"),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["export-topology", "--help"],
+            format!(
+                r#"export iggy server topology
+
+Export streams, topics, consumer groups, users with their permissions and
+personal access tokens of the current user to the topology file, which can be
+applied to the other server. User passwords are not exported and must be
+added to the file to create the users.
+
+Examples:
+ iggy export-topology
+ iggy export-topology -f topology.yaml
+
+{USAGE_PREFIX} export-topology [OPTIONS]
+
+Options:
+  -f, --file <FILE>
+          Path of the topology file (YAML, JSON or TOML based on the extension)
+{CLAP_INDENT}
+          Topology is printed as YAML on standard output when the file is not set.
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["export-topology", "-h"],
+            format!(
+                r#"export iggy server topology
+
+{USAGE_PREFIX} export-topology [OPTIONS]
+
+Options:
+  -f, --file <FILE>  Path of the topology file (YAML, JSON or TOML based on the extension)
+  -h, --help         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
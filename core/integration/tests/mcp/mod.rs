@@ -35,12 +35,16 @@ use integration::{
 use lazy_static::lazy_static;
 use rmcp::{
     ServiceError,
-    model::{CallToolRequestParam, CallToolResult, ListToolsResult},
+    model::{
+        CallToolRequestParam, CallToolResult, ListResourceTemplatesResult, ListResourcesResult,
+        ListToolsResult, ReadResourceRequestParam, ReadResourceResult, ResourceContents,
+    },
     serde::de::DeserializeOwned,
     serde_json::{self, json},
 };
 use serial_test::parallel;
-use std::collections::HashMap;
+use std::{collections::HashMap, io::Write};
+use tempfile::NamedTempFile;
 
 const STREAM_NAME: &str = "test_stream";
 const TOPIC_NAME: &str = "test_topic";
@@ -68,7 +72,7 @@ async fn mcp_server_should_list_tools() {
 
     assert!(!tools.tools.is_empty());
     let tools_count = tools.tools.len();
-    assert_eq!(tools_count, 40);
+    assert_eq!(tools_count, 44);
 }
 
 #[tokio::test]
@@ -266,6 +270,109 @@ async fn mcp_server_should_poll_messages() {
     .await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_sample_messages() {
+    assert_response::<Vec<serde_json::Value>>(
+        "sample_messages",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME })),
+        |messages| {
+            assert_eq!(messages.len(), 1);
+            let message = &messages[0];
+            assert_eq!(message["partition_id"], 1);
+            assert_eq!(message["offset"], 0);
+            assert_eq!(message["size"], MESSAGE_PAYLOAD.len());
+            assert_eq!(message["payload"], MESSAGE_PAYLOAD);
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_infer_schema() {
+    let infra = setup().await;
+    let client = infra.mcp_client;
+    invoke_request::<()>(
+        &client,
+        "send_messages",
+        Some(
+            json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME, "messages": [
+            { "payload": r#"{"id":1,"user":{"name":"alice","email":"alice@iggy.apache.org"}}"# },
+            { "payload": r#"{"id":2,"user":{"name":"bob","email":null}}"# },
+            { "payload": r#"{"id":3,"user":{"name":"carol"},"tags":["a","b"]}"# }
+        ] }),
+        ),
+    )
+    .await;
+
+    let schema = invoke_request::<serde_json::Value>(
+        &client,
+        "infer_schema",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME })),
+    )
+    .await;
+
+    assert_eq!(schema["sampled_messages"], 4);
+    assert_eq!(schema["json_messages"], 3);
+    let fields = schema["fields"]
+        .as_array()
+        .expect("Fields should be an array")
+        .iter()
+        .map(|field| (field["path"].as_str().unwrap().to_owned(), field.clone()))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(fields["$.id"]["types"], json!(["integer"]));
+    assert_eq!(fields["$.id"]["optional"], false);
+    assert_eq!(fields["$.user.name"]["nullable"], false);
+    assert_eq!(fields["$.user.email"]["types"], json!(["string"]));
+    assert_eq!(fields["$.user.email"]["nullable"], true);
+    assert_eq!(fields["$.user.email"]["optional"], true);
+    assert_eq!(fields["$.tags"]["optional"], true);
+    assert_eq!(fields["$.tags[*]"]["types"], json!(["string"]));
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_return_payload_stats() {
+    assert_response::<serde_json::Value>(
+        "get_payload_stats",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME })),
+        |stats| {
+            assert_eq!(stats["sampled_messages"], 1);
+            assert_eq!(stats["partitions"]["1"], 1);
+            assert_eq!(stats["payload_size"]["min"], MESSAGE_PAYLOAD.len());
+            assert_eq!(stats["payload_size"]["max"], MESSAGE_PAYLOAD.len());
+            assert_eq!(stats["payload_size"]["p99"], MESSAGE_PAYLOAD.len());
+            assert_eq!(stats["payload_kinds"]["text"], 1);
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_find_messages_by_time() {
+    let infra = setup().await;
+    let client = infra.mcp_client;
+    let messages = invoke_request::<Vec<serde_json::Value>>(
+        &client,
+        "find_messages_by_time",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME, "from": 0 })),
+    )
+    .await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["payload"], MESSAGE_PAYLOAD);
+
+    let timestamp = messages[0]["timestamp"].as_u64().unwrap();
+    let messages = invoke_request::<Vec<serde_json::Value>>(
+        &client,
+        "find_messages_by_time",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME, "from": 0, "to": timestamp - 1 })),
+    )
+    .await;
+    assert!(messages.is_empty());
+}
+
 #[tokio::test]
 #[parallel]
 async fn mcp_server_should_send_messages() {
@@ -531,6 +638,227 @@ async fn mcp_server_should_change_password() {
     .await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_list_resources() {
+    let infra = setup().await;
+    let client = infra.mcp_client;
+    let resources = client
+        .list_resources()
+        .await
+        .expect("Failed to list resources");
+
+    let uris = resources
+        .resources
+        .iter()
+        .map(|resource| resource.uri.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(uris.len(), 3);
+    assert!(uris.contains(&"iggy://streams"));
+    assert!(uris.contains(&"iggy://streams/1"));
+    assert!(uris.contains(&"iggy://streams/1/topics/1"));
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_list_resource_templates() {
+    let infra = setup().await;
+    let client = infra.mcp_client;
+    let templates = client
+        .list_resource_templates()
+        .await
+        .expect("Failed to list resource templates");
+
+    assert_eq!(templates.resource_templates.len(), 3);
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_read_topic_resource() {
+    let uri = format!("iggy://streams/{STREAM_NAME}/topics/{TOPIC_NAME}");
+    assert_resource::<TopicDetails>(&uri, |topic| {
+        assert_eq!(topic.name, TOPIC_NAME);
+        assert_eq!(topic.partitions_count, 1);
+        assert_eq!(topic.messages_count, 1);
+    })
+    .await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_read_recent_messages_resource() {
+    let uri = format!("iggy://streams/{STREAM_NAME}/topics/{TOPIC_NAME}/partitions/1/messages");
+    assert_resource::<PolledMessages>(&uri, |messages| {
+        assert_eq!(messages.messages.len(), 1);
+        assert_eq!(messages.messages[0].payload, MESSAGE_PAYLOAD.as_bytes());
+    })
+    .await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_fail_to_read_unknown_resource() {
+    let infra = setup().await;
+    let client = infra.mcp_client;
+    let result = client.read_resource("iggy://unknown").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_list_only_allowed_tools() {
+    let infra = setup_with_config(
+        r#"
+        [permissions.tools]
+        allowed = ["get_streams", "get_stream", "poll_messages"]
+        denied = ["poll_messages"]
+        "#,
+    )
+    .await;
+    let client = infra.mcp_client;
+    let tools = client.list_tools().await.expect("Failed to list tools");
+
+    let mut tools = tools
+        .tools
+        .into_iter()
+        .map(|tool| tool.name.to_string())
+        .collect::<Vec<_>>();
+    tools.sort();
+    assert_eq!(tools, vec!["get_stream", "get_streams"]);
+
+    let result = client
+        .invoke(
+            "poll_messages",
+            Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME, "partition_id": 1 })),
+        )
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_hide_not_allowed_streams() {
+    let infra = setup_with_config(
+        r#"
+        [permissions.streams]
+        allowed = ["observability"]
+        "#,
+    )
+    .await;
+    let client = infra.mcp_client;
+
+    let streams = invoke_request::<Vec<Stream>>(&client, "get_streams", None).await;
+    assert!(streams.is_empty());
+
+    let result = client
+        .invoke("get_stream", Some(json!({ "stream_id": STREAM_NAME })))
+        .await;
+    assert!(result.is_err());
+
+    let result = client
+        .invoke("delete_stream", Some(json!({ "stream_id": STREAM_NAME })))
+        .await;
+    assert!(result.is_err());
+
+    let resources = client
+        .list_resources()
+        .await
+        .expect("Failed to list resources");
+    assert_eq!(resources.resources.len(), 1);
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_deny_access_to_topic() {
+    let infra = setup_with_config(&format!(
+        r#"
+        [permissions.streams]
+        allowed = ["{STREAM_NAME}"]
+        denied = ["{STREAM_NAME}/{TOPIC_NAME}"]
+        "#
+    ))
+    .await;
+    let client = infra.mcp_client;
+
+    let stream = invoke_request::<StreamDetails>(
+        &client,
+        "get_stream",
+        Some(json!({ "stream_id": STREAM_NAME })),
+    )
+    .await;
+    assert_eq!(stream.name, STREAM_NAME);
+    assert!(stream.topics.is_empty());
+
+    let result = client
+        .invoke(
+            "poll_messages",
+            Some(json!({ "stream_id": "1", "topic_id": "1", "partition_id": 1 })),
+        )
+        .await;
+    assert!(result.is_err());
+
+    let uri = format!("iggy://streams/{STREAM_NAME}/topics/{TOPIC_NAME}");
+    let result = client.read_resource(&uri).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[parallel]
+async fn mcp_server_should_redact_polled_messages() {
+    let infra = setup_with_config(
+        r#"
+        [redaction]
+        enabled = true
+        json_paths = ["$.user.email"]
+        patterns = ["test_"]
+        "#,
+    )
+    .await;
+    let client = infra.mcp_client;
+    invoke_request::<()>(
+        &client,
+        "send_messages",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME, "partitioning": "partition", "partition_id": 1, "messages": [
+            {
+                "payload": r#"{"id":1,"user":{"email":"user@iggy.apache.org"}}"#
+            }
+        ] })),
+    )
+    .await;
+
+    let messages = invoke_request::<PolledMessages>(
+        &client,
+        "poll_messages",
+        Some(json!({ "stream_id": STREAM_NAME, "topic_id": TOPIC_NAME, "partition_id": 1, "offset": 0 })),
+    )
+    .await;
+
+    assert_eq!(messages.messages.len(), 2);
+    assert_eq!(messages.messages[0].payload, "***message".as_bytes());
+    let payload = serde_json::from_slice::<serde_json::Value>(&messages.messages[1].payload)
+        .expect("Failed to parse message payload");
+    assert_eq!(payload, json!({ "id": 1, "user": { "email": "***" } }));
+}
+
+async fn assert_resource<T: DeserializeOwned>(uri: &str, assert_resource: impl FnOnce(T)) {
+    let infra = setup().await;
+    let client = infra.mcp_client;
+    let mut result = client
+        .read_resource(uri)
+        .await
+        .expect("Failed to read MCP resource");
+
+    if result.contents.is_empty() {
+        panic!("No contents returned from MCP for resource: {uri}");
+    }
+
+    let ResourceContents::TextResourceContents { text, .. } = result.contents.remove(0) else {
+        panic!("Expected text contents for MCP resource: {uri}");
+    };
+
+    assert_resource(serde_json::from_str::<T>(&text).expect("Failed to parse JSON"))
+}
+
 async fn assert_empty_response(method: &str, data: Option<serde_json::Value>) {
     assert_response::<()>(method, data, |()| {}).await
 }
@@ -567,6 +895,21 @@ async fn invoke_request<T: DeserializeOwned>(
 }
 
 async fn setup() -> McpInfra {
+    setup_mcp(None).await
+}
+
+async fn setup_with_config(config: &str) -> McpInfra {
+    let mut config_file = tempfile::Builder::new()
+        .suffix(".toml")
+        .tempfile()
+        .expect("Failed to create MCP config file");
+    config_file
+        .write_all(config.as_bytes())
+        .expect("Failed to write MCP config file");
+    setup_mcp(Some(config_file)).await
+}
+
+async fn setup_mcp(config_file: Option<NamedTempFile>) -> McpInfra {
     let mut iggy_envs = HashMap::new();
     iggy_envs.insert("IGGY_QUIC_ENABLED".to_owned(), "false".to_owned());
     let mut test_server = TestServer::new(Some(iggy_envs), true, None, IpAddrKind::V4);
@@ -576,7 +919,13 @@ async fn setup() -> McpInfra {
         .expect("Failed to get Iggy TCP address");
     seed_data(&iggy_server_address).await;
 
-    let mut test_mcp_server = TestMcpServer::with_iggy_address(&iggy_server_address);
+    let mcp_envs = config_file.as_ref().map(|config_file| {
+        HashMap::from([(
+            "IGGY_MCP_CONFIG_PATH".to_owned(),
+            config_file.path().display().to_string(),
+        )])
+    });
+    let mut test_mcp_server = TestMcpServer::new(&iggy_server_address, mcp_envs, None);
     test_mcp_server.start();
     test_mcp_server.ensure_started().await;
     let mcp_client = test_mcp_server.get_client().await;
@@ -584,6 +933,7 @@ async fn setup() -> McpInfra {
     McpInfra {
         _iggy_server: test_server,
         _mcp_server: test_mcp_server,
+        _config_file: config_file,
         mcp_client: TestMcpClient { mcp_client },
     }
 }
@@ -673,6 +1023,7 @@ async fn seed_data(iggy_server_address: &str) {
 struct McpInfra {
     _iggy_server: TestServer,
     _mcp_server: TestMcpServer,
+    _config_file: Option<NamedTempFile>,
     mcp_client: TestMcpClient,
 }
 
@@ -686,6 +1037,26 @@ impl TestMcpClient {
         self.mcp_client.list_tools(Default::default()).await
     }
 
+    pub async fn list_resources(&self) -> Result<ListResourcesResult, ServiceError> {
+        self.mcp_client.list_resources(Default::default()).await
+    }
+
+    pub async fn list_resource_templates(
+        &self,
+    ) -> Result<ListResourceTemplatesResult, ServiceError> {
+        self.mcp_client
+            .list_resource_templates(Default::default())
+            .await
+    }
+
+    pub async fn read_resource(&self, uri: &str) -> Result<ReadResourceResult, ServiceError> {
+        self.mcp_client
+            .read_resource(ReadResourceRequestParam {
+                uri: uri.to_owned(),
+            })
+            .await
+    }
+
     pub async fn invoke(
         &self,
         method: &str,
@@ -17,6 +17,7 @@
  */
 
 mod background;
+mod trace_context;
 
 use bytes::Bytes;
 use iggy::clients::client::IggyClient;
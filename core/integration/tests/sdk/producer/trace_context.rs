@@ -0,0 +1,151 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::sdk::producer::{
+    PARTITION_ID, STREAM_ID, TOPIC_ID, cleanup, create_message_payload, init_system,
+};
+use futures::StreamExt;
+use iggy::prelude::*;
+use iggy::telemetry::TRACEPARENT_HEADER;
+use iggy::{clients::client::IggyClient, prelude::TcpClient};
+use iggy_common::TcpClientConfig;
+use integration::test_server::{TestServer, login_root};
+use opentelemetry::trace::{SpanKind, TracerProvider};
+use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+use serial_test::parallel;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{Instrument, info_span};
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{Registry, layer::SubscriberExt};
+
+#[tokio::test]
+#[parallel]
+async fn should_propagate_trace_context_from_producer_to_consumer() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+
+    let exporter = InMemorySpanExporter::default();
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber =
+        Registry::default().with(OpenTelemetryLayer::new(tracer_provider.tracer("test")));
+    let _subscriber_guard = tracing::subscriber::set_default(subscriber);
+
+    let tcp_client_config = TcpClientConfig {
+        server_address: test_server.get_raw_tcp_addr().unwrap(),
+        ..TcpClientConfig::default()
+    };
+    let client = ClientWrapper::Tcp(TcpClient::create(Arc::new(tcp_client_config)).unwrap());
+    let client = IggyClient::create(client, None, None);
+
+    client.connect().await.unwrap();
+    login_root(&client).await;
+    init_system(&client).await;
+
+    let messages_count = 10;
+    let messages = (0..messages_count)
+        .map(|offset| {
+            IggyMessage::builder()
+                .payload(create_message_payload(offset))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let producer = client
+        .producer(&STREAM_ID.to_string(), &TOPIC_ID.to_string())
+        .unwrap()
+        .partitioning(Partitioning::partition_id(PARTITION_ID))
+        .trace_context_propagation()
+        .build();
+    producer.init().await.unwrap();
+    producer
+        .send(messages)
+        .instrument(info_span!("test.send"))
+        .await
+        .unwrap();
+
+    let mut consumer = client
+        .consumer(
+            "trace-context-consumer",
+            &STREAM_ID.to_string(),
+            &TOPIC_ID.to_string(),
+            PARTITION_ID,
+        )
+        .unwrap()
+        .polling_strategy(PollingStrategy::offset(0))
+        .auto_commit(AutoCommit::Disabled)
+        .trace_context_propagation()
+        .build();
+    consumer.init().await.unwrap();
+
+    let traceparent_key = HeaderKey::from_str(TRACEPARENT_HEADER).unwrap();
+    let mut traceparents = Vec::new();
+    for _ in 0..messages_count {
+        let received = consumer.next().await.unwrap().unwrap();
+        let traceparent = received
+            .message
+            .get_user_header(&traceparent_key)
+            .unwrap()
+            .expect("Message should carry the trace context");
+        traceparents.push(traceparent.as_str().unwrap().to_owned());
+    }
+    drop(consumer);
+    tracer_provider.force_flush().unwrap();
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let parent_span = spans.iter().find(|span| span.name == "test.send").unwrap();
+    let producer_span = spans
+        .iter()
+        .find(|span| span.name == "iggy.producer.send")
+        .expect("Producer span should be exported");
+    assert_eq!(producer_span.span_kind, SpanKind::Producer);
+    assert_eq!(
+        producer_span.parent_span_id,
+        parent_span.span_context.span_id()
+    );
+
+    let producer_span_context = &producer_span.span_context;
+    let expected_traceparent = format!(
+        "00-{}-{}-01",
+        producer_span_context.trace_id(),
+        producer_span_context.span_id()
+    );
+    assert!(
+        traceparents
+            .iter()
+            .all(|traceparent| traceparent == &expected_traceparent)
+    );
+
+    let consumer_spans = spans
+        .iter()
+        .filter(|span| span.name == "iggy.consumer.receive")
+        .collect::<Vec<_>>();
+    assert_eq!(consumer_spans.len(), messages_count as usize);
+    for consumer_span in consumer_spans {
+        assert_eq!(consumer_span.span_kind, SpanKind::Consumer);
+        assert_eq!(consumer_span.links.len(), 1);
+        let link = &consumer_span.links[0].span_context;
+        assert_eq!(link.trace_id(), producer_span_context.trace_id());
+        assert_eq!(link.span_id(), producer_span_context.span_id());
+    }
+
+    cleanup(&client).await;
+}
@@ -0,0 +1,151 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::http_client::HttpClientFactory;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{ClientFactory, IpAddrKind, TestServer, login_root};
+use reqwest::StatusCode;
+use serial_test::parallel;
+use std::collections::HashMap;
+
+#[tokio::test]
+#[parallel]
+async fn should_report_server_as_live_and_ready() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+
+    let http_client = reqwest::Client::new();
+    let response = http_client
+        .get(format!("http://{http_api_addr}/health/live"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let health = response.json::<Health>().await.unwrap();
+    assert_eq!(health.status, HealthStatus::Up);
+
+    let response = http_client
+        .get(format!("http://{http_api_addr}/health/ready"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let health = response.json::<Health>().await.unwrap();
+    assert_eq!(health.status, HealthStatus::Up);
+    assert!(health.checks.is_empty());
+
+    let response = http_client
+        .get(format!("http://{http_api_addr}/health"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let tcp_client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+    let client = IggyClient::create(tcp_client_factory.create_client().await, None, None);
+    let error = client.get_health().await.unwrap_err();
+    assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+    login_root(&client).await;
+    let health = client.get_health().await.unwrap();
+    assert_ready(&health);
+
+    let http_client_factory = HttpClientFactory {
+        server_addr: http_api_addr,
+    };
+    let client = IggyClient::create(http_client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    let health = client.get_health().await.unwrap();
+    assert_ready(&health);
+}
+
+#[tokio::test]
+#[parallel]
+async fn should_report_server_as_not_ready_when_disk_space_is_below_threshold() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(
+        "IGGY_SYSTEM_HEALTH_MIN_FREE_DISK_SPACE".to_string(),
+        "10 EB".to_string(),
+    );
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+
+    let response = reqwest::get(format!("http://{http_api_addr}/health/live"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = reqwest::get(format!("http://{http_api_addr}/health/ready"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let health = response.json::<Health>().await.unwrap();
+    assert_eq!(health.status, HealthStatus::Down);
+    assert!(health.checks.is_empty());
+
+    let tcp_client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+    let client = IggyClient::create(tcp_client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    let health = client.get_health().await.unwrap();
+    assert_disk_down(&health);
+
+    let http_client_factory = HttpClientFactory {
+        server_addr: http_api_addr,
+    };
+    let client = IggyClient::create(http_client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    let health = client.get_health().await.unwrap();
+    assert_disk_down(&health);
+}
+
+fn assert_ready(health: &Health) {
+    assert!(health.is_ready());
+    assert_eq!(get_check(health, "state").status, HealthStatus::Up);
+    assert_eq!(get_check(health, "partitions").status, HealthStatus::Up);
+    assert_eq!(get_check(health, "disk").status, HealthStatus::Up);
+    assert_eq!(get_check(health, "persister").status, HealthStatus::Up);
+    assert_eq!(get_check(health, "archiver").status, HealthStatus::Disabled);
+    let listeners = get_check(health, "listeners");
+    assert_eq!(listeners.status, HealthStatus::Up);
+    for listener in ["http", "quic", "tcp"] {
+        assert!(listeners.details.contains(listener));
+    }
+}
+
+fn assert_disk_down(health: &Health) {
+    assert!(!health.is_ready());
+    assert_eq!(health.status, HealthStatus::Down);
+    assert_eq!(get_check(health, "state").status, HealthStatus::Up);
+    assert_eq!(get_check(health, "disk").status, HealthStatus::Down);
+}
+
+fn get_check<'a>(health: &'a Health, name: &str) -> &'a HealthCheck {
+    health
+        .checks
+        .iter()
+        .find(|check| check.name == name)
+        .unwrap_or_else(|| panic!("Health check {name} should be reported"))
+}
@@ -0,0 +1,198 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::post;
+use iggy::prelude::*;
+use integration::tcp_client::TcpClientFactory;
+use integration::test_server::{ClientFactory, IpAddrKind, TestServer, login_root};
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::common::v1::any_value;
+use opentelemetry_proto::tonic::metrics::v1::{metric, number_data_point};
+use prost::Message;
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+
+const METRICS_TOKEN: &str = "secret";
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[tokio::test]
+#[parallel]
+async fn should_serve_metrics_on_standalone_listener_with_http_api_disabled() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert("IGGY_HTTP_ENABLED".to_string(), "false".to_string());
+    extra_envs.insert("IGGY_METRICS_ENABLED".to_string(), "true".to_string());
+    extra_envs.insert(
+        "IGGY_METRICS_ADDRESS".to_string(),
+        "127.0.0.1:0".to_string(),
+    );
+    extra_envs.insert("IGGY_METRICS_TOKEN".to_string(), METRICS_TOKEN.to_string());
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let metrics_url = format!(
+        "http://{}/metrics",
+        test_server
+            .get_metrics_addr()
+            .expect("Metrics server should be started")
+    );
+
+    let http_client = reqwest::Client::new();
+    let response = http_client.get(&metrics_url).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = http_client
+        .get(&metrics_url)
+        .bearer_auth("invalid")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = http_client
+        .get(&metrics_url)
+        .bearer_auth(METRICS_TOKEN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let metrics = response.text().await.unwrap();
+    assert!(metrics.contains("# TYPE streams gauge"));
+    assert!(metrics.contains("# TYPE request_duration_seconds histogram"));
+}
+
+#[tokio::test]
+#[parallel]
+async fn should_export_metrics_to_otlp_collector() {
+    let (sender, mut receiver) = unbounded_channel();
+    let collector = Router::new()
+        .route("/v1/metrics", post(collect_metrics))
+        .fallback(|| async { StatusCode::OK })
+        .with_state(sender);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let collector_addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, collector).await.unwrap();
+    });
+
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert("IGGY_TELEMETRY_ENABLED".to_string(), "true".to_string());
+    extra_envs.insert(
+        "IGGY_TELEMETRY_SERVICE_NAME".to_string(),
+        "iggy-test".to_string(),
+    );
+    for signal in ["logs", "traces", "metrics"] {
+        let section = signal.to_uppercase();
+        extra_envs.insert(
+            format!("IGGY_TELEMETRY_{section}_TRANSPORT"),
+            "http".to_string(),
+        );
+        extra_envs.insert(
+            format!("IGGY_TELEMETRY_{section}_ENDPOINT"),
+            format!("http://{collector_addr}/v1/{signal}"),
+        );
+    }
+    extra_envs.insert(
+        "IGGY_TELEMETRY_METRICS_INTERVAL".to_string(),
+        "100ms".to_string(),
+    );
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+    let client = IggyClient::create(client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    client.create_stream("test-stream", Some(1)).await.unwrap();
+
+    let streams = tokio::time::timeout(EXPORT_TIMEOUT, async {
+        loop {
+            let request = receiver
+                .recv()
+                .await
+                .expect("Collector should receive metrics");
+            if let Some(streams) = get_streams_count(&request) {
+                if streams > 0 {
+                    return (request, streams);
+                }
+            }
+        }
+    })
+    .await
+    .expect("Metrics should be exported to the collector");
+
+    let (request, streams) = streams;
+    assert_eq!(streams, 1);
+    let resource = request.resource_metrics[0].resource.as_ref().unwrap();
+    let service_name = resource
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "service.name")
+        .and_then(|attribute| attribute.value.as_ref())
+        .and_then(|value| value.value.as_ref());
+    assert_eq!(
+        service_name,
+        Some(&any_value::Value::StringValue("iggy-test".to_string()))
+    );
+
+    let metrics = &request.resource_metrics[0].scope_metrics[0].metrics;
+    let requests = metrics
+        .iter()
+        .find(|metric| metric.name == "request_duration_seconds")
+        .expect("Request latency should be exported");
+    let Some(metric::Data::Histogram(histogram)) = &requests.data else {
+        panic!("Request latency should be exported as histogram");
+    };
+    assert!(!histogram.data_points.is_empty());
+}
+
+async fn collect_metrics(
+    State(sender): State<UnboundedSender<ExportMetricsServiceRequest>>,
+    body: Bytes,
+) -> StatusCode {
+    match ExportMetricsServiceRequest::decode(body) {
+        Ok(request) => {
+            let _ = sender.send(request);
+            StatusCode::OK
+        }
+        Err(_) => StatusCode::BAD_REQUEST,
+    }
+}
+
+fn get_streams_count(request: &ExportMetricsServiceRequest) -> Option<i64> {
+    request
+        .resource_metrics
+        .iter()
+        .flat_map(|resource_metrics| &resource_metrics.scope_metrics)
+        .flat_map(|scope_metrics| &scope_metrics.metrics)
+        .find(|metric| metric.name == "streams")
+        .and_then(|metric| match &metric.data {
+            Some(metric::Data::Gauge(gauge)) => gauge.data_points.first(),
+            _ => None,
+        })
+        .and_then(|data_point| match data_point.value {
+            Some(number_data_point::Value::AsInt(value)) => Some(value),
+            _ => None,
+        })
+}
@@ -18,6 +18,8 @@
 
 mod cg;
 mod general;
+mod health;
+mod metrics;
 mod scenarios;
 mod specific;
 
@@ -20,8 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, Health, IggyDuration, IggyError, Snapshot, SnapshotCompression,
+    Stats, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -71,6 +71,15 @@ impl SystemClient for ClientWrapper {
         }
     }
 
+    async fn get_health(&self) -> Result<Health, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_health().await,
+            ClientWrapper::Http(client) => client.get_health().await,
+            ClientWrapper::Tcp(client) => client.get_health().await,
+            ClientWrapper::Quic(client) => client.get_health().await,
+        }
+    }
+
     async fn heartbeat_interval(&self) -> IggyDuration {
         match self {
             ClientWrapper::Iggy(client) => client.heartbeat_interval().await,
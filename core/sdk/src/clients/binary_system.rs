@@ -21,8 +21,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, Health, IggyDuration, IggyError, Snapshot, SnapshotCompression,
+    Stats, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -47,6 +47,10 @@ impl SystemClient for IggyClient {
         self.client.read().await.ping().await
     }
 
+    async fn get_health(&self) -> Result<Health, IggyError> {
+        self.client.read().await.get_health().await
+    }
+
     async fn heartbeat_interval(&self) -> IggyDuration {
         self.client.read().await.heartbeat_interval().await
     }
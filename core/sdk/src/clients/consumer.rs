@@ -17,6 +17,8 @@
  */
 
 use crate::client_wrappers::client_wrapper::ClientWrapper;
+#[cfg(feature = "opentelemetry")]
+use crate::telemetry;
 use bytes::Bytes;
 use dashmap::DashMap;
 use futures::Stream;
@@ -38,6 +40,8 @@ use std::task::{Context, Poll};
 use std::time::Duration;
 use tokio::time;
 use tokio::time::sleep;
+#[cfg(feature = "opentelemetry")]
+use tracing::Span;
 use tracing::{error, info, trace, warn};
 
 const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::SeqCst;
@@ -127,6 +131,8 @@ pub struct IggyConsumer {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    #[cfg(feature = "opentelemetry")]
+    trace_context_propagation: bool,
 }
 
 impl IggyConsumer {
@@ -149,6 +155,7 @@ impl IggyConsumer {
         init_retries: Option<u32>,
         init_retry_interval: IggyDuration,
         allow_replay: bool,
+        #[cfg(feature = "opentelemetry")] trace_context_propagation: bool,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
         Self {
@@ -203,6 +210,8 @@ impl IggyConsumer {
             init_retries,
             init_retry_interval,
             allow_replay,
+            #[cfg(feature = "opentelemetry")]
+            trace_context_propagation,
         }
     }
 
@@ -493,6 +502,24 @@ impl IggyConsumer {
         }
     }
 
+    fn received_message(
+        &self,
+        message: IggyMessage,
+        current_offset: u64,
+        partition_id: u32,
+    ) -> ReceivedMessage {
+        #[cfg(feature = "opentelemetry")]
+        if self.trace_context_propagation {
+            let span =
+                telemetry::consumer_span(&message, &self.stream_id, &self.topic_id, partition_id);
+            let mut received_message = ReceivedMessage::new(message, current_offset, partition_id);
+            received_message.span = span;
+            return received_message;
+        }
+
+        ReceivedMessage::new(message, current_offset, partition_id)
+    }
+
     async fn init_consumer_group(&self) -> Result<(), IggyError> {
         if !self.is_consumer_group {
             return Ok(());
@@ -863,6 +890,10 @@ pub struct ReceivedMessage {
     pub message: IggyMessage,
     pub current_offset: u64,
     pub partition_id: u32,
+    /// Span of receiving the message, linked with the span of the producer that sent it.
+    /// Disabled unless the trace context propagation is enabled for the consumer; ends when dropped.
+    #[cfg(feature = "opentelemetry")]
+    pub span: Span,
 }
 
 impl ReceivedMessage {
@@ -871,6 +902,8 @@ impl ReceivedMessage {
             message,
             current_offset,
             partition_id,
+            #[cfg(feature = "opentelemetry")]
+            span: Span::none(),
         }
     }
 }
@@ -916,7 +949,7 @@ impl Stream for IggyConsumer {
                 current_offset = 0;
             }
 
-            return Poll::Ready(Some(Ok(ReceivedMessage::new(
+            return Poll::Ready(Some(Ok(self.received_message(
                 message,
                 current_offset,
                 partition_id,
@@ -995,7 +1028,7 @@ impl Stream for IggyConsumer {
                         }
 
                         self.poll_future = None;
-                        return Poll::Ready(Some(Ok(ReceivedMessage::new(
+                        return Poll::Ready(Some(Ok(self.received_message(
                             message,
                             polled_messages.current_offset,
                             polled_messages.partition_id,
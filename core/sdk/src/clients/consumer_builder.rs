@@ -41,6 +41,8 @@ pub struct IggyConsumerBuilder {
     init_retries: Option<u32>,
     init_retry_interval: IggyDuration,
     allow_replay: bool,
+    #[cfg(feature = "opentelemetry")]
+    trace_context_propagation: bool,
 }
 
 impl IggyConsumerBuilder {
@@ -76,6 +78,8 @@ impl IggyConsumerBuilder {
             init_retries: None,
             init_retry_interval: IggyDuration::ONE_SECOND,
             allow_replay: false,
+            #[cfg(feature = "opentelemetry")]
+            trace_context_propagation: false,
         }
     }
 
@@ -216,6 +220,25 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Starts a span for every consumed message, linked with the span of the producer
+    /// whose W3C trace context is found in the message's user headers.
+    #[cfg(feature = "opentelemetry")]
+    pub fn trace_context_propagation(self) -> Self {
+        Self {
+            trace_context_propagation: true,
+            ..self
+        }
+    }
+
+    /// Disables the trace context propagation. This is the default.
+    #[cfg(feature = "opentelemetry")]
+    pub fn without_trace_context_propagation(self) -> Self {
+        Self {
+            trace_context_propagation: false,
+            ..self
+        }
+    }
+
     /// Builds the consumer.
     ///
     /// Note: After building the consumer, `init()` must be invoked before producing messages.
@@ -238,6 +261,8 @@ impl IggyConsumerBuilder {
             self.init_retries,
             self.init_retry_interval,
             self.allow_replay,
+            #[cfg(feature = "opentelemetry")]
+            self.trace_context_propagation,
         )
     }
 }
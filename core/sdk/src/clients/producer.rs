@@ -21,6 +21,8 @@ use crate::clients::MAX_BATCH_LENGTH;
 use crate::clients::producer_builder::SendMode;
 use crate::clients::producer_config::DirectConfig;
 use crate::clients::producer_dispatcher::ProducerDispatcher;
+#[cfg(feature = "opentelemetry")]
+use crate::telemetry;
 use bytes::Bytes;
 use futures_util::StreamExt;
 use iggy_binary_protocol::{Client, MessageClient, StreamClient, TopicClient};
@@ -34,7 +36,11 @@ use std::sync::atomic::Ordering;
 use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::time::Duration;
 use tokio::time::{Interval, sleep};
+#[cfg(feature = "opentelemetry")]
+use tracing::Instrument;
 use tracing::{error, info, trace, warn};
+#[cfg(feature = "opentelemetry")]
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 #[cfg(test)]
 use mockall::automock;
@@ -427,6 +433,8 @@ unsafe impl Sync for IggyProducer {}
 pub struct IggyProducer {
     core: Arc<ProducerCore>,
     dispatcher: Option<ProducerDispatcher>,
+    #[cfg(feature = "opentelemetry")]
+    trace_context_propagation: bool,
 }
 
 impl IggyProducer {
@@ -449,6 +457,7 @@ impl IggyProducer {
         send_retries_count: Option<u32>,
         send_retries_interval: Option<IggyDuration>,
         mode: SendMode,
+        #[cfg(feature = "opentelemetry")] trace_context_propagation: bool,
     ) -> Self {
         let core = Arc::new(ProducerCore {
             initialized: AtomicBool::new(false),
@@ -481,7 +490,12 @@ impl IggyProducer {
             _ => None,
         };
 
-        Self { core, dispatcher }
+        Self {
+            core,
+            dispatcher,
+            #[cfg(feature = "opentelemetry")]
+            trace_context_propagation,
+        }
     }
 
     pub fn stream(&self) -> &Identifier {
@@ -500,22 +514,13 @@ impl IggyProducer {
     }
 
     pub async fn send(&self, messages: Vec<IggyMessage>) -> Result<(), IggyError> {
-        if messages.is_empty() {
-            trace!("No messages to send.");
-            return Ok(());
-        }
-
-        let stream_id = self.core.stream_id.clone();
-        let topic_id = self.core.topic_id.clone();
-
-        match &self.dispatcher {
-            Some(disp) => disp.dispatch(messages, stream_id, topic_id, None).await,
-            None => {
-                self.core
-                    .send_internal(&stream_id, &topic_id, messages, None)
-                    .await
-            }
-        }
+        self.send_to(
+            self.core.stream_id.clone(),
+            self.core.topic_id.clone(),
+            messages,
+            None,
+        )
+        .await
     }
 
     pub async fn send_one(&self, message: IggyMessage) -> Result<(), IggyError> {
@@ -526,40 +531,53 @@ impl IggyProducer {
         &self,
         messages: Vec<IggyMessage>,
         partitioning: Option<Arc<Partitioning>>,
+    ) -> Result<(), IggyError> {
+        self.send_to(
+            self.core.stream_id.clone(),
+            self.core.topic_id.clone(),
+            messages,
+            partitioning,
+        )
+        .await
+    }
+
+    pub async fn send_to(
+        &self,
+        stream: Arc<Identifier>,
+        topic: Arc<Identifier>,
+        messages: Vec<IggyMessage>,
+        partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
         if messages.is_empty() {
             trace!("No messages to send.");
             return Ok(());
         }
 
-        let stream_id = self.core.stream_id.clone();
-        let topic_id = self.core.topic_id.clone();
-
-        match &self.dispatcher {
-            Some(disp) => {
-                disp.dispatch(messages, stream_id, topic_id, partitioning)
-                    .await
-            }
-            None => {
-                self.core
-                    .send_internal(&stream_id, &topic_id, messages, partitioning)
-                    .await
+        #[cfg(feature = "opentelemetry")]
+        if self.trace_context_propagation {
+            // The context is injected before dispatching, as the background sending happens outside the caller's span.
+            let span = telemetry::producer_span(&stream, &topic, messages.len());
+            let context = span.context();
+            let mut messages = messages;
+            for message in &mut messages {
+                telemetry::inject_trace_context(&context, message)?;
             }
+            return self
+                .dispatch(stream, topic, messages, partitioning)
+                .instrument(span)
+                .await;
         }
+
+        self.dispatch(stream, topic, messages, partitioning).await
     }
 
-    pub async fn send_to(
+    async fn dispatch(
         &self,
         stream: Arc<Identifier>,
         topic: Arc<Identifier>,
         messages: Vec<IggyMessage>,
         partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
-        if messages.is_empty() {
-            trace!("No messages to send.");
-            return Ok(());
-        }
-
         match &self.dispatcher {
             Some(disp) => disp.dispatch(messages, stream, topic, partitioning).await,
             None => {
@@ -53,6 +53,8 @@ pub struct IggyProducerBuilder {
     topic_max_size: MaxTopicSize,
     partitioning: Option<Partitioning>,
     mode: SendMode,
+    #[cfg(feature = "opentelemetry")]
+    trace_context_propagation: bool,
 }
 
 impl IggyProducerBuilder {
@@ -84,6 +86,8 @@ impl IggyProducerBuilder {
             send_retries_count: Some(3),
             send_retries_interval: Some(IggyDuration::ONE_SECOND),
             mode: SendMode::default(),
+            #[cfg(feature = "opentelemetry")]
+            trace_context_propagation: false,
         }
     }
 
@@ -213,6 +217,25 @@ impl IggyProducerBuilder {
         self
     }
 
+    /// Injects the W3C trace context of the span sending the messages into their user headers,
+    /// so the consumers can link their spans with it.
+    #[cfg(feature = "opentelemetry")]
+    pub fn trace_context_propagation(self) -> Self {
+        Self {
+            trace_context_propagation: true,
+            ..self
+        }
+    }
+
+    /// Disables the trace context propagation. This is the default.
+    #[cfg(feature = "opentelemetry")]
+    pub fn without_trace_context_propagation(self) -> Self {
+        Self {
+            trace_context_propagation: false,
+            ..self
+        }
+    }
+
     pub fn build(self) -> IggyProducer {
         IggyProducer::new(
             self.client,
@@ -232,6 +255,8 @@ impl IggyProducerBuilder {
             self.send_retries_count,
             self.send_retries_interval,
             self.mode,
+            #[cfg(feature = "opentelemetry")]
+            self.trace_context_propagation,
         )
     }
 }
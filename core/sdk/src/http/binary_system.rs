@@ -21,6 +21,7 @@ use crate::http::http_transport::HttpTransport;
 use crate::prelude::{IggyDuration, IggyError};
 use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
+use iggy_common::Health;
 use iggy_common::Snapshot;
 use iggy_common::Stats;
 use iggy_common::get_snapshot::GetSnapshot;
@@ -28,6 +29,7 @@ use iggy_common::{ClientInfo, ClientInfoDetails};
 use iggy_common::{SnapshotCompression, SystemSnapshotType};
 
 const PING: &str = "/ping";
+const HEALTH: &str = "/health";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
@@ -78,6 +80,15 @@ impl SystemClient for HttpClient {
         Ok(())
     }
 
+    async fn get_health(&self) -> Result<Health, IggyError> {
+        let response = self.get(HEALTH).await?;
+        let health = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(health)
+    }
+
     async fn heartbeat_interval(&self) -> IggyDuration {
         self.heartbeat_interval
     }
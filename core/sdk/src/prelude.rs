@@ -53,15 +53,16 @@ pub use iggy_common::{
     Aes256GcmEncryptor, Args, ArgsOptional, AutoLogin, BytesSerializable, CacheMetrics,
     CacheMetricsKey, ClientError, ClientInfoDetails, CompressionAlgorithm, Confirmation, Consumer,
     ConsumerGroupDetails, ConsumerKind, EncryptorKind, FlushUnsavedBuffer, GlobalPermissions,
-    HeaderKey, HeaderValue, HttpClientConfig, HttpClientConfigBuilder, IdKind, Identifier,
-    IdentityInfo, IggyByteSize, IggyDuration, IggyError, IggyExpiry, IggyIndexView, IggyMessage,
-    IggyMessageHeader, IggyMessageHeaderView, IggyMessageView, IggyMessageViewIterator,
-    IggyTimestamp, MaxTopicSize, Partition, Partitioner, Partitioning, Permissions,
-    PersonalAccessTokenExpiry, PollMessages, PolledMessages, PollingKind, PollingStrategy,
-    QuicClientConfig, QuicClientConfigBuilder, QuicClientReconnectionConfig, SendMessages,
-    Sizeable, SnapshotCompression, Stats, Stream, StreamDetails, StreamPermissions,
-    SystemSnapshotType, TcpClientConfig, TcpClientConfigBuilder, TcpClientReconnectionConfig,
-    Topic, TopicDetails, TopicPermissions, UserId, UserStatus, Validatable, defaults, locking,
+    HeaderKey, HeaderKind, HeaderValue, Health, HealthCheck, HealthStatus, HttpClientConfig,
+    HttpClientConfigBuilder, IdKind, Identifier, IdentityInfo, IggyByteSize, IggyDuration,
+    IggyError, IggyExpiry, IggyIndexView, IggyMessage, IggyMessageHeader, IggyMessageHeaderView,
+    IggyMessageView, IggyMessageViewIterator, IggyTimestamp, MaxTopicSize, Partition, Partitioner,
+    Partitioning, Permissions, PersonalAccessTokenExpiry, PollMessages, PolledMessages,
+    PollingKind, PollingStrategy, QuicClientConfig, QuicClientConfigBuilder,
+    QuicClientReconnectionConfig, SendMessages, Sizeable, SnapshotCompression, Stats, Stream,
+    StreamDetails, StreamPermissions, SystemSnapshotType, TcpClientConfig, TcpClientConfigBuilder,
+    TcpClientReconnectionConfig, Topic, TopicDetails, TopicPermissions, UserId, UserStatus,
+    Validatable, defaults, locking,
 };
 pub use iggy_common::{
     IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADER_SIZE,
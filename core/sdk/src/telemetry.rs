@@ -0,0 +1,247 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! OpenTelemetry trace context propagation through the message headers.
+//!
+//! The producer injects the W3C `traceparent` and `tracestate` into the user headers of the sent messages
+//! and the consumer extracts them to link its spans with the ones of the producer.
+//! The spans are created with `tracing`, so the context is available only when the application
+//! registers the `tracing-opentelemetry` layer; otherwise nothing is injected.
+
+use bytes::Bytes;
+use iggy_common::{
+    BytesSerializable, HeaderKey, HeaderValue, Identifier, IggyError, IggyMessage,
+    MAX_USER_HEADERS_SIZE,
+};
+use opentelemetry::Context;
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::{Span, info_span, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// User header reserved for the W3C trace parent.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+/// User header reserved for the W3C trace state.
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+struct HeadersInjector<'a>(&'a mut HashMap<HeaderKey, HeaderValue>);
+
+impl Injector for HeadersInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        match (HeaderKey::new(key), HeaderValue::from_str(&value)) {
+            (Ok(key), Ok(value)) => {
+                self.0.insert(key, value);
+            }
+            _ => warn!("Skipping the trace context header: {key} with invalid value: {value}"),
+        }
+    }
+}
+
+struct HeadersExtractor<'a>(&'a HashMap<HeaderKey, HeaderValue>);
+
+impl Extractor for HeadersExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(header_key, _)| header_key.as_str() == key)
+            .and_then(|(_, value)| value.as_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderKey::as_str).collect()
+    }
+}
+
+/// Injects the trace context into the user headers of the message.
+///
+/// Nothing is injected if the context has no valid span. Messages which already carry a trace context,
+/// e.g. forwarded from another topic, keep it, as well as the messages with headers that can't be parsed.
+pub fn inject_trace_context(context: &Context, message: &mut IggyMessage) -> Result<(), IggyError> {
+    if !context.span().span_context().is_valid() {
+        return Ok(());
+    }
+
+    let mut headers = match message.user_headers_map()? {
+        Some(headers) => headers,
+        None if message.user_headers.is_none() => HashMap::new(),
+        None => return Ok(()),
+    };
+    if headers.keys().any(|key| key.as_str() == TRACEPARENT_HEADER) {
+        return Ok(());
+    }
+
+    TraceContextPropagator::new().inject_context(context, &mut HeadersInjector(&mut headers));
+    let user_headers: Bytes = headers.to_bytes();
+    if user_headers.len() > MAX_USER_HEADERS_SIZE as usize {
+        return Err(IggyError::TooBigUserHeaders);
+    }
+
+    message.header.user_headers_length = user_headers.len() as u32;
+    message.user_headers = Some(user_headers);
+    Ok(())
+}
+
+/// Extracts the trace context from the user headers of the message, empty if there's none.
+pub fn extract_trace_context(message: &IggyMessage) -> Context {
+    match message.user_headers_map() {
+        Ok(Some(headers)) => TraceContextPropagator::new().extract(&HeadersExtractor(&headers)),
+        _ => Context::new(),
+    }
+}
+
+/// Creates the span of sending the messages, whose context is injected into them.
+pub fn producer_span(stream: &Identifier, topic: &Identifier, messages_count: usize) -> Span {
+    info_span!(
+        "iggy.producer.send",
+        otel.kind = "producer",
+        messaging.system = "iggy",
+        messaging.operation.type = "send",
+        messaging.destination.name = %topic,
+        messaging.batch.message_count = messages_count,
+        iggy.stream = %stream,
+    )
+}
+
+/// Creates the span of receiving the message, linked with the span of the producer that sent it.
+pub fn consumer_span(
+    message: &IggyMessage,
+    stream: &Identifier,
+    topic: &Identifier,
+    partition_id: u32,
+) -> Span {
+    let span = info_span!(
+        "iggy.consumer.receive",
+        otel.kind = "consumer",
+        messaging.system = "iggy",
+        messaging.operation.type = "receive",
+        messaging.destination.name = %topic,
+        messaging.destination.partition.id = partition_id,
+        messaging.message.id = %message.header.id,
+        iggy.stream = %stream,
+        iggy.offset = message.header.offset,
+    );
+    let context = extract_trace_context(message);
+    let producer_span = context.span();
+    let producer_span_context = producer_span.span_context();
+    if producer_span_context.is_valid() {
+        span.add_link(producer_span_context.clone());
+    }
+    span
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{SpanContext, SpanId, TraceFlags, TraceId, TraceState};
+
+    fn remote_context() -> Context {
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::from_str("vendor=value").unwrap(),
+        );
+        Context::new().with_remote_span_context(span_context)
+    }
+
+    fn message() -> IggyMessage {
+        let headers = HashMap::from([(
+            HeaderKey::new("key").unwrap(),
+            HeaderValue::from_str("value").unwrap(),
+        )]);
+        IggyMessage::builder()
+            .payload(Bytes::from("payload"))
+            .user_headers(headers)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_inject_and_extract_trace_context_keeping_user_headers() {
+        let mut message = message();
+
+        inject_trace_context(&remote_context(), &mut message).unwrap();
+
+        let headers = message.user_headers_map().unwrap().unwrap();
+        assert_eq!(headers.len(), 3);
+        assert_eq!(
+            headers[&HeaderKey::new(TRACEPARENT_HEADER).unwrap()]
+                .as_str()
+                .unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+        assert_eq!(
+            message.header.user_headers_length as usize,
+            message.user_headers.as_ref().unwrap().len()
+        );
+
+        let context = extract_trace_context(&message);
+        let span = context.span();
+        let span_context = span.span_context();
+        assert!(span_context.is_valid());
+        assert_eq!(
+            span_context.trace_id(),
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+        );
+        assert_eq!(span_context.trace_state().get("vendor"), Some("value"));
+    }
+
+    #[test]
+    fn should_not_inject_empty_context() {
+        let mut message = message();
+        let user_headers = message.user_headers.clone();
+
+        inject_trace_context(&Context::new(), &mut message).unwrap();
+
+        assert_eq!(message.user_headers, user_headers);
+    }
+
+    #[test]
+    fn should_keep_trace_context_of_forwarded_message() {
+        let mut message = message();
+        inject_trace_context(&remote_context(), &mut message).unwrap();
+        let user_headers = message.user_headers.clone();
+        let other_context = Context::new().with_remote_span_context(SpanContext::new(
+            TraceId::from_hex("0af7651916cd43dd8448eb211c80319c").unwrap(),
+            SpanId::from_hex("b7ad6b7169203331").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+
+        inject_trace_context(&other_context, &mut message).unwrap();
+
+        assert_eq!(message.user_headers, user_headers);
+    }
+
+    #[test]
+    fn should_extract_empty_context_from_message_without_headers() {
+        let message = IggyMessage::builder()
+            .payload(Bytes::from("payload"))
+            .build()
+            .unwrap();
+
+        let context = extract_trace_context(&message);
+
+        assert!(!context.span().span_context().is_valid());
+    }
+}
@@ -96,4 +96,16 @@ impl Archiver for DiskArchiver {
 
         Ok(())
     }
+
+    async fn check_reachability(&self) -> Result<(), ArchiverError> {
+        match fs::metadata(&self.config.path).await {
+            Ok(metadata) if metadata.is_dir() => Ok(()),
+            Ok(_) => Err(ArchiverError::ArchiverUnreachable {
+                reason: format!("{} is not a directory", self.config.path),
+            }),
+            Err(error) => Err(ArchiverError::ArchiverUnreachable {
+                reason: format!("{}: {error}", self.config.path),
+            }),
+        }
+    }
 }
@@ -65,6 +65,7 @@ pub trait Archiver: Send {
         files: &[&str],
         base_directory: Option<String>,
     ) -> impl Future<Output = Result<(), ArchiverError>> + Send;
+    fn check_reachability(&self) -> impl Future<Output = Result<(), ArchiverError>> + Send;
 }
 
 #[derive(Debug)]
@@ -133,4 +134,16 @@ impl ArchiverKind {
             Self::S3(d) => d.archive(files, base_directory).await,
         }
     }
+
+    /// Checks if the archive destination is reachable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive destination cannot be reached.
+    pub async fn check_reachability(&self) -> Result<(), ArchiverError> {
+        match self {
+            Self::Disk(d) => d.check_reachability().await,
+            Self::S3(d) => d.check_reachability().await,
+        }
+    }
 }
@@ -191,4 +191,14 @@ impl Archiver for S3Archiver {
         }
         Ok(())
     }
+
+    async fn check_reachability(&self) -> Result<(), ArchiverError> {
+        self.bucket
+            .list_page("/".to_string(), None, None, None, Some(1))
+            .await
+            .map_err(|error| ArchiverError::ArchiverUnreachable {
+                reason: error.to_string(),
+            })?;
+        Ok(())
+    }
 }
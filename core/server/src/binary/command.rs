@@ -42,6 +42,7 @@ use iggy_common::get_clients::GetClients;
 use iggy_common::get_consumer_group::GetConsumerGroup;
 use iggy_common::get_consumer_groups::GetConsumerGroups;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
+use iggy_common::get_health::GetHealth;
 use iggy_common::get_me::GetMe;
 use iggy_common::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy_common::get_snapshot::GetSnapshot;
@@ -72,6 +73,7 @@ use tracing::error;
 define_server_command_enum! {
     Ping(Ping), PING_CODE, PING, false;
     GetStats(GetStats), GET_STATS_CODE, GET_STATS, false;
+    GetHealth(GetHealth), GET_HEALTH_CODE, GET_HEALTH, false;
     GetMe(GetMe), GET_ME_CODE, GET_ME, false;
     GetClient(GetClient), GET_CLIENT_CODE, GET_CLIENT, true;
     GetClients(GetClients), GET_CLIENTS_CODE, GET_CLIENTS, false;
@@ -170,6 +172,11 @@ mod tests {
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetHealth(GetHealth::default()),
+            GET_HEALTH_CODE,
+            &GetHealth::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -27,7 +27,7 @@ use anyhow::Result;
 use error_set::ErrContext;
 use iggy_common::{IggyError, PollMessages};
 use std::io::IoSlice;
-use tracing::{debug, trace};
+use tracing::{debug, instrument, trace};
 
 #[derive(Debug)]
 pub struct IggyPollMetadata {
@@ -49,6 +49,14 @@ impl ServerCommandHandler for PollMessages {
         iggy_common::POLL_MESSAGES_CODE
     }
 
+    #[instrument(skip_all, name = "trace_poll_messages", fields(
+        iggy_user_id = session.get_user_id(),
+        iggy_client_id = session.client_id,
+        iggy_stream_id = self.stream_id.as_string(),
+        iggy_topic_id = self.topic_id.as_string(),
+        iggy_partition_id = self.partition_id,
+        iggy_consumer = %self.consumer
+    ))]
     async fn handle(
         self,
         sender: &mut SenderKind,
@@ -27,7 +27,7 @@ use iggy_common::INDEX_SIZE;
 use iggy_common::Identifier;
 use iggy_common::Sizeable;
 use iggy_common::{IggyError, Partitioning, SendMessages, Validatable};
-use tracing::instrument;
+use tracing::{Span, field, instrument};
 
 impl ServerCommandHandler for SendMessages {
     fn code(&self) -> u32 {
@@ -37,9 +37,9 @@ impl ServerCommandHandler for SendMessages {
     #[instrument(skip_all, name = "trace_send_messages", fields(
         iggy_user_id = session.get_user_id(),
         iggy_client_id = session.client_id,
-        iggy_stream_id = self.stream_id.as_string(),
-        iggy_topic_id = self.topic_id.as_string(),
-        partitioning = %self.partitioning
+        iggy_stream_id = field::Empty,
+        iggy_topic_id = field::Empty,
+        partitioning = field::Empty
     ))]
     async fn handle(
         mut self,
@@ -73,6 +73,12 @@ impl ServerCommandHandler for SendMessages {
         element_size += partitioning.get_size_bytes().as_bytes_usize();
         self.partitioning = partitioning;
 
+        // The command is read from the sender in the handler, so the fields are known only now.
+        let span = Span::current();
+        span.record("iggy_stream_id", self.stream_id.as_string());
+        span.record("iggy_topic_id", self.topic_id.as_string());
+        span.record("partitioning", field::display(&self.partitioning));
+
         let messages_count = u32::from_le_bytes(
             metadata_buffer[element_size..element_size + 4]
                 .try_into()
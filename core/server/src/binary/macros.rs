@@ -72,6 +72,15 @@ macro_rules! define_server_command_enum {
                 }
             }
 
+            /// Returns the name of the command, without the payload.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(
+                        ServerCommand::$variant(_) => $display_str,
+                    )*
+                }
+            }
+
             /// Converts the command into raw bytes.
             pub fn to_bytes(&self) -> Bytes {
                 match self {
@@ -25,7 +25,7 @@ use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
-use iggy_common::{BytesSerializable, ConsumerOffsetInfo, Sizeable, Stats, UserId};
+use iggy_common::{BytesSerializable, ConsumerOffsetInfo, Health, Sizeable, Stats, UserId};
 use tokio::sync::RwLock;
 
 pub fn map_stats(stats: &Stats) -> Bytes {
@@ -76,6 +76,20 @@ pub fn map_stats(stats: &Stats) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_health(health: &Health) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u8(health.status.as_code());
+    bytes.put_u32_le(health.checks.len() as u32);
+    for check in &health.checks {
+        bytes.put_u32_le(check.name.len() as u32);
+        bytes.put_slice(check.name.as_bytes());
+        bytes.put_u8(check.status.as_code());
+        bytes.put_u32_le(check.details.len() as u32);
+        bytes.put_slice(check.details.as_bytes());
+    }
+    bytes.freeze()
+}
+
 pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     let mut bytes = BytesMut::with_capacity(20);
     bytes.put_u32_le(offset.partition_id);
@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::channels::server_command::BackgroundServerCommand;
+use crate::configs::server::{ServerConfig, TelemetryConfig};
+use crate::metrics::otlp_exporter::OtlpMetricsExporter;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy_common::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, warn};
+
+pub struct MetricsExporter {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<ExportMetricsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExportMetricsCommand;
+
+#[derive(Default)]
+pub struct ExportMetricsExecutor {
+    exporter: Option<OtlpMetricsExporter>,
+}
+
+impl MetricsExporter {
+    pub fn new(config: &TelemetryConfig, sender: Sender<ExportMetricsCommand>) -> Self {
+        Self {
+            enabled: config.enabled && config.metrics.interval.as_micros() > 0,
+            interval: config.metrics.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Metrics export is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Metrics will be exported every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(ExportMetricsCommand).unwrap_or_else(|error| {
+                    error!("Failed to send ExportMetricsCommand. Error: {error}");
+                });
+            }
+        });
+    }
+}
+
+impl BackgroundServerCommand<ExportMetricsCommand> for ExportMetricsExecutor {
+    async fn execute(&mut self, system: &SharedSystem, _command: ExportMetricsCommand) {
+        let Some(exporter) = self.exporter.as_mut() else {
+            return;
+        };
+
+        let formatted_metrics = system.read().await.get_formatted_metrics().await;
+        match exporter.export(&formatted_metrics).await {
+            Ok(()) => debug!("Exported metrics."),
+            Err(error) => error!("Failed to export metrics. Error: {error}"),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<ExportMetricsCommand>,
+    ) {
+        let exporter = MetricsExporter::new(&config.telemetry, sender);
+        if exporter.enabled {
+            self.exporter = Some(OtlpMetricsExporter::new(&config.telemetry));
+        }
+        exporter.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<ExportMetricsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Metrics exporter stopped receiving commands.");
+        });
+    }
+}
@@ -18,7 +18,9 @@
 
 pub mod archive_state;
 pub mod clean_personal_access_tokens;
+pub mod export_metrics;
 pub mod maintain_messages;
 pub mod print_sysinfo;
+pub mod refresh_health;
 pub mod save_messages;
 pub mod verify_heartbeats;
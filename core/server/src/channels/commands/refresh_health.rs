@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::channels::server_command::BackgroundServerCommand;
+use crate::configs::system::HealthConfig;
+use crate::streaming::systems::system::{SharedSystem, System};
+use flume::Sender;
+use iggy_common::IggyDuration;
+use tokio::time;
+use tracing::{debug, error, info, instrument};
+
+pub struct RefreshHealth {
+    interval: IggyDuration,
+    sender: Sender<RefreshHealthCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RefreshHealthCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct RefreshHealthExecutor;
+
+impl RefreshHealth {
+    pub fn new(config: &HealthConfig, sender: Sender<RefreshHealthCommand>) -> Self {
+        Self {
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Health checks will be refreshed every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            interval_timer.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+            loop {
+                interval_timer.tick().await;
+                debug!("Refreshing health checks...");
+                sender.send(RefreshHealthCommand).unwrap_or_else(|error| {
+                    error!("Failed to send RefreshHealthCommand. Error: {}", error);
+                });
+            }
+        });
+    }
+}
+
+impl BackgroundServerCommand<RefreshHealthCommand> for RefreshHealthExecutor {
+    #[instrument(skip_all, name = "trace_refresh_health")]
+    async fn execute(&mut self, system: &SharedSystem, _command: RefreshHealthCommand) {
+        System::refresh_health(system).await;
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<RefreshHealthCommand>,
+    ) {
+        let refresh_health = RefreshHealth::new(&config.system.health, sender);
+        refresh_health.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<RefreshHealthCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Health refresher receiver stopped.");
+        });
+    }
+}
@@ -21,17 +21,18 @@ use super::tcp::TcpSocketConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
+use crate::configs::metrics::MetricsServerConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
     ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
     MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
     ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    TelemetryMetricsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::{
-    BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig, SegmentConfig,
-    StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig, HealthConfig,
+    LoggingConfig, MessageDeduplicationConfig, MetricsConfig, PartitionConfig, RecoveryConfig,
+    RuntimeConfig, SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
 use iggy_common::IggyByteSize;
@@ -55,6 +56,7 @@ impl Default for ServerConfig {
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
+            metrics: MetricsServerConfig::default(),
             telemetry: TelemetryConfig::default(),
         }
     }
@@ -274,6 +276,17 @@ impl Default for HttpTlsConfig {
     }
 }
 
+impl Default for MetricsServerConfig {
+    fn default() -> MetricsServerConfig {
+        MetricsServerConfig {
+            enabled: SERVER_CONFIG.metrics.enabled,
+            address: SERVER_CONFIG.metrics.address.parse().unwrap(),
+            endpoint: SERVER_CONFIG.metrics.endpoint.parse().unwrap(),
+            token: SERVER_CONFIG.metrics.token.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for MessageSaverConfig {
     fn default() -> MessageSaverConfig {
         MessageSaverConfig {
@@ -324,6 +337,8 @@ impl Default for SystemConfig {
             message_deduplication: MessageDeduplicationConfig::default(),
             recovery: RecoveryConfig::default(),
             memory_pool: MemoryPoolConfig::default(),
+            metrics: MetricsConfig::default(),
+            health: HealthConfig::default(),
         }
     }
 }
@@ -505,6 +520,30 @@ impl Default for MemoryPoolConfig {
     }
 }
 
+impl Default for MetricsConfig {
+    fn default() -> MetricsConfig {
+        MetricsConfig {
+            max_partitions: SERVER_CONFIG.system.metrics.max_partitions as u32,
+            max_consumer_groups: SERVER_CONFIG.system.metrics.max_consumer_groups as u32,
+        }
+    }
+}
+
+impl Default for HealthConfig {
+    fn default() -> HealthConfig {
+        HealthConfig {
+            interval: SERVER_CONFIG.system.health.interval.parse().unwrap(),
+            min_free_disk_space: SERVER_CONFIG
+                .system
+                .health
+                .min_free_disk_space
+                .parse()
+                .unwrap(),
+            max_persister_backlog: SERVER_CONFIG.system.health.max_persister_backlog as u32,
+        }
+    }
+}
+
 impl Default for TelemetryConfig {
     fn default() -> TelemetryConfig {
         TelemetryConfig {
@@ -512,6 +551,7 @@ impl Default for TelemetryConfig {
             service_name: SERVER_CONFIG.telemetry.service_name.parse().unwrap(),
             logs: TelemetryLogsConfig::default(),
             traces: TelemetryTracesConfig::default(),
+            metrics: TelemetryMetricsConfig::default(),
         }
     }
 }
@@ -533,3 +573,13 @@ impl Default for TelemetryTracesConfig {
         }
     }
 }
+
+impl Default for TelemetryMetricsConfig {
+    fn default() -> TelemetryMetricsConfig {
+        TelemetryMetricsConfig {
+            transport: SERVER_CONFIG.telemetry.metrics.transport.parse().unwrap(),
+            endpoint: SERVER_CONFIG.telemetry.metrics.endpoint.parse().unwrap(),
+            interval: SERVER_CONFIG.telemetry.metrics.interval.parse().unwrap(),
+        }
+    }
+}
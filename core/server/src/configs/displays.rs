@@ -20,11 +20,12 @@ use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
     ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
     MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
-    TelemetryLogsConfig, TelemetryTracesConfig,
+    TelemetryLogsConfig, TelemetryMetricsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    metrics::MetricsServerConfig,
     server::{MessageSaverConfig, ServerConfig},
     system::{
         CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig, SegmentConfig,
@@ -86,6 +87,23 @@ impl Display for HttpMetricsConfig {
     }
 }
 
+impl Display for MetricsServerConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, endpoint: {}, token: {} }}",
+            self.enabled,
+            self.address,
+            self.endpoint,
+            if self.token.is_empty() {
+                "none"
+            } else {
+                "******"
+            }
+        )
+    }
+}
+
 impl Display for HttpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -206,7 +224,7 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
+            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, metrics: {}, telemetry: {} }}",
             self.data_maintenance,
             self.message_saver,
             self.heartbeat,
@@ -214,6 +232,7 @@ impl Display for ServerConfig {
             self.quic,
             self.tcp,
             self.http,
+            self.metrics,
             self.telemetry
         )
     }
@@ -351,8 +370,8 @@ impl Display for TelemetryConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, service_name: {}, logs: {}, traces: {} }}",
-            self.enabled, self.service_name, self.logs, self.traces
+            "{{ enabled: {}, service_name: {}, logs: {}, traces: {}, metrics: {} }}",
+            self.enabled, self.service_name, self.logs, self.traces, self.metrics
         )
     }
 }
@@ -387,6 +406,16 @@ impl Display for TelemetryTracesConfig {
     }
 }
 
+impl Display for TelemetryMetricsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ transport: {}, endpoint: {}, interval: {} }}",
+            self.transport, self.endpoint, self.interval
+        )
+    }
+}
+
 impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
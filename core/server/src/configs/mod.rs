@@ -21,6 +21,7 @@ pub mod config_provider;
 pub mod defaults;
 pub mod displays;
 pub mod http;
+pub mod metrics;
 pub mod quic;
 pub mod server;
 pub mod system;
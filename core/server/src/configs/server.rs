@@ -20,6 +20,7 @@ use crate::archiver::ArchiverKindType;
 use crate::configs::COMPONENT;
 use crate::configs::config_provider::ConfigProviderKind;
 use crate::configs::http::HttpConfig;
+use crate::configs::metrics::MetricsServerConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
@@ -44,6 +45,7 @@ pub struct ServerConfig {
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
+    pub metrics: MetricsServerConfig,
     pub telemetry: TelemetryConfig,
 }
 
@@ -133,6 +135,7 @@ pub struct TelemetryConfig {
     pub service_name: String,
     pub logs: TelemetryLogsConfig,
     pub traces: TelemetryTracesConfig,
+    pub metrics: TelemetryMetricsConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -147,6 +150,15 @@ pub struct TelemetryTracesConfig {
     pub endpoint: String,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelemetryMetricsConfig {
+    pub transport: TelemetryTransport,
+    pub endpoint: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum TelemetryTransport {
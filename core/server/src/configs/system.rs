@@ -42,6 +42,8 @@ pub struct SystemConfig {
     pub message_deduplication: MessageDeduplicationConfig,
     pub recovery: RecoveryConfig,
     pub memory_pool: MemoryPoolConfig,
+    pub metrics: MetricsConfig,
+    pub health: HealthConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -133,6 +135,21 @@ pub struct MemoryPoolConfig {
     pub bucket_capacity: u32,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MetricsConfig {
+    pub max_partitions: u32,
+    pub max_consumer_groups: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HealthConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub min_free_disk_space: IggyByteSize,
+    pub max_persister_backlog: u32,
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
@@ -18,11 +18,12 @@
 
 extern crate sysinfo;
 
+use super::metrics::MetricsServerConfig;
 use super::server::{
     ArchiverConfig, DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig,
     StateMaintenanceConfig, TelemetryConfig,
 };
-use super::system::{CompressionConfig, MemoryPoolConfig, PartitionConfig};
+use super::system::{CompressionConfig, HealthConfig, MemoryPoolConfig, PartitionConfig};
 use crate::archiver::ArchiverKindType;
 use crate::configs::COMPONENT;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
@@ -68,6 +69,12 @@ impl Validatable<ConfigError> for ServerConfig {
         self.telemetry.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate telemetry config")
         })?;
+        self.metrics.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate metrics server config")
+        })?;
+        self.system.health.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate health config")
+        })?;
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -125,6 +132,24 @@ impl Validatable<ConfigError> for TelemetryConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if self.metrics.endpoint.is_empty() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MetricsServerConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.address.is_empty() || !self.endpoint.starts_with('/') {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -200,6 +225,17 @@ impl Validatable<ConfigError> for MessageSaverConfig {
     }
 }
 
+impl Validatable<ConfigError> for HealthConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.interval.is_zero() {
+            error!("Health check interval cannot be zero.");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ConfigError> for DataMaintenanceConfig {
     fn validate(&self) -> Result<(), ConfigError> {
         self.archiver.validate().with_error_context(|error| {
@@ -122,10 +122,12 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppState> {
     let tokens_path;
     let persister;
+    let health;
     {
         let system = system.read().await;
         tokens_path = system.config.get_state_tokens_path();
         persister = system.storage.persister.clone();
+        health = system.health();
     }
 
     let jwt_manager = JwtManager::from_config(persister, &tokens_path, &config.jwt);
@@ -141,6 +143,7 @@ async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppSt
     Arc::new(AppState {
         jwt_manager,
         system,
+        health,
     })
 }
 
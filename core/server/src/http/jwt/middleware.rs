@@ -37,6 +37,8 @@ const PUBLIC_PATHS: &[&str] = &[
     "/",
     "/metrics",
     "/ping",
+    "/health/live",
+    "/health/ready",
     "/stats",
     "/users/login",
     "/users/refresh-token",
@@ -49,6 +49,7 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[instrument(skip_all, name = "trace_poll_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = query.partition_id))]
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -81,6 +82,7 @@ async fn poll_messages(
     Ok(Json(polled_messages))
 }
 
+#[instrument(skip_all, name = "trace_send_messages", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
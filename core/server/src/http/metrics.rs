@@ -17,20 +17,33 @@
  */
 
 use crate::http::shared::AppState;
+use crate::streaming::diagnostics::metrics::RequestTransport;
 use axum::body::Body;
 use axum::{
-    extract::State,
+    extract::{MatchedPath, State},
     http::{Request, StatusCode},
     middleware::Next,
     response::Response,
 };
 use std::sync::Arc;
+use std::time::Instant;
 
 pub async fn metrics(
     State(state): State<Arc<AppState>>,
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    state.system.read().await.metrics.increment_http_requests();
-    Ok(next.run(request).await)
+    let requests = {
+        let system = state.system.read().await;
+        system.metrics.increment_http_requests();
+        system.metrics.requests()
+    };
+    let command = match request.extensions().get::<MatchedPath>() {
+        Some(path) => format!("{} {}", request.method(), path.as_str()),
+        None => format!("{} unmatched", request.method()),
+    };
+    let started_at = Instant::now();
+    let response = next.run(request).await;
+    requests.record(command, RequestTransport::Http, started_at.elapsed());
+    Ok(response)
 }
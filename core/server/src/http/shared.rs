@@ -17,13 +17,16 @@
  */
 
 use crate::http::jwt::jwt_manager::JwtManager;
+use crate::streaming::systems::health::HealthState;
 use crate::streaming::systems::system::SharedSystem;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use ulid::Ulid;
 
 pub struct AppState {
     pub jwt_manager: JwtManager,
     pub system: SharedSystem,
+    pub health: Arc<HealthState>,
 }
 
 #[derive(Debug, Copy, Clone)]
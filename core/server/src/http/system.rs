@@ -25,18 +25,18 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::body::Body;
 use axum::extract::{Path, State};
-use axum::http::{HeaderMap, header};
+use axum::http::{HeaderMap, StatusCode, header};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use bytes::Bytes;
 use chrono::Local;
 use error_set::ErrContext;
-use iggy_common::Stats;
 use iggy_common::Validatable;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{ClientInfo, ClientInfoDetails};
+use iggy_common::{Health, Stats};
 use std::sync::Arc;
 
 const NAME: &str = "Iggy API";
@@ -46,6 +46,9 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
     let mut router = Router::new()
         .route("/", get(|| async { NAME }))
         .route("/ping", get(|| async { PONG }))
+        .route("/health/live", get(|| async { Json(Health::new(vec![])) }))
+        .route("/health/ready", get(get_readiness))
+        .route("/health", get(get_health))
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
         .route("/clients/{client_id}", get(get_client))
@@ -59,7 +62,21 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
-    Ok(system.metrics.get_formatted_output())
+    Ok(system.get_formatted_metrics().await)
+}
+
+async fn get_readiness(State(state): State<Arc<AppState>>) -> (StatusCode, Json<Health>) {
+    let health = state.health.get_health();
+    let status = if health.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(health.without_checks()))
+}
+
+async fn get_health(State(state): State<Arc<AppState>>) -> Json<Health> {
+    Json(state.health.get_health())
 }
 
 async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, CustomError> {
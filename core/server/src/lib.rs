@@ -34,6 +34,7 @@ pub(crate) mod compat;
 pub mod configs;
 pub mod http;
 pub mod log;
+pub mod metrics;
 pub mod quic;
 pub mod server_error;
 pub mod state;
@@ -23,8 +23,10 @@ use figlet_rs::FIGfont;
 use server::args::Args;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::export_metrics::ExportMetricsExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
+use server::channels::commands::refresh_health::RefreshHealthExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
 use server::channels::handler::BackgroundServerCommandHandler;
@@ -35,6 +37,7 @@ use server::http::http_server;
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+use server::metrics::metrics_server;
 use server::quic::quic_server;
 use server::server_error::ServerError;
 use server::streaming::systems::system::{SharedSystem, System};
@@ -112,7 +115,9 @@ async fn main() -> Result<(), ServerError> {
         .install_handler(ArchiveStateExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
         .install_handler(SysInfoPrintExecutor)
-        .install_handler(VerifyHeartbeatsExecutor);
+        .install_handler(VerifyHeartbeatsExecutor)
+        .install_handler(RefreshHealthExecutor)
+        .install_handler(ExportMetricsExecutor::default());
 
     #[cfg(unix)]
     let (mut ctrl_c, mut sigterm) = {
@@ -125,19 +130,40 @@ async fn main() -> Result<(), ServerError> {
 
     let mut current_config = config.clone();
 
+    let health = system.read().await.health();
+    for (listener, enabled) in [
+        ("http", config.http.enabled),
+        ("quic", config.quic.enabled),
+        ("tcp", config.tcp.enabled),
+        ("metrics", config.metrics.enabled),
+    ] {
+        if enabled {
+            health.register_listener(listener);
+        }
+    }
+
     if config.http.enabled {
         let http_addr = http_server::start(config.http, system.clone()).await;
         current_config.http.address = http_addr.to_string();
+        health.set_listener_address("http", http_addr);
     }
 
     if config.quic.enabled {
         let quic_addr = quic_server::start(config.quic, system.clone());
         current_config.quic.address = quic_addr.to_string();
+        health.set_listener_address("quic", quic_addr);
     }
 
     if config.tcp.enabled {
         let tcp_addr = tcp_server::start(config.tcp, system.clone()).await;
         current_config.tcp.address = tcp_addr.to_string();
+        health.set_listener_address("tcp", tcp_addr);
+    }
+
+    if config.metrics.enabled {
+        let metrics_addr = metrics_server::start(config.metrics, system.clone()).await;
+        current_config.metrics.address = metrics_addr.to_string();
+        health.set_listener_address("metrics", metrics_addr);
     }
 
     let runtime_path = current_config.system.get_runtime_path();
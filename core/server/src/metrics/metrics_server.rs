@@ -0,0 +1,83 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::metrics::MetricsServerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use axum::Router;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::routing::get;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use tracing::{error, info};
+
+struct MetricsServerState {
+    token: String,
+    system: SharedSystem,
+}
+
+/// Starts the standalone metrics server, independent of the HTTP API.
+/// Returns the address the server is listening on.
+pub async fn start(config: MetricsServerConfig, system: SharedSystem) -> SocketAddr {
+    let state = Arc::new(MetricsServerState {
+        token: config.token,
+        system,
+    });
+    let app = Router::new()
+        .route(&config.endpoint, get(get_metrics))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(config.address.clone())
+        .await
+        .unwrap_or_else(|_| panic!("Failed to bind to metrics address {}", config.address));
+    let address = listener
+        .local_addr()
+        .expect("Failed to get local address for metrics server");
+    info!("Started metrics server on: {address}");
+    tokio::task::spawn(async move {
+        if let Err(error) = axum::serve(listener, app).await {
+            error!("Failed to start metrics server, error: {error}");
+        }
+    });
+
+    address
+}
+
+async fn get_metrics(
+    State(state): State<Arc<MetricsServerState>>,
+    headers: HeaderMap,
+) -> Result<String, StatusCode> {
+    if !is_authorized(&headers, &state.token) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let system = state.system.read().await;
+    Ok(system.get_formatted_metrics().await)
+}
+
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    if token.is_empty() {
+        return true;
+    }
+
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|value| bool::from(value.as_bytes().ct_eq(token.as_bytes())))
+}
@@ -0,0 +1,516 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::VERSION;
+use crate::configs::server::{TelemetryConfig, TelemetryTransport};
+use crate::server_error::MetricsExportError;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::collector::metrics::v1::metrics_service_client::MetricsServiceClient;
+use opentelemetry_proto::tonic::common::v1::{AnyValue, InstrumentationScope, KeyValue, any_value};
+use opentelemetry_proto::tonic::metrics::v1::{
+    AggregationTemporality, Gauge, Histogram, HistogramDataPoint, Metric, NumberDataPoint,
+    ResourceMetrics, ScopeMetrics, Sum, metric, number_data_point,
+};
+use opentelemetry_proto::tonic::resource::v1::Resource;
+use prost::Message;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tonic::transport::Channel;
+
+const SCOPE_NAME: &str = "iggy-server";
+
+/// Pushes the metrics encoded in the OpenMetrics text format to the OTLP collector.
+pub struct OtlpMetricsExporter {
+    transport: TelemetryTransport,
+    endpoint: String,
+    resource: Resource,
+    start_time_unix_nano: u64,
+    http_client: reqwest::Client,
+    grpc_client: Option<MetricsServiceClient<Channel>>,
+}
+
+impl OtlpMetricsExporter {
+    pub fn new(config: &TelemetryConfig) -> Self {
+        Self {
+            transport: config.metrics.transport,
+            endpoint: config.metrics.endpoint.clone(),
+            resource: Resource {
+                attributes: vec![
+                    string_attribute("service.name", &config.service_name),
+                    string_attribute("service.version", VERSION),
+                ],
+                ..Default::default()
+            },
+            start_time_unix_nano: now_unix_nano(),
+            http_client: reqwest::Client::new(),
+            grpc_client: None,
+        }
+    }
+
+    pub async fn export(&mut self, formatted_metrics: &str) -> Result<(), MetricsExportError> {
+        let request = self.build_request(formatted_metrics);
+        match self.transport {
+            TelemetryTransport::HTTP => self.export_http(request).await,
+            TelemetryTransport::GRPC => self.export_grpc(request).await,
+        }
+    }
+
+    fn build_request(&self, formatted_metrics: &str) -> ExportMetricsServiceRequest {
+        let time_unix_nano = now_unix_nano();
+        let metrics = parse_families(formatted_metrics)
+            .into_iter()
+            .filter_map(|family| family.into_metric(self.start_time_unix_nano, time_unix_nano))
+            .collect();
+
+        ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: Some(self.resource.clone()),
+                scope_metrics: vec![ScopeMetrics {
+                    scope: Some(InstrumentationScope {
+                        name: SCOPE_NAME.to_owned(),
+                        version: VERSION.to_owned(),
+                        ..Default::default()
+                    }),
+                    metrics,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }
+    }
+
+    async fn export_http(
+        &self,
+        request: ExportMetricsServiceRequest,
+    ) -> Result<(), MetricsExportError> {
+        let response = self
+            .http_client
+            .post(&self.endpoint)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-protobuf")
+            .body(request.encode_to_vec())
+            .send()
+            .await
+            .map_err(|error| MetricsExportError::CannotExportMetrics {
+                endpoint: self.endpoint.clone(),
+                reason: error.to_string(),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(MetricsExportError::CannotExportMetrics {
+                endpoint: self.endpoint.clone(),
+                reason: format!("status code: {}", response.status()),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn export_grpc(
+        &mut self,
+        request: ExportMetricsServiceRequest,
+    ) -> Result<(), MetricsExportError> {
+        if self.grpc_client.is_none() {
+            let client = MetricsServiceClient::connect(self.endpoint.clone())
+                .await
+                .map_err(|_| MetricsExportError::CannotConnectToCollector {
+                    endpoint: self.endpoint.clone(),
+                })?;
+            self.grpc_client = Some(client);
+        }
+
+        let client = self.grpc_client.as_mut().unwrap();
+        if let Err(status) = client.export(request).await {
+            // Reconnect on the next export, the collector might have been restarted.
+            self.grpc_client = None;
+            return Err(MetricsExportError::CannotExportMetrics {
+                endpoint: self.endpoint.clone(),
+                reason: status.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FamilyKind {
+    Counter,
+    Gauge,
+    Histogram,
+    Unsupported,
+}
+
+#[derive(Debug)]
+struct Sample {
+    name: String,
+    labels: Vec<(String, String)>,
+    value: String,
+}
+
+#[derive(Debug)]
+struct Family {
+    name: String,
+    help: String,
+    kind: FamilyKind,
+    samples: Vec<Sample>,
+}
+
+#[derive(Debug, Default)]
+struct HistogramSeries {
+    labels: Vec<(String, String)>,
+    buckets: Vec<(f64, u64)>,
+    sum: f64,
+    count: u64,
+}
+
+impl Family {
+    fn into_metric(self, start_time_unix_nano: u64, time_unix_nano: u64) -> Option<Metric> {
+        let data = match self.kind {
+            FamilyKind::Counter => metric::Data::Sum(Sum {
+                data_points: self.number_data_points(start_time_unix_nano, time_unix_nano),
+                aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                is_monotonic: true,
+            }),
+            FamilyKind::Gauge => metric::Data::Gauge(Gauge {
+                data_points: self.number_data_points(start_time_unix_nano, time_unix_nano),
+            }),
+            FamilyKind::Histogram => metric::Data::Histogram(Histogram {
+                data_points: self.histogram_data_points(start_time_unix_nano, time_unix_nano),
+                aggregation_temporality: AggregationTemporality::Cumulative as i32,
+            }),
+            FamilyKind::Unsupported => return None,
+        };
+
+        Some(Metric {
+            name: self.name,
+            description: self.help,
+            data: Some(data),
+            ..Default::default()
+        })
+    }
+
+    fn number_data_points(
+        &self,
+        start_time_unix_nano: u64,
+        time_unix_nano: u64,
+    ) -> Vec<NumberDataPoint> {
+        let total_name = format!("{}_total", self.name);
+        self.samples
+            .iter()
+            .filter(|sample| sample.name == self.name || sample.name == total_name)
+            .map(|sample| NumberDataPoint {
+                attributes: attributes(&sample.labels),
+                start_time_unix_nano,
+                time_unix_nano,
+                value: Some(match sample.value.parse::<i64>() {
+                    Ok(value) => number_data_point::Value::AsInt(value),
+                    Err(_) => number_data_point::Value::AsDouble(parse_float(&sample.value)),
+                }),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn histogram_data_points(
+        &self,
+        start_time_unix_nano: u64,
+        time_unix_nano: u64,
+    ) -> Vec<HistogramDataPoint> {
+        let bucket_name = format!("{}_bucket", self.name);
+        let sum_name = format!("{}_sum", self.name);
+        let count_name = format!("{}_count", self.name);
+        let mut series: Vec<HistogramSeries> = Vec::new();
+        for sample in &self.samples {
+            let mut labels = sample.labels.clone();
+            let le = labels
+                .iter()
+                .position(|(key, _)| key == "le")
+                .map(|index| labels.remove(index).1);
+            let index = match series.iter().position(|series| series.labels == labels) {
+                Some(index) => index,
+                None => {
+                    series.push(HistogramSeries {
+                        labels,
+                        ..Default::default()
+                    });
+                    series.len() - 1
+                }
+            };
+
+            let series = &mut series[index];
+            if sample.name == bucket_name {
+                if let Some(le) = le {
+                    series
+                        .buckets
+                        .push((parse_float(&le), parse_float(&sample.value) as u64));
+                }
+            } else if sample.name == sum_name {
+                series.sum = parse_float(&sample.value);
+            } else if sample.name == count_name {
+                series.count = parse_float(&sample.value) as u64;
+            }
+        }
+
+        series
+            .into_iter()
+            .map(|mut series| {
+                // OpenMetrics buckets are cumulative, while OTLP expects the count per bucket,
+                // with the implicit +Inf bucket following the last explicit bound.
+                series.buckets.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let mut explicit_bounds = Vec::new();
+                let mut bucket_counts = Vec::new();
+                let mut previous = 0;
+                for (le, cumulative) in series.buckets.iter().filter(|(le, _)| le.is_finite()) {
+                    explicit_bounds.push(*le);
+                    bucket_counts.push(cumulative.saturating_sub(previous));
+                    previous = *cumulative;
+                }
+                bucket_counts.push(series.count.saturating_sub(previous));
+
+                HistogramDataPoint {
+                    attributes: attributes(&series.labels),
+                    start_time_unix_nano,
+                    time_unix_nano,
+                    count: series.count,
+                    sum: Some(series.sum),
+                    bucket_counts,
+                    explicit_bounds,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_families(formatted_metrics: &str) -> Vec<Family> {
+    let mut families: Vec<Family> = Vec::new();
+    let mut help = None;
+    for line in formatted_metrics.lines() {
+        let line = line.trim();
+        if line.is_empty() || line == "# EOF" {
+            continue;
+        }
+
+        if let Some(descriptor) = line.strip_prefix("# ") {
+            let mut parts = descriptor.splitn(3, ' ');
+            let (Some(keyword), Some(name)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let value = parts.next().unwrap_or_default();
+            match keyword {
+                "HELP" => help = Some((name.to_owned(), value.to_owned())),
+                "TYPE" => {
+                    let help = help
+                        .take()
+                        .filter(|(help_name, _)| help_name == name)
+                        .map(|(_, help)| help)
+                        .unwrap_or_default();
+                    families.push(Family {
+                        name: name.to_owned(),
+                        help,
+                        kind: match value {
+                            "counter" => FamilyKind::Counter,
+                            "gauge" => FamilyKind::Gauge,
+                            "histogram" => FamilyKind::Histogram,
+                            _ => FamilyKind::Unsupported,
+                        },
+                        samples: Vec::new(),
+                    });
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        if let (Some(family), Some(sample)) = (families.last_mut(), parse_sample(line)) {
+            family.samples.push(sample);
+        }
+    }
+    families
+}
+
+fn parse_sample(line: &str) -> Option<Sample> {
+    let name_end = line.find(['{', ' '])?;
+    let name = line[..name_end].to_owned();
+    let mut labels = Vec::new();
+    let mut rest = &line[name_end..];
+    if let Some(labels_part) = rest.strip_prefix('{') {
+        let mut chars = labels_part.char_indices();
+        let mut key = String::new();
+        let mut labels_end = None;
+        while let Some((index, char)) = chars.next() {
+            match char {
+                '}' => {
+                    labels_end = Some(index + 1);
+                    break;
+                }
+                ',' | ' ' => {}
+                '=' => {
+                    // Label values are quoted, with the backslash, quote and newline escaped.
+                    chars.next();
+                    let mut value = String::new();
+                    while let Some((_, char)) = chars.next() {
+                        match char {
+                            '"' => break,
+                            '\\' => match chars.next() {
+                                Some((_, 'n')) => value.push('\n'),
+                                Some((_, escaped)) => value.push(escaped),
+                                None => {}
+                            },
+                            _ => value.push(char),
+                        }
+                    }
+                    labels.push((std::mem::take(&mut key), value));
+                }
+                _ => key.push(char),
+            }
+        }
+        rest = &labels_part[labels_end?..];
+    }
+
+    let value = rest.split_whitespace().next()?.to_owned();
+    Some(Sample {
+        name,
+        labels,
+        value,
+    })
+}
+
+fn parse_float(value: &str) -> f64 {
+    value.parse().unwrap_or(f64::NAN)
+}
+
+fn attributes(labels: &[(String, String)]) -> Vec<KeyValue> {
+    labels
+        .iter()
+        .map(|(key, value)| string_attribute(key, value))
+        .collect()
+}
+
+fn string_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.to_owned(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.to_owned())),
+        }),
+    }
+}
+
+fn now_unix_nano() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATTED_METRICS: &str = r#"# HELP streams total count of streams.
+# TYPE streams gauge
+streams 2
+# HELP messages total count of messages.
+# TYPE messages counter
+messages_total 10
+# HELP partition_messages_in messages appended to the partition.
+# TYPE partition_messages_in counter
+partition_messages_in_total{stream_id="1",topic_id="1",partition_id="1"} 7
+partition_messages_in_total{stream_id="1",topic_id="1",partition_id="2"} 3
+# HELP request_duration_seconds request latency.
+# TYPE request_duration_seconds histogram
+request_duration_seconds_sum{command="ping",transport="tcp"} 0.5
+request_duration_seconds_count{command="ping",transport="tcp"} 4
+request_duration_seconds_bucket{le="0.1",command="ping",transport="tcp"} 1
+request_duration_seconds_bucket{le="0.2",command="ping",transport="tcp"} 3
+request_duration_seconds_bucket{le="+Inf",command="ping",transport="tcp"} 4
+# EOF
+"#;
+
+    #[test]
+    fn should_convert_openmetrics_text_to_otlp_metrics() {
+        let families = parse_families(FORMATTED_METRICS);
+        let metrics = families
+            .into_iter()
+            .filter_map(|family| family.into_metric(1, 2))
+            .collect::<Vec<_>>();
+        assert_eq!(metrics.len(), 4);
+
+        let streams = &metrics[0];
+        assert_eq!(streams.name, "streams");
+        assert_eq!(streams.description, "total count of streams.");
+        let Some(metric::Data::Gauge(gauge)) = &streams.data else {
+            panic!("Expected gauge");
+        };
+        assert_eq!(
+            gauge.data_points[0].value,
+            Some(number_data_point::Value::AsInt(2))
+        );
+
+        let Some(metric::Data::Sum(sum)) = &metrics[1].data else {
+            panic!("Expected sum");
+        };
+        assert!(sum.is_monotonic);
+        assert_eq!(
+            sum.data_points[0].value,
+            Some(number_data_point::Value::AsInt(10))
+        );
+
+        let Some(metric::Data::Sum(sum)) = &metrics[2].data else {
+            panic!("Expected sum");
+        };
+        assert_eq!(sum.data_points.len(), 2);
+        assert_eq!(
+            sum.data_points[1].attributes,
+            vec![
+                string_attribute("stream_id", "1"),
+                string_attribute("topic_id", "1"),
+                string_attribute("partition_id", "2"),
+            ]
+        );
+
+        let Some(metric::Data::Histogram(histogram)) = &metrics[3].data else {
+            panic!("Expected histogram");
+        };
+        let data_point = &histogram.data_points[0];
+        assert_eq!(
+            data_point.attributes,
+            vec![
+                string_attribute("command", "ping"),
+                string_attribute("transport", "tcp"),
+            ]
+        );
+        assert_eq!(data_point.count, 4);
+        assert_eq!(data_point.sum, Some(0.5));
+        assert_eq!(data_point.explicit_bounds, vec![0.1, 0.2]);
+        assert_eq!(data_point.bucket_counts, vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn should_unescape_label_values() {
+        let sample = parse_sample(r#"requests{command="GET /a\"b\\c",transport="http"} 1"#)
+            .expect("Sample should be parsed");
+        assert_eq!(sample.name, "requests");
+        assert_eq!(
+            sample.labels,
+            vec![
+                ("command".to_owned(), r#"GET /a"b\c"#.to_owned()),
+                ("transport".to_owned(), "http".to_owned()),
+            ]
+        );
+        assert_eq!(sample.value, "1");
+    }
+}
@@ -20,11 +20,13 @@ use crate::binary::command::{ServerCommand, ServerCommandHandler};
 use crate::binary::sender::SenderKind;
 use crate::server_error::ConnectionError;
 use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::diagnostics::metrics::{RequestMetrics, RequestTransport};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::anyhow;
 use iggy_common::IggyError;
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
+use std::time::Instant;
 use tracing::{error, info, trace};
 
 const LISTENERS_COUNT: u32 = 10;
@@ -74,12 +76,14 @@ async fn handle_connection(
         .await;
 
     let client_id = session.client_id;
+    let requests = system.read().await.metrics.requests();
     while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
         let system = system.clone();
         let session = session.clone();
+        let requests = requests.clone();
 
         let handle_stream_task = async move {
-            if let Err(err) = handle_stream(stream, system, session).await {
+            if let Err(err) = handle_stream(stream, system, session, requests).await {
                 error!("Error when handling QUIC stream: {:?}", err)
             }
         };
@@ -114,6 +118,7 @@ async fn handle_stream(
     stream: BiStream,
     system: SharedSystem,
     session: impl AsRef<Session> + std::fmt::Debug,
+    requests: RequestMetrics,
 ) -> anyhow::Result<()> {
     let (send_stream, mut recv_stream) = stream;
 
@@ -145,10 +150,13 @@ async fn handle_stream(
 
     trace!("Received a QUIC command: {command}, payload size: {length}");
 
-    match command
+    let command_name = command.name();
+    let started_at = Instant::now();
+    let result = command
         .handle(&mut sender, length, session.as_ref(), &system)
-        .await
-    {
+        .await;
+    requests.record(command_name, RequestTransport::Quic, started_at.elapsed());
+    match result {
         Ok(_) => {
             trace!(
                 "Command was handled successfully, session: {:?}. QUIC response was sent.",
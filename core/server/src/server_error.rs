@@ -22,7 +22,7 @@ use std::array::TryFromSliceError;
 use tokio::io;
 
 error_set!(
-    ServerError = ConfigError || ArchiverError || ConnectionError || LogError || CompatError || QuicError;
+    ServerError = ConfigError || ArchiverError || ConnectionError || LogError || CompatError || QuicError || MetricsExportError;
 
     IoError = {
         #[display("IO error")]
@@ -61,6 +61,9 @@ error_set!(
 
         #[display("Cannot archive file: {}", file_path)]
         CannotArchiveFile { file_path: String },
+
+        #[display("Archiver is unreachable: {}", reason)]
+        ArchiverUnreachable { reason: String },
     } || IoError;
 
     ConnectionError = {
@@ -102,4 +105,12 @@ error_set!(
         #[display("Transport config error")]
         TransportConfigError,
     };
+
+    MetricsExportError = {
+        #[display("Cannot connect to metrics collector: {}", endpoint)]
+        CannotConnectToCollector { endpoint: String },
+
+        #[display("Cannot export metrics to: {}, reason: {}", endpoint, reason)]
+        CannotExportMetrics { endpoint: String, reason: String },
+    };
 );
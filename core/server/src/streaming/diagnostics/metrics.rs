@@ -16,12 +16,139 @@
  * under the License.
  */
 
+use crate::configs::system::MetricsConfig;
+use crate::streaming::utils::MemoryPool;
+use dashmap::DashSet;
+use iggy_common::CacheMetrics;
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::{EncodeLabelSet, EncodeLabelValue, LabelValueEncoder};
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{Histogram, exponential_buckets};
 use prometheus_client::registry::Registry;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::time::Duration;
 use tracing::error;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct PartitionLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct ConsumerGroupLabels {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub consumer_group_id: u32,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub(crate) struct RequestLabels {
+    pub command: Cow<'static, str>,
+    pub transport: RequestTransport,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(crate) enum RequestTransport {
+    Tcp,
+    Quic,
+    Http,
+}
+
+impl EncodeLabelValue for RequestTransport {
+    fn encode(&self, encoder: &mut LabelValueEncoder) -> Result<(), std::fmt::Error> {
+        let value = match self {
+            RequestTransport::Tcp => "tcp",
+            RequestTransport::Quic => "quic",
+            RequestTransport::Http => "http",
+        };
+        EncodeLabelValue::encode(&value, encoder)
+    }
+}
+
+/// The state of a partition captured while refreshing the metrics.
+#[derive(Debug, Default)]
+pub(crate) struct PartitionSnapshot {
+    pub current_offset: u64,
+    pub segments_count: u32,
+    pub size_bytes: u64,
+    pub cache: CacheMetrics,
+    pub persister_queue_depth: usize,
+}
+
+type HistogramFamily<L> = Family<L, Histogram, fn() -> Histogram>;
+
+/// Latency of the handled requests, cheap to clone and to be used outside the system lock.
+#[derive(Clone, Debug)]
+pub(crate) struct RequestMetrics {
+    duration: HistogramFamily<RequestLabels>,
+}
+
+impl RequestMetrics {
+    pub fn record(
+        &self,
+        command: impl Into<Cow<'static, str>>,
+        transport: RequestTransport,
+        elapsed: Duration,
+    ) {
+        self.duration
+            .get_or_create(&RequestLabels {
+                command: command.into(),
+                transport,
+            })
+            .observe(elapsed.as_secs_f64());
+    }
+}
+
+/// Keeps track of the labeled series, so that their count never exceeds the configured limit.
+#[derive(Debug)]
+struct SeriesLimiter<L: Hash + Eq> {
+    limit: usize,
+    series: DashSet<L>,
+}
+
+impl<L: Clone + Hash + Eq> SeriesLimiter<L> {
+    fn new(limit: u32) -> Self {
+        Self {
+            limit: limit as usize,
+            series: DashSet::new(),
+        }
+    }
+
+    fn admit(&self, labels: &L) -> bool {
+        if self.series.contains(labels) {
+            return true;
+        }
+
+        if self.series.len() >= self.limit {
+            return false;
+        }
+
+        self.series.insert(labels.clone());
+        true
+    }
+
+    /// Forgets the series which are not live anymore, and returns their labels.
+    fn retain(&self, live: &HashSet<L>) -> Vec<L> {
+        let stale = self
+            .series
+            .iter()
+            .filter(|labels| !live.contains(labels.key()))
+            .map(|labels| labels.key().clone())
+            .collect::<Vec<_>>();
+        for labels in &stale {
+            self.series.remove(labels);
+        }
+        stale
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -33,10 +160,33 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    requests: RequestMetrics,
+    partition_messages_in: Family<PartitionLabels, Counter>,
+    partition_bytes_in: Family<PartitionLabels, Counter>,
+    partition_messages_out: Family<PartitionLabels, Counter>,
+    partition_bytes_out: Family<PartitionLabels, Counter>,
+    partition_current_offset: Family<PartitionLabels, Gauge>,
+    partition_segments: Family<PartitionLabels, Gauge>,
+    partition_size_bytes: Family<PartitionLabels, Gauge>,
+    partition_cache_hits: Family<PartitionLabels, Counter>,
+    partition_cache_misses: Family<PartitionLabels, Counter>,
+    partition_persister_queue_depth: Family<PartitionLabels, Gauge>,
+    consumer_group_lag: Family<ConsumerGroupLabels, Gauge>,
+    memory_pool_used_bytes: Gauge,
+    memory_pool_allocated_bytes: Gauge,
+    memory_pool_limit_bytes: Gauge,
+    memory_pool_external_allocations: Counter,
+    memory_pool_external_deallocations: Counter,
+    memory_pool_dropped_returns: Counter,
+    memory_pool_resize_events: Counter,
+    limited_partitions: Gauge,
+    limited_consumer_groups: Gauge,
+    partitions_limiter: SeriesLimiter<PartitionLabels>,
+    consumer_groups_limiter: SeriesLimiter<ConsumerGroupLabels>,
 }
 
 impl Metrics {
-    pub fn init() -> Self {
+    pub fn init(config: &MetricsConfig) -> Self {
         let mut metrics = Metrics {
             registry: <Registry>::default(),
             http_requests: Counter::default(),
@@ -47,6 +197,33 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            requests: RequestMetrics {
+                duration: HistogramFamily::new_with_constructor(|| {
+                    Histogram::new(exponential_buckets(0.000_1, 2.0, 16))
+                }),
+            },
+            partition_messages_in: Family::default(),
+            partition_bytes_in: Family::default(),
+            partition_messages_out: Family::default(),
+            partition_bytes_out: Family::default(),
+            partition_current_offset: Family::default(),
+            partition_segments: Family::default(),
+            partition_size_bytes: Family::default(),
+            partition_cache_hits: Family::default(),
+            partition_cache_misses: Family::default(),
+            partition_persister_queue_depth: Family::default(),
+            consumer_group_lag: Family::default(),
+            memory_pool_used_bytes: Gauge::default(),
+            memory_pool_allocated_bytes: Gauge::default(),
+            memory_pool_limit_bytes: Gauge::default(),
+            memory_pool_external_allocations: Counter::default(),
+            memory_pool_external_deallocations: Counter::default(),
+            memory_pool_dropped_returns: Counter::default(),
+            memory_pool_resize_events: Counter::default(),
+            limited_partitions: Gauge::default(),
+            limited_consumer_groups: Gauge::default(),
+            partitions_limiter: SeriesLimiter::new(config.max_partitions),
+            consumer_groups_limiter: SeriesLimiter::new(config.max_consumer_groups),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
@@ -58,6 +235,112 @@ impl Metrics {
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
 
+        metrics.registry.register(
+            "request_duration_seconds",
+            "duration of the handled requests per command and transport",
+            metrics.requests.duration.clone(),
+        );
+        metrics.registry.register(
+            "partition_messages_in",
+            "total count of messages appended to the partition",
+            metrics.partition_messages_in.clone(),
+        );
+        metrics.registry.register(
+            "partition_bytes_in",
+            "total size of messages appended to the partition",
+            metrics.partition_bytes_in.clone(),
+        );
+        metrics.registry.register(
+            "partition_messages_out",
+            "total count of messages polled from the partition",
+            metrics.partition_messages_out.clone(),
+        );
+        metrics.registry.register(
+            "partition_bytes_out",
+            "total size of messages polled from the partition",
+            metrics.partition_bytes_out.clone(),
+        );
+        metrics.registry.register(
+            "partition_current_offset",
+            "current offset of the partition",
+            metrics.partition_current_offset.clone(),
+        );
+        metrics.registry.register(
+            "partition_segments",
+            "count of segments of the partition",
+            metrics.partition_segments.clone(),
+        );
+        metrics.registry.register(
+            "partition_size_bytes",
+            "size of the messages stored in the partition",
+            metrics.partition_size_bytes.clone(),
+        );
+        metrics.registry.register(
+            "partition_cache_hits",
+            "total count of polls served from the in-memory messages",
+            metrics.partition_cache_hits.clone(),
+        );
+        metrics.registry.register(
+            "partition_cache_misses",
+            "total count of polls which had to read the messages from disk",
+            metrics.partition_cache_misses.clone(),
+        );
+        metrics.registry.register(
+            "partition_persister_queue_depth",
+            "count of message batches waiting to be persisted in the background",
+            metrics.partition_persister_queue_depth.clone(),
+        );
+        metrics.registry.register(
+            "consumer_group_lag",
+            "count of messages not yet consumed by the consumer group",
+            metrics.consumer_group_lag.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_used_bytes",
+            "size of the memory pool buffers in use",
+            metrics.memory_pool_used_bytes.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_allocated_bytes",
+            "size of the memory pool buffers ever allocated",
+            metrics.memory_pool_allocated_bytes.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_limit_bytes",
+            "configured size of the memory pool",
+            metrics.memory_pool_limit_bytes.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_external_allocations",
+            "total count of buffers allocated outside the memory pool",
+            metrics.memory_pool_external_allocations.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_external_deallocations",
+            "total count of buffers deallocated outside the memory pool",
+            metrics.memory_pool_external_deallocations.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_dropped_returns",
+            "total count of buffers dropped as the memory pool bucket was full",
+            metrics.memory_pool_dropped_returns.clone(),
+        );
+        metrics.registry.register(
+            "memory_pool_resize_events",
+            "total count of buffers resized while in use",
+            metrics.memory_pool_resize_events.clone(),
+        );
+        metrics.registry.register(
+            "metrics_limited_partitions",
+            "count of partitions without labeled series due to the cardinality limit",
+            metrics.limited_partitions.clone(),
+        );
+        metrics.registry.register(
+            "metrics_limited_consumer_groups",
+            "count of consumer groups without labeled series due to the cardinality limit",
+            metrics.limited_consumer_groups.clone(),
+        );
+
         metrics
     }
 
@@ -79,6 +362,10 @@ impl Metrics {
         buffer
     }
 
+    pub fn requests(&self) -> RequestMetrics {
+        self.requests.clone()
+    }
+
     pub fn increment_http_requests(&self) {
         self.http_requests.inc();
     }
@@ -138,4 +425,217 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn record_messages_in(&self, labels: &PartitionLabels, count: u32, size_bytes: u32) {
+        if !self.partitions_limiter.admit(labels) {
+            return;
+        }
+
+        self.partition_messages_in
+            .get_or_create(labels)
+            .inc_by(count as u64);
+        self.partition_bytes_in
+            .get_or_create(labels)
+            .inc_by(size_bytes as u64);
+    }
+
+    pub fn record_messages_out(&self, labels: &PartitionLabels, count: u32, size_bytes: u32) {
+        if !self.partitions_limiter.admit(labels) {
+            return;
+        }
+
+        self.partition_messages_out
+            .get_or_create(labels)
+            .inc_by(count as u64);
+        self.partition_bytes_out
+            .get_or_create(labels)
+            .inc_by(size_bytes as u64);
+    }
+
+    /// Updates the series of the partition, returns `false` if the partition is over the cardinality limit.
+    pub fn update_partition(&self, labels: &PartitionLabels, snapshot: &PartitionSnapshot) -> bool {
+        if !self.partitions_limiter.admit(labels) {
+            return false;
+        }
+
+        self.partition_current_offset
+            .get_or_create(labels)
+            .set(snapshot.current_offset as i64);
+        self.partition_segments
+            .get_or_create(labels)
+            .set(snapshot.segments_count as i64);
+        self.partition_size_bytes
+            .get_or_create(labels)
+            .set(snapshot.size_bytes as i64);
+        self.partition_persister_queue_depth
+            .get_or_create(labels)
+            .set(snapshot.persister_queue_depth as i64);
+        sync_counter(
+            &self.partition_cache_hits.get_or_create(labels),
+            snapshot.cache.hits,
+        );
+        sync_counter(
+            &self.partition_cache_misses.get_or_create(labels),
+            snapshot.cache.misses,
+        );
+        true
+    }
+
+    /// Updates the lag of the consumer group, returns `false` if the consumer group is over the cardinality limit.
+    pub fn update_consumer_group_lag(&self, labels: &ConsumerGroupLabels, lag: u64) -> bool {
+        if !self.consumer_groups_limiter.admit(labels) {
+            return false;
+        }
+
+        self.consumer_group_lag
+            .get_or_create(labels)
+            .set(lag as i64);
+        true
+    }
+
+    pub fn update_memory_pool(&self, pool: &MemoryPool) {
+        self.memory_pool_used_bytes
+            .set(pool.pool_current_size() as i64);
+        self.memory_pool_allocated_bytes
+            .set(pool.pool_allocated_size() as i64);
+        self.memory_pool_limit_bytes
+            .set(pool.pool_maximum_size() as i64);
+        sync_counter(
+            &self.memory_pool_external_allocations,
+            pool.external_allocations() as u64,
+        );
+        sync_counter(
+            &self.memory_pool_external_deallocations,
+            pool.external_deallocations() as u64,
+        );
+        sync_counter(
+            &self.memory_pool_dropped_returns,
+            pool.dropped_returns() as u64,
+        );
+        sync_counter(&self.memory_pool_resize_events, pool.resize_events() as u64);
+    }
+
+    /// Removes the series of the partitions and consumer groups which do not exist anymore,
+    /// and records how many of them were left out due to the cardinality limits.
+    pub fn retain(
+        &self,
+        partitions: &HashSet<PartitionLabels>,
+        consumer_groups: &HashSet<ConsumerGroupLabels>,
+        limited_partitions: u32,
+        limited_consumer_groups: u32,
+    ) {
+        for labels in self.partitions_limiter.retain(partitions) {
+            self.partition_messages_in.remove(&labels);
+            self.partition_bytes_in.remove(&labels);
+            self.partition_messages_out.remove(&labels);
+            self.partition_bytes_out.remove(&labels);
+            self.partition_current_offset.remove(&labels);
+            self.partition_segments.remove(&labels);
+            self.partition_size_bytes.remove(&labels);
+            self.partition_cache_hits.remove(&labels);
+            self.partition_cache_misses.remove(&labels);
+            self.partition_persister_queue_depth.remove(&labels);
+        }
+        for labels in self.consumer_groups_limiter.retain(consumer_groups) {
+            self.consumer_group_lag.remove(&labels);
+        }
+        self.limited_partitions.set(limited_partitions as i64);
+        self.limited_consumer_groups
+            .set(limited_consumer_groups as i64);
+    }
+}
+
+/// Brings the counter up to the value tracked elsewhere, e.g. by the partition or the memory pool.
+fn sync_counter(counter: &Counter, value: u64) {
+    let current = counter.get();
+    if value > current {
+        counter.inc_by(value - current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_metrics(max_partitions: u32, max_consumer_groups: u32) -> Metrics {
+        Metrics::init(&MetricsConfig {
+            max_partitions,
+            max_consumer_groups,
+        })
+    }
+
+    fn partition_labels(partition_id: u32) -> PartitionLabels {
+        PartitionLabels {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id,
+        }
+    }
+
+    #[test]
+    fn should_expose_labeled_partition_series() {
+        let metrics = init_metrics(10, 10);
+        metrics.record_messages_in(&partition_labels(1), 10, 1000);
+        metrics.record_messages_out(&partition_labels(1), 5, 500);
+        metrics.update_partition(
+            &partition_labels(1),
+            &PartitionSnapshot {
+                current_offset: 9,
+                segments_count: 1,
+                size_bytes: 1000,
+                ..Default::default()
+            },
+        );
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(
+            r#"partition_messages_in_total{stream_id="1",topic_id="1",partition_id="1"} 10"#
+        ));
+        assert!(output.contains(
+            r#"partition_bytes_out_total{stream_id="1",topic_id="1",partition_id="1"} 500"#
+        ));
+        assert!(output.contains(
+            r#"partition_current_offset{stream_id="1",topic_id="1",partition_id="1"} 9"#
+        ));
+    }
+
+    #[test]
+    fn should_not_expose_partition_series_over_the_limit() {
+        let metrics = init_metrics(2, 10);
+        for partition_id in 1..=3 {
+            metrics.record_messages_in(&partition_labels(partition_id), 1, 100);
+        }
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(r#"partition_id="2""#));
+        assert!(!output.contains(r#"partition_id="3""#));
+    }
+
+    #[test]
+    fn should_remove_series_of_deleted_partitions() {
+        let metrics = init_metrics(2, 10);
+        metrics.record_messages_in(&partition_labels(1), 1, 100);
+        metrics.record_messages_in(&partition_labels(2), 1, 100);
+
+        let live = HashSet::from([partition_labels(2)]);
+        metrics.retain(&live, &HashSet::new(), 0, 0);
+        metrics.record_messages_in(&partition_labels(3), 1, 100);
+
+        let output = metrics.get_formatted_output();
+        assert!(!output.contains(r#"partition_id="1""#));
+        assert!(output.contains(r#"partition_id="3""#));
+    }
+
+    #[test]
+    fn should_record_request_duration_per_command_and_transport() {
+        let metrics = init_metrics(10, 10);
+        metrics
+            .requests()
+            .record("ping", RequestTransport::Tcp, Duration::from_millis(1));
+
+        let output = metrics.get_formatted_output();
+        assert!(
+            output.contains(r#"request_duration_seconds_count{command="ping",transport="tcp"} 1"#)
+        );
+    }
 }
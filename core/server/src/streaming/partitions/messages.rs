@@ -21,7 +21,7 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
-use iggy_common::{Confirmation, IggyError, IggyTimestamp, Sizeable};
+use iggy_common::{CacheMetrics, Confirmation, IggyError, IggyTimestamp, Sizeable};
 use std::sync::atomic::Ordering;
 use tracing::trace;
 
@@ -50,6 +50,10 @@ impl Partition {
             .filter(|segment| segment.end_timestamp() >= query_ts)
             .collect();
 
+        if let Some(segment) = filtered_segments.first() {
+            self.record_cache_access(segment.is_timestamp_cached(query_ts));
+        }
+
         Self::get_messages_from_segments_by_timestamp(filtered_segments, query_ts, count).await
     }
 
@@ -77,6 +81,7 @@ impl Partition {
             None => return Ok(IggyMessagesBatchSet::empty()),
         };
 
+        self.record_cache_access(self.segments[start_segment_idx].is_offset_cached(start_offset));
         let relevant_segments: Vec<&Segment> = self.segments[start_segment_idx..].iter().collect();
 
         Self::get_messages_from_segments(relevant_segments, start_offset, count).await
@@ -141,6 +146,30 @@ impl Partition {
         self.get_messages_by_offset(offset, count).await
     }
 
+    /// Returns the hits and misses of the in-memory messages cache (the accumulator of the open segment).
+    pub fn get_cache_metrics(&self) -> CacheMetrics {
+        let hits = self.cache_hits.load(Ordering::Relaxed);
+        let misses = self.cache_misses.load(Ordering::Relaxed);
+        let total = hits + misses;
+        CacheMetrics {
+            hits,
+            misses,
+            hit_ratio: if total > 0 {
+                hits as f32 / total as f32
+            } else {
+                0.0
+            },
+        }
+    }
+
+    fn record_cache_access(&self, hit: bool) {
+        if hit {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
     /// Retrieves messages from multiple segments.
     async fn get_messages_from_segments(
         segments: Vec<&Segment>,
@@ -54,6 +54,8 @@ pub struct Partition {
     pub size_of_parent_topic: Arc<AtomicU64>,
     pub size_bytes: Arc<AtomicU64>,
     pub segments_count_of_parent_stream: Arc<AtomicU32>,
+    pub(crate) cache_hits: AtomicU64,
+    pub(crate) cache_misses: AtomicU64,
     pub(crate) message_expiry: IggyExpiry,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
@@ -151,6 +153,8 @@ impl Partition {
             messages_count_of_parent_topic,
             messages_count: Arc::new(AtomicU64::new(0)),
             segments_count_of_parent_stream,
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
         };
 
         if with_segment {
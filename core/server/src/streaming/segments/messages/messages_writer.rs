@@ -173,6 +173,13 @@ impl MessagesWriter {
         Ok(())
     }
 
+    /// Returns the number of batches waiting for the persister task, `0` for synchronous writes.
+    pub fn persister_queue_depth(&self) -> usize {
+        self.persister_task
+            .as_ref()
+            .map_or(0, |task| task.queue_depth())
+    }
+
     pub async fn shutdown_persister_task(self) {
         if let Some(task) = self.persister_task {
             task.shutdown().await;
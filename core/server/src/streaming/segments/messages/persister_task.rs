@@ -76,6 +76,11 @@ impl PersisterTask {
         }
     }
 
+    /// Returns the number of write requests waiting to be persisted.
+    pub fn queue_depth(&self) -> usize {
+        self.sender.len()
+    }
+
     /// Sends the shutdown command to the persister task and waits for a response.
     pub async fn shutdown(self) {
         let start_time = tokio::time::Instant::now();
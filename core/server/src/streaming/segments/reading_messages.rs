@@ -40,6 +40,16 @@ impl Segment {
         (self.end_offset - self.start_offset + 1) as u32
     }
 
+    /// Checks whether the messages starting at the given offset are served from the accumulator, without reading from disk.
+    pub fn is_offset_cached(&self, offset: u64) -> bool {
+        !self.accumulator.is_empty() && offset >= self.accumulator.first_offset()
+    }
+
+    /// Checks whether the messages starting at the given timestamp are served from the accumulator, without reading from disk.
+    pub fn is_timestamp_cached(&self, timestamp: u64) -> bool {
+        !self.accumulator.is_empty() && timestamp >= self.accumulator.first_timestamp()
+    }
+
     pub async fn get_messages_by_timestamp(
         &self,
         timestamp: u64,
@@ -411,6 +411,12 @@ impl Segment {
         &self.messages_path
     }
 
+    pub fn persister_queue_depth(&self) -> usize {
+        self.messages_writer
+            .as_ref()
+            .map_or(0, |writer| writer.persister_queue_depth())
+    }
+
     /// Explicitly drop the old indexes to ensure memory is freed
     pub fn drop_indexes(&mut self) {
         let old_indexes = std::mem::replace(&mut self.indexes, IggyIndexesMut::empty());
@@ -0,0 +1,263 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::archiver::ArchiverKind;
+use crate::configs::system::SystemConfig;
+use crate::streaming::systems::system::{SharedSystem, System};
+use dashmap::DashMap;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Health, HealthCheck, HealthStatus, IggyByteSize};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{PoisonError, RwLock};
+use std::time::Duration;
+use tokio::time::timeout;
+
+const ARCHIVER_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Startup progress of the server used by the readiness checks, updated by the system
+/// initialization and the listeners once they are bound, along with the results of the checks
+/// which are too expensive to be performed on every request, refreshed in the background.
+#[derive(Debug, Default)]
+pub struct HealthState {
+    state_loaded: AtomicBool,
+    expected_partitions: AtomicU32,
+    loaded_partitions: AtomicU32,
+    listeners: DashMap<String, Option<SocketAddr>>,
+    refreshed_checks: RwLock<Vec<HealthCheck>>,
+}
+
+impl HealthState {
+    /// Returns the health with the last refreshed checks, without acquiring the system lock.
+    pub fn get_health(&self) -> Health {
+        let mut checks = vec![self.check_state(), self.check_partitions()];
+        checks.extend(
+            self.refreshed_checks
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter()
+                .cloned(),
+        );
+        checks.push(self.check_listeners());
+        Health::new(checks)
+    }
+
+    /// Performs the disk space and archiver checks and stores them along with the persister backlog check.
+    async fn refresh(
+        &self,
+        config: &SystemConfig,
+        archiver: Option<&ArchiverKind>,
+        persister_backlog: HealthCheck,
+    ) {
+        let checks = vec![
+            check_disk_space(config),
+            persister_backlog,
+            check_archiver(archiver).await,
+        ];
+        *self
+            .refreshed_checks
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = checks;
+    }
+
+    pub fn set_state_loaded(&self) {
+        self.state_loaded.store(true, Ordering::Release);
+    }
+
+    pub fn set_partitions(&self, expected: u32, loaded: u32) {
+        self.expected_partitions.store(expected, Ordering::Release);
+        self.loaded_partitions.store(loaded, Ordering::Release);
+    }
+
+    /// Registers the listener which is expected to be started, it's reported as down until its address is set.
+    pub fn register_listener(&self, name: &str) {
+        self.listeners.entry(name.to_owned()).or_insert(None);
+    }
+
+    pub fn set_listener_address(&self, name: &str, address: SocketAddr) {
+        self.listeners.insert(name.to_owned(), Some(address));
+    }
+
+    fn check_state(&self) -> HealthCheck {
+        if self.state_loaded.load(Ordering::Acquire) {
+            HealthCheck::new("state", HealthStatus::Up, "state has been loaded")
+        } else {
+            HealthCheck::new("state", HealthStatus::Down, "state is being loaded")
+        }
+    }
+
+    fn check_partitions(&self) -> HealthCheck {
+        let expected = self.expected_partitions.load(Ordering::Acquire);
+        let loaded = self.loaded_partitions.load(Ordering::Acquire);
+        let status = if self.state_loaded.load(Ordering::Acquire) && loaded >= expected {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+        HealthCheck::new(
+            "partitions",
+            status,
+            format!("loaded {loaded} of {expected} partitions"),
+        )
+    }
+
+    fn check_listeners(&self) -> HealthCheck {
+        let mut listeners = self
+            .listeners
+            .iter()
+            .map(|listener| (listener.key().clone(), *listener.value()))
+            .collect::<Vec<_>>();
+        if listeners.is_empty() {
+            return HealthCheck::new("listeners", HealthStatus::Down, "no listeners are enabled");
+        }
+
+        listeners.sort_by(|a, b| a.0.cmp(&b.0));
+        let status = if listeners.iter().all(|(_, address)| address.is_some()) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+        let details = listeners
+            .iter()
+            .map(|(name, address)| match address {
+                Some(address) => format!("{name}: {address}"),
+                None => format!("{name}: not started"),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        HealthCheck::new("listeners", status, details)
+    }
+}
+
+impl System {
+    /// Refreshes the health checks, the system lock is held only while the persister backlog
+    /// is collected, the archiver is checked without it.
+    pub async fn refresh_health(system: &SharedSystem) {
+        let (health, config, archiver, persister_backlog) = {
+            let system = system.read().await;
+            (
+                system.health(),
+                system.config.clone(),
+                system.archiver.clone(),
+                system.check_persister_backlog().await,
+            )
+        };
+        health
+            .refresh(&config, archiver.as_deref(), persister_backlog)
+            .await;
+    }
+
+    /// Performs the health checks during the initialization, before the background refresh starts.
+    pub(crate) async fn init_health(&self) {
+        self.health
+            .refresh(
+                &self.config,
+                self.archiver.as_deref(),
+                self.check_persister_backlog().await,
+            )
+            .await;
+    }
+
+    async fn check_persister_backlog(&self) -> HealthCheck {
+        let max_persister_backlog = self.config.health.max_persister_backlog;
+        if max_persister_backlog == 0 {
+            return HealthCheck::new("persister", HealthStatus::Disabled, "");
+        }
+
+        let mut backlog = 0;
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    backlog += partition
+                        .read()
+                        .await
+                        .segments
+                        .iter()
+                        .map(|segment| segment.persister_queue_depth())
+                        .sum::<usize>();
+                }
+            }
+        }
+
+        let status = if backlog <= max_persister_backlog as usize {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+        HealthCheck::new(
+            "persister",
+            status,
+            format!("{backlog} pending writes, max: {max_persister_backlog}"),
+        )
+    }
+}
+
+fn check_disk_space(config: &SystemConfig) -> HealthCheck {
+    let min_free_disk_space = config.health.min_free_disk_space;
+    if min_free_disk_space.as_bytes_u64() == 0 {
+        return HealthCheck::new("disk", HealthStatus::Disabled, "");
+    }
+
+    let system_path = config.get_system_path();
+    let stats = match nix::sys::statvfs::statvfs(system_path.as_str()) {
+        Ok(stats) => stats,
+        Err(error) => {
+            return HealthCheck::new(
+                "disk",
+                HealthStatus::Down,
+                format!("cannot get the free disk space at {system_path}: {error}"),
+            );
+        }
+    };
+
+    #[allow(clippy::unnecessary_cast)]
+    let available =
+        IggyByteSize::from(stats.blocks_available() as u64 * stats.fragment_size() as u64);
+    let status = if available >= min_free_disk_space {
+        HealthStatus::Up
+    } else {
+        HealthStatus::Down
+    };
+    HealthCheck::new(
+        "disk",
+        status,
+        format!(
+            "{} available at {system_path}, required: {min_free_disk_space}",
+            available.as_human_string()
+        ),
+    )
+}
+
+async fn check_archiver(archiver: Option<&ArchiverKind>) -> HealthCheck {
+    let Some(archiver) = archiver else {
+        return HealthCheck::new("archiver", HealthStatus::Disabled, "");
+    };
+
+    match timeout(ARCHIVER_CHECK_TIMEOUT, archiver.check_reachability()).await {
+        Ok(Ok(())) => HealthCheck::new("archiver", HealthStatus::Up, "archiver is reachable"),
+        Ok(Err(error)) => HealthCheck::new("archiver", HealthStatus::Down, error.to_string()),
+        Err(_) => HealthCheck::new(
+            "archiver",
+            HealthStatus::Down,
+            format!(
+                "archiver did not respond within {} s",
+                ARCHIVER_CHECK_TIMEOUT.as_secs()
+            ),
+        ),
+    }
+}
@@ -17,6 +17,7 @@
  */
 
 use crate::binary::handlers::messages::poll_messages_handler::IggyPollMetadata;
+use crate::streaming::diagnostics::metrics::PartitionLabels;
 use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet};
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
@@ -70,6 +71,16 @@ impl System {
             .get_messages(polling_consumer, partition_id, args.strategy, args.count)
             .await?;
 
+        self.metrics.record_messages_out(
+            &PartitionLabels {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+            },
+            batch_set.count(),
+            batch_set.size(),
+        );
+
         if args.auto_commit && !batch_set.is_empty() {
             let offset = batch_set
                 .last_offset()
@@ -115,6 +126,7 @@ impl System {
             topic.topic_id
         ))?;
         let messages_count = messages.count();
+        let messages_size = messages.size();
 
         // Encrypt messages if encryptor is configured
         let messages = if let Some(encryptor) = &self.encryptor {
@@ -123,11 +135,22 @@ impl System {
             messages
         };
 
-        topic
+        let partition_id = topic
             .append_messages(partitioning, messages, confirmation)
             .await?;
 
         self.metrics.increment_messages(messages_count as u64);
+        if let Some(partition_id) = partition_id {
+            self.metrics.record_messages_in(
+                &PartitionLabels {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                    partition_id,
+                },
+                messages_count,
+                messages_size,
+            );
+        }
         Ok(())
     }
 
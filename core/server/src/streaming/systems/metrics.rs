@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::diagnostics::metrics::{
+    ConsumerGroupLabels, PartitionLabels, PartitionSnapshot,
+};
+use crate::streaming::systems::system::System;
+use crate::streaming::utils::memory_pool;
+use iggy_common::Sizeable;
+use iggy_common::locking::IggySharedMutFn;
+use std::collections::HashSet;
+
+impl System {
+    /// Refreshes the metrics captured from the current state and returns them in the text format.
+    pub async fn get_formatted_metrics(&self) -> String {
+        self.refresh_metrics().await;
+        self.metrics.get_formatted_output()
+    }
+
+    async fn refresh_metrics(&self) {
+        let mut partitions = HashSet::new();
+        let mut consumer_groups = HashSet::new();
+        let mut limited_partitions = 0;
+        let mut limited_consumer_groups = 0;
+
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    let labels = PartitionLabels {
+                        stream_id: stream.stream_id,
+                        topic_id: topic.topic_id,
+                        partition_id: partition.partition_id,
+                    };
+                    let snapshot = PartitionSnapshot {
+                        current_offset: partition.current_offset,
+                        segments_count: partition.segments.len() as u32,
+                        size_bytes: partition.get_size_bytes().as_bytes_u64(),
+                        cache: partition.get_cache_metrics(),
+                        persister_queue_depth: partition
+                            .segments
+                            .iter()
+                            .map(|segment| segment.persister_queue_depth())
+                            .sum(),
+                    };
+                    if !self.metrics.update_partition(&labels, &snapshot) {
+                        limited_partitions += 1;
+                        continue;
+                    }
+
+                    for offset in partition.consumer_group_offsets.iter() {
+                        let group_labels = ConsumerGroupLabels {
+                            stream_id: labels.stream_id,
+                            topic_id: labels.topic_id,
+                            partition_id: labels.partition_id,
+                            consumer_group_id: offset.consumer_id,
+                        };
+                        let lag = partition.current_offset.saturating_sub(offset.offset);
+                        if !self.metrics.update_consumer_group_lag(&group_labels, lag) {
+                            limited_consumer_groups += 1;
+                            continue;
+                        }
+                        consumer_groups.insert(group_labels);
+                    }
+                    partitions.insert(labels);
+                }
+            }
+        }
+
+        self.metrics.retain(
+            &partitions,
+            &consumer_groups,
+            limited_partitions,
+            limited_consumer_groups,
+        );
+        self.metrics.update_memory_pool(memory_pool());
+    }
+}
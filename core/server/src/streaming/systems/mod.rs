@@ -19,8 +19,10 @@
 pub mod clients;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod health;
 pub mod info;
 pub mod messages;
+pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod segments;
@@ -20,7 +20,7 @@ use crate::VERSION;
 use crate::streaming::systems::system::System;
 use crate::versioning::SemanticVersion;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{IggyDuration, IggyError, Stats};
+use iggy_common::{CacheMetricsKey, IggyDuration, IggyError, Stats};
 use std::sync::OnceLock;
 use sysinfo::{Pid, ProcessesToUpdate, System as SysinfoSystem};
 use tokio::sync::Mutex;
@@ -106,6 +106,19 @@ impl System {
                 .values()
                 .map(|t| t.consumer_groups.len() as u32)
                 .sum::<u32>();
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    stats.cache_metrics.insert(
+                        CacheMetricsKey {
+                            stream_id: stream.stream_id,
+                            topic_id: topic.topic_id,
+                            partition_id: partition.partition_id,
+                        },
+                        partition.get_cache_metrics(),
+                    );
+                }
+            }
         }
 
         Ok(stats)
@@ -30,6 +30,7 @@ use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::health::HealthState;
 use crate::streaming::users::permissioner::Permissioner;
 use crate::streaming::users::user::User;
 use crate::versioning::SemanticVersion;
@@ -87,6 +88,7 @@ pub struct System {
     pub(crate) metrics: Metrics,
     pub(crate) state: Arc<StateKind>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
+    pub(crate) health: Arc<HealthState>,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -169,6 +171,7 @@ impl System {
         };
 
         System {
+            metrics: Metrics::init(&system_config.metrics),
             config: system_config,
             streams: AHashMap::new(),
             streams_ids: AHashMap::new(),
@@ -176,14 +179,18 @@ impl System {
             encryptor,
             client_manager: IggySharedMut::new(ClientManager::default()),
             permissioner: Permissioner::default(),
-            metrics: Metrics::init(),
             users: AHashMap::new(),
             state,
             personal_access_token: pat_config,
             archiver,
+            health: Arc::new(HealthState::default()),
         }
     }
 
+    pub fn health(&self) -> Arc<HealthState> {
+        self.health.clone()
+    }
+
     #[instrument(skip_all, name = "trace_system_init")]
     pub async fn init(&mut self) -> Result<(), IggyError> {
         let system_path = self.config.get_system_path();
@@ -232,17 +239,32 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load users")
             })?;
+        let expected_partitions = system_state
+            .streams
+            .values()
+            .flat_map(|stream| stream.topics.values())
+            .map(|topic| topic.partitions.len() as u32)
+            .sum();
         self.load_streams(system_state.streams.into_values().collect())
             .await
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load streams")
             })?;
+        let loaded_partitions = self
+            .streams
+            .values()
+            .map(|stream| stream.get_partitions_count())
+            .sum();
+        self.health
+            .set_partitions(expected_partitions, loaded_partitions);
+        self.health.set_state_loaded();
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
                 .await
                 .expect("Failed to initialize archiver");
         }
+        self.init_health().await;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
     }
@@ -28,13 +28,14 @@ use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{Confirmation, IggyTimestamp, PollingStrategy};
 use iggy_common::{IggyError, IggyExpiry, Partitioning, PartitioningKind, PollingKind};
 use std::sync::atomic::Ordering;
-use tracing::trace;
+use tracing::{instrument, trace};
 
 impl Topic {
     pub fn get_messages_count(&self) -> u64 {
         self.messages_count.load(Ordering::SeqCst)
     }
 
+    #[instrument(skip_all, name = "trace_poll_partition_messages", fields(iggy_stream_id = self.stream_id, iggy_topic_id = self.topic_id, iggy_partition_id = partition_id, iggy_polling_kind = %strategy.kind, iggy_count = count))]
     pub async fn get_messages(
         &self,
         consumer: PollingConsumer,
@@ -76,12 +77,14 @@ impl Topic {
         Ok((metadata, messages))
     }
 
+    /// Appends the messages to the partition resolved from the partitioning, and returns its ID,
+    /// or `None` if there were no messages to append.
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
         messages: IggyMessagesBatchMut,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<Option<u32>, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
@@ -93,7 +96,7 @@ impl Topic {
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
         let partition_id = match partitioning.kind {
@@ -109,7 +112,8 @@ impl Topic {
         };
 
         self.append_messages_to_partition(messages, partition_id, confirmation)
-            .await
+            .await?;
+        Ok(Some(partition_id))
     }
 
     pub async fn flush_unsaved_buffer(
@@ -130,6 +134,7 @@ impl Topic {
             .await
     }
 
+    #[instrument(skip_all, name = "trace_append_partition_messages", fields(iggy_stream_id = self.stream_id, iggy_topic_id = self.topic_id, iggy_partition_id = partition_id, iggy_messages_count = messages.count()))]
     async fn append_messages_to_partition(
         &self,
         messages: IggyMessagesBatchMut,
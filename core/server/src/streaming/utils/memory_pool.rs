@@ -330,19 +330,19 @@ impl MemoryPool {
     }
 
     /// Returns the configured maximum size of the pool, usually from config, in bytes.
-    fn pool_maximum_size(&self) -> usize {
+    pub(crate) fn pool_maximum_size(&self) -> usize {
         self.memory_limit
     }
 
     /// Sums the sizes (in bytes) of all buffers currently in use across the pool.
-    fn pool_current_size(&self) -> usize {
+    pub(crate) fn pool_current_size(&self) -> usize {
         (0..NUM_BUCKETS)
             .filter(|&i| self.bucket_current_elements(i) > 0)
             .map(|i| self.bucket_current_size(i))
             .sum()
     }
     /// Sums the sizes (in bytes) of all buffers ever allocated across the pool (historical).
-    fn pool_allocated_size(&self) -> usize {
+    pub(crate) fn pool_allocated_size(&self) -> usize {
         let mut size = 0;
         for i in 0..NUM_BUCKETS {
             size += self.bucket_allocated_size(i);
@@ -375,19 +375,19 @@ impl MemoryPool {
         self.returned[idx].load(Ordering::Acquire)
     }
 
-    fn resize_events(&self) -> usize {
+    pub(crate) fn resize_events(&self) -> usize {
         self.resize_events.load(Ordering::Acquire)
     }
 
-    fn dropped_returns(&self) -> usize {
+    pub(crate) fn dropped_returns(&self) -> usize {
         self.dropped_returns.load(Ordering::Acquire)
     }
 
-    fn external_allocations(&self) -> usize {
+    pub(crate) fn external_allocations(&self) -> usize {
         self.external_allocations.load(Ordering::Acquire)
     }
 
-    fn external_deallocations(&self) -> usize {
+    pub(crate) fn external_deallocations(&self) -> usize {
         self.external_deallocations.load(Ordering::Acquire)
     }
 
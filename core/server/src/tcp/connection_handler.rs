@@ -19,12 +19,14 @@
 use crate::binary::command::ServerCommandHandler;
 use crate::binary::{command, sender::SenderKind};
 use crate::server_error::ConnectionError;
+use crate::streaming::diagnostics::metrics::RequestTransport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::command::ServerCommand;
 use iggy_common::IggyError;
 use std::io::ErrorKind;
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
@@ -36,6 +38,7 @@ pub(crate) async fn handle_connection(
 ) -> Result<(), ConnectionError> {
     let mut length_buffer = [0u8; INITIAL_BYTES_LENGTH];
     let mut code_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let requests = system.read().await.metrics.requests();
     loop {
         let read_length = match sender.read(&mut length_buffer).await {
             Ok(read_length) => read_length,
@@ -62,7 +65,11 @@ pub(crate) async fn handle_connection(
         debug!("Received a TCP request, length: {length}, code: {code}");
         let command = ServerCommand::from_code_and_reader(code, sender, length - 4).await?;
         debug!("Received a TCP command: {command}, payload size: {length}");
-        match command.handle(sender, length, &session, &system).await {
+        let command_name = command.name();
+        let started_at = Instant::now();
+        let result = command.handle(sender, length, &session, &system).await;
+        requests.record(command_name, RequestTransport::Tcp, started_at.elapsed());
+        match result {
             Ok(_) => {
                 debug!(
                     "Command was handled successfully, session: {session}. TCP response was sent."
@@ -0,0 +1,373 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use anyhow::{Context, Result, bail};
+use iggy::prelude::IggyTimestamp;
+use rand::Rng;
+use rand::rngs::StdRng;
+use serde::Deserialize;
+
+const FIRST_NAMES: &[&str] = &[
+    "Alice", "Bob", "Carol", "Dave", "Eve", "Frank", "Grace", "Heidi", "Ivan", "Judy", "Mallory",
+    "Niaj", "Olivia", "Peggy", "Rupert", "Sybil", "Trent", "Victor", "Walter", "Zoe",
+];
+const LAST_NAMES: &[&str] = &[
+    "Smith",
+    "Johnson",
+    "Williams",
+    "Brown",
+    "Jones",
+    "Garcia",
+    "Miller",
+    "Davis",
+    "Rodriguez",
+    "Martinez",
+    "Hernandez",
+    "Lopez",
+    "Wilson",
+    "Anderson",
+    "Thomas",
+    "Taylor",
+    "Moore",
+    "Jackson",
+];
+const CITIES: &[&str] = &[
+    "Amsterdam",
+    "Berlin",
+    "Chicago",
+    "Krakow",
+    "Lisbon",
+    "London",
+    "Madrid",
+    "New York",
+    "Oslo",
+    "Paris",
+    "Prague",
+    "Seoul",
+    "Sydney",
+    "Tokyo",
+    "Toronto",
+    "Vienna",
+    "Warsaw",
+    "Zurich",
+];
+const COUNTRIES: &[&str] = &[
+    "Australia",
+    "Austria",
+    "Canada",
+    "France",
+    "Germany",
+    "Japan",
+    "Netherlands",
+    "Norway",
+    "Poland",
+    "Portugal",
+    "South Korea",
+    "Spain",
+    "Switzerland",
+    "United Kingdom",
+    "United States",
+];
+const DOMAINS: &[&str] = &["example.com", "example.org", "example.net", "mail.test"];
+const WORDS: &[&str] = &[
+    "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliet",
+    "kilo", "lima", "mike", "november", "oscar", "papa", "quebec", "romeo", "sierra", "tango",
+    "uniform", "victor", "whiskey", "xray", "yankee", "zulu",
+];
+
+/// Field generated for every message, used in templates as `{{name}}` or `{{name:arg:arg}}`.
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Key,
+    Sequence,
+    Uuid,
+    Int(i64, i64),
+    Float(f64, f64),
+    Bool,
+    FirstName,
+    LastName,
+    Name,
+    Email,
+    City,
+    Country,
+    Word,
+    Sentence(usize),
+    Choice(Vec<String>),
+    Timestamp,
+    Date,
+}
+
+impl Field {
+    fn parse(spec: &str) -> Result<Self> {
+        let mut parts = spec.trim().split(':');
+        let name = parts.next().unwrap_or_default();
+        let args = parts.collect::<Vec<_>>();
+        let range = |default: (&str, &str)| -> (String, String) {
+            match args.as_slice() {
+                [min, max] => (min.to_string(), max.to_string()),
+                _ => (default.0.to_owned(), default.1.to_owned()),
+            }
+        };
+        let field = match name {
+            "key" => Field::Key,
+            "sequence" => Field::Sequence,
+            "uuid" => Field::Uuid,
+            "int" => {
+                let (min, max) = range(("0", "1000"));
+                let (min, max) = (min.parse::<i64>()?, max.parse::<i64>()?);
+                if min > max {
+                    bail!("Invalid range of field: {spec}");
+                }
+                Field::Int(min, max)
+            }
+            "float" => {
+                let (min, max) = range(("0", "1"));
+                let (min, max) = (min.parse::<f64>()?, max.parse::<f64>()?);
+                if min > max {
+                    bail!("Invalid range of field: {spec}");
+                }
+                Field::Float(min, max)
+            }
+            "bool" => Field::Bool,
+            "first_name" => Field::FirstName,
+            "last_name" => Field::LastName,
+            "name" => Field::Name,
+            "email" => Field::Email,
+            "city" => Field::City,
+            "country" => Field::Country,
+            "word" => Field::Word,
+            "sentence" => Field::Sentence(match args.first() {
+                Some(words) => words.parse()?,
+                None => 6,
+            }),
+            "choice" if !args.is_empty() => {
+                Field::Choice(args.iter().map(|arg| arg.to_string()).collect())
+            }
+            "timestamp" => Field::Timestamp,
+            "date" => Field::Date,
+            _ => bail!("Unknown template field: {spec}"),
+        };
+        Ok(field)
+    }
+
+    fn render(&self, context: &mut TemplateContext, output: &mut String) {
+        let rng = &mut context.rng;
+        match self {
+            Field::Key => output.push_str(&context.key.unwrap_or_default().to_string()),
+            Field::Sequence => output.push_str(&context.sequence.to_string()),
+            Field::Uuid => {
+                let uuid = uuid::Builder::from_random_bytes(rng.random()).into_uuid();
+                output.push_str(&uuid.to_string());
+            }
+            Field::Int(min, max) => output.push_str(&rng.random_range(*min..=*max).to_string()),
+            Field::Float(min, max) => {
+                output.push_str(&format!("{:.2}", rng.random_range(*min..=*max)))
+            }
+            Field::Bool => output.push_str(&rng.random_bool(0.5).to_string()),
+            Field::FirstName => output.push_str(pick(rng, FIRST_NAMES)),
+            Field::LastName => output.push_str(pick(rng, LAST_NAMES)),
+            Field::Name => {
+                output.push_str(pick(rng, FIRST_NAMES));
+                output.push(' ');
+                output.push_str(pick(rng, LAST_NAMES));
+            }
+            Field::Email => {
+                let first_name = pick(rng, FIRST_NAMES).to_lowercase();
+                let last_name = pick(rng, LAST_NAMES).to_lowercase();
+                let domain = pick(rng, DOMAINS);
+                output.push_str(&format!("{first_name}.{last_name}@{domain}"));
+            }
+            Field::City => output.push_str(pick(rng, CITIES)),
+            Field::Country => output.push_str(pick(rng, COUNTRIES)),
+            Field::Word => output.push_str(pick(rng, WORDS)),
+            Field::Sentence(words) => {
+                let sentence = (0..*words)
+                    .map(|_| pick(rng, WORDS))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                output.push_str(&sentence);
+            }
+            Field::Choice(values) => output.push_str(&values[rng.random_range(0..values.len())]),
+            Field::Timestamp => output.push_str(&IggyTimestamp::now().as_micros().to_string()),
+            Field::Date => output.push_str(&IggyTimestamp::now().to_rfc3339_string()),
+        }
+    }
+}
+
+fn pick<'a>(rng: &mut StdRng, values: &[&'a str]) -> &'a str {
+    values[rng.random_range(0..values.len())]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    Field(Field),
+}
+
+/// Text with `{{field}}` placeholders replaced with generated values for every message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+/// State shared by all templates rendered for a single message.
+pub struct TemplateContext {
+    pub rng: StdRng,
+    pub key: Option<u64>,
+    pub sequence: u64,
+}
+
+impl Template {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut remaining = text;
+        while let Some(start) = remaining.find("{{") {
+            if start > 0 {
+                parts.push(Part::Text(remaining[..start].to_owned()));
+            }
+            let end = remaining[start..]
+                .find("}}")
+                .with_context(|| format!("Unclosed template field in: {text}"))?;
+            let spec = &remaining[start + 2..start + end];
+            parts.push(Part::Field(Field::parse(spec)?));
+            remaining = &remaining[start + end + 2..];
+        }
+        if !remaining.is_empty() {
+            parts.push(Part::Text(remaining.to_owned()));
+        }
+        Ok(Self { parts })
+    }
+
+    pub fn uses_key(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part, Part::Field(Field::Key)))
+    }
+
+    pub fn render(&self, context: &mut TemplateContext) -> String {
+        let mut output = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Field(field) => field.render(context, &mut output),
+            }
+        }
+        output
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DistributionKind {
+    #[default]
+    Uniform,
+    Zipf,
+}
+
+/// Generates message keys from `1` to `values`, either uniformly or following the Zipf's law,
+/// where the key `k` is chosen with probability proportional to `1 / k^exponent`.
+#[derive(Debug)]
+pub enum KeyDistribution {
+    Uniform(u64),
+    Zipf(Vec<f64>),
+}
+
+impl KeyDistribution {
+    pub fn new(kind: DistributionKind, values: u64, exponent: f64) -> Result<Self> {
+        if values == 0 {
+            bail!("Number of key values must be greater than 0");
+        }
+
+        match kind {
+            DistributionKind::Uniform => Ok(KeyDistribution::Uniform(values)),
+            DistributionKind::Zipf => {
+                if exponent <= 0.0 {
+                    bail!("Zipf exponent must be greater than 0");
+                }
+                let mut total = 0.0;
+                let mut cumulative = Vec::with_capacity(values as usize);
+                for key in 1..=values {
+                    total += 1.0 / (key as f64).powf(exponent);
+                    cumulative.push(total);
+                }
+                for weight in &mut cumulative {
+                    *weight /= total;
+                }
+                Ok(KeyDistribution::Zipf(cumulative))
+            }
+        }
+    }
+
+    pub fn sample(&self, rng: &mut StdRng) -> u64 {
+        match self {
+            KeyDistribution::Uniform(values) => rng.random_range(1..=*values),
+            KeyDistribution::Zipf(cumulative) => {
+                let value = rng.random::<f64>();
+                let index = cumulative.partition_point(|weight| *weight < value);
+                index.min(cumulative.len() - 1) as u64 + 1
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn context() -> TemplateContext {
+        TemplateContext {
+            rng: StdRng::seed_from_u64(1),
+            key: Some(7),
+            sequence: 3,
+        }
+    }
+
+    #[test]
+    fn template_should_render_text_and_fields() {
+        let template =
+            Template::parse(r#"{"key":"user-{{key}}","seq":{{sequence}},"n":{{int:5:5}}}"#)
+                .unwrap();
+
+        assert!(template.uses_key());
+        assert_eq!(
+            template.render(&mut context()),
+            r#"{"key":"user-7","seq":3,"n":5}"#
+        );
+    }
+
+    #[test]
+    fn template_should_fail_for_unknown_or_unclosed_field() {
+        assert!(Template::parse("{{unknown}}").is_err());
+        assert!(Template::parse("{{uuid").is_err());
+        assert!(Template::parse("{{int:10:1}}").is_err());
+    }
+
+    #[test]
+    fn zipf_distribution_should_prefer_lower_keys() {
+        let distribution = KeyDistribution::new(DistributionKind::Zipf, 100, 1.2).unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut counts = [0u32; 100];
+        for _ in 0..10_000 {
+            counts[distribution.sample(&mut rng) as usize - 1] += 1;
+        }
+
+        assert!(counts[0] > counts[9]);
+        assert!(counts[9] > counts[99]);
+    }
+}
@@ -16,7 +16,9 @@
  * under the License.
  */
 
+mod generator;
 mod seeder;
+mod workload;
 
 use anyhow::Result;
 use clap::Parser;
@@ -25,6 +27,7 @@ use iggy::client_provider::ClientProviderConfig;
 use iggy::clients::client::IggyClient;
 use iggy::prelude::{Aes256GcmEncryptor, Args, ArgsOptional, Client, EncryptorKind, UserClient};
 use std::error::Error;
+use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::info;
 use tracing_subscriber::layer::SubscriberExt;
@@ -42,11 +45,20 @@ pub struct DataSeederArgs {
 
     #[arg(long, default_value = "iggy")]
     pub password: String,
+
+    /// Path to the TOML workload file, the default sample data is seeded if not provided
+    #[arg(long)]
+    pub workload: Option<PathBuf>,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let args = DataSeederArgs::parse();
+    let workload = args
+        .workload
+        .as_deref()
+        .map(workload::WorkloadFile::load)
+        .transpose()?;
     let iggy_args = Args::from(vec![args.iggy.clone()]);
 
     Registry::default()
@@ -68,7 +80,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
     client.connect().await?;
     client.login_user(&username, &password).await.unwrap();
     info!("Data seeder has started...");
-    seeder::seed(&client).await.unwrap();
+    match workload {
+        Some(workload) => workload::run(Arc::new(client), workload).await?,
+        None => seeder::seed(&client).await.unwrap(),
+    }
     info!("Data seeder has finished.");
     Ok(())
 }
@@ -0,0 +1,428 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::generator::{DistributionKind, KeyDistribution, Template, TemplateContext};
+use anyhow::{Context, Result, bail};
+use iggy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, serde_as};
+use server::streaming::utils::hash;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{info, warn};
+
+const RECENT_IDS_CAPACITY: usize = 1000;
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Workload file describing the topology to create and the messages to send.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkloadFile {
+    /// Seed of the random generator, makes the generated data reproducible.
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub streams: Vec<StreamConfig>,
+    pub workloads: Vec<WorkloadConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StreamConfig {
+    pub name: String,
+    #[serde(default)]
+    pub topics: Vec<TopicConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TopicConfig {
+    pub name: String,
+    #[serde(default = "default_partitions")]
+    pub partitions: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkloadConfig {
+    pub name: Option<String>,
+    pub stream: String,
+    pub topic: String,
+    /// Target rate in messages per second, messages are sent as fast as possible if not set.
+    pub rate: Option<u32>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub duration: Option<IggyDuration>,
+    pub messages: Option<u64>,
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u32,
+    pub payload: String,
+    /// Header sets, one of them (rendered as templates) is attached to every message.
+    #[serde(default)]
+    pub headers: Vec<BTreeMap<String, String>>,
+    pub key: Option<KeyConfig>,
+    /// Fraction of messages reusing the ID of one of the recently sent messages.
+    #[serde(default)]
+    pub duplicate_ids: f64,
+    /// Maximum difference between the origin timestamp of the message and the current time.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub clock_skew: Option<IggyDuration>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeyConfig {
+    #[serde(default)]
+    pub distribution: DistributionKind,
+    pub values: u64,
+    #[serde(default = "default_exponent")]
+    pub exponent: f64,
+}
+
+fn default_partitions() -> u32 {
+    1
+}
+
+fn default_batch_size() -> u32 {
+    100
+}
+
+fn default_exponent() -> f64 {
+    1.0
+}
+
+impl WorkloadFile {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read workload file: {}", path.display()))?;
+        let file: WorkloadFile = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse workload file: {}", path.display()))?;
+        if file.workloads.is_empty() {
+            bail!("Workload file: {} contains no workloads", path.display());
+        }
+        Ok(file)
+    }
+}
+
+/// Workload ready to be run, with parsed templates and resolved partitions count.
+struct Workload {
+    name: String,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partitions_count: u32,
+    rate: Option<u32>,
+    duration: Option<Duration>,
+    messages: Option<u64>,
+    batch_size: u32,
+    payload: Template,
+    headers: Vec<Vec<(HeaderKey, Template)>>,
+    key: Option<KeyDistribution>,
+    duplicate_ids: f64,
+    clock_skew: Option<u64>,
+}
+
+impl Workload {
+    fn new(config: &WorkloadConfig, index: usize, partitions_count: u32) -> Result<Self> {
+        let name = config
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("{}/{}#{}", config.stream, config.topic, index + 1));
+        if config.duration.is_none() && config.messages.is_none() {
+            bail!("Workload: {name} must define duration or messages");
+        }
+        if config.batch_size == 0 {
+            bail!("Workload: {name} batch size must be greater than 0");
+        }
+        if config.rate == Some(0) {
+            bail!("Workload: {name} rate must be greater than 0");
+        }
+        if !(0.0..1.0).contains(&config.duplicate_ids) {
+            bail!("Workload: {name} duplicate IDs fraction must be in range [0, 1)");
+        }
+
+        let payload = Template::parse(&config.payload)
+            .with_context(|| format!("Invalid payload template of workload: {name}"))?;
+        let headers = config
+            .headers
+            .iter()
+            .map(|headers| {
+                headers
+                    .iter()
+                    .map(|(key, value)| Ok((HeaderKey::new(key)?, Template::parse(value)?)))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()
+            .with_context(|| format!("Invalid headers of workload: {name}"))?;
+        let uses_key = payload.uses_key()
+            || headers
+                .iter()
+                .flatten()
+                .any(|(_, template)| template.uses_key());
+        let key = match &config.key {
+            Some(key) => Some(KeyDistribution::new(
+                key.distribution,
+                key.values,
+                key.exponent,
+            )?),
+            None if uses_key => bail!("Workload: {name} uses {{{{key}}}} field without key"),
+            None => None,
+        };
+
+        Ok(Self {
+            name,
+            stream_id: Identifier::from_str(&config.stream)?,
+            topic_id: Identifier::from_str(&config.topic)?,
+            partitions_count,
+            rate: config.rate,
+            duration: config.duration.map(|duration| duration.get_duration()),
+            messages: config.messages,
+            batch_size: config.batch_size,
+            payload,
+            headers,
+            key,
+            duplicate_ids: config.duplicate_ids,
+            clock_skew: config.clock_skew.map(|skew| skew.as_micros()),
+        })
+    }
+
+    /// Partition chosen the same way as the server does for messages key partitioning.
+    fn partition_id(&self, key: u64) -> u32 {
+        let partition_id = hash::calculate_32(key.to_string().as_bytes()) % self.partitions_count;
+        match partition_id {
+            0 => self.partitions_count,
+            partition_id => partition_id,
+        }
+    }
+}
+
+struct WorkloadStats {
+    messages: u64,
+    duplicates: u64,
+    elapsed: Duration,
+}
+
+pub async fn run(client: Arc<IggyClient>, file: WorkloadFile) -> Result<()> {
+    create_topology(&client, &file.streams).await?;
+
+    let mut workloads = Vec::with_capacity(file.workloads.len());
+    for (index, config) in file.workloads.iter().enumerate() {
+        let stream_id = Identifier::from_str(&config.stream)?;
+        let topic_id = Identifier::from_str(&config.topic)?;
+        let topic = client
+            .get_topic(&stream_id, &topic_id)
+            .await?
+            .with_context(|| {
+                format!(
+                    "Topic: {} in stream: {} does not exist",
+                    config.topic, config.stream
+                )
+            })?;
+        workloads.push(Workload::new(config, index, topic.partitions_count)?);
+    }
+
+    let mut tasks = Vec::with_capacity(workloads.len());
+    for (index, workload) in workloads.into_iter().enumerate() {
+        let rng = match file.seed {
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(index as u64)),
+            None => StdRng::from_os_rng(),
+        };
+        let client = client.clone();
+        tasks.push(tokio::spawn(async move {
+            let stats = run_workload(&client, &workload, rng).await;
+            (workload.name, stats)
+        }));
+    }
+
+    for task in tasks {
+        let (name, stats) = task.await?;
+        let stats = stats.with_context(|| format!("Workload: {name} failed"))?;
+        let elapsed = stats.elapsed.as_secs_f64();
+        info!(
+            "Workload: {name} sent {} messages ({} with duplicated IDs) in {elapsed:.2} s, {:.0} msg/s",
+            stats.messages,
+            stats.duplicates,
+            stats.messages as f64 / elapsed.max(f64::EPSILON)
+        );
+    }
+
+    Ok(())
+}
+
+async fn create_topology(client: &IggyClient, streams: &[StreamConfig]) -> Result<()> {
+    for stream in streams {
+        let stream_id = Identifier::from_str(&stream.name)?;
+        if client.get_stream(&stream_id).await?.is_none() {
+            client.create_stream(&stream.name, None).await?;
+            info!("Created stream: {}", stream.name);
+        }
+
+        for topic in &stream.topics {
+            let topic_id = Identifier::from_str(&topic.name)?;
+            match client.get_topic(&stream_id, &topic_id).await? {
+                Some(existing) if existing.partitions_count != topic.partitions => warn!(
+                    "Topic: {} in stream: {} already exists with {} partitions instead of {}",
+                    topic.name, stream.name, existing.partitions_count, topic.partitions
+                ),
+                Some(_) => {}
+                None => {
+                    client
+                        .create_topic(
+                            &stream_id,
+                            &topic.name,
+                            topic.partitions,
+                            Default::default(),
+                            None,
+                            None,
+                            IggyExpiry::NeverExpire,
+                            MaxTopicSize::ServerDefault,
+                        )
+                        .await?;
+                    info!(
+                        "Created topic: {} with {} partitions in stream: {}",
+                        topic.name, topic.partitions, stream.name
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn run_workload(
+    client: &IggyClient,
+    workload: &Workload,
+    rng: StdRng,
+) -> Result<WorkloadStats> {
+    let mut context = TemplateContext {
+        rng,
+        key: None,
+        sequence: 0,
+    };
+    let mut recent_ids = VecDeque::with_capacity(RECENT_IDS_CAPACITY);
+    let mut duplicates = 0;
+    let start = Instant::now();
+    let mut last_progress = start;
+    let balanced = Partitioning::balanced();
+    info!("Workload: {} has started...", workload.name);
+
+    loop {
+        let remaining = workload
+            .messages
+            .map_or(u64::MAX, |messages| messages - context.sequence);
+        let expired = workload
+            .duration
+            .is_some_and(|duration| start.elapsed() >= duration);
+        if remaining == 0 || expired {
+            break;
+        }
+
+        let count = remaining.min(workload.batch_size as u64);
+        let mut batches: HashMap<u32, Vec<IggyMessage>> = HashMap::new();
+        for _ in 0..count {
+            context.sequence += 1;
+            context.key = workload
+                .key
+                .as_ref()
+                .map(|distribution| distribution.sample(&mut context.rng));
+
+            let id = if !recent_ids.is_empty() && context.rng.random_bool(workload.duplicate_ids) {
+                duplicates += 1;
+                recent_ids[context.rng.random_range(0..recent_ids.len())]
+            } else {
+                // IDs are kept within u64 range, as HTTP transport sends them as JSON numbers.
+                let id = context.rng.random_range(1..=u64::MAX) as u128;
+                if recent_ids.len() == RECENT_IDS_CAPACITY {
+                    recent_ids.pop_front();
+                }
+                recent_ids.push_back(id);
+                id
+            };
+
+            let payload = workload.payload.render(&mut context);
+            let headers = match workload.headers.len() {
+                0 => None,
+                count => {
+                    let headers = &workload.headers[context.rng.random_range(0..count)];
+                    let mut user_headers = HashMap::with_capacity(headers.len());
+                    for (key, template) in headers {
+                        user_headers.insert(
+                            key.clone(),
+                            HeaderValue::from_str(&template.render(&mut context))?,
+                        );
+                    }
+                    Some(user_headers).filter(|headers| !headers.is_empty())
+                }
+            };
+
+            let mut message = IggyMessage::builder()
+                .id(id)
+                .payload(payload.into())
+                .maybe_user_headers(headers)
+                .build()?;
+            if let Some(clock_skew) = workload.clock_skew {
+                let skew = context.rng.random_range(0..=clock_skew * 2);
+                message.header.origin_timestamp =
+                    (message.header.origin_timestamp + skew).saturating_sub(clock_skew);
+            }
+
+            let partition_id = context.key.map_or(0, |key| workload.partition_id(key));
+            batches.entry(partition_id).or_default().push(message);
+        }
+
+        for (partition_id, mut messages) in batches {
+            let partitioning = match partition_id {
+                0 => balanced.clone(),
+                partition_id => Partitioning::partition_id(partition_id),
+            };
+            client
+                .send_messages(
+                    &workload.stream_id,
+                    &workload.topic_id,
+                    &partitioning,
+                    &mut messages,
+                )
+                .await?;
+        }
+
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            last_progress = Instant::now();
+            info!(
+                "Workload: {} sent {} messages...",
+                workload.name, context.sequence
+            );
+        }
+
+        if let Some(rate) = workload.rate {
+            let target = start + Duration::from_secs_f64(context.sequence as f64 / rate as f64);
+            tokio::time::sleep_until(target).await;
+        }
+    }
+
+    Ok(WorkloadStats {
+        messages: context.sequence,
+        duplicates,
+        elapsed: start.elapsed(),
+    })
+}
@@ -0,0 +1,367 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod segment;
+mod state;
+
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+use iggy::prelude::{
+    Aes256GcmEncryptor, BytesSerializable, EncryptorKind, HeaderKey, HeaderValue, IggyByteSize,
+    IggyMessageView, IggyTimestamp,
+};
+use segment::{SegmentFiles, SegmentIssue, SegmentScan};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Offline inspection and repair of the server data directory, the server must not be running.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct SegmentToolArgs {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print message headers (and optionally payloads) stored in a segment
+    Dump {
+        /// Path to the segment `.log` or `.index` file
+        path: PathBuf,
+
+        /// Print only messages with offset greater than or equal to this one
+        #[arg(short, long)]
+        offset: Option<u64>,
+
+        /// Maximum number of messages to print
+        #[arg(short, long)]
+        count: Option<usize>,
+
+        /// Print message payloads and user headers
+        #[arg(short, long, default_value_t = false)]
+        payload: bool,
+
+        /// Print index file entries
+        #[arg(short, long, default_value_t = false)]
+        indexes: bool,
+    },
+    /// Verify checksums and index/log consistency of segments
+    Verify {
+        /// Path to a segment file or a directory (e.g. system, stream or partition directory)
+        path: PathBuf,
+    },
+    /// Truncate torn writes and rebuild missing or inconsistent indexes
+    Repair {
+        /// Path to a segment file or a directory (e.g. system, stream or partition directory)
+        path: PathBuf,
+
+        /// Rebuild index files even if they are consistent with the log files
+        #[arg(short, long, default_value_t = false)]
+        rebuild_index: bool,
+
+        /// Truncate the log at the corruption even if it is followed by valid messages, which are lost
+        #[arg(short, long, default_value_t = false)]
+        force: bool,
+
+        /// Only print what would be repaired
+        #[arg(short, long, default_value_t = false)]
+        dry_run: bool,
+    },
+    /// Print entries of the state log
+    State {
+        /// Path to the state log file or the system directory
+        path: PathBuf,
+
+        /// Base64 encoded key used by the server to encrypt the state
+        #[arg(short, long)]
+        encryption_key: Option<String>,
+    },
+}
+
+fn main() -> Result<()> {
+    let args = SegmentToolArgs::parse();
+    match args.command {
+        Command::Dump {
+            path,
+            offset,
+            count,
+            payload,
+            indexes,
+        } => dump(&path, offset, count, payload, indexes),
+        Command::Verify { path } => verify(&path),
+        Command::Repair {
+            path,
+            rebuild_index,
+            force,
+            dry_run,
+        } => repair(&path, rebuild_index, force, dry_run),
+        Command::State {
+            path,
+            encryption_key,
+        } => print_state(&path, encryption_key),
+    }
+}
+
+fn print_segment(segment: &SegmentFiles, scan: &SegmentScan) {
+    println!(
+        "Segment: {segment}, start offset: {}, size: {}, messages: {}, index entries: {}",
+        scan.start_offset,
+        IggyByteSize::from(scan.log_size as u64).as_human_string(),
+        scan.valid_messages().len(),
+        scan.indexes.len()
+    );
+}
+
+fn dump(
+    path: &Path,
+    offset: Option<u64>,
+    count: Option<usize>,
+    payload: bool,
+    indexes: bool,
+) -> Result<()> {
+    let segment = SegmentFiles::from_path(path)?;
+    let log = segment.read_log()?;
+    let index = segment.read_index()?;
+    let scan = SegmentScan::new(segment.start_offset, &log, index.as_deref());
+    print_segment(&segment, &scan);
+
+    let messages = scan
+        .messages
+        .iter()
+        .filter(|message| offset.is_none_or(|offset| message.header.offset >= offset))
+        .take(count.unwrap_or(usize::MAX));
+    for message in messages {
+        let header = &message.header;
+        let checksum = match message.is_checksum_valid() {
+            true => "valid".to_owned(),
+            false => format!("invalid, calculated: {}", message.calculated_checksum),
+        };
+        println!(
+            "offset: {}, position: {}, size: {} B, ID: {}, timestamp: {}, origin timestamp: {}, checksum: {} ({checksum}), user headers: {} B, payload: {} B",
+            header.offset,
+            message.position,
+            message.size,
+            header.id,
+            IggyTimestamp::from(header.timestamp).to_rfc3339_string(),
+            IggyTimestamp::from(header.origin_timestamp).to_rfc3339_string(),
+            header.checksum,
+            header.user_headers_length,
+            header.payload_length,
+        );
+        if payload {
+            let view = IggyMessageView::new(&log[message.position as usize..]);
+            println!("  payload: {}", format_bytes(view.payload()));
+            if let Some(user_headers) = view.user_headers() {
+                match HashMap::<HeaderKey, HeaderValue>::from_bytes(user_headers.to_vec().into()) {
+                    Ok(user_headers) => {
+                        let mut user_headers = user_headers
+                            .iter()
+                            .map(|(key, value)| format!("{key}: {value}"))
+                            .collect::<Vec<_>>();
+                        user_headers.sort();
+                        println!("  user headers: {}", user_headers.join(", "));
+                    }
+                    Err(error) => {
+                        println!("  user headers: {}, {error}", format_bytes(user_headers))
+                    }
+                }
+            }
+        }
+    }
+
+    for issue in scan
+        .issues
+        .iter()
+        .filter(|issue| matches!(issue, SegmentIssue::Corruption { .. }))
+    {
+        println!("{issue}");
+    }
+    if scan.has_torn_write() {
+        println!(
+            "Torn write of {} bytes at position: {}",
+            scan.log_size - scan.valid_size,
+            scan.valid_size
+        );
+    }
+
+    if indexes {
+        for (entry, index) in scan.indexes.iter().enumerate() {
+            println!("index entry: {entry}, {index}");
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(path: &Path) -> Result<()> {
+    let segments = SegmentFiles::find_all(path)?;
+    let mut issues_count = 0;
+    for segment in &segments {
+        let scan = segment.scan()?;
+        print_segment(segment, &scan);
+        for issue in &scan.issues {
+            println!("  {issue}");
+        }
+        issues_count += scan.issues.len();
+    }
+
+    println!(
+        "Verified {} segments, found {issues_count} issues",
+        segments.len()
+    );
+    if issues_count > 0 {
+        bail!("Segments verification failed");
+    }
+
+    Ok(())
+}
+
+fn repair(path: &Path, rebuild_index: bool, force: bool, dry_run: bool) -> Result<()> {
+    let segments = SegmentFiles::find_all(path)?;
+    let mut repaired_count = 0;
+    let mut skipped_count = 0;
+    for segment in &segments {
+        let scan = segment::repair(segment, rebuild_index, force, dry_run)?;
+        if !scan.can_repair(force) {
+            skipped_count += 1;
+            print_segment(segment, &scan);
+            for issue in scan
+                .issues
+                .iter()
+                .filter(|issue| matches!(issue, SegmentIssue::Corruption { .. }))
+            {
+                println!("  {issue}");
+            }
+            println!(
+                "  skipped, truncating the log would drop valid messages, use --force to truncate it at position: {}",
+                scan.repaired_size(true)
+            );
+            continue;
+        }
+
+        let repaired_size = scan.repaired_size(force);
+        let repaired_messages = scan.repaired_messages(force);
+        let truncate = repaired_size < scan.log_size;
+        let rebuild_index = rebuild_index || scan.has_index_issues() || scan.has_corruption();
+        if !truncate && !rebuild_index {
+            continue;
+        }
+
+        repaired_count += 1;
+        print_segment(segment, &scan);
+        let (truncate_verb, rebuild_verb) = match dry_run {
+            true => ("would truncate", "would rebuild"),
+            false => ("truncated", "rebuilt"),
+        };
+        if truncate {
+            let truncated = match scan.has_corruption() {
+                true => "corrupted log",
+                false => "torn write",
+            };
+            println!(
+                "  {truncate_verb} {truncated} of {} bytes at position: {repaired_size}, containing {} complete messages",
+                scan.log_size - repaired_size,
+                scan.messages.len() - repaired_messages.len()
+            );
+        }
+        if rebuild_index {
+            println!(
+                "  {rebuild_verb} index file with {} entries",
+                repaired_messages.len()
+            );
+        }
+        for message in repaired_messages
+            .iter()
+            .filter(|message| !message.is_checksum_valid())
+        {
+            println!(
+                "  message with offset: {} at position: {} has invalid checksum and cannot be repaired",
+                message.header.offset, message.position
+            );
+        }
+    }
+
+    match dry_run {
+        true => println!(
+            "Dry run, {repaired_count} of {} segments would be repaired",
+            segments.len()
+        ),
+        false => println!("Repaired {repaired_count} of {} segments", segments.len()),
+    }
+    if skipped_count > 0 {
+        bail!("{skipped_count} segments with corruption followed by valid messages were skipped");
+    }
+
+    Ok(())
+}
+
+fn print_state(path: &Path, encryption_key: Option<String>) -> Result<()> {
+    let path = match path.is_dir() {
+        true => path.join("state").join("log"),
+        false => path.to_path_buf(),
+    };
+    let encryptor = match encryption_key {
+        Some(key) => Some(EncryptorKind::Aes256Gcm(
+            Aes256GcmEncryptor::from_base64_key(&key)?,
+        )),
+        None => None,
+    };
+
+    let state = state::read(&path, encryptor.as_ref())?;
+    println!(
+        "State log: {}, size: {}, entries: {}",
+        path.display(),
+        IggyByteSize::from(state.file_size as u64).as_human_string(),
+        state.entries.len()
+    );
+    for entry in &state.entries {
+        let checksum = match entry.entry.checksum == entry.stored_checksum {
+            true => "valid".to_owned(),
+            false => format!("invalid, calculated: {}", entry.entry.checksum),
+        };
+        println!(
+            "index: {}, term: {}, leader ID: {}, version: {}, timestamp: {}, user ID: {}, checksum: {} ({checksum})",
+            entry.entry.index,
+            entry.entry.term,
+            entry.entry.leader_id,
+            entry.entry.version,
+            entry.entry.timestamp.to_rfc3339_string(),
+            entry.entry.user_id,
+            entry.stored_checksum,
+        );
+        match &entry.command {
+            Ok(command) => println!("  {command}"),
+            Err(error) => println!("  {error:#}"),
+        }
+    }
+
+    if let Some(error) = &state.error {
+        println!(
+            "Stopped reading at position: {} of {} bytes, {error}",
+            state.read_size, state.file_size
+        );
+    }
+    Ok(())
+}
+
+fn format_bytes(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => format!("{text:?}"),
+        Err(_) => bytes.iter().map(|byte| format!("{byte:02x}")).collect(),
+    }
+}
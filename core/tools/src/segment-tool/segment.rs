@@ -0,0 +1,722 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use anyhow::{Context, Result};
+use iggy::prelude::{
+    IGGY_MESSAGE_HEADER_SIZE, INDEX_SIZE, IggyIndexView, IggyMessageHeader, IggyMessageView,
+};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+const LOG_EXTENSION: &str = "log";
+const INDEX_EXTENSION: &str = "index";
+
+/// Pair of `.log` and `.index` files sharing the same start offset.
+#[derive(Debug)]
+pub struct SegmentFiles {
+    pub start_offset: u64,
+    pub log_path: PathBuf,
+    pub index_path: PathBuf,
+}
+
+impl SegmentFiles {
+    /// Resolves a segment from its `.log` or `.index` file, or from the path without extension.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let base = match path.extension() {
+            Some(extension) if extension == LOG_EXTENSION || extension == INDEX_EXTENSION => {
+                path.with_extension("")
+            }
+            _ => path.to_path_buf(),
+        };
+        let start_offset = base
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<u64>().ok())
+            .with_context(|| format!("Invalid segment file name: {}", path.display()))?;
+
+        Ok(Self {
+            start_offset,
+            log_path: base.with_extension(LOG_EXTENSION),
+            index_path: base.with_extension(INDEX_EXTENSION),
+        })
+    }
+
+    /// Finds all segments in the given directory (recursively) or returns the single segment.
+    pub fn find_all(path: &Path) -> Result<Vec<Self>> {
+        if !path.is_dir() {
+            return Ok(vec![Self::from_path(path)?]);
+        }
+
+        let mut log_paths = Vec::new();
+        let mut directories = vec![path.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            for entry in std::fs::read_dir(&directory)
+                .with_context(|| format!("Failed to read directory: {}", directory.display()))?
+            {
+                let entry_path = entry?.path();
+                if entry_path.is_dir() {
+                    directories.push(entry_path);
+                } else if entry_path
+                    .extension()
+                    .is_some_and(|extension| extension == LOG_EXTENSION)
+                {
+                    log_paths.push(entry_path);
+                }
+            }
+        }
+
+        log_paths.sort();
+        log_paths
+            .iter()
+            .map(|log_path| Self::from_path(log_path))
+            .collect()
+    }
+
+    pub fn read_log(&self) -> Result<Vec<u8>> {
+        std::fs::read(&self.log_path)
+            .with_context(|| format!("Failed to read log file: {}", self.log_path.display()))
+    }
+
+    pub fn read_index(&self) -> Result<Option<Vec<u8>>> {
+        if !self.index_path.exists() {
+            return Ok(None);
+        }
+
+        std::fs::read(&self.index_path)
+            .map(Some)
+            .with_context(|| format!("Failed to read index file: {}", self.index_path.display()))
+    }
+
+    /// Scans the segment files, see [`SegmentScan::new`].
+    pub fn scan(&self) -> Result<SegmentScan> {
+        let log = self.read_log()?;
+        let index = self.read_index()?;
+        Ok(SegmentScan::new(self.start_offset, &log, index.as_deref()))
+    }
+}
+
+impl Display for SegmentFiles {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.log_path.with_extension("").display())
+    }
+}
+
+/// Message found in the log file, `position` is the byte position of its first byte.
+#[derive(Debug)]
+pub struct MessageEntry {
+    pub position: u32,
+    pub size: u32,
+    pub header: IggyMessageHeader,
+    pub calculated_checksum: u64,
+}
+
+impl MessageEntry {
+    pub fn end_position(&self) -> u32 {
+        self.position + self.size
+    }
+
+    pub fn is_checksum_valid(&self) -> bool {
+        self.header.checksum == self.calculated_checksum
+    }
+}
+
+/// Index entry as stored in the index file: relative offset, end position of the message and timestamp.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct IndexEntry {
+    pub offset: u32,
+    pub position: u32,
+    pub timestamp: u64,
+}
+
+impl IndexEntry {
+    fn expected(start_offset: u64, message: &MessageEntry) -> Self {
+        Self {
+            offset: message.header.offset.saturating_sub(start_offset) as u32,
+            position: message.end_position(),
+            timestamp: message.header.timestamp,
+        }
+    }
+}
+
+impl Display for IndexEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "offset: {}, position: {}, timestamp: {}",
+            self.offset, self.position, self.timestamp
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SegmentIssue {
+    TornWrite {
+        position: u32,
+        size: u32,
+    },
+    Corruption {
+        position: u32,
+        size: u32,
+    },
+    InvalidChecksum {
+        offset: u64,
+        position: u32,
+        stored: u64,
+        calculated: u64,
+    },
+    OffsetNotIncreasing {
+        offset: u64,
+        position: u32,
+        previous_offset: u64,
+    },
+    MissingIndex,
+    UnalignedIndex {
+        size: usize,
+    },
+    IndexCountMismatch {
+        indexes: usize,
+        messages: usize,
+    },
+    IndexMismatch {
+        entry: usize,
+        expected: IndexEntry,
+        actual: IndexEntry,
+    },
+}
+
+impl SegmentIssue {
+    /// Returns true if the issue can be fixed by rebuilding the index file.
+    pub fn is_index_issue(&self) -> bool {
+        matches!(
+            self,
+            SegmentIssue::MissingIndex
+                | SegmentIssue::UnalignedIndex { .. }
+                | SegmentIssue::IndexCountMismatch { .. }
+                | SegmentIssue::IndexMismatch { .. }
+        )
+    }
+}
+
+impl Display for SegmentIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SegmentIssue::TornWrite { position, size } => write!(
+                f,
+                "torn write of {size} bytes at position: {position} in log file"
+            ),
+            SegmentIssue::Corruption { position, size } => write!(
+                f,
+                "corruption of {size} bytes at position: {position} in log file, followed by valid messages"
+            ),
+            SegmentIssue::InvalidChecksum {
+                offset,
+                position,
+                stored,
+                calculated,
+            } => write!(
+                f,
+                "invalid checksum of message with offset: {offset} at position: {position}, stored: {stored}, calculated: {calculated}"
+            ),
+            SegmentIssue::OffsetNotIncreasing {
+                offset,
+                position,
+                previous_offset,
+            } => write!(
+                f,
+                "offset: {offset} of message at position: {position} is not greater than previous offset: {previous_offset}"
+            ),
+            SegmentIssue::MissingIndex => write!(f, "index file is missing"),
+            SegmentIssue::UnalignedIndex { size } => write!(
+                f,
+                "index file size: {size} is not a multiple of index size: {INDEX_SIZE}"
+            ),
+            SegmentIssue::IndexCountMismatch { indexes, messages } => write!(
+                f,
+                "index file contains {indexes} entries, log file contains {messages} messages"
+            ),
+            SegmentIssue::IndexMismatch {
+                entry,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "index entry: {entry} is {{ {actual} }}, expected {{ {expected} }}"
+            ),
+        }
+    }
+}
+
+/// Result of scanning a segment log file and comparing it with its index file.
+#[derive(Debug)]
+pub struct SegmentScan {
+    pub start_offset: u64,
+    pub log_size: u32,
+    /// Size of the log file without the torn write at its end.
+    pub valid_size: u32,
+    /// All messages found in the log file, including the ones belonging to the torn write
+    /// and the ones following the corrupted parts of the log.
+    pub messages: Vec<MessageEntry>,
+    pub valid_messages_count: usize,
+    pub indexes: Vec<IndexEntry>,
+    pub issues: Vec<SegmentIssue>,
+}
+
+impl SegmentScan {
+    /// Reads all messages from the log and verifies their framing and checksums.
+    ///
+    /// When the frame at some position is broken, the scan resyncs on the next message
+    /// with a valid checksum, and the skipped bytes are reported as a corruption.
+    /// Incomplete message at the end of the log and complete messages with invalid checksums
+    /// which are not followed by any valid message are treated as a torn write.
+    /// Index entries are compared with the messages which remain after cutting off the torn write,
+    /// as the log would look after the repair.
+    pub fn new(start_offset: u64, log: &[u8], index: Option<&[u8]>) -> Self {
+        let mut messages = Vec::new();
+        let mut issues = Vec::new();
+        let mut position = 0;
+        while position + IGGY_MESSAGE_HEADER_SIZE <= log.len() {
+            let message = read_message(log, position);
+            if let Some(message) = message {
+                if message.is_checksum_valid() {
+                    position = message.end_position() as usize;
+                    messages.push(message);
+                    continue;
+                }
+
+                // Complete frame with invalid checksum is kept, unless its (possibly corrupted)
+                // size covers the beginning of a valid message.
+                let end_position = message.end_position() as usize;
+                match find_valid_message(log, position + 1, end_position) {
+                    None => {
+                        position = end_position;
+                        messages.push(message);
+                    }
+                    Some(next_position) => {
+                        issues.push(SegmentIssue::Corruption {
+                            position: position as u32,
+                            size: (next_position - position) as u32,
+                        });
+                        position = next_position;
+                    }
+                }
+                continue;
+            }
+
+            let Some(next_position) = find_valid_message(log, position + 1, log.len()) else {
+                break;
+            };
+            issues.push(SegmentIssue::Corruption {
+                position: position as u32,
+                size: (next_position - position) as u32,
+            });
+            position = next_position;
+        }
+
+        let valid_messages = messages
+            .iter()
+            .rposition(|message| message.is_checksum_valid())
+            .map_or(0, |last_valid| last_valid + 1);
+        let valid_size = valid_messages
+            .checked_sub(1)
+            .map_or(0, |last_valid| messages[last_valid].end_position());
+
+        let mut previous_offset = None;
+        for message in &messages {
+            if !message.is_checksum_valid() {
+                issues.push(SegmentIssue::InvalidChecksum {
+                    offset: message.header.offset,
+                    position: message.position,
+                    stored: message.header.checksum,
+                    calculated: message.calculated_checksum,
+                });
+            }
+            if let Some(previous_offset) = previous_offset
+                && message.header.offset <= previous_offset
+            {
+                issues.push(SegmentIssue::OffsetNotIncreasing {
+                    offset: message.header.offset,
+                    position: message.position,
+                    previous_offset,
+                });
+            }
+            previous_offset = Some(message.header.offset);
+        }
+
+        if (valid_size as usize) < log.len() {
+            issues.push(SegmentIssue::TornWrite {
+                position: valid_size,
+                size: (log.len() - valid_size as usize) as u32,
+            });
+        }
+
+        let indexes = index.map(parse_indexes).unwrap_or_default();
+        match index {
+            None => issues.push(SegmentIssue::MissingIndex),
+            Some(index) => {
+                if index.len() % INDEX_SIZE != 0 {
+                    issues.push(SegmentIssue::UnalignedIndex { size: index.len() });
+                }
+                if indexes.len() != valid_messages {
+                    issues.push(SegmentIssue::IndexCountMismatch {
+                        indexes: indexes.len(),
+                        messages: valid_messages,
+                    });
+                }
+                for (entry, (actual, message)) in
+                    indexes.iter().zip(&messages[..valid_messages]).enumerate()
+                {
+                    let expected = IndexEntry::expected(start_offset, message);
+                    if *actual != expected {
+                        issues.push(SegmentIssue::IndexMismatch {
+                            entry,
+                            expected,
+                            actual: *actual,
+                        });
+                    }
+                }
+            }
+        }
+
+        Self {
+            start_offset,
+            log_size: log.len() as u32,
+            valid_size,
+            messages,
+            valid_messages_count: valid_messages,
+            indexes,
+            issues,
+        }
+    }
+
+    /// Messages which remain in the log after cutting off the torn write.
+    pub fn valid_messages(&self) -> &[MessageEntry] {
+        &self.messages[..self.valid_messages_count]
+    }
+
+    pub fn has_torn_write(&self) -> bool {
+        self.valid_size < self.log_size
+    }
+
+    /// Position of the first corrupted part of the log which is followed by valid messages.
+    pub fn corruption_position(&self) -> Option<u32> {
+        self.issues.iter().find_map(|issue| match issue {
+            SegmentIssue::Corruption { position, .. } => Some(*position),
+            _ => None,
+        })
+    }
+
+    pub fn has_corruption(&self) -> bool {
+        self.corruption_position().is_some()
+    }
+
+    /// Returns false if the repair would have to drop valid messages following the corruption,
+    /// which happens only when forced.
+    pub fn can_repair(&self, force: bool) -> bool {
+        force || !self.has_corruption()
+    }
+
+    /// Messages which remain in the log after the repair, when forced the log is truncated
+    /// at the first corruption and the valid messages following it are dropped as well.
+    pub fn repaired_messages(&self, force: bool) -> &[MessageEntry] {
+        let Some(corruption_position) = self.corruption_position().filter(|_| force) else {
+            return self.valid_messages();
+        };
+
+        let messages = &self.messages[..self
+            .messages
+            .partition_point(|message| message.position < corruption_position)];
+        let count = messages
+            .iter()
+            .rposition(|message| message.is_checksum_valid())
+            .map_or(0, |last_valid| last_valid + 1);
+        &messages[..count]
+    }
+
+    /// Size of the log file after the repair, see [`SegmentScan::repaired_messages`].
+    pub fn repaired_size(&self, force: bool) -> u32 {
+        match self.corruption_position().filter(|_| force) {
+            Some(_) => self
+                .repaired_messages(force)
+                .last()
+                .map_or(0, MessageEntry::end_position),
+            None => self.valid_size,
+        }
+    }
+
+    pub fn has_index_issues(&self) -> bool {
+        self.issues.iter().any(SegmentIssue::is_index_issue)
+    }
+
+    /// Builds the index file content matching the messages remaining in the log file after the repair.
+    pub fn build_index(&self, force: bool) -> Vec<u8> {
+        let messages = self.repaired_messages(force);
+        let mut index = Vec::with_capacity(messages.len() * INDEX_SIZE);
+        for message in messages {
+            let entry = IndexEntry::expected(self.start_offset, message);
+            index.extend_from_slice(&entry.offset.to_le_bytes());
+            index.extend_from_slice(&entry.position.to_le_bytes());
+            index.extend_from_slice(&entry.timestamp.to_le_bytes());
+        }
+        index
+    }
+}
+
+/// Reads the complete message starting at the given position, its checksum is not verified.
+fn read_message(log: &[u8], position: usize) -> Option<MessageEntry> {
+    let remaining = &log[position..];
+    if remaining.len() < IGGY_MESSAGE_HEADER_SIZE {
+        return None;
+    }
+
+    let view = IggyMessageView::new(remaining);
+    view.validate().ok()?;
+    Some(MessageEntry {
+        position: position as u32,
+        size: view.size() as u32,
+        header: view.header().to_header(),
+        calculated_checksum: view.calculate_checksum(),
+    })
+}
+
+/// Finds the first position in the given range at which a complete message with a valid checksum starts.
+fn find_valid_message(log: &[u8], from: usize, to: usize) -> Option<usize> {
+    (from..to.min(log.len())).find(|&position| {
+        read_message(log, position).is_some_and(|message| message.is_checksum_valid())
+    })
+}
+
+fn parse_indexes(index: &[u8]) -> Vec<IndexEntry> {
+    index
+        .chunks_exact(INDEX_SIZE)
+        .map(|chunk| {
+            let view = IggyIndexView::new(chunk);
+            IndexEntry {
+                offset: view.offset(),
+                position: view.position(),
+                timestamp: view.timestamp(),
+            }
+        })
+        .collect()
+}
+
+/// Truncates the torn write and rebuilds the index file if needed, returns the scan before the repair.
+///
+/// Segment with a corruption followed by valid messages is left untouched, unless forced,
+/// in which case the log is truncated at the corruption.
+pub fn repair(
+    segment: &SegmentFiles,
+    rebuild_index: bool,
+    force: bool,
+    dry_run: bool,
+) -> Result<SegmentScan> {
+    let scan = segment.scan()?;
+    if dry_run || !scan.can_repair(force) {
+        return Ok(scan);
+    }
+
+    let repaired_size = scan.repaired_size(force);
+    if repaired_size < scan.log_size {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&segment.log_path)
+            .with_context(|| format!("Failed to open log file: {}", segment.log_path.display()))?;
+        file.set_len(repaired_size as u64)?;
+        file.sync_all()?;
+    }
+
+    if rebuild_index || scan.has_index_issues() || scan.has_corruption() {
+        let temp_path = segment.index_path.with_extension("index.tmp");
+        std::fs::write(&temp_path, scan.build_index(force))
+            .with_context(|| format!("Failed to write index file: {}", temp_path.display()))?;
+        std::fs::rename(&temp_path, &segment.index_path)?;
+    }
+
+    Ok(scan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::prelude::{
+        IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADERS_LENGTH_OFFSET_RANGE,
+        IGGY_MESSAGE_OFFSET_OFFSET_RANGE, IGGY_MESSAGE_PAYLOAD_LENGTH_OFFSET_RANGE,
+        IGGY_MESSAGE_TIMESTAMP_OFFSET_RANGE,
+    };
+
+    const START_OFFSET: u64 = 100;
+
+    fn message(offset: u64, payload: &[u8]) -> Vec<u8> {
+        let mut message = vec![0; IGGY_MESSAGE_HEADER_SIZE];
+        message[IGGY_MESSAGE_OFFSET_OFFSET_RANGE].copy_from_slice(&offset.to_le_bytes());
+        message[IGGY_MESSAGE_TIMESTAMP_OFFSET_RANGE].copy_from_slice(&(offset * 10).to_le_bytes());
+        message[IGGY_MESSAGE_HEADERS_LENGTH_OFFSET_RANGE].copy_from_slice(&0u32.to_le_bytes());
+        message[IGGY_MESSAGE_PAYLOAD_LENGTH_OFFSET_RANGE]
+            .copy_from_slice(&(payload.len() as u32).to_le_bytes());
+        message.extend_from_slice(payload);
+        let checksum = IggyMessageView::new(&message).calculate_checksum();
+        message[IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE].copy_from_slice(&checksum.to_le_bytes());
+        message
+    }
+
+    fn segment(count: u64) -> Vec<u8> {
+        (0..count)
+            .flat_map(|i| message(START_OFFSET + i, format!("message-{i}").as_bytes()))
+            .collect()
+    }
+
+    fn index_for(log: &[u8]) -> Vec<u8> {
+        SegmentScan::new(START_OFFSET, log, None).build_index(false)
+    }
+
+    #[test]
+    fn consistent_segment_should_have_no_issues() {
+        let log = segment(3);
+        let index = index_for(&log);
+
+        let scan = SegmentScan::new(START_OFFSET, &log, Some(&index));
+
+        assert!(scan.issues.is_empty());
+        assert_eq!(scan.valid_messages().len(), 3);
+        assert_eq!(scan.valid_size, log.len() as u32);
+        assert_eq!(scan.indexes[2].offset, 2);
+        assert_eq!(scan.indexes[2].position, log.len() as u32);
+    }
+
+    #[test]
+    fn incomplete_message_should_be_detected_as_torn_write() {
+        let log = segment(3);
+        let index = index_for(&log);
+        let full_size = log.len();
+        let mut torn_log = log.clone();
+        torn_log.extend_from_slice(&message(START_OFFSET + 3, b"torn")[..20]);
+
+        let scan = SegmentScan::new(START_OFFSET, &torn_log, Some(&index));
+
+        assert_eq!(
+            scan.issues,
+            vec![SegmentIssue::TornWrite {
+                position: full_size as u32,
+                size: 20
+            }]
+        );
+        assert_eq!(scan.valid_messages().len(), 3);
+    }
+
+    #[test]
+    fn trailing_message_with_invalid_checksum_should_be_detected_as_torn_write() {
+        let mut log = segment(3);
+        let last = log.len() - 1;
+        log[last] ^= 0xFF;
+
+        let scan = SegmentScan::new(START_OFFSET, &log, None);
+
+        assert!(scan.has_torn_write());
+        assert!(scan.has_index_issues());
+        assert_eq!(scan.messages.len(), 3);
+        assert_eq!(scan.valid_messages().len(), 2);
+        assert_eq!(scan.build_index(false).len(), 2 * INDEX_SIZE);
+    }
+
+    fn corrupt_payload_length(log: &mut [u8], position: usize, payload_length: u32) {
+        let range = IGGY_MESSAGE_PAYLOAD_LENGTH_OFFSET_RANGE;
+        log[position + range.start..position + range.end]
+            .copy_from_slice(&payload_length.to_le_bytes());
+    }
+
+    fn write_segment(log: &[u8], index: &[u8]) -> SegmentFiles {
+        let directory = std::env::temp_dir().join(format!("iggy_segment_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let segment = SegmentFiles::from_path(&directory.join(START_OFFSET.to_string())).unwrap();
+        std::fs::write(&segment.log_path, log).unwrap();
+        std::fs::write(&segment.index_path, index).unwrap();
+        segment
+    }
+
+    #[test]
+    fn corrupted_header_followed_by_valid_messages_should_be_detected_as_corruption() {
+        let mut log = segment(4);
+        let index = index_for(&log);
+        let scan = SegmentScan::new(START_OFFSET, &log, None);
+        let (position, size) = (scan.messages[1].position, scan.messages[1].size);
+        corrupt_payload_length(&mut log, position as usize, u32::MAX);
+
+        let scan = SegmentScan::new(START_OFFSET, &log, Some(&index));
+
+        assert_eq!(scan.issues[0], SegmentIssue::Corruption { position, size });
+        assert!(scan.has_index_issues());
+        assert!(!scan.has_torn_write());
+        assert!(!scan.can_repair(false));
+        assert_eq!(scan.valid_messages().len(), 3);
+        assert_eq!(scan.valid_messages()[1].header.offset, START_OFFSET + 2);
+        assert_eq!(scan.repaired_size(true), position);
+        assert_eq!(scan.repaired_messages(true).len(), 1);
+    }
+
+    #[test]
+    fn message_size_covering_next_message_should_be_detected_as_corruption() {
+        let mut log = segment(3);
+        let scan = SegmentScan::new(START_OFFSET, &log, None);
+        let (position, size) = (scan.messages[0].position, scan.messages[0].size);
+        let payload_length = scan.messages[0].header.payload_length;
+        corrupt_payload_length(&mut log, position as usize, payload_length + 8);
+
+        let scan = SegmentScan::new(START_OFFSET, &log, None);
+
+        assert_eq!(scan.corruption_position(), Some(position));
+        assert_eq!(scan.valid_messages().len(), 2);
+        assert_eq!(scan.valid_messages()[0].position, position + size);
+    }
+
+    #[test]
+    fn repair_should_keep_valid_messages_following_corruption_unless_forced() {
+        let mut log = segment(4);
+        let index = index_for(&log);
+        let position = SegmentScan::new(START_OFFSET, &log, None).messages[1].position;
+        corrupt_payload_length(&mut log, position as usize, u32::MAX);
+        let segment = write_segment(&log, &index);
+
+        repair(&segment, true, false, false).unwrap();
+
+        assert_eq!(segment.read_log().unwrap(), log);
+        assert_eq!(segment.read_index().unwrap(), Some(index));
+        assert_eq!(segment.scan().unwrap().valid_messages().len(), 3);
+
+        repair(&segment, false, true, false).unwrap();
+        let scan = segment.scan().unwrap();
+        std::fs::remove_dir_all(segment.log_path.parent().unwrap()).unwrap();
+
+        assert!(scan.issues.is_empty());
+        assert_eq!(scan.log_size, position);
+        assert_eq!(scan.valid_messages().len(), 1);
+    }
+
+    #[test]
+    fn partially_written_index_entry_should_be_detected() {
+        let log = segment(3);
+        let index = index_for(&segment(4));
+
+        let scan = SegmentScan::new(START_OFFSET, &log, Some(&index[..index.len() - 4]));
+
+        assert_eq!(
+            scan.issues,
+            vec![SegmentIssue::UnalignedIndex {
+                size: 4 * INDEX_SIZE - 4
+            },]
+        );
+    }
+}
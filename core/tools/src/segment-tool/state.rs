@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use anyhow::{Context, Result};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::prelude::{BytesSerializable, EncryptorKind, IggyTimestamp};
+use server::state::command::EntryCommand;
+use server::state::entry::StateEntry;
+use std::path::Path;
+
+/// Size of the fixed part of the entry: index, term, leader ID, version, flags, timestamp,
+/// user ID, checksum and context length.
+const ENTRY_HEADER_SIZE: usize = 8 + 8 + 4 + 4 + 8 + 8 + 4 + 4 + 4;
+
+/// Entry read from the state log together with its decoded command.
+pub struct StateLogEntry {
+    pub entry: StateEntry,
+    pub command: Result<EntryCommand>,
+    pub stored_checksum: u32,
+}
+
+/// Content of the state log, read up to the first entry which could not be parsed.
+pub struct StateLog {
+    pub entries: Vec<StateLogEntry>,
+    pub file_size: usize,
+    pub read_size: usize,
+    pub error: Option<String>,
+}
+
+/// Reads the state log in the same format as `FileState`, but instead of failing on the first
+/// invalid entry, it returns all entries read so far and the reason why reading stopped.
+pub fn read(path: &Path, encryptor: Option<&EncryptorKind>) -> Result<StateLog> {
+    let mut bytes = Bytes::from(
+        std::fs::read(path)
+            .with_context(|| format!("Failed to read state log: {}", path.display()))?,
+    );
+    let file_size = bytes.len();
+    let mut entries = Vec::new();
+    let mut read_size = 0;
+    let mut error = None;
+    while !bytes.is_empty() {
+        match read_entry(&mut bytes, encryptor) {
+            Ok(entry) => {
+                if let Some(previous) = entries.last().map(|e: &StateLogEntry| e.entry.index)
+                    && entry.entry.index != previous + 1
+                {
+                    error = Some(format!(
+                        "expected entry with index: {}, got: {}",
+                        previous + 1,
+                        entry.entry.index
+                    ));
+                    break;
+                }
+                entries.push(entry);
+                read_size = file_size - bytes.len();
+            }
+            Err(e) => {
+                error = Some(e.to_string());
+                break;
+            }
+        }
+    }
+
+    Ok(StateLog {
+        entries,
+        file_size,
+        read_size,
+        error,
+    })
+}
+
+fn read_entry(bytes: &mut Bytes, encryptor: Option<&EncryptorKind>) -> Result<StateLogEntry> {
+    if bytes.len() < ENTRY_HEADER_SIZE {
+        anyhow::bail!("incomplete entry header of {} bytes", bytes.len());
+    }
+
+    let mut header = bytes.slice(..ENTRY_HEADER_SIZE);
+    let index = header.get_u64_le();
+    let term = header.get_u64_le();
+    let leader_id = header.get_u32_le();
+    let version = header.get_u32_le();
+    let flags = header.get_u64_le();
+    let timestamp = IggyTimestamp::from(header.get_u64_le());
+    let user_id = header.get_u32_le();
+    let stored_checksum = header.get_u32_le();
+    let context_length = header.get_u32_le() as usize;
+    if bytes.len() < ENTRY_HEADER_SIZE + context_length + 8 {
+        anyhow::bail!("incomplete context of entry with index: {index}");
+    }
+
+    let context = bytes.slice(ENTRY_HEADER_SIZE..ENTRY_HEADER_SIZE + context_length);
+    let mut command_header = bytes.slice(ENTRY_HEADER_SIZE + context_length..);
+    let code = command_header.get_u32_le();
+    let command_length = command_header.get_u32_le() as usize;
+    let command_start = ENTRY_HEADER_SIZE + context_length + 8;
+    if bytes.len() < command_start + command_length {
+        anyhow::bail!("incomplete command of entry with index: {index}");
+    }
+
+    let payload = bytes.slice(command_start..command_start + command_length);
+    bytes.advance(command_start + command_length);
+    let payload = match encryptor {
+        Some(encryptor) => Bytes::from(
+            encryptor
+                .decrypt(&payload)
+                .with_context(|| format!("failed to decrypt entry with index: {index}"))?,
+        ),
+        None => payload,
+    };
+
+    let mut command = BytesMut::with_capacity(8 + payload.len());
+    command.put_u32_le(code);
+    command.put_u32_le(payload.len() as u32);
+    command.extend(payload);
+    let command = command.freeze();
+    let checksum = StateEntry::calculate_checksum(
+        index, term, leader_id, version, flags, timestamp, user_id, &context, &command,
+    );
+    let decoded_command = EntryCommand::from_bytes(command.clone())
+        .with_context(|| format!("failed to parse command with code: {code}"));
+
+    Ok(StateLogEntry {
+        entry: StateEntry::new(
+            index, term, leader_id, version, flags, timestamp, user_id, checksum, context, command,
+        ),
+        command: decoded_command,
+        stored_checksum,
+    })
+}